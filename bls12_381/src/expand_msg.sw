@@ -0,0 +1,140 @@
+library;
+
+use std::{alloc::alloc_bytes, assert::assert, vec::Vec, option::Option};
+
+// Implementation of expand_message_xmd with SHA-256, as specified in
+// https://www.rfc-editor.org/rfc/rfc9380.html#name-expand_message_xmd
+
+// Block size of SHA-256 in bytes (s_in_bytes in the RFC)
+const SHA256_BLOCK_SIZE: u64 = 64;
+// Output size of SHA-256 in bytes (b_in_bytes in the RFC)
+const SHA256_OUTPUT_SIZE: u64 = 32;
+
+// returns the SHA-256 hash of the bytes in `input`.
+// std::hash::sha256 can't be used here, because it hashes the memory layout of a value,
+// and a u8 in a Vec takes up a full word. So the bytes are first packed into a buffer.
+pub fn sha256_bytes(input: Vec<u8>) -> b256 {
+    let len = input.len();
+    let buf = alloc_bytes(len);
+    let mut i = 0;
+    while i < len {
+        let byte = input.get(i).unwrap();
+        asm(r1: buf.add_uint_offset(i), r2: byte) { // set register 1 (r1) to the address of byte i, r2 to the byte value
+            sb r1 r2 i0; // store the lowest byte of r2 at address r1
+        };
+        i += 1;
+    }
+
+    let result: b256 = 0x0000000000000000000000000000000000000000000000000000000000000000;
+    asm(r1: result, r2: buf, r3: len) { // r1 points to the result, r2 to the packed input, r3 holds the length
+        s256 r1 r2 r3; // r1 = sha256(input)
+        r1: b256
+    }
+}
+
+// appends the 32 bytes of `digest` to `bytes`, in big-endian order
+fn push_digest(ref mut bytes: Vec<u8>, digest: b256) {
+    let words = asm(r1: digest) { r1: (u64, u64, u64, u64) };
+    push_word(bytes, words.0);
+    push_word(bytes, words.1);
+    push_word(bytes, words.2);
+    push_word(bytes, words.3);
+}
+
+// appends the 8 bytes of `word` to `bytes`, in big-endian order
+pub fn push_word(ref mut bytes: Vec<u8>, word: u64) {
+    let mut j = 8;
+    while j > 0 {
+        j -= 1;
+        bytes.push((word >> (8 * j)) & 0xff);
+    }
+}
+
+// appends all bytes of `other` to `bytes`
+fn append(ref mut bytes: Vec<u8>, other: Vec<u8>) {
+    let mut i = 0;
+    while i < other.len() {
+        bytes.push(other.get(i).unwrap());
+        i += 1;
+    }
+}
+
+// returns the byte at position `i` of `digest`, where position 0 is the most significant byte
+fn digest_byte(digest: b256, i: u64) -> u8 {
+    let words = asm(r1: digest) { r1: (u64, u64, u64, u64) };
+    let word = if i < 8 {
+        words.0
+    } else if i < 16 {
+        words.1
+    } else if i < 24 {
+        words.2
+    } else {
+        words.3
+    };
+    (word >> (8 * (7 - (i % 8)))) & 0xff
+}
+
+/// Expands `msg` to `len_in_bytes` uniformly random bytes, using the domain separation tag `dst`.
+/// This is expand_message_xmd from RFC 9380, with SHA-256 as the hash function.
+/// `dst` has to be at most 255 bytes, and `len_in_bytes` at most 255 * 32 bytes.
+pub fn expand_message_xmd(msg: Vec<u8>, dst: Vec<u8>, len_in_bytes: u64) -> Vec<u8> {
+    let ell = (len_in_bytes + SHA256_OUTPUT_SIZE - 1) / SHA256_OUTPUT_SIZE;
+    assert(ell <= 255);
+    assert(dst.len() <= 255);
+
+    // DST_prime = DST || I2OSP(len(DST), 1)
+    let mut dst_prime = Vec::new();
+    append(dst_prime, dst);
+    dst_prime.push(dst.len());
+
+    // msg_prime = Z_pad || msg || l_i_b_str || I2OSP(0, 1) || DST_prime
+    let mut msg_prime = Vec::new();
+    let mut i = 0;
+    while i < SHA256_BLOCK_SIZE {
+        msg_prime.push(0u8);
+        i += 1;
+    }
+    append(msg_prime, msg);
+    msg_prime.push((len_in_bytes >> 8) & 0xff);
+    msg_prime.push(len_in_bytes & 0xff);
+    msg_prime.push(0u8);
+    append(msg_prime, dst_prime);
+
+    // b_0 = H(msg_prime)
+    let b_0 = sha256_bytes(msg_prime);
+
+    // b_1 = H(b_0 || I2OSP(1, 1) || DST_prime)
+    let mut input = Vec::new();
+    push_digest(input, b_0);
+    input.push(1u8);
+    append(input, dst_prime);
+    let mut b_i = sha256_bytes(input);
+
+    let mut uniform_bytes = Vec::new();
+    push_digest(uniform_bytes, b_i);
+
+    // b_i = H(strxor(b_0, b_(i - 1)) || I2OSP(i, 1) || DST_prime)
+    let mut i = 2;
+    while i <= ell {
+        let mut input = Vec::new();
+        let mut j = 0;
+        while j < SHA256_OUTPUT_SIZE {
+            input.push(digest_byte(b_0, j) ^ digest_byte(b_i, j));
+            j += 1;
+        }
+        input.push(i);
+        append(input, dst_prime);
+        b_i = sha256_bytes(input);
+        push_digest(uniform_bytes, b_i);
+        i += 1;
+    }
+
+    // uniform_bytes = substr(b_1 || ... || b_ell, 0, len_in_bytes)
+    let mut res = Vec::new();
+    let mut i = 0;
+    while i < len_in_bytes {
+        res.push(uniform_bytes.get(i).unwrap());
+        i += 1;
+    }
+    res
+}
@@ -1,12 +1,15 @@
-library fp;
-
-dep util;
+library;
 
 //This wildcard import is needed because of importing ConstantTimeEq for u64 (since it's a trait for a primitive type)
 use utils::{choice::*, integer_utils::adc}; 
-use util::*;
+use ::util::*;
 use std::{option::Option, u128::U128};
 use core::ops::{Eq, Add, Subtract, Multiply};
+use utils::{
+    ff::Field,
+    bigint::U384,
+    monty::{MontyField384, MontyParams384, montgomery_reduce_384 as monty_reduce},
+};
 
 // Little endian big integer with 6 limbs
 // in Montgomery form
@@ -36,7 +39,7 @@ const R: Fp = Fp{ls: [
 ]};
 
 /// R2 = 2^(384*2) mod p
-const R2: Fp = Fp{ls: [
+pub const R2: Fp = Fp{ls: [
     0xf4df_1f34_1c34_1746,
     0x0a76_e6a6_09d1_04f1,
     0x8de5_476c_4c95_b6d5,
@@ -55,34 +58,71 @@ const R3: Fp = Fp{ls: [
     0x0aa6_3460_9175_5d4d,
 ]};
 
+// Montgomery arithmetic modulo p
+pub struct FpParams {}
+
+impl MontyParams384 for FpParams {
+    fn modulus() -> U384 {
+        U384 { ls: MODULUS }
+    }
+
+    fn r() -> U384 {
+        U384 { ls: R.ls }
+    }
+
+    fn r2() -> U384 {
+        U384 { ls: R2.ls }
+    }
+
+    fn r3() -> U384 {
+        U384 { ls: R3.ls }
+    }
+
+    fn inv() -> u64 {
+        INV
+    }
+}
+
+impl Fp {
+    fn monty(self) -> MontyField384<FpParams> {
+        MontyField384::from_montgomery(U384 { ls: self.ls }, FpParams {})
+    }
+
+    fn from_monty(m: MontyField384<FpParams>) -> Self {
+        Fp { ls: m.montgomery_form.ls }
+    }
+}
+
 impl ConditionallySelectable for Fp {
     // Select a if choice == 1 or select b if choice == 0, in constant time.
     fn conditional_select(a: Fp, b: Fp, choice: Choice) -> Fp {
         Fp{ ls: [
-            ~u64::conditional_select(a.ls[0], b.ls[0], choice),
-            ~u64::conditional_select(a.ls[1], b.ls[1], choice),
-            ~u64::conditional_select(a.ls[2], b.ls[2], choice),
-            ~u64::conditional_select(a.ls[3], b.ls[3], choice),
-            ~u64::conditional_select(a.ls[4], b.ls[4], choice),
-            ~u64::conditional_select(a.ls[5], b.ls[5], choice),
+            u64::conditional_select(a.ls[0], b.ls[0], choice),
+            u64::conditional_select(a.ls[1], b.ls[1], choice),
+            u64::conditional_select(a.ls[2], b.ls[2], choice),
+            u64::conditional_select(a.ls[3], b.ls[3], choice),
+            u64::conditional_select(a.ls[4], b.ls[4], choice),
+            u64::conditional_select(a.ls[5], b.ls[5], choice),
         ]}
     }
 }
 
-// returns the binary not for u64
-fn not(input: u64) -> u64 {
-    ~u64::max() - input
+impl ConditionallyNegatable for Fp {
+    // Negate self if choice == 1, leave it unchanged if choice == 0
+    fn conditional_negate(ref mut self, choice: Choice) {
+        self = Fp::conditional_select(self.neg(), self, choice);
+    }
 }
 
 impl ConstantTimeEq for Fp {
     // returns (self == other), as a choice
     fn ct_eq(self, other: Fp) -> Choice {
-        ~u64::ct_eq(self.ls[0], other.ls[0])
-        & ~u64::ct_eq(self.ls[1], other.ls[1])
-        & ~u64::ct_eq(self.ls[2], other.ls[2])
-        & ~u64::ct_eq(self.ls[3], other.ls[3])
-        & ~u64::ct_eq(self.ls[4], other.ls[4])
-        & ~u64::ct_eq(self.ls[5], other.ls[5])
+        u64::ct_eq(self.ls[0], other.ls[0])
+        & u64::ct_eq(self.ls[1], other.ls[1])
+        & u64::ct_eq(self.ls[2], other.ls[2])
+        & u64::ct_eq(self.ls[3], other.ls[3])
+        & u64::ct_eq(self.ls[4], other.ls[4])
+        & u64::ct_eq(self.ls[5], other.ls[5])
     }
 }
 
@@ -97,48 +137,12 @@ impl Fp {
 
     // returns -a mod p
     pub fn neg(self) -> Fp {
-        let (d0, borrow) = sbb(MODULUS[0], self.ls[0], 0);
-        let (d1, borrow) = sbb(MODULUS[1], self.ls[1], borrow);
-        let (d2, borrow) = sbb(MODULUS[2], self.ls[2], borrow);
-        let (d3, borrow) = sbb(MODULUS[3], self.ls[3], borrow);
-        let (d4, borrow) = sbb(MODULUS[4], self.ls[4], borrow);
-        let (d5, _) = sbb(MODULUS[5], self.ls[5], borrow);
-
-        // The mask should be 0 when a==p, otherwise 2^65-1 (= 11..11) 
-        // limbs = 0 when self = 0
-        let limbs = (self.ls[0] | self.ls[1] | self.ls[2] | self.ls[3] | self.ls[4] | self.ls[5]);
-        // p mod p = 0, so this checks whether self is p
-        // a_is_p = 0 when self = 0, otherwise a_is_p = 1
-        let a_is_p: u64 = is_zero_u64(limbs);
-        // mask = a_is_p - 1. This will give either 1-1 (=0) or 0-1 (wrap around to 2^64-1)
-        let mask = subtract_1_wrap(a_is_p);
-
-        Fp {
-            ls: [d0 & mask, d1 & mask, d2 & mask, d3 & mask, d4 & mask, d5 & mask]
-        }
+        Fp::from_monty(self.monty().neg())
     }
 
     // If a >= p, return a-p, else return a
     pub fn subtract_p(self) -> Fp {
-        let (r0, borrow) = sbb(self.ls[0], MODULUS[0], 0);
-        let (r1, borrow) = sbb(self.ls[1], MODULUS[1], borrow);
-        let (r2, borrow) = sbb(self.ls[2], MODULUS[2], borrow);
-        let (r3, borrow) = sbb(self.ls[3], MODULUS[3], borrow);
-        let (r4, borrow) = sbb(self.ls[4], MODULUS[4], borrow);
-        let (r5, borrow) = sbb(self.ls[5], MODULUS[5], borrow);
-
-        // The final borrow is 11..11 if there was underflow. Otherwise 0. Therefore, it's used as a mask
-        let mut mask = borrow;
-        let r0 = (self.ls[0] & mask) | (r0 & not(mask));
-        let r1 = (self.ls[1] & mask) | (r1 & not(mask));
-        let r2 = (self.ls[2] & mask) | (r2 & not(mask));
-        let r3 = (self.ls[3] & mask) | (r3 & not(mask));
-        let r4 = (self.ls[4] & mask) | (r4 & not(mask));
-        let r5 = (self.ls[5] & mask) | (r5 & not(mask));
-
-        Fp {
-            ls: [r0, r1, r2, r3, r4, r5]
-        }
+        Fp { ls: U384 { ls: self.ls }.sub_mod(FpParams::modulus(), FpParams::modulus()).ls }
     }
 }
 
@@ -151,91 +155,17 @@ impl Fp {
     // Sway will not recognize them from inside the same impl
 
     pub fn is_zero(self) -> Choice {
-        self.ct_eq(~Fp::zero())
+        self.ct_eq(Fp::zero())
     }
 
-    /* 
-    returns self + rhs mod p. 
-
-    each limbs is added and the possible carry is carried over to next limb
-    if needed, 1 reduction by p is done to make it mod p
-    */ 
+    // returns self + rhs mod p
     fn add(self, rhs: Fp) -> Fp {
-        let (d0, carry) = adc(self.ls[0], rhs.ls[0], 0);
-        let (d1, carry) = adc(self.ls[1], rhs.ls[1], carry);
-        let (d2, carry) = adc(self.ls[2], rhs.ls[2], carry);
-        let (d3, carry) = adc(self.ls[3], rhs.ls[3], carry);
-        let (d4, carry) = adc(self.ls[4], rhs.ls[4], carry);
-        let (d5, _) = adc(self.ls[5], rhs.ls[5], carry);
-
-        // Subtract p if necessary, so the element is always mod p
-        (Fp{ ls: [d0, d1, d2, d3, d4, d5] }).subtract_p()
+        Fp::from_monty(self.monty().add(rhs.monty()))
     }
 
-    /*
-    returns self * rhs mod p
-
-    schoolbook mult, followed by montgomery reduction
-    */
+    // returns self * rhs mod p
     pub fn mul(self, rhs: Fp) -> Fp {
-        let self0 = self.ls[0];
-        let self1 = self.ls[1];
-        let self2 = self.ls[2];
-        let self3 = self.ls[3];
-        let self4 = self.ls[4];
-        let self5 = self.ls[5];
-
-        let rhs0 = rhs.ls[0];
-        let rhs1 = rhs.ls[1];
-        let rhs2 = rhs.ls[2];
-        let rhs3 = rhs.ls[3];
-        let rhs4 = rhs.ls[4];
-        let rhs5 = rhs.ls[5];
-
-        let (t0, carry) = mac(0, self0, rhs0, 0);
-        let (t1, carry) = mac(0, self0, rhs1, carry);
-        let (t2, carry) = mac(0, self0, rhs2, carry);
-        let (t3, carry) = mac(0, self0, rhs3, carry);
-        let (t4, carry) = mac(0, self0, rhs4, carry);
-        let (t5, t6) = mac(0, self0, rhs5, carry);
-
-        let (t1, carry) = mac(t1, self1, rhs0, 0);
-        let (t2, carry) = mac(t2, self1, rhs1, carry);
-        let (t3, carry) = mac(t3, self1, rhs2, carry);
-        let (t4, carry) = mac(t4, self1, rhs3, carry);
-        let (t5, carry) = mac(t5, self1, rhs4, carry);
-        let (t6, t7) = mac(t6, self1, rhs5, carry);
-
-        let (t2, carry) = mac(t2, self2, rhs0, 0);
-        let (t3, carry) = mac(t3, self2, rhs1, carry);
-        let (t4, carry) = mac(t4, self2, rhs2, carry);
-        let (t5, carry) = mac(t5, self2, rhs3, carry);
-        let (t6, carry) = mac(t6, self2, rhs4, carry);
-        let (t7, t8) = mac(t7, self2, rhs5, carry);
-
-        let (t3, carry) = mac(t3, self3, rhs0, 0);
-        let (t4, carry) = mac(t4, self3, rhs1, carry);
-        let (t5, carry) = mac(t5, self3, rhs2, carry);
-        let (t6, carry) = mac(t6, self3, rhs3, carry);
-        let (t7, carry) = mac(t7, self3, rhs4, carry);
-        let (t8, t9) = mac(t8, self3, rhs5, carry);
-
-        let (t4, carry) = mac(t4, self4, rhs0, 0);
-        let (t5, carry) = mac(t5, self4, rhs1, carry);
-        let (t6, carry) = mac(t6, self4, rhs2, carry);
-        let (t7, carry) = mac(t7, self4, rhs3, carry);
-        let (t8, carry) = mac(t8, self4, rhs4, carry);
-        let (t9, t10) = mac(t9, self4, rhs5, carry);
-
-        let (t5, carry) = mac(t5, self5, rhs0, 0);
-        let (t6, carry) = mac(t6, self5, rhs1, carry);
-        let (t7, carry) = mac(t7, self5, rhs2, carry);
-        let (t8, carry) = mac(t8, self5, rhs3, carry);
-        let (t9, carry) = mac(t9, self5, rhs4, carry);
-        let (t10, t11) = mac(t10, self5, rhs5, carry);
-
-        let res: [u64;12] = [t0, t1, t2, t3, t4, t5, t6, t7, t8, t9, t10, t11];
-        montgomery_reduce(res)
+        Fp::from_monty(self.monty().mul(rhs.monty()))
     }
 
     // returns self^2 mod p 
@@ -296,23 +226,17 @@ impl Fp {
 
     // returns self - rhs mod p
     fn sub(self, rhs: Fp) -> Fp {
-        (rhs.neg()).add(self)
+        Fp::from_monty(self.monty().sub(rhs.monty()))
     }
 
-// TODO implement when performing *many* squares is possible
+    /// Exponentiates `self` by `by`, where `by` is a
+    /// little-endian order integer exponent.
+    ///
+    /// **This operation is variable time with respect
+    /// to the exponent.** If the exponent is fixed,
+    /// this operation is effectively constant time.
     pub fn pow_vartime(self, by: [u64; 6]) -> Self {
-        // let mut res = Self::one();
-        // for e in by.iter().rev() {
-        //     for i in (0..64).rev() {
-        //         res = res.square();
-
-        //         if ((*e >> i) & 1) == 1 {
-        //             res *= self;
-        //         }
-        //     }
-        // }
-        // res
-        ~Fp::zero()
+        Fp::from_monty(self.monty().pow_vartime(U384 { ls: by }))
     }
 
     /*
@@ -475,7 +399,7 @@ impl Fp {
         // If there was underflow, borrow is 11..11. Otherwise, it is 0. 
         let borrow_u8: u8 = borrow;
         // Return "true" if there was no underflow. Otherwise return "false"
-        ~Choice::from(borrow & 1).not()
+        Choice::from(borrow & 1).not()
     }
 
 }
@@ -484,20 +408,84 @@ impl Fp {
     // This goes in a separate impl, because if we use previously defined functions in Fp impl, 
     // Sway will not recognize them from inside the same impl
 
-//TODO pow_vartime has to be implemented for this to work
     // returns Some(self^-1 mod p) or None if self == 0
     pub fn invert(self) -> CtOption<Fp> {
-        // Exponentiate by p - 2
-        let t = self.pow_vartime([
-            0xb9fe_ffff_ffff_aaa9,
-            0x1eab_fffe_b153_ffff,
-            0x6730_d2a0_f6b0_f624,
-            0x6477_4b84_f385_12bf,
-            0x4b1b_a7b6_434b_acd7,
-            0x1a01_11ea_397f_e69a,
+        // Exponentiates by p - 2
+        let t = self.monty().invert();
+        CtOption::new(Fp::from_monty(t.value), t.is_some)
+    }
+
+    // returns Some(sqrt(self)) if it exists, otherwise None
+    pub fn sqrt(self) -> CtOption<Fp> {
+        // Comment from zkcrypto
+        // We use Shank's method, as p = 3 (mod 4). This means
+        // we only need to exponentiate by (p+1)/4. This only
+        // works for elements that are actually quadratic residue,
+        // so we check that we got the correct result at the end.
+        let sqrt = self.pow_vartime([
+            0xee7f_bfff_ffff_eaab,
+            0x07aa_ffff_ac54_ffff,
+            0xd9cc_34a8_3dac_3d89,
+            0xd91d_d2e1_3ce1_44af,
+            0x92c6_e9ed_90d2_eb35,
+            0x0680_447a_8e5f_f9a6,
         ]);
 
-        ~CtOption::new_from_bool(t, !self.is_zero().unwrap_as_bool())
+        CtOption::new(sqrt, sqrt.square().ct_eq(self))
+    }
+}
+
+// The result of the Legendre symbol (a/p)
+pub enum LegendreSymbol {
+    Zero: (),
+    QuadraticResidue: (),
+    QuadraticNonResidue: (),
+}
+
+impl Fp {
+    // This goes in a separate impl, because if we use previously defined functions in Fp impl,
+    // Sway will not recognize them from inside the same impl
+
+    // returns the Legendre symbol of self, using Euler's criterion: self^((p-1)/2)
+    // This is 0 if self is zero, 1 if self is a nonzero square, and -1 otherwise.
+    pub fn legendre(self) -> LegendreSymbol {
+        let s = self.pow_vartime([
+            0xdcff_7fff_ffff_d555,
+            0x0f55_ffff_58a9_ffff,
+            0xb398_6950_7b58_7b12,
+            0xb23b_a5c2_79c2_895f,
+            0x258d_d3db_21a5_d66b,
+            0x0d00_88f5_1cbf_f34d,
+        ]);
+
+        if s.is_zero().unwrap_as_bool() {
+            LegendreSymbol::Zero
+        } else if s.ct_eq(Fp::one()).unwrap_as_bool() {
+            LegendreSymbol::QuadraticResidue
+        } else {
+            LegendreSymbol::QuadraticNonResidue
+        }
+    }
+}
+
+impl Fp {
+    // This goes in a separate impl, because if we use previously defined functions in Fp impl,
+    // Sway will not recognize them from inside the same impl
+
+    /// Attempts to convert a big-endian byte representation of
+    /// a scalar into an `Fp`, failing if the input is not canonical.
+    pub fn from_bytes(bytes: [u8; 48]) -> CtOption<Fp> {
+        // The value is converted to Montgomery form by computing (a.R^0 * R^2) / R = a.R
+        let res = MontyField384::from_be_bytes(bytes, FpParams {});
+        CtOption::new(Fp::from_monty(res.value), res.is_some)
+    }
+
+    /// Converts an element of `Fp` into a byte representation in
+    /// big-endian byte order.
+    pub fn to_bytes(self) -> [u8; 48] {
+        // Turn into canonical form by computing
+        // (a.R) / R = a
+        self.monty().to_be_bytes()
     }
 }
 
@@ -526,67 +514,43 @@ impl Multiply for Fp {
     }
 }
 
-/*
-returns t mod p (as Fp)
+impl Field for Fp {
+    fn zero() -> Self {
+        Fp::zero()
+    }
+
+    fn one() -> Self {
+        Fp::one()
+    }
+
+    fn is_zero(self) -> Choice {
+        self.is_zero()
+    }
+
+    fn square(self) -> Self {
+        self.square()
+    }
+
+    fn double(self) -> Self {
+        self.add(self)
+    }
+
+    fn invert(self) -> CtOption<Self> {
+        self.invert()
+    }
+
+    fn sqrt(self) -> CtOption<Self> {
+        self.sqrt()
+    }
+}
 
-according to zkcrypto repo this is based on Algorithm 14.32 in Handbook of Applied Cryptography
-<http://cacr.uwaterloo.ca/hac/about/chap14.pdf>
-*/
+// returns t / R mod p (as Fp), where t = t[0] + t[1] * 2^64 + ... + t[11] * 2^704
+// The reduction itself is done by the generic Montgomery backend in utils.
 pub fn montgomery_reduce(t: [u64;12]) -> Fp {
-    let k = wrapping_mul(t[0], INV);
-
-    let r0: (u64, u64) = mac(t[0], k, MODULUS[0], 0);
-    let r1: (u64, u64) = mac(t[1], k, MODULUS[1], r0.1);
-    let r2: (u64, u64) = mac(t[2], k, MODULUS[2], r1.1);
-    let r3: (u64, u64) = mac(t[3], k, MODULUS[3], r2.1);
-    let r4: (u64, u64) = mac(t[4], k, MODULUS[4], r3.1);
-    let r5: (u64, u64) = mac(t[5], k, MODULUS[5], r4.1);
-    let r6_7: (u64, u64) = adc(t[6], 0, r5.1);
-
-    let k = wrapping_mul(r1.0, INV);
-    let r0: (u64, u64) = mac(r1.0, k, MODULUS[0], 0);
-    let r2: (u64, u64) = mac(r2.0, k, MODULUS[1], r0.1);
-    let r3: (u64, u64) = mac(r3.0, k, MODULUS[2], r2.1);
-    let r4: (u64, u64) = mac(r4.0, k, MODULUS[3], r3.1);
-    let r5: (u64, u64) = mac(r5.0, k, MODULUS[4], r4.1);
-    let r6: (u64, u64) = mac(r6_7.0, k, MODULUS[5], r5.1);
-    let r7_8: (u64, u64) = adc(t[7], r6_7.1, r6.1);
-
-    let k = wrapping_mul(r2.0, INV);
-    let r0: (u64, u64) = mac(r2.0, k, MODULUS[0], 0);
-    let r3: (u64, u64) = mac(r3.0, k, MODULUS[1], r0.1);
-    let r4: (u64, u64) = mac(r4.0, k, MODULUS[2], r3.1);
-    let r5: (u64, u64) = mac(r5.0, k, MODULUS[3], r4.1);
-    let r6: (u64, u64) = mac(r6.0, k, MODULUS[4], r5.1);
-    let r7: (u64, u64) = mac(r7_8.0, k, MODULUS[5], r6.1);
-    let r8_9: (u64, u64) = adc(t[8], r7_8.1, r7.1);
-
-    let k = wrapping_mul(r3.0, INV);
-    let r0: (u64, u64) = mac(r3.0, k, MODULUS[0], 0);
-    let r4: (u64, u64) = mac(r4.0, k, MODULUS[1], r0.1);
-    let r5: (u64, u64) = mac(r5.0, k, MODULUS[2], r4.1);
-    let r6: (u64, u64) = mac(r6.0, k, MODULUS[3], r5.1);
-    let r7: (u64, u64) = mac(r7.0, k, MODULUS[4], r6.1);
-    let r8: (u64, u64) = mac(r8_9.0, k, MODULUS[5], r7.1);
-    let r9_10: (u64, u64) = adc(t[9], r8_9.1, r8.1);
-
-    let k = wrapping_mul(r4.0, INV);
-    let r0: (u64, u64) = mac(r4.0, k, MODULUS[0], 0);
-    let r5: (u64, u64) = mac(r5.0, k, MODULUS[1], r0.1);
-    let r6: (u64, u64) = mac(r6.0, k, MODULUS[2], r5.1);
-    let r7: (u64, u64) = mac(r7.0, k, MODULUS[3], r6.1);
-    let r8: (u64, u64) = mac(r8.0, k, MODULUS[4], r7.1);
-    let r9: (u64, u64) = mac(r9_10.0, k, MODULUS[5], r8.1);
-    let r10_11: (u64, u64) = adc(t[10], r9_10.1, r9.1);
-
-    let k = wrapping_mul(r5.0, INV);
-    let r0: (u64, u64) = mac(r5.0, k, MODULUS[0], 0);
-    let r6: (u64, u64) = mac(r6.0, k, MODULUS[1], r0.1);
-    let r7: (u64, u64) = mac(r7.0, k, MODULUS[2], r6.1);
-    let r8: (u64, u64) = mac(r8.0, k, MODULUS[3], r7.1);
-    let r9: (u64, u64) = mac(r9.0, k, MODULUS[4], r8.1);
-    let r10: (u64, u64) = mac(r10_11.0, k, MODULUS[5], r9.1);
-    let r11_12 = adc(t[11], r10_11.1, r10.1);
-
-    (Fp{ ls: [r6.0, r7.0, r8.0, r9.0, r10.0, r11_12.0] }).subtract_p()
+    Fp{ ls: monty_reduce(
+        U384 { ls: [t[0], t[1], t[2], t[3], t[4], t[5]] },
+        U384 { ls: [t[6], t[7], t[8], t[9], t[10], t[11]] },
+        FpParams::modulus(),
+        FpParams::inv(),
+    ).ls }
 }
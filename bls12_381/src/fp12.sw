@@ -0,0 +1,259 @@
+library;
+
+use ::fp::{Fp, from_raw_unchecked};
+use ::fp2::Fp2;
+use ::fp6::Fp6;
+use utils::{choice::{Choice, CtOption, ConstantTimeEq, ConditionallySelectable}, ff::Field};
+use core::ops::{Eq, Add, Subtract, Multiply};
+
+// Element in F_{p^12}
+pub struct Fp12 {
+    c0: Fp6,
+    c1: Fp6,
+}
+
+impl ConditionallySelectable for Fp12 {
+    // Select a if choice == 1 or select b if choice == 0, in constant time
+    fn conditional_select(a: Self, b: Self, choice: Choice) -> Self {
+        Fp12 {
+            c0: Fp6::conditional_select(a.c0, b.c0, choice),
+            c1: Fp6::conditional_select(a.c1, b.c1, choice),
+        }
+    }
+}
+
+impl ConstantTimeEq for Fp12 {
+    // returns (self == other), as a choice
+    fn ct_eq(self, other: Self) -> Choice {
+        self.c0.ct_eq(other.c0) & self.c1.ct_eq(other.c1)
+    }
+}
+
+impl Fp12 {
+    fn eq(self, other: Self) -> bool {
+        self.ct_eq(other).unwrap_as_bool()
+    }
+
+    pub fn zero() -> Self {
+        Fp12 {
+            c0: Fp6::zero(),
+            c1: Fp6::zero(),
+        }
+    }
+
+    pub fn one() -> Self {
+        Fp12 {
+            c0: Fp6::one(),
+            c1: Fp6::zero(),
+        }
+    }
+
+    fn from(f: Fp) -> Fp12 {
+        Fp12 {
+            c0: Fp6::from(f),
+            c1: Fp6::zero(),
+        }
+    }
+
+    fn from(f: Fp2) -> Fp12 {
+        Fp12 {
+            c0: Fp6::from(f),
+            c1: Fp6::zero(),
+        }
+    }
+
+    fn from(f: Fp6) -> Fp12 {
+        Fp12 {
+            c0: f,
+            c1: Fp6::zero(),
+        }
+    }
+
+    fn is_zero(self) -> Choice {
+        self.c0.is_zero().binary_and(self.c1.is_zero())
+    }
+
+    fn neg(self) -> Self {
+        Fp12 {
+            c0: self.c0.neg(),
+            c1: self.c1.neg(),
+        }
+    }
+
+    fn add(self, rhs: Fp12) -> Self {
+        Fp12 {
+            c0: self.c0 + rhs.c0,
+            c1: self.c1 + rhs.c1,
+        }
+    }
+
+    fn sub(self, rhs: Fp12) -> Self {
+        Fp12 {
+            c0: self.c0 - rhs.c0,
+            c1: self.c1 - rhs.c1,
+        }
+    }
+}
+
+impl Fp12 {
+    // This goes in a separate impl, because if we use previously defined functions in Fp12 impl,
+    // Sway will not recognize them from inside the same impl
+
+    fn mul_by_014(self, c0: Fp2, c1: Fp2, c4: Fp2) -> Fp12 {
+        let aa = self.c0.mul_by_01(c0, c1);
+        let bb = self.c1.mul_by_1(c4);
+        let o = c1 + c4;
+        let c1 = self.c1 + self.c0;
+        let c1 = c1.mul_by_01(c0, o);
+        let c1 = c1 - aa - bb;
+        let c0 = bb;
+        let c0 = c0.mul_by_nonresidue();
+        let c0 = c0 + aa;
+
+        Fp12 {
+            c0: c0,
+            c1: c1,
+        }
+    }
+
+    // returns (self.c0, -self.c1)
+    fn conjugate(self) -> Fp12 {
+        Fp12 {
+            c0: self.c0,
+            c1: self.c1.neg(),
+        }
+    }
+
+    // returns self^p, the Frobenius map
+    fn frobenius_map(self) -> Fp12 {
+        let c0 = self.c0.frobenius_map();
+        let c1 = self.c1.frobenius_map();
+
+        // c1 = c1 * (u + 1)^((p - 1) / 6)
+        let c1 = c1 * Fp6::from(Fp2 {
+            c0: from_raw_unchecked([
+                0x0708_9552_b319_d465,
+                0xc669_5f92_b50a_8313,
+                0x97e8_3ccc_d117_228f,
+                0xa35b_aeca_b2dc_29ee,
+                0x1ce3_93ea_5daa_ce4d,
+                0x08f2_220f_b0fb_66eb,
+            ]),
+            c1: from_raw_unchecked([
+                0xb2f6_6aad_4ce5_d646,
+                0x5842_a06b_fc49_7cec,
+                0xcf48_95d4_2599_d394,
+                0xc11b_9cba_40a8_e8d0,
+                0x2e38_13cb_e5a0_de89,
+                0x110e_efda_8884_7faf,
+            ]),
+        });
+
+        Fp12 {
+            c0: c0,
+            c1: c1,
+        }
+    }
+
+    fn mul(self, other: Fp12) -> Fp12 {
+        let aa = self.c0 * other.c0;
+        let bb = self.c1 * other.c1;
+        let o = other.c0 + other.c1;
+        let c1 = self.c1 + self.c0;
+        let c1 = c1 * o;
+        let c1 = c1 - aa;
+        let c1 = c1 - bb;
+        let c0 = bb.mul_by_nonresidue();
+        let c0 = c0 + aa;
+
+        Fp12 {
+            c0: c0,
+            c1: c1,
+        }
+    }
+
+    fn square(self) -> Fp12 {
+        let ab = self.c0 * self.c1;
+        let c0c1 = self.c0 + self.c1;
+        let c0 = self.c1.mul_by_nonresidue();
+        let c0 = c0 + self.c0;
+        let c0 = c0 * c0c1;
+        let c0 = c0 - ab;
+        let c1 = ab + ab;
+        let c0 = c0 - ab.mul_by_nonresidue();
+
+        Fp12 {
+            c0: c0,
+            c1: c1,
+        }
+    }
+
+    // returns Some(self^-1) or None if self == 0
+    fn invert(self) -> CtOption<Fp12> {
+        let t = (self.c0.square() - self.c1.square().mul_by_nonresidue()).invert();
+
+        CtOption::new(
+            Fp12 {
+                c0: self.c0 * t.value,
+                c1: self.c1 * t.value.neg(),
+            },
+            t.is_some,
+        )
+    }
+}
+
+impl Eq for Fp12 {
+    fn eq(self, other: Self) -> bool {
+        self.eq(other)
+    }
+}
+
+impl Add for Fp12 {
+    fn add(self, other: Fp12) -> Self {
+        self.add(other)
+    }
+}
+
+impl Subtract for Fp12 {
+    fn subtract(self, other: Fp12) -> Self {
+        self.sub(other)
+    }
+}
+
+impl Multiply for Fp12 {
+    fn multiply(self, other: Fp12) -> Self {
+        self.mul(other)
+    }
+}
+
+impl Field for Fp12 {
+    fn zero() -> Self {
+        Fp12::zero()
+    }
+
+    fn one() -> Self {
+        Fp12::one()
+    }
+
+    fn is_zero(self) -> Choice {
+        self.is_zero()
+    }
+
+    fn square(self) -> Self {
+        self.square()
+    }
+
+    fn double(self) -> Self {
+        self.add(self)
+    }
+
+    fn invert(self) -> CtOption<Self> {
+        self.invert()
+    }
+
+    // Square roots in Fp12 aren't supported, so this is always none.
+    // Nothing in the pairing needs them, and the tower has no cheap sqrt like Fp2's.
+    fn sqrt(self) -> CtOption<Self> {
+        CtOption::new(Fp12::zero(), Choice::from(0u8))
+    }
+}
@@ -1,10 +1,8 @@
-library fp2;
+library;
 
-dep fp;
-
-use fp::Fp;
+use ::fp::Fp;
 use core::ops::{Eq, Add, Subtract, Multiply};
-use utils::choice::*; 
+use utils::{choice::*, ff::Field};
 
 // Element in the quadratic extension field F_{p^2}
 pub struct Fp2 {
@@ -23,32 +21,39 @@ impl ConditionallySelectable for Fp2 {
     // Select a if choice == 1 or select b if choice == 0, in constant time
     fn conditional_select(a: Self, b: Self, choice: Choice) -> Self {
         Fp2 {
-            c0: ~Fp::conditional_select(a.c0, b.c0, choice),
-            c1: ~Fp::conditional_select(a.c1, b.c1, choice),
+            c0: Fp::conditional_select(a.c0, b.c0, choice),
+            c1: Fp::conditional_select(a.c1, b.c1, choice),
         }
     }
 }
 
+impl ConditionallyNegatable for Fp2 {
+    // Negate self if choice == 1, leave it unchanged if choice == 0
+    fn conditional_negate(ref mut self, choice: Choice) {
+        self = Fp2::conditional_select(self.neg(), self, choice);
+    }
+}
+
 impl Fp2 {
     // in the zkcrypto repo this is implemented as trait From<Fp>, but this isn't possible in Sway
     fn from(f: Fp) -> Fp2 {
         Fp2 {
             c0: f,
-            c1: ~Fp::zero(),
+            c1: Fp::zero(),
         }
     }
 
     fn zero() -> Fp2 {
         Fp2 {
-            c0: ~Fp::zero(),
-            c1: ~Fp::zero(),
+            c0: Fp::zero(),
+            c1: Fp::zero(),
         }
     }
 
     fn one() -> Fp2 {
         Fp2 {
-            c0: ~Fp::one(),
-            c1: ~Fp::zero(),
+            c0: Fp::one(),
+            c1: Fp::zero(),
         }
     }
 
@@ -60,8 +65,6 @@ impl Fp2 {
         self.ct_eq(other).unwrap_as_bool()
     }
 
-/*
-    // not tested, gives Immediate18TooLarge error
     fn square(self) -> Fp2 {
         // Complex squaring:
         //
@@ -84,7 +87,6 @@ impl Fp2 {
             c1: c * self.c1,
         }
     }
-  */
 
     fn mul(self, rhs: Fp2) -> Fp2 {
         // Explanation from zkcrypto repo:
@@ -100,8 +102,8 @@ impl Fp2 {
         //
         // Each of these is a "sum of products", which we can compute efficiently.
         Fp2 {
-            c0: ~Fp::sum_of_products_2([self.c0, self.c1.neg()], [rhs.c0, rhs.c1]),
-            c1: ~Fp::sum_of_products_2([self.c0, self.c1], [rhs.c1, rhs.c0]),
+            c0: Fp::sum_of_products_2([self.c0, self.c1.neg()], [rhs.c0, rhs.c1]),
+            c1: Fp::sum_of_products_2([self.c0, self.c1], [rhs.c1, rhs.c0]),
         }
     }
 
@@ -166,6 +168,109 @@ impl Fp2 {
         // Example explanation here: https://alicebob.modp.net/the-frobenius-endomorphism-with-finite-fields/
         self.conjugate()
     }
+
+    /// Although this is labeled "vartime", it is only
+    /// variable time with respect to the exponent.
+    // `by` is a little-endian order integer exponent
+    fn pow_vartime(self, by: [u64; 6]) -> Fp2 {
+        let mut res = Fp2::one();
+        let mut i = 6;
+        while i > 0 {
+            i -= 1;
+            let mut j = 64;
+            while j > 0 {
+                j -= 1;
+                res = res.square();
+
+                if ((by[i] >> j) & 1) == 1 {
+                    res = res.mul(self);
+                }
+            }
+        }
+        res
+    }
+
+    // returns Some(self^-1) or None if self == 0
+    fn invert(self) -> CtOption<Fp2> {
+        // Comment from zkcrypto
+        // We wish to find the multiplicative inverse of a nonzero
+        // element a + bu in Fp2. We leverage an identity
+        //
+        // (a + bu)(a - bu) = a^2 + b^2
+        //
+        // which holds because u^2 = -1. This can be rewritten as
+        //
+        // (a + bu)(a - bu)/(a^2 + b^2) = 1
+        //
+        // because a^2 + b^2 = 0 has no nonzero solutions for (a, b).
+        // This gives that (a - bu)/(a^2 + b^2) is the inverse
+        // of (a + bu). Importantly, this can be computing using
+        // only a single inversion in Fp.
+        let t = (self.c0.square() + self.c1.square()).invert();
+
+        CtOption::new(
+            Fp2 {
+                c0: self.c0 * t.value,
+                c1: self.c1 * t.value.neg(),
+            },
+            t.is_some,
+        )
+    }
+}
+
+impl Fp2 {
+    // This goes in a separate impl, because if we use previously defined functions in Fp2 impl,
+    // Sway will not recognize them from inside the same impl
+
+    // returns Some(sqrt(self)) if it exists, otherwise None
+    fn sqrt(self) -> CtOption<Fp2> {
+        // Algorithm 9, https://eprint.iacr.org/2012/685.pdf
+        // with constant time modifications.
+
+        // a1 = self^((p - 3) / 4)
+        let a1 = self.pow_vartime([
+            0xee7f_bfff_ffff_eaaa,
+            0x07aa_ffff_ac54_ffff,
+            0xd9cc_34a8_3dac_3d89,
+            0xd91d_d2e1_3ce1_44af,
+            0x92c6_e9ed_90d2_eb35,
+            0x0680_447a_8e5f_f9a6,
+        ]);
+
+        // alpha = a1^2 * self = self^((p - 3) / 2 + 1) = self^((p - 1) / 2)
+        let alpha = a1.square().mul(self);
+
+        // x0 = self^((p + 1) / 4)
+        let x0 = a1.mul(self);
+
+        // Comment from zkcrypto
+        // In the event that alpha = -1, the element is order p - 1 and so
+        // we're just trying to get the square of an element of the subfield
+        // Fp. This is given by x0 * u, since u = sqrt(-1). Since the element
+        // x0 = a + bu has b = 0, the solution is therefore au.
+        let sqrt_subfield = Fp2 {
+            c0: x0.c1.neg(),
+            c1: x0.c0,
+        };
+
+        // Otherwise, the correct solution is (1 + alpha)^((q - 1) // 2) * x0
+        let sqrt_other = (alpha.add(Fp2::one())).pow_vartime([
+            0xdcff_7fff_ffff_d555,
+            0x0f55_ffff_58a9_ffff,
+            0xb398_6950_7b58_7b12,
+            0xb23b_a5c2_79c2_895f,
+            0x258d_d3db_21a5_d66b,
+            0x0d00_88f5_1cbf_f34d,
+        ]).mul(x0);
+
+        let sqrt = Fp2::conditional_select(sqrt_subfield, sqrt_other, alpha.ct_eq(Fp2::one().neg()));
+        // The square root of zero is zero
+        let sqrt = Fp2::conditional_select(Fp2::zero(), sqrt, self.is_zero());
+
+        // Only return the result if it's really the square root (and so
+        // self is actually quadratic nonresidue)
+        CtOption::new(sqrt, sqrt.square().ct_eq(self))
+    }
 }
 
 impl Eq for Fp2 {
@@ -191,3 +296,33 @@ impl Multiply for Fp2 {
         self.mul(other)
     }
 }
+
+impl Field for Fp2 {
+    fn zero() -> Self {
+        Fp2::zero()
+    }
+
+    fn one() -> Self {
+        Fp2::one()
+    }
+
+    fn is_zero(self) -> Choice {
+        self.is_zero()
+    }
+
+    fn square(self) -> Self {
+        self.square()
+    }
+
+    fn double(self) -> Self {
+        self.add(self)
+    }
+
+    fn invert(self) -> CtOption<Self> {
+        self.invert()
+    }
+
+    fn sqrt(self) -> CtOption<Self> {
+        self.sqrt()
+    }
+}
@@ -1,11 +1,8 @@
-library fp6;
+library;
 
-dep fp2;
-dep fp;
-
-use fp::{Fp, from_raw_unchecked};
-use fp2::Fp2;
-use utils::choice::{Choice, CtOption, ConstantTimeEq, ConditionallySelectable};
+use ::fp::{Fp, from_raw_unchecked};
+use ::fp2::Fp2;
+use utils::{choice::{Choice, CtOption, ConstantTimeEq, ConditionallySelectable}, ff::Field};
 use core::ops::{Eq, Add, Subtract, Multiply};
 
 // Element in F_{p^6}
@@ -26,9 +23,9 @@ impl ConditionallySelectable for Fp6 {
     // Select a if choice == 1 or select b if choice == 0, in constant time
     fn conditional_select(a: Self, b: Self, choice: Choice) -> Self {
         Fp6 {
-            c0: ~Fp2::conditional_select(a.c0, b.c0, choice),
-            c1: ~Fp2::conditional_select(a.c1, b.c1, choice),
-            c2: ~Fp2::conditional_select(a.c2, b.c2, choice),
+            c0: Fp2::conditional_select(a.c0, b.c0, choice),
+            c1: Fp2::conditional_select(a.c1, b.c1, choice),
+            c2: Fp2::conditional_select(a.c2, b.c2, choice),
         }
     }
 }
@@ -40,33 +37,33 @@ impl Fp6 {
 
     fn from(f: Fp) -> Fp6 {//TODO is it possibly to have multiple functions with same name and different arguments?
         Fp6 {
-            c0: ~Fp2::from(f),
-            c1: ~Fp2::zero(),
-            c2: ~Fp2::zero(),
+            c0: Fp2::from(f),
+            c1: Fp2::zero(),
+            c2: Fp2::zero(),
         }
     }
 
     fn from(f: Fp2) -> Fp6 {
         Fp6 {
             c0: f,
-            c1: ~Fp2::zero(),
-            c2: ~Fp2::zero(),
+            c1: Fp2::zero(),
+            c2: Fp2::zero(),
         }
     }
 
     fn zero() -> Self {
         Fp6 {
-            c0: ~Fp2::zero(),
-            c1: ~Fp2::zero(),
-            c2: ~Fp2::zero(),
+            c0: Fp2::zero(),
+            c1: Fp2::zero(),
+            c2: Fp2::zero(),
         }
     }
 
     fn one() -> Self {
         Fp6 {
-            c0: ~Fp2::one(),
-            c1: ~Fp2::zero(),
-            c2: ~Fp2::zero(),
+            c0: Fp2::one(),
+            c1: Fp2::zero(),
+            c2: Fp2::zero(),
         }
     }
     
@@ -114,6 +111,175 @@ impl Fp6 {
     }
 }
 
+impl Fp6 {
+    // This goes in a separate impl, because if we use previously defined functions in Fp6 impl,
+    // Sway will not recognize them from inside the same impl
+
+    fn mul_by_1(self, c1: Fp2) -> Fp6 {
+        Fp6 {
+            c0: (self.c2 * c1).mul_by_nonresidue(),
+            c1: self.c0 * c1,
+            c2: self.c1 * c1,
+        }
+    }
+
+    fn mul_by_01(self, c0: Fp2, c1: Fp2) -> Fp6 {
+        let a_a = self.c0 * c0;
+        let b_b = self.c1 * c1;
+
+        let t1 = (self.c2 * c1).mul_by_nonresidue() + a_a;
+
+        let t2 = (c0 + c1) * (self.c0 + self.c1) - a_a - b_b;
+
+        let t3 = self.c2 * c0 + b_b;
+
+        Fp6 {
+            c0: t1,
+            c1: t2,
+            c2: t3,
+        }
+    }
+
+    // returns self^p, the Frobenius map
+    fn frobenius_map(self) -> Fp6 {
+        let c0 = self.c0.frobenius_map();
+        let c1 = self.c1.frobenius_map();
+        let c2 = self.c2.frobenius_map();
+
+        // c1 = c1 * (u + 1)^((p - 1) / 3)
+        let c1 = c1 * Fp2 {
+            c0: Fp::zero(),
+            c1: from_raw_unchecked([
+                0xcd03_c9e4_8671_f071,
+                0x5dab_2246_1fcd_a5d2,
+                0x5870_42af_d385_1b95,
+                0x8eb6_0ebe_01ba_cb9e,
+                0x03f9_7d6e_83d0_50d2,
+                0x18f0_2065_5463_8741,
+            ]),
+        };
+
+        // c2 = c2 * (u + 1)^((2p - 2) / 3)
+        let c2 = c2 * Fp2 {
+            c0: from_raw_unchecked([
+                0x890d_c9e4_8675_45c3,
+                0x2af3_2253_3285_a5d5,
+                0x5088_0866_309b_7e2c,
+                0xa20d_1b8c_7e88_1024,
+                0x14e4_f04f_e2db_9068,
+                0x14e5_6d3f_1564_853a,
+            ]),
+            c1: Fp::zero(),
+        };
+
+        Fp6 {
+            c0: c0,
+            c1: c1,
+            c2: c2,
+        }
+    }
+
+    // returns self * b
+    fn mul(self, b: Fp6) -> Fp6 {
+        // Explanation from zkcrypto
+        // Implements the full-tower interleaving strategy from ePrint 2022-376,
+        // expressing the multiplication over F_p instead of F_p^2:
+        //
+        //   c_0,0 = a_0,0 b_0,0 - a_0,1 b_0,1 + a_1,0 (b_2,0 - b_2,1) - a_1,1 (b_2,0 + b_2,1)
+        //                                     + a_2,0 (b_1,0 - b_1,1) - a_2,1 (b_1,0 + b_1,1).
+        //   c_0,1 = a_0,0 b_0,1 + a_0,1 b_0,0 + a_1,0(b_2,0 + b_2,1) + a_1,1(b_2,0 - b_2,1)
+        //                                     + a_2,0(b_1,0 + b_1,1) + a_2,1(b_1,0 - b_1,1).
+        //   c_1,0 = a_0,0 b_1,0 - a_0,1 b_1,1 + a_1,0 b_0,0 - a_1,1 b_0,1 + a_2,0(b_2,0 - b_2,1)
+        //                                                                 - a_2,1(b_2,0 + b_2,1).
+        //   c_1,1 = a_0,0 b_1,1 + a_0,1 b_1,0 + a_1,0 b_0,1 + a_1,1 b_0,0 + a_2,0(b_2,0 + b_2,1)
+        //                                                                 + a_2,1(b_2,0 - b_2,1).
+        //   c_2,0 = a_0,0 b_2,0 - a_0,1 b_2,1 + a_1,0 b_1,0 - a_1,1 b_1,1 + a_2,0 b_0,0 - a_2,1 b_0,1.
+        //   c_2,1 = a_0,0 b_2,1 + a_0,1 b_2,0 + a_1,0 b_1,1 + a_1,1 b_1,0 + a_2,0 b_0,1 + a_2,1 b_0,0.
+        //
+        // Each of these is a "sum of products", which we can compute efficiently.
+        let a = self;
+        let b10_p_b11 = b.c1.c0 + b.c1.c1;
+        let b10_m_b11 = b.c1.c0 - b.c1.c1;
+        let b20_p_b21 = b.c2.c0 + b.c2.c1;
+        let b20_m_b21 = b.c2.c0 - b.c2.c1;
+
+        Fp6 {
+            c0: Fp2 {
+                c0: Fp::sum_of_products_6(
+                    [a.c0.c0, a.c0.c1.neg(), a.c1.c0, a.c1.c1.neg(), a.c2.c0, a.c2.c1.neg()],
+                    [b.c0.c0, b.c0.c1, b20_m_b21, b20_p_b21, b10_m_b11, b10_p_b11],
+                ),
+                c1: Fp::sum_of_products_6(
+                    [a.c0.c0, a.c0.c1, a.c1.c0, a.c1.c1, a.c2.c0, a.c2.c1],
+                    [b.c0.c1, b.c0.c0, b20_p_b21, b20_m_b21, b10_p_b11, b10_m_b11],
+                ),
+            },
+            c1: Fp2 {
+                c0: Fp::sum_of_products_6(
+                    [a.c0.c0, a.c0.c1.neg(), a.c1.c0, a.c1.c1.neg(), a.c2.c0, a.c2.c1.neg()],
+                    [b.c1.c0, b.c1.c1, b.c0.c0, b.c0.c1, b20_m_b21, b20_p_b21],
+                ),
+                c1: Fp::sum_of_products_6(
+                    [a.c0.c0, a.c0.c1, a.c1.c0, a.c1.c1, a.c2.c0, a.c2.c1],
+                    [b.c1.c1, b.c1.c0, b.c0.c1, b.c0.c0, b20_p_b21, b20_m_b21],
+                ),
+            },
+            c2: Fp2 {
+                c0: Fp::sum_of_products_6(
+                    [a.c0.c0, a.c0.c1.neg(), a.c1.c0, a.c1.c1.neg(), a.c2.c0, a.c2.c1.neg()],
+                    [b.c2.c0, b.c2.c1, b.c1.c0, b.c1.c1, b.c0.c0, b.c0.c1],
+                ),
+                c1: Fp::sum_of_products_6(
+                    [a.c0.c0, a.c0.c1, a.c1.c0, a.c1.c1, a.c2.c0, a.c2.c1],
+                    [b.c2.c1, b.c2.c0, b.c1.c1, b.c1.c0, b.c0.c1, b.c0.c0],
+                ),
+            },
+        }
+    }
+
+    fn square(self) -> Fp6 {
+        let s0 = self.c0.square();
+        let ab = self.c0 * self.c1;
+        let s1 = ab + ab;
+        let s2 = (self.c0 - self.c1 + self.c2).square();
+        let bc = self.c1 * self.c2;
+        let s3 = bc + bc;
+        let s4 = self.c2.square();
+
+        Fp6 {
+            c0: s3.mul_by_nonresidue() + s0,
+            c1: s4.mul_by_nonresidue() + s1,
+            c2: s1 + s2 + s3 - s0 - s4,
+        }
+    }
+
+    // returns Some(self^-1) or None if self == 0
+    fn invert(self) -> CtOption<Fp6> {
+        let c0 = (self.c1 * self.c2).mul_by_nonresidue();
+        let c0 = self.c0.square() - c0;
+
+        let c1 = self.c2.square().mul_by_nonresidue();
+        let c1 = c1 - (self.c0 * self.c1);
+
+        let c2 = self.c1.square();
+        let c2 = c2 - (self.c0 * self.c2);
+
+        let tmp = ((self.c1 * c2) + (self.c2 * c1)).mul_by_nonresidue();
+        let tmp = tmp + (self.c0 * c0);
+
+        let t = tmp.invert();
+
+        CtOption::new(
+            Fp6 {
+                c0: t.value * c0,
+                c1: t.value * c1,
+                c2: t.value * c2,
+            },
+            t.is_some,
+        )
+    }
+}
+
 impl Eq for Fp6 {
     fn eq(self, other: Self) -> bool {
         self.eq(other)
@@ -131,3 +297,41 @@ impl Subtract for Fp6 {
         self.sub(other)
     }
 }
+
+impl Multiply for Fp6 {
+    fn multiply(self, other: Self) -> Self {
+        self.mul(other)
+    }
+}
+
+impl Field for Fp6 {
+    fn zero() -> Self {
+        Fp6::zero()
+    }
+
+    fn one() -> Self {
+        Fp6::one()
+    }
+
+    fn is_zero(self) -> Choice {
+        self.is_zero()
+    }
+
+    fn square(self) -> Self {
+        self.square()
+    }
+
+    fn double(self) -> Self {
+        self.add(self)
+    }
+
+    fn invert(self) -> CtOption<Self> {
+        self.invert()
+    }
+
+    // Square roots in Fp6 aren't supported, so this is always none.
+    // Nothing in the pairing needs them, and the tower has no cheap sqrt like Fp2's.
+    fn sqrt(self) -> CtOption<Self> {
+        CtOption::new(Fp6::zero(), Choice::from(0u8))
+    }
+}
@@ -1,10 +1,12 @@
-library g1;
+library;
 
-dep fp;
-
-use fp::{Fp, from_raw_unchecked};
-use utils::choice::{Choice, CtOption, ConditionallySelectable, ConstantTimeEq};
+use ::fp::{Fp, from_raw_unchecked};
+use ::util::BLS_X;
+use ::scalar::Scalar;
+use utils::choice::{Choice, CtOption, ConditionallySelectable, ConditionallyNegatable, ConstantTimeEq};
+use utils::group::{Group, CurveAffine, Neg};
 use core::ops::{Eq, Add, Subtract};
+use std::{assert::assert, vec::Vec};
 
 // Comment from zkcrypto
 /// This is an element of $\mathbb{G}_1$ represented in the affine coordinate space.
@@ -55,33 +57,24 @@ pub trait FROM_PROJ {
     fn from(p: G1Projective) -> Self;
 }
 
-fn unwrap_or(input: CtOption<Fp>, default: Fp) -> Fp {
-    match input.is_some()  {
-        true => input.unwrap(),
-        false => default,
-    }   
-}
-
-//TODO
-// - needs fp invert, which is not working yet
-// - will use already created fn `unwrap_or` since adding unwrap_or to trait CtOption can't work yet
-// impl FROM_PROJ for G1Affine {
-//     fn from(p: G1Projective) -> Self {
-// ..
-//     }
-// }
-
 impl ConditionallySelectable for G1Affine {
     // Select a if choice == 1 or select b if choice == 0, in constant time.
     fn conditional_select(a: Self, b: Self, choice: Choice) -> Self {
         G1Affine {
-            x: ~Fp::conditional_select(a.x, b.x, choice),
-            y: ~Fp::conditional_select(a.y, b.y, choice),
-            infinity: ~Choice::conditional_select(a.infinity, b.infinity, choice),
+            x: Fp::conditional_select(a.x, b.x, choice),
+            y: Fp::conditional_select(a.y, b.y, choice),
+            infinity: Choice::conditional_select(a.infinity, b.infinity, choice),
         }
     }
 }
 
+impl ConditionallyNegatable for G1Affine {
+    // Negate self if choice == 1, leave it unchanged if choice == 0
+    fn conditional_negate(ref mut self, choice: Choice) {
+        self = G1Affine::conditional_select(self.neg(), self, choice);
+    }
+}
+
 impl Eq for G1Affine {
     fn eq(self, other: Self) -> bool {
         self.ct_eq(other).unwrap_as_bool()
@@ -92,9 +85,9 @@ impl G1Affine {
     /// Returns the identity of the group: the point at infinity.
     fn identity() -> G1Affine {
         G1Affine {
-            x: ~Fp::zero(),
-            y: ~Fp::one(),
-            infinity: ~Choice::from(1u8),
+            x: Fp::zero(),
+            y: Fp::one(),
+            infinity: Choice::from(1u8),
         }
     }
 
@@ -103,24 +96,15 @@ impl G1Affine {
         self.infinity
     }
 
-    // TODO TEST WHEN POSSIBLE: Uses mul_by_x on G1Projective which uses double, which can't compile
-    // fn is_torsion_free(self) -> Choice {
-        // Comment from zkcrypto
-    //     // Algorithm from Section 6 of https://eprint.iacr.org/2021/1130
-    //     // Updated proof of correctness in https://eprint.iacr.org/2022/352
-    //     //
-    //     // Check that endomorphism_p(P) == -[x^2] P
-
-    //     let minus_x_squared_times_p = from(self).mul_by_x().mul_by_x().neg();
-    //     let endomorphism_p = endomorphism(self);
-    //     minus_x_squared_times_p.ct_eq(from(endomorphism_p))
-    // }
-
-    //Errors to Immediate18TooLarge
-    // fn is_on_curve(self) -> Choice {
-    //     // y^2 - x^3 ?= 4
-    //     (self.y.square() - (self.x.square() * self.x)).ct_eq(B) | self.infinity
-    // }
+    // Comment from zkcrypto
+    /// Returns true if this point is on the curve. This should always return
+    /// true unless an "unchecked" API was used.
+    fn is_on_curve(self) -> Choice {
+        // y^2 - x^3 ?= 4
+        let y_squared = self.y.square();
+        let x_cubed = self.x.square() * self.x;
+        (y_squared - x_cubed).ct_eq(B).binary_or(self.infinity)
+    }
 
     // returns a fixed generator of the group 
     // see notes of zkcrypto on how this was chosen [here at paragraph `Fixed generators`](https://github.com/zkcrypto/bls12_381/blob/main/src/notes/design.rs)
@@ -142,7 +126,7 @@ impl G1Affine {
                 0x0e1c_8c3f_ad00_59c0,
                 0x0bbc_3efc_5008_a26a,
             ]),
-            infinity: ~Choice::from(0u8),
+            infinity: Choice::from(0u8),
         }
     }
 
@@ -150,7 +134,7 @@ impl G1Affine {
     fn neg(self) -> G1Affine {//will be tested with subtraction (TODO)
         G1Affine {
             x: self.x,
-            y: ~Fp::conditional_select(self.y.neg(), ~Fp::one(), self.infinity),
+            y: Fp::conditional_select(Fp::one(), self.y.neg(), self.infinity),
             infinity: self.infinity,
         }
     }
@@ -191,9 +175,9 @@ impl G1Projective {
     /// Returns the identity of the group: the point at infinity.
     fn identity() -> G1Projective {
         G1Projective {
-            x: ~Fp::zero(),
-            y: ~Fp::one(),
-            z: ~Fp::zero(),
+            x: Fp::zero(),
+            y: Fp::one(),
+            z: Fp::zero(),
         }
     }
 
@@ -211,13 +195,15 @@ impl G1Projective {
         }
     }
 
-    //Errors to Immediate18TooLarge
-    // fn is_on_curve(self) -> Choice {
-    //     // Y^2 Z = X^3 + b Z^3
-
-    //     (self.y.square() * self.z).ct_eq(self.x.square() * self.x + self.z.square() * self.z * B)
-    //         | self.z.is_zero()
-    // }
+    // Comment from zkcrypto
+    /// Returns true if this point is on the curve. This should always return
+    /// true unless an "unchecked" API was used.
+    fn is_on_curve(self) -> Choice {
+        // Y^2 Z = X^3 + b Z^3
+        let lhs = self.y.square() * self.z;
+        let rhs = self.x.square() * self.x + self.z.square() * self.z * B;
+        lhs.ct_eq(rhs).binary_or(self.z.is_zero())
+    }
 
     // returns a fixed generator of the group 
     // see notes of zkcrypto on how this was chosen [here at paragraph `Fixed generators`](https://github.com/zkcrypto/bls12_381/blob/main/src/notes/design.rs)
@@ -239,7 +225,7 @@ impl G1Projective {
                 0x0e1c_8c3f_ad00_59c0,
                 0x0bbc_3efc_5008_a26a,
             ]),
-            z: ~Fp::one(),
+            z: Fp::one(),
         }
     }
 } 
@@ -248,13 +234,20 @@ impl ConditionallySelectable for G1Projective {
     // Select a if choice == 1 or select b if choice == 0, in constant time.
     fn conditional_select(a: Self, b: Self, choice: Choice) -> Self {
         G1Projective {
-            x: ~Fp::conditional_select(a.x, b.x, choice),
-            y: ~Fp::conditional_select(a.y, b.y, choice),
-            z: ~Fp::conditional_select(a.z, b.z, choice),
+            x: Fp::conditional_select(a.x, b.x, choice),
+            y: Fp::conditional_select(a.y, b.y, choice),
+            z: Fp::conditional_select(a.z, b.z, choice),
         }
     }
 }
 
+impl ConditionallyNegatable for G1Projective {
+    // Negate self if choice == 1, leave it unchanged if choice == 0
+    fn conditional_negate(ref mut self, choice: Choice) {
+        self = G1Projective::conditional_select(self.neg(), self, choice);
+    }
+}
+
 impl G1Projective {
 
     // Not able to test this yet, doesn't terminate
@@ -286,7 +279,7 @@ impl G1Projective {
             z: z3,
         };
 
-        ~G1Projective::conditional_select(tmp, ~G1Projective::identity(), self.is_identity())
+        G1Projective::conditional_select(G1Projective::identity(), tmp, self.is_identity())
     }
 
     // return self + rhs
@@ -369,7 +362,7 @@ impl G1Projective {
             z: z3,
         };
 
-        ~G1Projective::conditional_select(tmp, self, rhs.is_identity())
+        G1Projective::conditional_select(self, tmp, rhs.is_identity())
     }
 }
 
@@ -382,11 +375,186 @@ impl FROM_AFF for G1Projective {
         G1Projective {
             x: p.x,
             y: p.y,
-            z: ~Fp::conditional_select(~Fp::one(), ~Fp::zero(), p.infinity),
+            z: Fp::conditional_select(Fp::zero(), Fp::one(), p.infinity),
         }
     }
 }
 
+impl FROM_PROJ for G1Affine {
+    fn from(p: G1Projective) -> Self {
+        let zinv = p.z.invert().unwrap_or(Fp::zero());
+        let x = p.x * zinv;
+        let y = p.y * zinv;
+
+        let tmp = G1Affine {
+            x: x,
+            y: y,
+            infinity: Choice::from(0u8),
+        };
+
+        G1Affine::conditional_select(G1Affine::identity(), tmp, zinv.is_zero())
+    }
+}
+
+impl G1Projective {
+    // This goes in a separate impl, because if we use previously defined functions in G1Projective impl, 
+    // Sway will not recognize them from inside the same impl
+
+    // returns self * BLS_X, using double and add
+    fn mul_by_x(self) -> G1Projective {
+        let mut xself = G1Projective::identity();
+        // Comment from zkcrypto
+        // NOTE: in BLS12-381 we can just skip the first bit.
+        let mut x = BLS_X >> 1;
+        let mut tmp = self;
+        while x != 0 {
+            tmp = tmp.double();
+
+            if x % 2 == 1 {
+                xself = xself.add(tmp);
+            }
+            x = x >> 1;
+        }
+        // finally, flip the sign, since BLS_X is negative
+        xself.neg()
+    }
+
+    // Comment from zkcrypto
+    /// Multiplies by $(1 - z)$, where $z$ is the parameter of BLS12-381, which
+    /// [suffices to clear](https://ia.cr/2019/403) the cofactor and map
+    /// elliptic curve points to elements of $\mathbb{G}\_1$.
+    fn clear_cofactor(self) -> G1Projective {
+        self.add(self.mul_by_x().neg())
+    }
+
+    // returns self * by, using double and add
+    // Comment from zkcrypto
+    // This is a simple double-and-add implementation of point
+    // multiplication, moving from most significant to least
+    // significant bit of the scalar.
+    //
+    // We skip the leading bit because it's always unset for Fq
+    // elements.
+    fn mul(self, by: Scalar) -> G1Projective {
+        // Take the scalar out of Montgomery form
+        let by = Scalar::montgomery_reduce(by.ls[0], by.ls[1], by.ls[2], by.ls[3], 0, 0, 0, 0);
+
+        let mut acc = G1Projective::identity();
+        let mut i = 4;
+        while i > 0 {
+            i -= 1;
+            let mut j = if i == 3 { 63 } else { 64 };
+            while j > 0 {
+                j -= 1;
+                acc = acc.double();
+                let bit: u8 = (by.ls[i] >> j) & 1;
+                acc = G1Projective::conditional_select(acc.add(self), acc, Choice::from(bit));
+            }
+        }
+
+        acc
+    }
+}
+
+// returns the c bits of the little-endian integer `limbs`, starting at bit `start`
+fn get_window(limbs: [u64; 4], start: u64, c: u64) -> u64 {
+    let limb = start / 64;
+    let offset = start % 64;
+    let mut window = limbs[limb] >> offset;
+    // The window may continue in the next limb
+    if offset + c > 64 && limb < 3 {
+        window = window | (limbs[limb + 1] << (64 - offset));
+    }
+    window & ((1 << c) - 1)
+}
+
+impl G1Projective {
+    // This goes in a separate impl, because if we use previously defined functions in G1Projective impl,
+    // Sway will not recognize them from inside the same impl
+
+    /// Returns \sum scalars[i] * points[i], using the bucket method of Pippenger.
+    /// Reverts if the number of points and scalars differ.
+    ///
+    /// **This operation is variable time with respect to the scalars.**
+    pub fn multi_exp(points: Vec<G1Affine>, scalars: Vec<Scalar>) -> G1Projective {
+        let n = points.len();
+        assert(scalars.len() == n);
+
+        // Window size as in arkworks: c = 3 for less than 32 points, otherwise roughly ln(n) + 2
+        let c = if n < 32 {
+            3
+        } else {
+            let mut log_n = 0;
+            let mut m = n;
+            while m > 1 {
+                m = m >> 1;
+                log_n += 1;
+            }
+            log_n * 69 / 100 + 2
+        };
+
+        // Take the scalars out of Montgomery form
+        let mut limbs = Vec::new();
+        let mut i = 0;
+        while i < n {
+            let s = scalars.get(i).unwrap();
+            limbs.push(Scalar::montgomery_reduce(s.ls[0], s.ls[1], s.ls[2], s.ls[3], 0, 0, 0, 0).ls);
+            i += 1;
+        }
+
+        // Compute the sum for every c-bit window of the 255-bit scalars.
+        // The sum for window w is \sum b * B_b, where bucket B_b is the sum of all points
+        // whose scalar has value b in window w.
+        let num_buckets = (1 << c) - 1;
+        let mut window_sums = Vec::new();
+        let mut start = 0;
+        while start < 255 {
+            // Fill the buckets in a single pass over the points; buckets[b - 1] holds B_b.
+            // Points whose scalar is 0 in this window don't contribute.
+            let mut buckets = Vec::new();
+            let mut b = 0;
+            while b < num_buckets {
+                buckets.push(G1Projective::identity());
+                b += 1;
+            }
+            let mut i = 0;
+            while i < n {
+                let digit = get_window(limbs.get(i).unwrap(), start, c);
+                if digit != 0 {
+                    buckets.set(digit - 1, buckets.get(digit - 1).unwrap().add_mixed(points.get(i).unwrap()));
+                }
+                i += 1;
+            }
+
+            // Running sum from the highest bucket down, so that every bucket is only added once
+            let mut res = G1Projective::identity();
+            let mut running_sum = G1Projective::identity();
+            let mut b = num_buckets;
+            while b > 0 {
+                b -= 1;
+                running_sum = running_sum.add(buckets.get(b).unwrap());
+                res = res.add(running_sum);
+            }
+            window_sums.push(res);
+            start += c;
+        }
+
+        // Combine the window sums, starting from the highest window
+        let mut w = window_sums.len() - 1;
+        let mut acc = window_sums.get(w).unwrap();
+        while w > 0 {
+            w -= 1;
+            let mut i = 0;
+            while i < c {
+                acc = acc.double();
+                i += 1;
+            }
+            acc = acc.add(window_sums.get(w).unwrap());
+        }
+        acc
+    }
+}
+
 impl ConstantTimeEq for G1Projective {
     // returns (self == other), as a choice
     fn ct_eq(self, other: Self) -> Choice {
@@ -407,7 +575,7 @@ impl ConstantTimeEq for G1Projective {
         // - neither is infinity, and coordinates are the same
         self_is_zero.binary_and(other_is_zero)
         .binary_or(
-            ((~Choice::not(self_is_zero)).binary_and(~Choice::not(other_is_zero))
+            ((Choice::not(self_is_zero)).binary_and(Choice::not(other_is_zero))
                 .binary_and(x1.ct_eq(x2).binary_and(y1.ct_eq(y2))))
             )
     }
@@ -429,4 +597,250 @@ impl Subtract for G1Projective {
     fn subtract(self, other: Self) -> Self {
         self + (other.neg())
     }
-}
\ No newline at end of file
+}
+
+impl G1Affine {
+    // This goes in a separate impl, because if we use previously defined functions in G1Affine impl,
+    // Sway will not recognize them from inside the same impl
+
+    // Comment from zkcrypto
+    /// Returns true if this point is free of an $h$-torsion component, and so it
+    /// exists within the $q$-order subgroup $\mathbb{G}_1$. This should always return true
+    /// unless an "unchecked" API was used.
+    fn is_torsion_free(self) -> Choice {
+        // Algorithm from Section 6 of https://eprint.iacr.org/2021/1130
+        // Updated proof of correctness in https://eprint.iacr.org/2022/352
+        //
+        // Check that endomorphism_p(P) == -[x^2] P
+        let minus_x_squared_times_p = G1Projective::from(self).mul_by_x().mul_by_x().neg();
+        let endomorphism_p = endomorphism(self);
+        minus_x_squared_times_p.ct_eq(G1Projective::from(endomorphism_p))
+    }
+}
+
+impl G1Affine {
+    // This goes in a separate impl, because if we use previously defined functions in G1Affine impl,
+    // Sway will not recognize them from inside the same impl
+
+    // Comment from zkcrypto
+    /// Serializes this element into compressed form. See [`notes::serialization`](crate::notes::serialization)
+    /// for details about how group elements are serialized.
+    pub fn to_compressed(self) -> [u8; 48] {
+        // Strictly speaking, self.x is zero already when self.infinity is true, but
+        // to guard against implementation mistakes we do not assume this.
+        let mut res = Fp::conditional_select(Fp::zero(), self.x, self.infinity).to_bytes();
+
+        // This point is in compressed form, so we set the most significant bit.
+        res[0] = res[0] | (1u8 << 7);
+
+        // Is this point at infinity? If so, set the second-most significant bit.
+        res[0] = res[0] | (self.infinity.unwrap_u8() << 6);
+
+        // Is the y-coordinate the lexicographically largest of the two associated with the
+        // x-coordinate? If so, set the third-most significant bit so long as this is not
+        // the point at infinity.
+        res[0] = res[0] | ((self.infinity.not().binary_and(self.y.lexicographically_largest())).unwrap_u8() << 5);
+
+        res
+    }
+
+    // Comment from zkcrypto
+    /// Serializes this element into uncompressed form. See [`notes::serialization`](crate::notes::serialization)
+    /// for details about how group elements are serialized.
+    pub fn to_uncompressed(self) -> [u8; 96] {
+        let x = Fp::conditional_select(Fp::zero(), self.x, self.infinity).to_bytes();
+        let y = Fp::conditional_select(Fp::zero(), self.y, self.infinity).to_bytes();
+
+        let mut res: [u8; 96] = [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        let mut i = 0;
+        while i < 48 {
+            res[i] = x[i];
+            res[i + 48] = y[i];
+            i += 1;
+        }
+
+        // Is this point at infinity? If so, set the second-most significant bit.
+        res[0] = res[0] | (self.infinity.unwrap_u8() << 6);
+
+        res
+    }
+
+    // Comment from zkcrypto
+    /// Attempts to deserialize an uncompressed element, not checking if the
+    /// element is on the curve and not checking if it is in the correct subgroup.
+    /// **This is dangerous to call unless you trust the bytes you are reading; otherwise,
+    /// API invariants may be broken.** Please consider using `from_uncompressed()` instead.
+    pub fn from_uncompressed_unchecked(bytes: [u8; 96]) -> CtOption<G1Affine> {
+        // Obtain the three flags from the start of the byte sequence
+        let compression_flag_set = Choice::from((bytes[0] >> 7) & 1);
+        let infinity_flag_set = Choice::from((bytes[0] >> 6) & 1);
+        let sort_flag_set = Choice::from((bytes[0] >> 5) & 1);
+
+        let mut x_bytes: [u8; 48] = [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        let mut y_bytes = x_bytes;
+        let mut i = 0;
+        while i < 48 {
+            x_bytes[i] = bytes[i];
+            y_bytes[i] = bytes[i + 48];
+            i += 1;
+        }
+        // Mask away the flag bits
+        x_bytes[0] = x_bytes[0] & 0b0001_1111;
+
+        // Attempt to obtain the x-coordinate and the y-coordinate
+        let x = Fp::from_bytes(x_bytes);
+        let y = Fp::from_bytes(y_bytes);
+
+        // Create a point representing this value
+        let p = G1Affine::conditional_select(
+            G1Affine::identity(),
+            G1Affine {
+                x: x.value,
+                y: y.value,
+                infinity: infinity_flag_set,
+            },
+            infinity_flag_set,
+        );
+
+        CtOption::new(
+            p,
+            // If the infinity flag is set, the x and y coordinates should have been zero.
+            (infinity_flag_set.not().binary_or(infinity_flag_set.binary_and(x.value.is_zero()).binary_and(y.value.is_zero())))
+            // The compression flag should not have been set, as this is an uncompressed element
+            .binary_and(compression_flag_set.not())
+            // The sort flag should not have been set, as this is an uncompressed element
+            .binary_and(sort_flag_set.not())
+            // Both coordinates have to be canonical field elements
+            .binary_and(x.is_some).binary_and(y.is_some),
+        )
+    }
+
+    // Comment from zkcrypto
+    /// Attempts to deserialize a compressed element, not checking if the
+    /// element is in the correct subgroup.
+    /// **This is dangerous to call unless you trust the bytes you are reading; otherwise,
+    /// API invariants may be broken.** Please consider using `from_compressed()` instead.
+    pub fn from_compressed_unchecked(bytes: [u8; 48]) -> CtOption<G1Affine> {
+        // Obtain the three flags from the start of the byte sequence
+        let compression_flag_set = Choice::from((bytes[0] >> 7) & 1);
+        let infinity_flag_set = Choice::from((bytes[0] >> 6) & 1);
+        let sort_flag_set = Choice::from((bytes[0] >> 5) & 1);
+
+        // Attempt to obtain the x-coordinate
+        let mut x_bytes = bytes;
+        // Mask away the flag bits
+        x_bytes[0] = x_bytes[0] & 0b0001_1111;
+        let x = Fp::from_bytes(x_bytes);
+
+        // If the infinity flag is set, return the value assuming
+        // the x-coordinate is zero and the sort bit is not set.
+        //
+        // Otherwise, return a recovered point (assuming the correct
+        // y-coordinate can be found) so long as the infinity flag
+        // was not set.
+        let infinity_ok = infinity_flag_set
+            .binary_and(sort_flag_set.not())
+            .binary_and(x.value.is_zero());
+
+        // Recover a y-coordinate given x by y = sqrt(x^3 + 4)
+        let y = ((x.value.square() * x.value) + B).sqrt();
+        // Switch to the correct y-coordinate if necessary.
+        let y_value = Fp::conditional_select(
+            y.value.neg(),
+            y.value,
+            Choice::from(y.value.lexicographically_largest().unwrap_u8() ^ sort_flag_set.unwrap_u8()),
+        );
+        let point_ok = infinity_flag_set.not().binary_and(y.is_some);
+
+        let p = G1Affine::conditional_select(
+            G1Affine::identity(),
+            G1Affine {
+                x: x.value,
+                y: y_value,
+                infinity: infinity_flag_set,
+            },
+            infinity_flag_set,
+        );
+
+        CtOption::new(
+            p,
+            infinity_ok.binary_or(point_ok)
+            // The compression flag should have been set, as this is a compressed element
+            .binary_and(compression_flag_set)
+            .binary_and(x.is_some),
+        )
+    }
+
+    // Comment from zkcrypto
+    /// Attempts to deserialize an uncompressed element. See [`notes::serialization`](crate::notes::serialization)
+    /// for details about how group elements are serialized.
+    pub fn from_uncompressed(bytes: [u8; 96]) -> CtOption<G1Affine> {
+        let p = G1Affine::from_uncompressed_unchecked(bytes);
+        CtOption::new(
+            p.value,
+            p.is_some.binary_and(p.value.is_on_curve()).binary_and(p.value.is_torsion_free()),
+        )
+    }
+
+    // Comment from zkcrypto
+    /// Attempts to deserialize a compressed element. See [`notes::serialization`](crate::notes::serialization)
+    /// for details about how group elements are serialized.
+    // Points that are not on the curve are already rejected because the square root doesn't exist.
+    pub fn from_compressed(bytes: [u8; 48]) -> CtOption<G1Affine> {
+        let p = G1Affine::from_compressed_unchecked(bytes);
+        CtOption::new(p.value, p.is_some.binary_and(p.value.is_torsion_free()))
+    }
+}
+
+impl Neg for G1Projective {
+    fn neg(self) -> Self {
+        self.neg()
+    }
+}
+
+impl Group<Scalar> for G1Projective {
+    fn identity() -> Self {
+        G1Projective::identity()
+    }
+
+    fn generator() -> Self {
+        G1Projective::generator()
+    }
+
+    fn is_identity(self) -> Choice {
+        self.is_identity()
+    }
+
+    fn double(self) -> Self {
+        self.double()
+    }
+
+    fn mul_scalar(self, scalar: Scalar) -> Self {
+        self.mul(scalar)
+    }
+}
+
+impl CurveAffine for G1Affine {
+    fn identity() -> Self {
+        G1Affine::identity()
+    }
+
+    fn generator() -> Self {
+        G1Affine::generator()
+    }
+
+    fn is_identity(self) -> Choice {
+        self.is_identity()
+    }
+}
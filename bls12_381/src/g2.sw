@@ -0,0 +1,893 @@
+library;
+
+use ::fp::{Fp, from_raw_unchecked};
+use ::fp2::Fp2;
+use ::util::BLS_X;
+use ::scalar::Scalar;
+use utils::choice::{Choice, CtOption, ConditionallySelectable, ConditionallyNegatable, ConstantTimeEq};
+use utils::group::{Group, CurveAffine, Neg};
+use core::ops::{Eq, Add, Subtract};
+
+// Comment from zkcrypto
+/// This is an element of $\mathbb{G}_2$ represented in the affine coordinate space.
+/// It is ideal to keep elements in this representation to reduce memory usage and
+/// improve performance through the use of mixed curve model arithmetic.
+///
+/// Values of `G2Affine` are guaranteed to be in the $q$-order subgroup unless an
+/// "unchecked" API was misused.
+pub struct G2Affine {
+    x: Fp2,
+    y: Fp2,
+    infinity: Choice,
+}
+
+// B = 4(u + 1)
+pub const B: Fp2 = Fp2 {
+    c0: from_raw_unchecked([
+        0xaa27_0000_000c_fff3,
+        0x53cc_0032_fc34_000a,
+        0x478f_e97a_6b0a_807f,
+        0xb1d3_7ebe_e6ba_24d7,
+        0x8ec9_733b_bf78_ab2f,
+        0x09d6_4551_3d83_de7e,
+    ]),
+    c1: from_raw_unchecked([
+        0xaa27_0000_000c_fff3,
+        0x53cc_0032_fc34_000a,
+        0x478f_e97a_6b0a_807f,
+        0xb1d3_7ebe_e6ba_24d7,
+        0x8ec9_733b_bf78_ab2f,
+        0x09d6_4551_3d83_de7e,
+    ]),
+};
+
+// B3 = 3 * B = 12(u + 1)
+// In zkcrypto this is computed as B + B + B, but that can't be done in a const in Sway
+pub const B3: Fp2 = Fp2 {
+    c0: from_raw_unchecked([
+        0x4476_0000_0027_552e,
+        0xdcb8_009a_4348_0020,
+        0x6f7e_e9ce_4a6e_8b59,
+        0xb103_30b7_c0a9_5bc6,
+        0x6140_b1fc_fb1e_54b7,
+        0x0381_be09_7f0b_b4e1,
+    ]),
+    c1: from_raw_unchecked([
+        0x4476_0000_0027_552e,
+        0xdcb8_009a_4348_0020,
+        0x6f7e_e9ce_4a6e_8b59,
+        0xb103_30b7_c0a9_5bc6,
+        0x6140_b1fc_fb1e_54b7,
+        0x0381_be09_7f0b_b4e1,
+    ]),
+};
+
+fn mul_by_3b(x: Fp2) -> Fp2 {
+    x * B3
+}
+
+impl ConstantTimeEq for G2Affine {
+    // returns (self == other), as a choice
+    fn ct_eq(self, other: Self) -> Choice {
+        // Comment from zkcrypto
+        // The only cases in which two points are equal are
+        // 1. infinity is set on both
+        // 2. infinity is not set on both, and their coordinates are equal
+        self.infinity.binary_and(other.infinity)
+        .binary_or(
+                (self.infinity.not())
+                .binary_and(other.infinity.not())
+                .binary_and(self.x.ct_eq(other.x))
+                .binary_and(self.y.ct_eq(other.y))
+                )
+    }
+}
+
+impl ConditionallySelectable for G2Affine {
+    // Select a if choice == 1 or select b if choice == 0, in constant time.
+    fn conditional_select(a: Self, b: Self, choice: Choice) -> Self {
+        G2Affine {
+            x: Fp2::conditional_select(a.x, b.x, choice),
+            y: Fp2::conditional_select(a.y, b.y, choice),
+            infinity: Choice::conditional_select(a.infinity, b.infinity, choice),
+        }
+    }
+}
+
+impl ConditionallyNegatable for G2Affine {
+    // Negate self if choice == 1, leave it unchanged if choice == 0
+    fn conditional_negate(ref mut self, choice: Choice) {
+        self = G2Affine::conditional_select(self.neg(), self, choice);
+    }
+}
+
+impl Eq for G2Affine {
+    fn eq(self, other: Self) -> bool {
+        self.ct_eq(other).unwrap_as_bool()
+    }
+}
+
+impl G2Affine {
+    /// Returns the identity of the group: the point at infinity.
+    fn identity() -> G2Affine {
+        G2Affine {
+            x: Fp2::zero(),
+            y: Fp2::one(),
+            infinity: Choice::from(1u8),
+        }
+    }
+
+    // returns true if this is the point at infinity
+    fn is_identity(self) -> Choice {
+        self.infinity
+    }
+
+    // Comment from zkcrypto
+    /// Returns true if this point is on the curve. This should always return
+    /// true unless an "unchecked" API was used.
+    fn is_on_curve(self) -> Choice {
+        // y^2 - x^3 ?= 4(u + 1)
+        let y_squared = self.y.square();
+        let x_cubed = self.x.square() * self.x;
+        (y_squared - x_cubed).ct_eq(B).binary_or(self.infinity)
+    }
+
+    // returns a fixed generator of the group
+    // see notes of zkcrypto on how this was chosen [here at paragraph `Fixed generators`](https://github.com/zkcrypto/bls12_381/blob/main/src/notes/design.rs)
+    fn generator() -> G2Affine {
+        G2Affine {
+            x: Fp2 {
+                c0: from_raw_unchecked([
+                    0xf5f2_8fa2_0294_0a10,
+                    0xb3f5_fb26_87b4_961a,
+                    0xa1a8_93b5_3e2a_e580,
+                    0x9894_999d_1a3c_aee9,
+                    0x6f67_b763_1863_366b,
+                    0x0581_9192_4350_bcd7,
+                ]),
+                c1: from_raw_unchecked([
+                    0xa5a9_c075_9e23_f606,
+                    0xaaa0_c59d_bccd_60c3,
+                    0x3bb1_7e18_e286_7806,
+                    0x1b1a_b6cc_8541_b367,
+                    0xc2b6_ed0e_f215_8547,
+                    0x1192_2a09_7360_edf3,
+                ]),
+            },
+            y: Fp2 {
+                c0: from_raw_unchecked([
+                    0x4c73_0af8_6049_4c4a,
+                    0x597c_fa1f_5e36_9c5a,
+                    0xe7e6_856c_aa0a_635a,
+                    0xbbef_b5e9_6e0d_495f,
+                    0x07d3_a975_f0ef_25a2,
+                    0x0083_fd8e_7e80_dae5,
+                ]),
+                c1: from_raw_unchecked([
+                    0xadc0_fc92_df64_b05d,
+                    0x18aa_270a_2b14_61dc,
+                    0x86ad_ac6a_3be4_eba0,
+                    0x7949_5c4e_c93d_a33a,
+                    0xe717_5850_a43c_caed,
+                    0x0b2b_c2a1_63de_1bf2,
+                ]),
+            },
+            infinity: Choice::from(0u8),
+        }
+    }
+
+    // returns negation of point
+    fn neg(self) -> G2Affine {
+        G2Affine {
+            x: self.x,
+            y: Fp2::conditional_select(Fp2::one(), self.y.neg(), self.infinity),
+            infinity: self.infinity,
+        }
+    }
+}
+
+// Element of G2, represented with projective coordinates
+pub struct G2Projective {
+    x: Fp2,
+    y: Fp2,
+    z: Fp2,
+}
+
+impl G2Projective {
+    // Comment from zkcrypto
+    /// Returns the identity of the group: the point at infinity.
+    fn identity() -> G2Projective {
+        G2Projective {
+            x: Fp2::zero(),
+            y: Fp2::one(),
+            z: Fp2::zero(),
+        }
+    }
+
+    // returns true if self is the point at infinity
+    fn is_identity(self) -> Choice {
+        self.z.is_zero()
+    }
+
+    // returns point negation
+    fn neg(self) -> G2Projective {
+        G2Projective {
+            x: self.x,
+            y: self.y.neg(),
+            z: self.z,
+        }
+    }
+
+    // returns a fixed generator of the group
+    // see notes of zkcrypto on how this was chosen [here at paragraph `Fixed generators`](https://github.com/zkcrypto/bls12_381/blob/main/src/notes/design.rs)
+    fn generator() -> G2Projective {
+        let g = G2Affine::generator();
+        G2Projective {
+            x: g.x,
+            y: g.y,
+            z: Fp2::one(),
+        }
+    }
+}
+
+impl ConditionallySelectable for G2Projective {
+    // Select a if choice == 1 or select b if choice == 0, in constant time.
+    fn conditional_select(a: Self, b: Self, choice: Choice) -> Self {
+        G2Projective {
+            x: Fp2::conditional_select(a.x, b.x, choice),
+            y: Fp2::conditional_select(a.y, b.y, choice),
+            z: Fp2::conditional_select(a.z, b.z, choice),
+        }
+    }
+}
+
+impl ConditionallyNegatable for G2Projective {
+    // Negate self if choice == 1, leave it unchanged if choice == 0
+    fn conditional_negate(ref mut self, choice: Choice) {
+        self = G2Projective::conditional_select(self.neg(), self, choice);
+    }
+}
+
+impl G2Projective {
+
+    // returns doubling of point
+    // uses Algorithm 9, https://eprint.iacr.org/2015/1060.pdf
+    fn double(self) -> G2Projective {
+        let t0 = self.y.square();
+        let z3 = t0 + t0;
+        let z3 = z3 + z3;
+        let z3 = z3 + z3;
+        let t1 = self.y * self.z;
+        let t2 = self.z.square();
+        let t2 = mul_by_3b(t2);
+        let x3 = t2 * z3;
+        let y3 = t0 + t2;
+        let z3 = t1 * z3;
+        let t1 = t2 + t2;
+        let t2 = t1 + t2;
+        let t0 = t0 - t2;
+        let y3 = t0 * y3;
+        let y3 = x3 + y3;
+        let t1 = self.x * self.y;
+        let x3 = t0 * t1;
+        let x3 = x3 + x3;
+
+        let tmp = G2Projective {
+            x: x3,
+            y: y3,
+            z: z3,
+        };
+
+        G2Projective::conditional_select(G2Projective::identity(), tmp, self.is_identity())
+    }
+
+    // return self + rhs
+    // Uses Algorithm 7, https://eprint.iacr.org/2015/1060.pdf
+    fn add(self, rhs: G2Projective) -> G2Projective {
+        let t0 = self.x * rhs.x;
+        let t1 = self.y * rhs.y;
+        let t2 = self.z * rhs.z;
+        let t3 = self.x + self.y;
+        let t4 = rhs.x + rhs.y;
+        let t3 = t3 * t4;
+        let t4 = t0 + t1;
+        let t3 = t3 - t4;
+        let t4 = self.y + self.z;
+        let x3 = rhs.y + rhs.z;
+        let t4 = t4 * x3;
+        let x3 = t1 + t2;
+        let t4 = t4 - x3;
+        let x3 = self.x + self.z;
+        let y3 = rhs.x + rhs.z;
+        let x3 = x3 * y3;
+        let y3 = t0 + t2;
+        let y3 = x3 - y3;
+        let x3 = t0 + t0;
+        let t0 = x3 + t0;
+        let t2 = mul_by_3b(t2);
+        let z3 = t1 + t2;
+        let t1 = t1 - t2;
+        let y3 = mul_by_3b(y3);
+        let x3 = t4 * y3;
+        let t2 = t3 * t1;
+        let x3 = t2 - x3;
+        let y3 = y3 * t0;
+        let t1 = t1 * z3;
+        let y3 = t1 + y3;
+        let t0 = t0 * t3;
+        let z3 = z3 * t4;
+        let z3 = z3 + t0;
+
+        G2Projective {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    // returns self added to another point that is in the affine representation
+    // Uses Algorithm 8, https://eprint.iacr.org/2015/1060.pdf
+    fn add_mixed(self, rhs: G2Affine) -> G2Projective {
+        let t0 = self.x * rhs.x;
+        let t1 = self.y * rhs.y;
+        let t3 = rhs.x + rhs.y;
+        let t4 = self.x + self.y;
+        let t3 = t3 * t4;
+        let t4 = t0 + t1;
+        let t3 = t3 - t4;
+        let t4 = rhs.y * self.z;
+        let t4 = t4 + self.y;
+        let y3 = rhs.x * self.z;
+        let y3 = y3 + self.x;
+        let x3 = t0 + t0;
+        let t0 = x3 + t0;
+        let t2 = mul_by_3b(self.z);
+        let z3 = t1 + t2;
+        let t1 = t1 - t2;
+        let y3 = mul_by_3b(y3);
+        let x3 = t4 * y3;
+        let t2 = t3 * t1;
+        let x3 = t2 - x3;
+        let y3 = y3 * t0;
+        let t1 = t1 * z3;
+        let y3 = t1 + y3;
+        let t0 = t0 * t3;
+        let z3 = z3 * t4;
+        let z3 = z3 + t0;
+
+        let tmp = G2Projective {
+            x: x3,
+            y: y3,
+            z: z3,
+        };
+
+        G2Projective::conditional_select(self, tmp, rhs.is_identity())
+    }
+
+    // returns the untwist-Frobenius-twist endomorphism psi(self)
+    fn psi(self) -> G2Projective {
+        // Comment from zkcrypto
+        // 1 / ((u+1) ^ ((q-1)/3))
+        let psi_coeff_x = Fp2 {
+            c0: Fp::zero(),
+            c1: from_raw_unchecked([
+                0x890d_c9e4_8675_45c3,
+                0x2af3_2253_3285_a5d5,
+                0x5088_0866_309b_7e2c,
+                0xa20d_1b8c_7e88_1024,
+                0x14e4_f04f_e2db_9068,
+                0x14e5_6d3f_1564_853a,
+            ]),
+        };
+        // 1 / ((u+1) ^ (p-1)/2)
+        let psi_coeff_y = Fp2 {
+            c0: from_raw_unchecked([
+                0x3e2f_585d_a55c_9ad1,
+                0x4294_213d_86c1_8183,
+                0x3828_44c8_8b62_3732,
+                0x92ad_2afd_1910_3e18,
+                0x1d79_4e4f_ac7c_f0b9,
+                0x0bd5_92fc_7d82_5ec8,
+            ]),
+            c1: from_raw_unchecked([
+                0x7bcf_a7a2_5aa3_0fda,
+                0xdc17_dec1_2a92_7e7c,
+                0x2f08_8dd8_6b4e_bef1,
+                0xd1ca_2087_da74_d4a7,
+                0x2da2_5966_96ce_bc1d,
+                0x0e2b_7eed_bbfd_87d2,
+            ]),
+        };
+
+        G2Projective {
+            // x = frobenius(x)/((u+1)^((p-1)/3))
+            x: self.x.frobenius_map() * psi_coeff_x,
+            // y = frobenius(y)/(u+1)^((p-1)/2)
+            y: self.y.frobenius_map() * psi_coeff_y,
+            // z = frobenius(z)
+            z: self.z.frobenius_map(),
+        }
+    }
+
+    // returns psi(psi(self))
+    fn psi2(self) -> G2Projective {
+        // Comment from zkcrypto
+        // 1 / 2 ^ ((q-1)/3)
+        let psi2_coeff_x = Fp2 {
+            c0: from_raw_unchecked([
+                0xcd03_c9e4_8671_f071,
+                0x5dab_2246_1fcd_a5d2,
+                0x5870_42af_d385_1b95,
+                0x8eb6_0ebe_01ba_cb9e,
+                0x03f9_7d6e_83d0_50d2,
+                0x18f0_2065_5463_8741,
+            ]),
+            c1: Fp::zero(),
+        };
+
+        G2Projective {
+            // x = frobenius^2(x)/2^((p-1)/3); note that q^2 is the order of the field.
+            x: self.x * psi2_coeff_x,
+            // y = -frobenius^2(y); note that q^2 is the order of the field.
+            y: self.y.neg(),
+            // z = z
+            z: self.z,
+        }
+    }
+}
+
+impl G2Projective {
+    // This goes in a separate impl, because if we use previously defined functions in G2Projective impl,
+    // Sway will not recognize them from inside the same impl
+
+    // returns self * BLS_X, using double and add
+    fn mul_by_x(self) -> G2Projective {
+        let mut xself = G2Projective::identity();
+        // Comment from zkcrypto
+        // NOTE: in BLS12-381 we can just skip the first bit.
+        let mut x = BLS_X >> 1;
+        let mut acc = self;
+        while x != 0 {
+            acc = acc.double();
+
+            if x % 2 == 1 {
+                xself = xself.add(acc);
+            }
+            x = x >> 1;
+        }
+        // finally, flip the sign, since BLS_X is negative
+        xself.neg()
+    }
+}
+
+impl G2Projective {
+    // Comment from zkcrypto
+    /// Clears the cofactor, using [Budroni-Pintore](https://ia.cr/2017/419).
+    /// This is equivalent to multiplying by $h\_\textrm{eff} = 3(z^2 - 1) \cdot
+    /// h_2$, where $h_2$ is the cofactor of $\mathbb{G}\_2$ and $z$ is the
+    /// parameter of BLS12-381.
+    fn clear_cofactor(self) -> G2Projective {
+        let t1 = self.mul_by_x(); // [x] P
+        let t2 = self.psi(); // psi(P)
+
+        self.double().psi2() // psi^2(2P)
+            .add((t1.add(t2)).mul_by_x()) // psi^2(2P) + [x^2] P + [x] psi(P)
+            .add(t1.neg()) // psi^2(2P) + [x^2 - x] P + [x] psi(P)
+            .add(t2.neg()) // psi^2(2P) + [x^2 - x] P + [x - 1] psi(P)
+            .add(self.neg()) // psi^2(2P) + [x^2 - x - 1] P + [x - 1] psi(P)
+    }
+
+    // returns self * by, using double and add
+    // Comment from zkcrypto
+    // This is a simple double-and-add implementation of point
+    // multiplication, moving from most significant to least
+    // significant bit of the scalar.
+    //
+    // We skip the leading bit because it's always unset for Fq
+    // elements.
+    fn mul(self, by: Scalar) -> G2Projective {
+        // Take the scalar out of Montgomery form
+        let by = Scalar::montgomery_reduce(by.ls[0], by.ls[1], by.ls[2], by.ls[3], 0, 0, 0, 0);
+
+        let mut acc = G2Projective::identity();
+        let mut i = 4;
+        while i > 0 {
+            i -= 1;
+            let mut j = if i == 3 { 63 } else { 64 };
+            while j > 0 {
+                j -= 1;
+                acc = acc.double();
+                let bit: u8 = (by.ls[i] >> j) & 1;
+                acc = G2Projective::conditional_select(acc.add(self), acc, Choice::from(bit));
+            }
+        }
+
+        acc
+    }
+}
+
+pub trait FROM_AFF2 {
+    fn from(p: G2Affine) -> Self;
+}
+
+pub trait FROM_PROJ2 {
+    fn from(p: G2Projective) -> Self;
+}
+
+impl FROM_AFF2 for G2Projective {
+    fn from(p: G2Affine) -> Self {
+        G2Projective {
+            x: p.x,
+            y: p.y,
+            z: Fp2::conditional_select(Fp2::zero(), Fp2::one(), p.infinity),
+        }
+    }
+}
+
+impl FROM_PROJ2 for G2Affine {
+    fn from(p: G2Projective) -> Self {
+        let zinv = p.z.invert().unwrap_or(Fp2::zero());
+        let x = p.x * zinv;
+        let y = p.y * zinv;
+
+        let tmp = G2Affine {
+            x: x,
+            y: y,
+            infinity: Choice::from(0u8),
+        };
+
+        G2Affine::conditional_select(G2Affine::identity(), tmp, zinv.is_zero())
+    }
+}
+
+impl ConstantTimeEq for G2Projective {
+    // returns (self == other), as a choice
+    fn ct_eq(self, other: Self) -> Choice {
+        // Comments from zkcrypto
+        // Is (xz, yz, z) equal to (x'z', y'z', z') when converted to affine?
+
+        let x1 = self.x * other.z;
+        let x2 = other.x * self.z;
+
+        let y1 = self.y * other.z;
+        let y2 = other.y * self.z;
+
+        let self_is_zero = self.z.is_zero();
+        let other_is_zero = other.z.is_zero();
+
+        // they are equal if:
+        // - both points are infinity
+        // - neither is infinity, and coordinates are the same
+        self_is_zero.binary_and(other_is_zero)
+        .binary_or(
+            ((Choice::not(self_is_zero)).binary_and(Choice::not(other_is_zero))
+                .binary_and(x1.ct_eq(x2).binary_and(y1.ct_eq(y2))))
+            )
+    }
+}
+
+impl Eq for G2Projective {
+    fn eq(self, other: Self) -> bool {
+        self.ct_eq(other).unwrap_as_bool()
+    }
+}
+
+impl Add for G2Projective {
+    fn add(self, other: Self) -> Self {
+        self.add(other)
+    }
+}
+
+impl Subtract for G2Projective {
+    fn subtract(self, other: Self) -> Self {
+        self + (other.neg())
+    }
+}
+
+// returns bytes[start..start + 48]
+fn read_48_bytes(bytes: [u8; 192], start: u64) -> [u8; 48] {
+    let mut res: [u8; 48] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    let mut i = 0;
+    while i < 48 {
+        res[i] = bytes[start + i];
+        i += 1;
+    }
+    res
+}
+
+impl G2Affine {
+    // This goes in a separate impl, because if we use previously defined functions in G2Affine impl,
+    // Sway will not recognize them from inside the same impl
+
+    // Comment from zkcrypto
+    /// Returns true if this point is free of an $h$-torsion component, and so it
+    /// exists within the $q$-order subgroup $\mathbb{G}_2$. This should always return true
+    /// unless an "unchecked" API was used.
+    fn is_torsion_free(self) -> Choice {
+        // Algorithm from Section 4 of https://eprint.iacr.org/2021/1130
+        // Updated proof of correctness in https://eprint.iacr.org/2022/352
+        //
+        // Check that psi(P) == [x] P
+        let p = G2Projective::from(self);
+        p.psi().ct_eq(p.mul_by_x())
+    }
+}
+
+impl G2Affine {
+    // This goes in a separate impl, because if we use previously defined functions in G2Affine impl,
+    // Sway will not recognize them from inside the same impl
+
+    // Comment from zkcrypto
+    /// Serializes this element into compressed form. See [`notes::serialization`](crate::notes::serialization)
+    /// for details about how group elements are serialized.
+    pub fn to_compressed(self) -> [u8; 96] {
+        // Strictly speaking, self.x is zero already when self.infinity is true, but
+        // to guard against implementation mistakes we do not assume this.
+        let x = Fp2::conditional_select(Fp2::zero(), self.x, self.infinity);
+        let c1 = x.c1.to_bytes();
+        let c0 = x.c0.to_bytes();
+
+        let mut res: [u8; 96] = [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        let mut i = 0;
+        while i < 48 {
+            res[i] = c1[i];
+            res[i + 48] = c0[i];
+            i += 1;
+        }
+
+        // This point is in compressed form, so we set the most significant bit.
+        res[0] = res[0] | (1u8 << 7);
+
+        // Is this point at infinity? If so, set the second-most significant bit.
+        res[0] = res[0] | (self.infinity.unwrap_u8() << 6);
+
+        // Is the y-coordinate the lexicographically largest of the two associated with the
+        // x-coordinate? If so, set the third-most significant bit so long as this is not
+        // the point at infinity.
+        res[0] = res[0] | ((self.infinity.not().binary_and(self.y.lexicographically_largest())).unwrap_u8() << 5);
+
+        res
+    }
+
+    // Comment from zkcrypto
+    /// Serializes this element into uncompressed form. See [`notes::serialization`](crate::notes::serialization)
+    /// for details about how group elements are serialized.
+    pub fn to_uncompressed(self) -> [u8; 192] {
+        let x = Fp2::conditional_select(Fp2::zero(), self.x, self.infinity);
+        let y = Fp2::conditional_select(Fp2::zero(), self.y, self.infinity);
+        let x_c1 = x.c1.to_bytes();
+        let x_c0 = x.c0.to_bytes();
+        let y_c1 = y.c1.to_bytes();
+        let y_c0 = y.c0.to_bytes();
+
+        let mut res: [u8; 192] = [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        let mut i = 0;
+        while i < 48 {
+            res[i] = x_c1[i];
+            res[i + 48] = x_c0[i];
+            res[i + 96] = y_c1[i];
+            res[i + 144] = y_c0[i];
+            i += 1;
+        }
+
+        // Is this point at infinity? If so, set the second-most significant bit.
+        res[0] = res[0] | (self.infinity.unwrap_u8() << 6);
+
+        res
+    }
+
+    // Comment from zkcrypto
+    /// Attempts to deserialize an uncompressed element, not checking if the
+    /// element is on the curve and not checking if it is in the correct subgroup.
+    /// **This is dangerous to call unless you trust the bytes you are reading; otherwise,
+    /// API invariants may be broken.** Please consider using `from_uncompressed()` instead.
+    pub fn from_uncompressed_unchecked(bytes: [u8; 192]) -> CtOption<G2Affine> {
+        // Obtain the three flags from the start of the byte sequence
+        let compression_flag_set = Choice::from((bytes[0] >> 7) & 1);
+        let infinity_flag_set = Choice::from((bytes[0] >> 6) & 1);
+        let sort_flag_set = Choice::from((bytes[0] >> 5) & 1);
+
+        let mut xc1_bytes = read_48_bytes(bytes, 0);
+        // Mask away the flag bits
+        xc1_bytes[0] = xc1_bytes[0] & 0b0001_1111;
+
+        // Attempt to obtain the x-coordinate and the y-coordinate
+        let xc1 = Fp::from_bytes(xc1_bytes);
+        let xc0 = Fp::from_bytes(read_48_bytes(bytes, 48));
+        let yc1 = Fp::from_bytes(read_48_bytes(bytes, 96));
+        let yc0 = Fp::from_bytes(read_48_bytes(bytes, 144));
+
+        let x = Fp2 {
+            c0: xc0.value,
+            c1: xc1.value,
+        };
+        let y = Fp2 {
+            c0: yc0.value,
+            c1: yc1.value,
+        };
+
+        // Create a point representing this value
+        let p = G2Affine::conditional_select(
+            G2Affine::identity(),
+            G2Affine {
+                x: x,
+                y: y,
+                infinity: infinity_flag_set,
+            },
+            infinity_flag_set,
+        );
+
+        CtOption::new(
+            p,
+            // If the infinity flag is set, the x and y coordinates should have been zero.
+            (infinity_flag_set.not().binary_or(infinity_flag_set.binary_and(x.is_zero()).binary_and(y.is_zero())))
+            // The compression flag should not have been set, as this is an uncompressed element
+            .binary_and(compression_flag_set.not())
+            // The sort flag should not have been set, as this is an uncompressed element
+            .binary_and(sort_flag_set.not())
+            // All coordinates have to be canonical field elements
+            .binary_and(xc1.is_some).binary_and(xc0.is_some)
+            .binary_and(yc1.is_some).binary_and(yc0.is_some),
+        )
+    }
+
+    // Comment from zkcrypto
+    /// Attempts to deserialize an uncompressed element. See [`notes::serialization`](crate::notes::serialization)
+    /// for details about how group elements are serialized.
+    pub fn from_uncompressed(bytes: [u8; 192]) -> CtOption<G2Affine> {
+        let p = G2Affine::from_uncompressed_unchecked(bytes);
+        CtOption::new(
+            p.value,
+            p.is_some.binary_and(p.value.is_on_curve()).binary_and(p.value.is_torsion_free()),
+        )
+    }
+
+
+    // Comment from zkcrypto
+    /// Attempts to deserialize a compressed element, not checking if the
+    /// element is in the correct subgroup.
+    /// **This is dangerous to call unless you trust the bytes you are reading; otherwise,
+    /// API invariants may be broken.** Please consider using `from_compressed()` instead.
+    pub fn from_compressed_unchecked(bytes: [u8; 96]) -> CtOption<G2Affine> {
+        // Obtain the three flags from the start of the byte sequence
+        let compression_flag_set = Choice::from((bytes[0] >> 7) & 1);
+        let infinity_flag_set = Choice::from((bytes[0] >> 6) & 1);
+        let sort_flag_set = Choice::from((bytes[0] >> 5) & 1);
+
+        let mut xc1_bytes: [u8; 48] = [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        let mut xc0_bytes = xc1_bytes;
+        let mut i = 0;
+        while i < 48 {
+            xc1_bytes[i] = bytes[i];
+            xc0_bytes[i] = bytes[i + 48];
+            i += 1;
+        }
+        // Mask away the flag bits
+        xc1_bytes[0] = xc1_bytes[0] & 0b0001_1111;
+
+        // Attempt to obtain the x-coordinate
+        let xc1 = Fp::from_bytes(xc1_bytes);
+        let xc0 = Fp::from_bytes(xc0_bytes);
+        let x = Fp2 {
+            c0: xc0.value,
+            c1: xc1.value,
+        };
+
+        // If the infinity flag is set, return the value assuming
+        // the x-coordinate is zero and the sort bit is not set.
+        //
+        // Otherwise, return a recovered point (assuming the correct
+        // y-coordinate can be found) so long as the infinity flag
+        // was not set.
+        let infinity_ok = infinity_flag_set
+            .binary_and(sort_flag_set.not())
+            .binary_and(x.is_zero());
+
+        // Recover a y-coordinate given x by y = sqrt(x^3 + 4)
+        let y = ((x.square() * x) + B).sqrt();
+        // Switch to the correct y-coordinate if necessary.
+        let y_value = Fp2::conditional_select(
+            y.value.neg(),
+            y.value,
+            Choice::from(y.value.lexicographically_largest().unwrap_u8() ^ sort_flag_set.unwrap_u8()),
+        );
+        let point_ok = infinity_flag_set.not().binary_and(y.is_some);
+
+        let p = G2Affine::conditional_select(
+            G2Affine::identity(),
+            G2Affine {
+                x: x,
+                y: y_value,
+                infinity: infinity_flag_set,
+            },
+            infinity_flag_set,
+        );
+
+        CtOption::new(
+            p,
+            infinity_ok.binary_or(point_ok)
+            // The compression flag should have been set, as this is a compressed element
+            .binary_and(compression_flag_set)
+            .binary_and(xc1.is_some).binary_and(xc0.is_some),
+        )
+    }
+
+    // Comment from zkcrypto
+    /// Attempts to deserialize a compressed element. See [`notes::serialization`](crate::notes::serialization)
+    /// for details about how group elements are serialized.
+    // Points that are not on the curve are already rejected because the square root doesn't exist.
+    pub fn from_compressed(bytes: [u8; 96]) -> CtOption<G2Affine> {
+        let p = G2Affine::from_compressed_unchecked(bytes);
+        CtOption::new(p.value, p.is_some.binary_and(p.value.is_torsion_free()))
+    }
+}
+
+impl Neg for G2Projective {
+    fn neg(self) -> Self {
+        self.neg()
+    }
+}
+
+impl Group<Scalar> for G2Projective {
+    fn identity() -> Self {
+        G2Projective::identity()
+    }
+
+    fn generator() -> Self {
+        G2Projective::generator()
+    }
+
+    fn is_identity(self) -> Choice {
+        self.is_identity()
+    }
+
+    fn double(self) -> Self {
+        self.double()
+    }
+
+    fn mul_scalar(self, scalar: Scalar) -> Self {
+        self.mul(scalar)
+    }
+}
+
+impl CurveAffine for G2Affine {
+    fn identity() -> Self {
+        G2Affine::identity()
+    }
+
+    fn generator() -> Self {
+        G2Affine::generator()
+    }
+
+    fn is_identity(self) -> Choice {
+        self.is_identity()
+    }
+}
@@ -0,0 +1,137 @@
+library;
+
+use ::scalar::Scalar;
+use ::g1::{G1Affine, G1Projective, FROM_AFF, FROM_PROJ};
+use ::g2::G2Affine;
+use ::pairings::{G2Prepared, Gt, multi_miller_loop};
+use utils::choice::CtOption;
+use std::{assert::assert, vec::Vec};
+
+// Verification of Groth16 proofs over BLS12-381, https://eprint.iacr.org/2016/260.pdf
+// Follows the verifier of bellman (https://github.com/zkcrypto/bellman), so proofs and
+// verifying keys generated by bellman can be verified directly.
+
+/// The verifying key of a Groth16 circuit.
+pub struct VerifyingKey {
+    alpha_g1: G1Affine,
+    beta_g2: G2Affine,
+    gamma_g2: G2Affine,
+    delta_g2: G2Affine,
+    // Comment from bellman
+    // Elements of the form (beta * u_i(tau) + alpha v_i(tau) + w_i(tau)) / gamma
+    // for all public inputs. Because all Groth16 proofs are "relative" to the
+    // constant 1, there is always at least one element here.
+    ic: Vec<G1Affine>,
+}
+
+/// A Groth16 proof.
+pub struct Proof {
+    a: G1Affine,
+    b: G2Affine,
+    c: G1Affine,
+}
+
+// Decodes a compressed G1 point, failing if it is not in the q-order subgroup or is the
+// point at infinity. Bellman rejects the point at infinity in proofs and verifying keys as well.
+fn read_g1(bytes: [u8; 48]) -> CtOption<G1Affine> {
+    let p = G1Affine::from_compressed(bytes);
+    CtOption::new(p.value, p.is_some.binary_and(p.value.is_identity().not()))
+}
+
+// Decodes a compressed G2 point, failing if it is not in the q-order subgroup or is the point at infinity.
+fn read_g2(bytes: [u8; 96]) -> CtOption<G2Affine> {
+    let p = G2Affine::from_compressed(bytes);
+    CtOption::new(p.value, p.is_some.binary_and(p.value.is_identity().not()))
+}
+
+impl VerifyingKey {
+    /// Decodes a verifying key from the compressed encodings of its points.
+    /// Fails if any point is not a valid group element or is the point at infinity.
+    pub fn from_compressed(
+        alpha_g1: [u8; 48],
+        beta_g2: [u8; 96],
+        gamma_g2: [u8; 96],
+        delta_g2: [u8; 96],
+        ic: Vec<[u8; 48]>,
+    ) -> CtOption<VerifyingKey> {
+        let alpha_g1 = read_g1(alpha_g1);
+        let beta_g2 = read_g2(beta_g2);
+        let gamma_g2 = read_g2(gamma_g2);
+        let delta_g2 = read_g2(delta_g2);
+        let mut is_some = alpha_g1.is_some
+            .binary_and(beta_g2.is_some)
+            .binary_and(gamma_g2.is_some)
+            .binary_and(delta_g2.is_some);
+
+        let mut ic_points = Vec::new();
+        let mut i = 0;
+        while i < ic.len() {
+            let p = read_g1(ic.get(i).unwrap());
+            is_some = is_some.binary_and(p.is_some);
+            ic_points.push(p.value);
+            i += 1;
+        }
+
+        CtOption::new(
+            VerifyingKey {
+                alpha_g1: alpha_g1.value,
+                beta_g2: beta_g2.value,
+                gamma_g2: gamma_g2.value,
+                delta_g2: delta_g2.value,
+                ic: ic_points,
+            },
+            is_some,
+        )
+    }
+}
+
+impl Proof {
+    /// Decodes a proof from the compressed encodings of its points.
+    /// Fails if any point is not a valid group element or is the point at infinity.
+    pub fn from_compressed(a: [u8; 48], b: [u8; 96], c: [u8; 48]) -> CtOption<Proof> {
+        let a = read_g1(a);
+        let b = read_g2(b);
+        let c = read_g1(c);
+        CtOption::new(
+            Proof {
+                a: a.value,
+                b: b.value,
+                c: c.value,
+            },
+            a.is_some.binary_and(b.is_some).binary_and(c.is_some),
+        )
+    }
+}
+
+/// Returns whether `proof` is a valid proof for `public_inputs` under the verifying key `vk`.
+/// The points of `vk` and `proof` are assumed to be in the right subgroups, which is guaranteed
+/// when they are obtained with `VerifyingKey::from_compressed` and `Proof::from_compressed`.
+/// Reverts if the number of public inputs doesn't match the verifying key.
+pub fn verify(vk: VerifyingKey, proof: Proof, public_inputs: Vec<Scalar>) -> bool {
+    assert(public_inputs.len() + 1 == vk.ic.len());
+
+    // acc = IC_0 + \sum public_input_i * IC_{i+1}
+    let mut ic = Vec::new();
+    let mut i = 1;
+    while i < vk.ic.len() {
+        ic.push(vk.ic.get(i).unwrap());
+        i += 1;
+    }
+    let acc = G1Projective::from(vk.ic.get(0).unwrap()) + G1Projective::multi_exp(ic, public_inputs);
+
+    // Verify: e(A, B) == e(alpha, beta) * e(acc, gamma) * e(C, delta),
+    // which is checked as e(A, B) * e(-alpha, beta) * e(-acc, gamma) * e(-C, delta) == 1
+    let mut g1_points = Vec::new();
+    g1_points.push(proof.a);
+    g1_points.push(vk.alpha_g1.neg());
+    g1_points.push(G1Affine::from(acc.neg()));
+    g1_points.push(proof.c.neg());
+
+    let mut g2_points = Vec::new();
+    g2_points.push(G2Prepared::from(proof.b));
+    g2_points.push(G2Prepared::from(vk.beta_g2));
+    g2_points.push(G2Prepared::from(vk.gamma_g2));
+    g2_points.push(G2Prepared::from(vk.delta_g2));
+
+    multi_miller_loop(g1_points, g2_points).final_exponentiation() == Gt::identity()
+}
@@ -0,0 +1,72 @@
+library;
+
+use ::g1::G1Projective;
+use ::g2::G2Projective;
+use ::expand_msg::expand_message_xmd;
+use ::map_g1::{from_okm, map_to_curve_g1};
+use ::map_g2::{from_okm_fp2, map_to_curve_g2};
+use std::vec::Vec;
+
+// Hashing to G1 and G2, following the BLS12381G1_XMD:SHA-256_SSWU_RO_ and
+// BLS12381G2_XMD:SHA-256_SSWU_RO_ suites of https://www.rfc-editor.org/rfc/rfc9380.html
+// (and the _NU_ suites for the encode_to_curve variants).
+
+// Comment from zkcrypto
+/// Implements a uniform encoding from byte strings to elements of G1.
+///
+/// This function is suitable for most applications requiring a random
+/// oracle returning points in G1.
+pub fn hash_to_curve_g1(msg: Vec<u8>, dst: Vec<u8>) -> G1Projective {
+    // hash_to_field with count = 2, L = 64
+    let uniform_bytes = expand_message_xmd(msg, dst, 128);
+    let u0 = from_okm(uniform_bytes, 0);
+    let u1 = from_okm(uniform_bytes, 64);
+
+    let q0 = map_to_curve_g1(u0);
+    let q1 = map_to_curve_g1(u1);
+    (q0 + q1).clear_cofactor()
+}
+
+// Comment from zkcrypto
+/// Implements a **non-uniform** encoding from byte strings to elements of G1.
+///
+/// The distribution of its output is not uniformly random in G1: the set of
+/// possible outputs of this function is only a fraction of the points in G1, and
+/// some elements of this set are more likely to be output than others.
+pub fn encode_to_curve_g1(msg: Vec<u8>, dst: Vec<u8>) -> G1Projective {
+    // hash_to_field with count = 1, L = 64
+    let uniform_bytes = expand_message_xmd(msg, dst, 64);
+    let u = from_okm(uniform_bytes, 0);
+
+    map_to_curve_g1(u).clear_cofactor()
+}
+
+// Comment from zkcrypto
+/// Implements a uniform encoding from byte strings to elements of G2.
+///
+/// This function is suitable for most applications requiring a random
+/// oracle returning points in G2.
+pub fn hash_to_curve_g2(msg: Vec<u8>, dst: Vec<u8>) -> G2Projective {
+    // hash_to_field with count = 2, m = 2, L = 64
+    let uniform_bytes = expand_message_xmd(msg, dst, 256);
+    let u0 = from_okm_fp2(uniform_bytes, 0);
+    let u1 = from_okm_fp2(uniform_bytes, 128);
+
+    let q0 = map_to_curve_g2(u0);
+    let q1 = map_to_curve_g2(u1);
+    (q0 + q1).clear_cofactor()
+}
+
+// Comment from zkcrypto
+/// Implements a **non-uniform** encoding from byte strings to elements of G2.
+///
+/// The distribution of its output is not uniformly random in G2: the set of
+/// possible outputs of this function is only a fraction of the points in G2, and
+/// some elements of this set are more likely to be output than others.
+pub fn encode_to_curve_g2(msg: Vec<u8>, dst: Vec<u8>) -> G2Projective {
+    // hash_to_field with count = 1, m = 2, L = 64
+    let uniform_bytes = expand_message_xmd(msg, dst, 128);
+    let u = from_okm_fp2(uniform_bytes, 0);
+
+    map_to_curve_g2(u).clear_cofactor()
+}
@@ -0,0 +1,177 @@
+library;
+
+use ::scalar::{Scalar, R2};
+use ::g1::{G1Affine, G1Projective, FROM_AFF, FROM_PROJ};
+use ::g2::{G2Affine, G2Projective, FROM_AFF2, FROM_PROJ2};
+use ::pairings::{G2Prepared, Gt, multi_miller_loop};
+use ::expand_msg::{sha256_bytes, push_word};
+use std::{assert::assert, vec::Vec};
+
+// Verification of KZG proofs, as used in EIP-4844.
+// Follows the verify_kzg_proof and verify_kzg_proof_batch functions of
+// https://github.com/ethereum/c-kzg-4844, and thereby the consensus specs at
+// https://github.com/ethereum/consensus-specs/blob/dev/specs/deneb/polynomial-commitments.md
+
+// Domain separator for the random challenge used in batch verification
+const RANDOM_CHALLENGE_KZG_BATCH_DOMAIN: [u8; 16] = [
+    0x52, 0x43, 0x4b, 0x5a, 0x47, 0x42, 0x41, 0x54, // "RCKZGBAT"
+    0x43, 0x48, 0x5f, 0x5f, 0x5f, 0x56, 0x31, 0x5f, // "CH___V1_"
+];
+
+// The degree of the committed polynomials, which is the number of field elements in a blob
+const FIELD_ELEMENTS_PER_BLOB: u64 = 4096;
+
+// Decodes a compressed G1 point, reverting if it is not a valid point of the q-order subgroup.
+// As in c-kzg, the point at infinity is allowed.
+fn decode_g1(bytes: [u8; 48]) -> G1Affine {
+    G1Affine::from_compressed(bytes).unwrap()
+}
+
+// Decodes a compressed G2 point, reverting if it is not a valid point of the q-order subgroup.
+fn decode_g2(bytes: [u8; 96]) -> G2Affine {
+    G2Affine::from_compressed(bytes).unwrap()
+}
+
+// returns whether e(a1, a2) * e(b1, b2) == 1
+fn pairings_check(a1: G1Projective, a2: G2Projective, b1: G1Projective, b2: G2Projective) -> bool {
+    let mut g1_points = Vec::new();
+    g1_points.push(G1Affine::from(a1));
+    g1_points.push(G1Affine::from(b1));
+
+    let mut g2_points = Vec::new();
+    g2_points.push(G2Prepared::from(G2Affine::from(a2)));
+    g2_points.push(G2Prepared::from(G2Affine::from(b2)));
+
+    multi_miller_loop(g1_points, g2_points).final_exponentiation() == Gt::identity()
+}
+
+/// Verifies a KZG proof claiming that the polynomial in `commitment` evaluates to `y` at `z`.
+/// `setup_g2` is [τ]G2, the second G2 point of the trusted setup.
+/// All points are given compressed. Reverts if one of them is not a valid point of the q-order subgroup.
+pub fn verify_kzg_proof(
+    commitment: [u8; 48],
+    z: Scalar,
+    y: Scalar,
+    proof: [u8; 48],
+    setup_g2: [u8; 96],
+) -> bool {
+    let commitment = decode_g1(commitment);
+    let proof = decode_g1(proof);
+    let setup_g2 = decode_g2(setup_g2);
+
+    // [τ - z]G2
+    let x_minus_z = G2Projective::from(setup_g2) - G2Projective::generator().mul(z);
+    // C - [y]G1
+    let p_minus_y = G1Projective::from(commitment) - G1Projective::generator().mul(y);
+
+    // Verify: e(C - [y]G1, G2) == e(proof, [τ - z]G2)
+    pairings_check(p_minus_y, G2Projective::generator().neg(), G1Projective::from(proof), x_minus_z)
+}
+
+// appends the 48 bytes of the compressed point `p` to `bytes`
+fn push_g1(ref mut bytes: Vec<u8>, p: G1Affine) {
+    let p_bytes = p.to_compressed();
+    let mut i = 0;
+    while i < 48 {
+        bytes.push(p_bytes[i]);
+        i += 1;
+    }
+}
+
+// appends the 32 bytes of `s` to `bytes`, in big-endian order
+fn push_scalar(ref mut bytes: Vec<u8>, s: Scalar) {
+    // Take the scalar out of Montgomery form
+    let s = Scalar::montgomery_reduce(s.ls[0], s.ls[1], s.ls[2], s.ls[3], 0, 0, 0, 0);
+    push_word(bytes, s.ls[3]);
+    push_word(bytes, s.ls[2]);
+    push_word(bytes, s.ls[1]);
+    push_word(bytes, s.ls[0]);
+}
+
+// returns the random challenge r for batch verification, by hashing all inputs
+fn compute_challenge(
+    commitments: Vec<G1Affine>,
+    zs: Vec<Scalar>,
+    ys: Vec<Scalar>,
+    proofs: Vec<G1Affine>,
+) -> Scalar {
+    let n = commitments.len();
+
+    // bytes = domain || degree || n || (commitment_i || z_i || y_i || proof_i)*
+    let mut bytes = Vec::new();
+    let mut i = 0;
+    while i < 16 {
+        bytes.push(RANDOM_CHALLENGE_KZG_BATCH_DOMAIN[i]);
+        i += 1;
+    }
+    push_word(bytes, FIELD_ELEMENTS_PER_BLOB);
+    push_word(bytes, n);
+
+    let mut i = 0;
+    while i < n {
+        push_g1(bytes, commitments.get(i).unwrap());
+        push_scalar(bytes, zs.get(i).unwrap());
+        push_scalar(bytes, ys.get(i).unwrap());
+        push_g1(bytes, proofs.get(i).unwrap());
+        i += 1;
+    }
+
+    // Interpret the hash as a big-endian integer and reduce it mod q.
+    // Multiplying by R2 gives the Montgomery form of the reduced value, since the
+    // hash is smaller than 2^256.
+    let digest = sha256_bytes(bytes);
+    let words = asm(r1: digest) { r1: (u64, u64, u64, u64) };
+    Scalar {
+        ls: [words.3, words.2, words.1, words.0],
+    } * R2
+}
+
+/// Verifies `n` KZG proofs at once, using a random linear combination of the pairing checks.
+/// The i-th proof claims that the polynomial in `commitments[i]` evaluates to `ys[i]` at `zs[i]`.
+/// `setup_g2` is [τ]G2, the second G2 point of the trusted setup.
+/// All points are given compressed. Reverts if one of them is not a valid point of the q-order subgroup.
+pub fn verify_kzg_proof_batch(
+    commitment_bytes: Vec<[u8; 48]>,
+    zs: Vec<Scalar>,
+    ys: Vec<Scalar>,
+    proof_bytes: Vec<[u8; 48]>,
+    setup_g2: [u8; 96],
+) -> bool {
+    let n = commitment_bytes.len();
+    assert(zs.len() == n && ys.len() == n && proof_bytes.len() == n);
+    let setup_g2 = decode_g2(setup_g2);
+    if n == 0 {
+        return true;
+    }
+
+    let mut commitments = Vec::new();
+    let mut proofs = Vec::new();
+    let mut i = 0;
+    while i < n {
+        commitments.push(decode_g1(commitment_bytes.get(i).unwrap()));
+        proofs.push(decode_g1(proof_bytes.get(i).unwrap()));
+        i += 1;
+    }
+
+    let r = compute_challenge(commitments, zs, ys, proofs);
+
+    // proof_lincomb = \sum r^i * proof_i
+    // rhs = \sum r^i * (C_i - [y_i]G1 + z_i * proof_i)
+    let mut proof_lincomb = G1Projective::identity();
+    let mut rhs = G1Projective::identity();
+    let mut r_power = Scalar::one();
+    let mut i = 0;
+    while i < n {
+        let proof = G1Projective::from(proofs.get(i).unwrap());
+        let c_minus_y = G1Projective::from(commitments.get(i).unwrap()) - G1Projective::generator().mul(ys.get(i).unwrap());
+
+        proof_lincomb = proof_lincomb + proof.mul(r_power);
+        rhs = rhs + (c_minus_y + proof.mul(zs.get(i).unwrap())).mul(r_power);
+
+        r_power = r_power * r;
+        i += 1;
+    }
+
+    // Verify: e(proof_lincomb, [τ]G2) == e(rhs, G2)
+    pairings_check(proof_lincomb, G2Projective::from(setup_g2).neg(), rhs, G2Projective::generator())
+}
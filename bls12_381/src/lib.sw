@@ -1,7 +1,20 @@
-library bls12_381;
+library;
 
-dep fp;
-dep fp2;
-dep fp6;
-dep scalar;
-dep g1;
\ No newline at end of file
+mod util;
+mod fp;
+mod fp2;
+mod fp6;
+mod scalar;
+mod g1;
+mod g2;
+mod expand_msg;
+mod map_g1;
+mod map_g2;
+mod hash_to_curve;
+mod fp12;
+mod pairings;
+mod kzg;
+mod groth16;
+mod ntt;
+mod poseidon_constants;
+mod poseidon;
\ No newline at end of file
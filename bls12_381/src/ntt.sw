@@ -0,0 +1,311 @@
+library;
+
+use ::scalar::{Scalar, ROOT_OF_UNITY, S};
+use std::{assert::assert, vec::Vec};
+
+// Radix-2 number theoretic transform (NTT) over the BLS12-381 scalar field.
+// Follows Radix2EvaluationDomain of arkworks (https://github.com/arkworks-rs/algebra),
+// so for the same domain size the roots of unity, and thereby the evaluations, match.
+
+/// A multiplicative subgroup of the scalar field of power-of-two order,
+/// over which polynomials can be evaluated and interpolated with the NTT.
+pub struct EvaluationDomain {
+    // The number of elements in the domain, a power of two
+    size: u64,
+    // log_2(size)
+    log_size: u32,
+    // A primitive size-th root of unity, which generates the domain
+    generator: Scalar,
+    // The inverse of the generator
+    generator_inv: Scalar,
+    // The inverse of size, as a field element
+    size_inv: Scalar,
+}
+
+// returns the lowest `log_n` bits of `i` in reversed order
+fn bit_reverse(i: u64, log_n: u32) -> u64 {
+    let mut res = 0;
+    let mut i = i;
+    let mut j = 0;
+    while j < log_n {
+        res = (res << 1) | (i & 1);
+        i = i >> 1;
+        j += 1;
+    }
+    res
+}
+
+// returns the evaluations of the polynomial with coefficients `a` at the powers of `omega`,
+// using the iterative Cooley-Tukey algorithm. `omega` must be a primitive n-th root of unity,
+// where n = a.len() = 2^log_n.
+fn serial_fft(a: Vec<Scalar>, omega: Scalar, log_n: u32) -> Vec<Scalar> {
+    let n = a.len();
+
+    let mut values = Vec::new();
+    let mut i = 0;
+    while i < n {
+        values.push(a.get(bit_reverse(i, log_n)).unwrap());
+        i += 1;
+    }
+
+    // m is half the size of the butterflies in the current stage
+    let mut m = 1;
+    while m < n {
+        // w_m is a primitive (2m)-th root of unity
+        let w_m = omega.pow_vartime([n / (2 * m), 0, 0, 0]);
+
+        let mut next = Vec::new();
+        let mut k = 0;
+        while k < n {
+            // t_j = w_m^j * values[k + j + m]
+            let mut t = Vec::new();
+            let mut w = Scalar::one();
+            let mut j = 0;
+            while j < m {
+                t.push(values.get(k + j + m).unwrap() * w);
+                w = w * w_m;
+                j += 1;
+            }
+
+            let mut j = 0;
+            while j < m {
+                next.push(values.get(k + j).unwrap() + t.get(j).unwrap());
+                j += 1;
+            }
+            let mut j = 0;
+            while j < m {
+                next.push(values.get(k + j).unwrap() - t.get(j).unwrap());
+                j += 1;
+            }
+            k += 2 * m;
+        }
+
+        values = next;
+        m = 2 * m;
+    }
+    values
+}
+
+// returns the inverses of all elements in `v` using Montgomery's trick,
+// which needs a single inversion. Reverts if any element is zero.
+fn batch_invert(v: Vec<Scalar>) -> Vec<Scalar> {
+    let n = v.len();
+
+    // prefix[i] = v[0] * ... * v[i]
+    let mut prefix = Vec::new();
+    let mut acc = Scalar::one();
+    let mut i = 0;
+    while i < n {
+        acc = acc * v.get(i).unwrap();
+        prefix.push(acc);
+        i += 1;
+    }
+
+    // Walk backwards, so the inverses are computed in reverse order
+    let mut inv = acc.invert().unwrap();
+    let mut reversed = Vec::new();
+    let mut i = n;
+    while i > 1 {
+        i -= 1;
+        reversed.push(inv * prefix.get(i - 1).unwrap());
+        inv = inv * v.get(i).unwrap();
+    }
+    if n > 0 {
+        reversed.push(inv);
+    }
+
+    let mut res = Vec::new();
+    let mut i = n;
+    while i > 0 {
+        i -= 1;
+        res.push(reversed.get(i).unwrap());
+    }
+    res
+}
+
+impl EvaluationDomain {
+    /// Returns the domain of the given size. Reverts if `size` is not a power of two,
+    /// or larger than 2^32, the largest power of two dividing q - 1.
+    pub fn new(size: u64) -> EvaluationDomain {
+        assert(size > 0 && (size & (size - 1)) == 0);
+        let mut log_size: u32 = 0;
+        let mut m = 1;
+        while m < size {
+            m = m << 1;
+            log_size += 1;
+        }
+        assert(log_size <= S);
+
+        // ROOT_OF_UNITY is a primitive 2^S-th root of unity,
+        // so squaring it S - log_size times gives a primitive size-th root of unity
+        let mut generator = ROOT_OF_UNITY;
+        let mut i = log_size;
+        while i < S {
+            generator = generator.square();
+            i += 1;
+        }
+
+        EvaluationDomain {
+            size: size,
+            log_size: log_size,
+            generator: generator,
+            generator_inv: generator.invert().unwrap(),
+            size_inv: Scalar::from(size).invert().unwrap(),
+        }
+    }
+}
+
+impl EvaluationDomain {
+    // This goes in a separate impl, because if we use previously defined functions in EvaluationDomain impl,
+    // Sway will not recognize them from inside the same impl
+
+    /// Returns the i-th element of the domain, generator^i.
+    pub fn element(self, i: u64) -> Scalar {
+        self.generator.pow_vartime([i, 0, 0, 0])
+    }
+
+    /// Evaluates the polynomial with coefficients `coeffs` over the domain.
+    /// Reverts if the number of coefficients doesn't equal the domain size.
+    pub fn fft(self, coeffs: Vec<Scalar>) -> Vec<Scalar> {
+        assert(coeffs.len() == self.size);
+        serial_fft(coeffs, self.generator, self.log_size)
+    }
+
+    /// Interpolates the polynomial with evaluations `evals` over the domain, and returns its coefficients.
+    /// Reverts if the number of evaluations doesn't equal the domain size.
+    pub fn ifft(self, evals: Vec<Scalar>) -> Vec<Scalar> {
+        assert(evals.len() == self.size);
+        let values = serial_fft(evals, self.generator_inv, self.log_size);
+
+        let mut res = Vec::new();
+        let mut i = 0;
+        while i < self.size {
+            res.push(values.get(i).unwrap() * self.size_inv);
+            i += 1;
+        }
+        res
+    }
+
+    /// Evaluates the vanishing polynomial of the domain, Z(X) = X^size - 1, at `tau`.
+    pub fn evaluate_vanishing_polynomial(self, tau: Scalar) -> Scalar {
+        tau.pow_vartime([self.size, 0, 0, 0]) - Scalar::one()
+    }
+}
+
+impl EvaluationDomain {
+    // This goes in a separate impl, because if we use previously defined functions in EvaluationDomain impl,
+    // Sway will not recognize them from inside the same impl
+
+    /// Evaluates all Lagrange basis polynomials of the domain at `tau`.
+    /// For a polynomial P of degree < size, P(tau) = \sum L_i(tau) * P(generator^i).
+    pub fn evaluate_all_lagrange_coefficients(self, tau: Scalar) -> Vec<Scalar> {
+        // L_i(tau) = Z(tau) * generator^i / (size * (tau - generator^i))
+        let z_at_tau = self.evaluate_vanishing_polynomial(tau);
+
+        let mut res = Vec::new();
+        if z_at_tau == Scalar::zero() {
+            // tau is in the domain, so L_i(tau) is 1 for tau = generator^i and 0 elsewhere
+            let mut omega_i = Scalar::one();
+            let mut i = 0;
+            while i < self.size {
+                if omega_i == tau {
+                    res.push(Scalar::one());
+                } else {
+                    res.push(Scalar::zero());
+                }
+                omega_i = omega_i * self.generator;
+                i += 1;
+            }
+            return res;
+        }
+
+        // Compute the inverses l_i * (tau - generator^i), where l_i = size / (Z(tau) * generator^i),
+        // and batch invert them
+        let mut l_i = z_at_tau.invert().unwrap() * Scalar::from(self.size);
+        let mut omega_i = Scalar::one();
+        let mut i = 0;
+        while i < self.size {
+            res.push(l_i * (tau - omega_i));
+            l_i = l_i * self.generator_inv;
+            omega_i = omega_i * self.generator;
+            i += 1;
+        }
+        batch_invert(res)
+    }
+}
+
+// Transforms on fixed-size arrays. Sway doesn't have const generics, so there is a function for each size.
+
+/// Evaluates the polynomial with coefficients `coeffs` over the domain of size 8.
+pub fn fft_8(coeffs: [Scalar; 8]) -> [Scalar; 8] {
+    to_array_8(EvaluationDomain::new(8).fft(to_vec_8(coeffs)))
+}
+
+/// Interpolates the polynomial with evaluations `evals` over the domain of size 8, and returns its coefficients.
+pub fn ifft_8(evals: [Scalar; 8]) -> [Scalar; 8] {
+    to_array_8(EvaluationDomain::new(8).ifft(to_vec_8(evals)))
+}
+
+/// Evaluates the polynomial with coefficients `coeffs` over the domain of size 16.
+pub fn fft_16(coeffs: [Scalar; 16]) -> [Scalar; 16] {
+    to_array_16(EvaluationDomain::new(16).fft(to_vec_16(coeffs)))
+}
+
+/// Interpolates the polynomial with evaluations `evals` over the domain of size 16, and returns its coefficients.
+pub fn ifft_16(evals: [Scalar; 16]) -> [Scalar; 16] {
+    to_array_16(EvaluationDomain::new(16).ifft(to_vec_16(evals)))
+}
+
+fn to_vec_8(a: [Scalar; 8]) -> Vec<Scalar> {
+    let mut res = Vec::new();
+    let mut i = 0;
+    while i < 8 {
+        res.push(a[i]);
+        i += 1;
+    }
+    res
+}
+
+fn to_array_8(v: Vec<Scalar>) -> [Scalar; 8] {
+    [
+        v.get(0).unwrap(),
+        v.get(1).unwrap(),
+        v.get(2).unwrap(),
+        v.get(3).unwrap(),
+        v.get(4).unwrap(),
+        v.get(5).unwrap(),
+        v.get(6).unwrap(),
+        v.get(7).unwrap(),
+    ]
+}
+
+fn to_vec_16(a: [Scalar; 16]) -> Vec<Scalar> {
+    let mut res = Vec::new();
+    let mut i = 0;
+    while i < 16 {
+        res.push(a[i]);
+        i += 1;
+    }
+    res
+}
+
+fn to_array_16(v: Vec<Scalar>) -> [Scalar; 16] {
+    [
+        v.get(0).unwrap(),
+        v.get(1).unwrap(),
+        v.get(2).unwrap(),
+        v.get(3).unwrap(),
+        v.get(4).unwrap(),
+        v.get(5).unwrap(),
+        v.get(6).unwrap(),
+        v.get(7).unwrap(),
+        v.get(8).unwrap(),
+        v.get(9).unwrap(),
+        v.get(10).unwrap(),
+        v.get(11).unwrap(),
+        v.get(12).unwrap(),
+        v.get(13).unwrap(),
+        v.get(14).unwrap(),
+        v.get(15).unwrap(),
+    ]
+}
@@ -0,0 +1,385 @@
+library;
+
+use ::fp2::Fp2;
+use ::fp6::Fp6;
+use ::fp12::Fp12;
+use ::g1::G1Affine;
+use ::g2::{G2Affine, G2Projective, FROM_AFF2};
+use ::util::BLS_X;
+use utils::choice::{Choice, ConditionallySelectable, ConstantTimeEq};
+use core::ops::{Eq, Add, Subtract, Multiply};
+use std::{assert::assert, vec::Vec};
+
+// Comment from zkcrypto
+/// Represents results of a Miller loop, one of the most expensive portions
+/// of the pairing function. `MillerLoopResult`s cannot be compared with each
+/// other until `.final_exponentiation()` is called, which is also expensive.
+pub struct MillerLoopResult {
+    f: Fp12,
+}
+
+// Comment from zkcrypto
+/// This is an element of $\mathbb{G}_T$, the target group of the pairing function. As with
+/// $\mathbb{G}_1$ and $\mathbb{G}_2$ this group has order $q$.
+///
+/// Typically, $\mathbb{G}_T$ is written multiplicatively but we will write it additively to
+/// keep code and abstractions consistent.
+pub struct Gt {
+    f: Fp12,
+}
+
+impl ConstantTimeEq for Gt {
+    // returns (self == other), as a choice
+    fn ct_eq(self, other: Self) -> Choice {
+        self.f.ct_eq(other.f)
+    }
+}
+
+impl Eq for Gt {
+    fn eq(self, other: Self) -> bool {
+        self.ct_eq(other).unwrap_as_bool()
+    }
+}
+
+impl Gt {
+    /// Returns the group identity, which is $1$.
+    pub fn identity() -> Gt {
+        Gt {
+            f: Fp12::one(),
+        }
+    }
+}
+
+fn fp4_square(a: Fp2, b: Fp2) -> (Fp2, Fp2) {
+    let t0 = a.square();
+    let t1 = b.square();
+    let mut t2 = t1.mul_by_nonresidue();
+    let c0 = t2 + t0;
+    t2 = a + b;
+    t2 = t2.square();
+    t2 = t2 - t0;
+    let c1 = t2 - t1;
+
+    (c0, c1)
+}
+
+// Comment from zkcrypto
+// Adaptation of Algorithm 5.5.4, Guide to Pairing-Based Cryptography
+// Faster Squaring in the Cyclotomic Subgroup of Sixth Degree Extensions
+// https://eprint.iacr.org/2009/565.pdf
+fn cyclotomic_square(f: Fp12) -> Fp12 {
+    let mut z0 = f.c0.c0;
+    let mut z4 = f.c0.c1;
+    let mut z3 = f.c0.c2;
+    let mut z2 = f.c1.c0;
+    let mut z1 = f.c1.c1;
+    let mut z5 = f.c1.c2;
+
+    let (t0, t1) = fp4_square(z0, z1);
+
+    // For A
+    z0 = t0 - z0;
+    z0 = z0 + z0 + t0;
+
+    z1 = t1 + z1;
+    z1 = z1 + z1 + t1;
+
+    let (t0, t1) = fp4_square(z2, z3);
+    let (t2, t3) = fp4_square(z4, z5);
+
+    // For C
+    z4 = t0 - z4;
+    z4 = z4 + z4 + t0;
+
+    z5 = t1 + z5;
+    z5 = z5 + z5 + t1;
+
+    // For B
+    let t0 = t3.mul_by_nonresidue();
+    z2 = t0 + z2;
+    z2 = z2 + z2 + t0;
+
+    z3 = t2 - z3;
+    z3 = z3 + z3 + t2;
+
+    Fp12 {
+        c0: Fp6 {
+            c0: z0,
+            c1: z4,
+            c2: z3,
+        },
+        c1: Fp6 {
+            c0: z2,
+            c1: z1,
+            c2: z5,
+        },
+    }
+}
+
+// returns f^BLS_X, using cyclotomic squarings
+fn cyclotomic_exp(f: Fp12) -> Fp12 {
+    let mut tmp = Fp12::one();
+    let mut found_one = false;
+    let mut b = 64;
+    while b > 0 {
+        b -= 1;
+        let i = ((BLS_X >> b) & 1) == 1;
+        if found_one {
+            tmp = cyclotomic_square(tmp);
+        } else {
+            found_one = i;
+        }
+
+        if i {
+            tmp = tmp * f;
+        }
+    }
+
+    // BLS_X is negative
+    tmp.conjugate()
+}
+
+impl MillerLoopResult {
+    // Comment from zkcrypto
+    /// This performs a "final exponentiation" routine to convert the result
+    /// of a Miller loop into an element of `Gt` with help of efficient squaring
+    /// operation in the so-called `cyclotomic subgroup` of `Fq6` so that
+    /// it can be compared with other elements of `Gt`.
+    pub fn final_exponentiation(self) -> Gt {
+        let mut f = self.f;
+        let mut t0 = f
+            .frobenius_map()
+            .frobenius_map()
+            .frobenius_map()
+            .frobenius_map()
+            .frobenius_map()
+            .frobenius_map();
+        // The result of a Miller loop is never zero, so f is invertible
+        let mut t1 = f.invert().unwrap();
+        let mut t2 = t0 * t1;
+        t1 = t2;
+        t2 = t2.frobenius_map().frobenius_map();
+        t2 = t2 * t1;
+        t1 = cyclotomic_square(t2).conjugate();
+        let mut t3 = cyclotomic_exp(t2);
+        let mut t4 = cyclotomic_square(t3);
+        let mut t5 = t1 * t3;
+        t1 = cyclotomic_exp(t5);
+        t0 = cyclotomic_exp(t1);
+        let mut t6 = cyclotomic_exp(t0);
+        t6 = t6 * t4;
+        t4 = cyclotomic_exp(t6);
+        t5 = t5.conjugate();
+        t4 = t4 * (t5 * t2);
+        t5 = t2.conjugate();
+        t1 = t1 * t2;
+        t1 = t1.frobenius_map().frobenius_map().frobenius_map();
+        t6 = t6 * t5;
+        t6 = t6.frobenius_map();
+        t3 = t3 * t0;
+        t3 = t3.frobenius_map().frobenius_map();
+        t3 = t3 * t1;
+        t3 = t3 * t6;
+        f = t3 * t4;
+
+        Gt {
+            f: f,
+        }
+    }
+}
+
+// Comment from zkcrypto
+/// This structure contains cached computations pertaining to a $\mathbb{G}_2$
+/// element as part of the pairing function (specifically, the Miller loop) and
+/// so should be computed whenever a $\mathbb{G}_2$ element is being used in
+/// multiple pairings or is otherwise known in advance.
+pub struct G2Prepared {
+    infinity: Choice,
+    coeffs: Vec<(Fp2, Fp2, Fp2)>,
+}
+
+// Adaptation of Algorithm 26, https://eprint.iacr.org/2010/354.pdf
+fn doubling_step(ref mut r: G2Projective) -> (Fp2, Fp2, Fp2) {
+    let tmp0 = r.x.square();
+    let tmp1 = r.y.square();
+    let tmp2 = tmp1.square();
+    let tmp3 = (tmp1 + r.x).square() - tmp0 - tmp2;
+    let tmp3 = tmp3 + tmp3;
+    let tmp4 = tmp0 + tmp0 + tmp0;
+    let tmp6 = r.x + tmp4;
+    let tmp5 = tmp4.square();
+    let zsquared = r.z.square();
+    r.x = tmp5 - tmp3 - tmp3;
+    r.z = (r.z + r.y).square() - tmp1 - zsquared;
+    r.y = (tmp3 - r.x) * tmp4;
+    let tmp2 = tmp2 + tmp2;
+    let tmp2 = tmp2 + tmp2;
+    let tmp2 = tmp2 + tmp2;
+    r.y = r.y - tmp2;
+    let tmp3 = tmp4 * zsquared;
+    let tmp3 = tmp3 + tmp3;
+    let tmp3 = tmp3.neg();
+    let tmp6 = tmp6.square() - tmp0 - tmp5;
+    let tmp1 = tmp1 + tmp1;
+    let tmp1 = tmp1 + tmp1;
+    let tmp6 = tmp6 - tmp1;
+    let tmp0 = r.z * zsquared;
+    let tmp0 = tmp0 + tmp0;
+
+    (tmp0, tmp3, tmp6)
+}
+
+// Adaptation of Algorithm 27, https://eprint.iacr.org/2010/354.pdf
+fn addition_step(ref mut r: G2Projective, q: G2Affine) -> (Fp2, Fp2, Fp2) {
+    let zsquared = r.z.square();
+    let ysquared = q.y.square();
+    let t0 = zsquared * q.x;
+    let t1 = ((q.y + r.z).square() - ysquared - zsquared) * zsquared;
+    let t2 = t0 - r.x;
+    let t3 = t2.square();
+    let t4 = t3 + t3;
+    let t4 = t4 + t4;
+    let t5 = t4 * t2;
+    let t6 = t1 - r.y - r.y;
+    let t9 = t6 * q.x;
+    let t7 = t4 * r.x;
+    r.x = t6.square() - t5 - t7 - t7;
+    r.z = (r.z + t2).square() - zsquared - t3;
+    let t10 = q.y + r.z;
+    let t8 = (t7 - r.x) * t6;
+    let t0 = r.y * t5;
+    let t0 = t0 + t0;
+    r.y = t8 - t0;
+    let t10 = t10.square() - ysquared;
+    let ztsquared = r.z.square();
+    let t10 = t10 - ztsquared;
+    let t9 = t9 + t9 - t10;
+    let t10 = r.z + r.z;
+    let t6 = t6.neg();
+    let t1 = t6 + t6;
+
+    (t10, t1, t9)
+}
+
+// evaluates the line with coefficients `coeffs` at `p` and multiplies it into `f`
+fn ell(f: Fp12, coeffs: (Fp2, Fp2, Fp2), p: G1Affine) -> Fp12 {
+    let c0 = Fp2 {
+        c0: coeffs.0.c0 * p.y,
+        c1: coeffs.0.c1 * p.y,
+    };
+    let c1 = Fp2 {
+        c0: coeffs.1.c0 * p.x,
+        c1: coeffs.1.c1 * p.x,
+    };
+
+    f.mul_by_014(coeffs.2, c1, c0)
+}
+
+impl G2Prepared {
+    // in the zkcrypto repo this is implemented as trait From<G2Affine>
+    // returns the line coefficients of all doubling and addition steps of the Miller loop for q
+    pub fn from(q: G2Affine) -> G2Prepared {
+        let is_identity = q.is_identity();
+        let q = G2Affine::conditional_select(G2Affine::generator(), q, is_identity);
+
+        let mut cur = G2Projective::from(q);
+        let mut coeffs = Vec::new();
+
+        // Comment from zkcrypto
+        // The Miller loop runs over the bits of BLS_X, skipping the leading one.
+        let mut found_one = false;
+        let mut b = 64;
+        while b > 0 {
+            b -= 1;
+            let i = (((BLS_X >> 1) >> b) & 1) == 1;
+            if !found_one {
+                found_one = i;
+            } else {
+                coeffs.push(doubling_step(cur));
+
+                if i {
+                    coeffs.push(addition_step(cur, q));
+                }
+            }
+        }
+
+        coeffs.push(doubling_step(cur));
+
+        assert(coeffs.len() == 68);
+
+        G2Prepared {
+            infinity: is_identity,
+            coeffs: coeffs,
+        }
+    }
+}
+
+// Comment from zkcrypto
+/// Computes $$\sum_{i=1}^n \textbf{ML}(a_i, b_i)$$ given a series of terms
+/// $$(a_1, b_1), (a_2, b_2), ..., (a_n, b_n).$$
+// The terms are given as two vectors `a` and `b` of the same length.
+pub fn multi_miller_loop(a: Vec<G1Affine>, b: Vec<G2Prepared>) -> MillerLoopResult {
+    assert(a.len() == b.len());
+
+    let mut f = Fp12::one();
+    let mut index = 0;
+
+    let mut found_one = false;
+    let mut bit = 64;
+    while bit > 0 {
+        bit -= 1;
+        let i = (((BLS_X >> 1) >> bit) & 1) == 1;
+        if !found_one {
+            found_one = i;
+        } else {
+            // doubling step
+            f = ell_all(f, a, b, index);
+            index += 1;
+
+            if i {
+                // addition step
+                f = ell_all(f, a, b, index);
+                index += 1;
+            }
+
+            f = f.square();
+        }
+    }
+
+    // final doubling step
+    f = ell_all(f, a, b, index);
+
+    // BLS_X is negative
+    f = f.conjugate();
+
+    MillerLoopResult {
+        f: f,
+    }
+}
+
+// multiplies the lines at position `index` of all terms into `f`, skipping terms with an identity element
+fn ell_all(f: Fp12, a: Vec<G1Affine>, b: Vec<G2Prepared>, index: u64) -> Fp12 {
+    let mut f = f;
+    let mut j = 0;
+    while j < a.len() {
+        let p = a.get(j).unwrap();
+        let q = b.get(j).unwrap();
+        let either_identity = p.is_identity().binary_or(q.infinity);
+
+        let new_f = ell(f, q.coeffs.get(index).unwrap(), p);
+        f = Fp12::conditional_select(f, new_f, either_identity);
+        j += 1;
+    }
+    f
+}
+
+// Comment from zkcrypto
+/// Invoke the pairing function without the use of precomputation and other optimizations.
+pub fn pairing(p: G1Affine, q: G2Affine) -> Gt {
+    let mut a = Vec::new();
+    a.push(p);
+    let mut b = Vec::new();
+    b.push(G2Prepared::from(q));
+
+    multi_miller_loop(a, b).final_exponentiation()
+}
@@ -0,0 +1,109 @@
+library;
+
+use ::scalar::Scalar;
+use ::poseidon_constants::{MDS_3, MDS_5, ROUND_CONSTANTS_3, ROUND_CONSTANTS_5};
+use std::{assert::assert, vec::Vec};
+
+// The Poseidon hash function over the BLS12-381 scalar field, https://eprint.iacr.org/2019/458.pdf
+// Supported widths are 3 and 5, hashing 2 resp. 4 field elements. The parameters and the
+// domain separation follow neptune (https://github.com/lurk-lab/neptune), so hashes match
+// neptune's Poseidon with HashType::MerkleTree and standard strength.
+
+/// The number of full rounds, half of which are done before the partial rounds and half after
+pub const FULL_ROUNDS: u64 = 8;
+/// The number of partial rounds for width 3
+pub const PARTIAL_ROUNDS_3: u64 = 55;
+/// The number of partial rounds for width 5
+pub const PARTIAL_ROUNDS_5: u64 = 56;
+
+// returns x^5
+fn sbox(x: Scalar) -> Scalar {
+    x.square().square() * x
+}
+
+// returns the i-th round constant for the given width
+fn round_constant(width: u64, i: u64) -> Scalar {
+    if width == 3 {
+        ROUND_CONSTANTS_3[i]
+    } else {
+        ROUND_CONSTANTS_5[i]
+    }
+}
+
+// returns the entry at row i and column j of the MDS matrix for the given width
+fn mds(width: u64, i: u64, j: u64) -> Scalar {
+    if width == 3 {
+        MDS_3[i][j]
+    } else {
+        MDS_5[i][j]
+    }
+}
+
+/// Applies the Poseidon permutation to `state`, whose length is the width.
+/// Reverts if the width is not 3 or 5.
+pub fn permutation(state: Vec<Scalar>) -> Vec<Scalar> {
+    let width = state.len();
+    assert(width == 3 || width == 5);
+    let partial_rounds = if width == 3 {
+        PARTIAL_ROUNDS_3
+    } else {
+        PARTIAL_ROUNDS_5
+    };
+    let half_full_rounds = FULL_ROUNDS / 2;
+
+    let mut state = state;
+    let mut round = 0;
+    while round < FULL_ROUNDS + partial_rounds {
+        let is_full_round = round < half_full_rounds || round >= half_full_rounds + partial_rounds;
+
+        // Add the round constants and apply the S-box, to all elements in a full round
+        // and only to the first element in a partial round
+        let mut after_sbox = Vec::new();
+        let mut i = 0;
+        while i < width {
+            let x = state.get(i).unwrap() + round_constant(width, round * width + i);
+            if is_full_round || i == 0 {
+                after_sbox.push(sbox(x));
+            } else {
+                after_sbox.push(x);
+            }
+            i += 1;
+        }
+
+        // Multiply by the MDS matrix
+        let mut next = Vec::new();
+        let mut i = 0;
+        while i < width {
+            let mut acc = Scalar::zero();
+            let mut j = 0;
+            while j < width {
+                acc = acc + mds(width, i, j) * after_sbox.get(j).unwrap();
+                j += 1;
+            }
+            next.push(acc);
+            i += 1;
+        }
+
+        state = next;
+        round += 1;
+    }
+    state
+}
+
+/// Returns the Poseidon hash of `inputs`, which must consist of 2 or 4 elements.
+/// The permutation is applied to the domain tag 2^n - 1 followed by the n inputs,
+/// and the hash is the second element of the resulting state.
+pub fn hash(inputs: Vec<Scalar>) -> Scalar {
+    let n = inputs.len();
+    assert(n == 2 || n == 4);
+
+    let mut state = Vec::new();
+    state.push(Scalar::from((1 << n) - 1));
+    let mut i = 0;
+    while i < n {
+        state.push(inputs.get(i).unwrap());
+        i += 1;
+    }
+
+    permutation(state).get(1).unwrap()
+}
@@ -0,0 +1,3299 @@
+library;
+
+use ::scalar::Scalar;
+
+// Round constants and MDS matrices of Poseidon over the BLS12-381 scalar field, in Montgomery form.
+// These are the constants of neptune (https://github.com/lurk-lab/neptune) for arity 2 and 4 with
+// standard strength: the round constants are generated with the Grain LFSR as in the reference
+// implementation (https://extgit.iaik.tugraz.at/krypto/hadeshash), and the MDS matrices are the
+// Cauchy matrices with entries 1 / (i + j + width), for 0 <= i, j < width.
+
+/// Round constants for width 3, 3 for each of the 63 rounds
+pub const ROUND_CONSTANTS_3: [Scalar; 189] = [
+    Scalar{ ls: [
+        0x23e3_66d1_c822_c12d,
+        0x01c1_bdf2_0d09_026b,
+        0x5864_40d2_9d17_a3e8,
+        0x2a17_5464_246e_8941,
+    ]},
+    Scalar{ ls: [
+        0xdd35_fb45_585c_6aed,
+        0xc28b_6be8_69a2_cf82,
+        0xdbfb_4087_da06_af8e,
+        0x600f_d1b8_3639_56cb,
+    ]},
+    Scalar{ ls: [
+        0x3fa3_79d7_e2b8_bfbe,
+        0x49df_8416_5dcc_b57f,
+        0x3deb_63f1_3fe0_0e42,
+        0x6921_2d4e_6495_f368,
+    ]},
+    Scalar{ ls: [
+        0xdfb3_29fa_93d7_3614,
+        0xae7e_729e_7a11_2e47,
+        0xac7c_5d4e_22c7_b93a,
+        0x31ab_82b4_37c0_683f,
+    ]},
+    Scalar{ ls: [
+        0xe3bb_1fd0_6eea_42e6,
+        0xc131_4cab_c847_33fb,
+        0xeffa_4505_0162_d3a1,
+        0x09d3_4fe9_54d5_5775,
+    ]},
+    Scalar{ ls: [
+        0xe9d4_995b_df90_5e26,
+        0x14e0_b3b8_b79e_cf58,
+        0x45b8_73dc_863e_0fe0,
+        0x1362_3bc7_af1a_13ef,
+    ]},
+    Scalar{ ls: [
+        0x7544_41f1_57cd_0de8,
+        0x696f_bda8_ba98_8d3d,
+        0x999d_51dd_0e12_b029,
+        0x3b88_be9f_4bc2_f681,
+    ]},
+    Scalar{ ls: [
+        0xd90e_4b6a_46a7_5344,
+        0xdf32_cbfa_e12d_93e1,
+        0x535b_69da_825d_3b1d,
+        0x054e_d1a7_3dbe_78d9,
+    ]},
+    Scalar{ ls: [
+        0x18bb_92e4_0b92_b23b,
+        0xef6e_bc5a_1c17_04bc,
+        0x8186_42ca_890c_6c0e,
+        0x31d9_f7ab_a9d7_0be4,
+    ]},
+    Scalar{ ls: [
+        0x7987_37d8_6589_df99,
+        0x0a2a_8927_db68_46ce,
+        0xb7d3_a331_dca2_f63b,
+        0x5f99_d65a_d0de_df07,
+    ]},
+    Scalar{ ls: [
+        0xc9e5_c02a_5223_87a1,
+        0xb1a4_a94e_216a_857d,
+        0x9258_021b_7972_0ee4,
+        0x1ea2_b386_2ee2_c4a8,
+    ]},
+    Scalar{ ls: [
+        0xbf5b_e55d_1411_2726,
+        0x6d44_aa04_ae39_a3b2,
+        0xea19_61a9_3f50_b5de,
+        0x1ea1_f14c_e0e9_fe2d,
+    ]},
+    Scalar{ ls: [
+        0x0ae9_35e4_f19e_abe7,
+        0x9188_c5e2_adca_f81b,
+        0x81e4_1f09_45bd_fc7f,
+        0x52be_54cd_c7bf_836f,
+    ]},
+    Scalar{ ls: [
+        0xcd18_9760_5c2c_9a12,
+        0x0d8d_1d3b_d76c_038c,
+        0x785a_d735_9e90_966d,
+        0x16c6_a231_4528_8f32,
+    ]},
+    Scalar{ ls: [
+        0xfc68_1539_effa_2f4e,
+        0xa6b3_79c9_b714_d180,
+        0x4a94_63b5_fa6c_127f,
+        0x075d_50ce_9b0e_5b4a,
+    ]},
+    Scalar{ ls: [
+        0xe089_8563_02d3_6024,
+        0xecce_c8b4_f34d_7175,
+        0xd0f9_fd99_012b_2a74,
+        0x1aef_335d_5c2d_bdab,
+    ]},
+    Scalar{ ls: [
+        0xb29b_18b2_6983_4e6b,
+        0x82a3_608d_bb88_048e,
+        0xeed7_641b_7768_27e5,
+        0x0ccd_8cc5_df59_0679,
+    ]},
+    Scalar{ ls: [
+        0x7638_22d9_a2f0_a15e,
+        0x92e6_e2b8_533c_8176,
+        0x28a9_c462_f9c7_4fcc,
+        0x2538_bd4b_a89b_0444,
+    ]},
+    Scalar{ ls: [
+        0xcd20_2e8c_f3b4_ae25,
+        0x99f2_dfb7_1495_94a4,
+        0xfcaf_ddf9_78f8_6d6a,
+        0x4ab9_48b2_9bbd_a3c2,
+    ]},
+    Scalar{ ls: [
+        0x0f52_ba6e_401b_cb9b,
+        0x09aa_ffaa_93c1_ae5e,
+        0xa104_f6a0_7185_5aa4,
+        0x146d_5560_437e_3ef2,
+    ]},
+    Scalar{ ls: [
+        0x4a8d_2abd_9965_3bdb,
+        0x8cef_f37d_3682_8a56,
+        0xea29_22a2_20e7_97da,
+        0x7261_69f6_b1d9_5476,
+    ]},
+    Scalar{ ls: [
+        0x1ade_cd41_6910_17c6,
+        0xc6d6_2d3c_de72_3894,
+        0xd549_763d_ee85_c34e,
+        0x48af_336b_d23b_fc1c,
+    ]},
+    Scalar{ ls: [
+        0x3e6c_fc53_f212_75d4,
+        0xf71a_f54e_b277_fa1f,
+        0x15cb_66f3_9d29_5e55,
+        0x095c_a702_c6b2_4779,
+    ]},
+    Scalar{ ls: [
+        0x040e_5478_ab23_c7c5,
+        0x41d2_e9aa_2f89_e06e,
+        0x9748_f647_b2d5_66ea,
+        0x73ec_8f0f_83dd_c5db,
+    ]},
+    Scalar{ ls: [
+        0xee9f_a658_0d07_bb61,
+        0xf68d_e793_3416_4dff,
+        0x15c1_8956_ddd0_c827,
+        0x4fec_4e7f_5d34_7a91,
+    ]},
+    Scalar{ ls: [
+        0x601b_c782_c38f_aa89,
+        0x36db_0727_187b_d311,
+        0x1b65_1142_c859_9085,
+        0x4acd_362e_5c0a_0844,
+    ]},
+    Scalar{ ls: [
+        0x33e3_9c9c_7ed8_d3bd,
+        0xb5ef_1076_b56e_cef6,
+        0x0147_68e2_a4b4_84f0,
+        0x03af_266f_a9a6_7346,
+    ]},
+    Scalar{ ls: [
+        0xc1af_8ab2_6f3a_dc21,
+        0xfb71_7509_2804_253d,
+        0x99e9_d703_f272_5a4b,
+        0x4844_7e65_4d45_e7f5,
+    ]},
+    Scalar{ ls: [
+        0xc40c_8ba9_4e75_0011,
+        0x9c6a_3f44_befe_fac4,
+        0x28b2_db75_ae48_f527,
+        0x57c1_46fa_83b8_029f,
+    ]},
+    Scalar{ ls: [
+        0xb321_2078_4d94_4f0c,
+        0x6a65_9e63_309c_2ef5,
+        0x8430_5373_7983_183e,
+        0x6db9_bb35_4d63_2820,
+    ]},
+    Scalar{ ls: [
+        0x59a1_1526_e407_317b,
+        0x4532_5922_36b2_9811,
+        0x302a_d9be_9293_454f,
+        0x17ca_fa02_da12_ca3b,
+    ]},
+    Scalar{ ls: [
+        0x99d9_7ccc_516b_5bfa,
+        0xe6a2_2439_e251_69e9,
+        0x39be_9b32_2b19_69d5,
+        0x33b4_c7fc_d8fc_2177,
+    ]},
+    Scalar{ ls: [
+        0x0b34_ef40_348d_c838,
+        0x1820_7c1e_d90d_50bb,
+        0xd14c_f775_2a42_f2cd,
+        0x533f_a9e2_4f22_f8fe,
+    ]},
+    Scalar{ ls: [
+        0xeb45_b4bd_b906_e6aa,
+        0x4946_8376_1546_6380,
+        0xac32_23f2_d40a_9cd3,
+        0x4fd8_9011_b1f3_1413,
+    ]},
+    Scalar{ ls: [
+        0xe504_8ae2_a4d5_f902,
+        0xd901_0465_80f0_2d0f,
+        0xfc82_63b1_f81f_7ddc,
+        0x38e8_bc19_41e7_8fb9,
+    ]},
+    Scalar{ ls: [
+        0xcfef_33ab_0413_73e1,
+        0x62e7_b4cb_922a_c686,
+        0x4040_d31e_ca82_b2e2,
+        0x255d_2700_27ce_6794,
+    ]},
+    Scalar{ ls: [
+        0xcdc6_cbb4_cd28_5551,
+        0x2deb_8d00_93a2_a038,
+        0x1e8d_7222_7277_b7c0,
+        0x46bb_f6e3_5676_fa11,
+    ]},
+    Scalar{ ls: [
+        0x5693_25ca_9c9a_0483,
+        0x5ebd_c445_1ae2_f8f3,
+        0xb279_d27b_6c81_03c1,
+        0x4ede_e2c9_fe9f_966d,
+    ]},
+    Scalar{ ls: [
+        0xaa7a_1715_f42b_cfbf,
+        0x89fd_5e1f_48c1_7964,
+        0x2294_188f_2c3e_23b5,
+        0x2249_46a9_347a_0db7,
+    ]},
+    Scalar{ ls: [
+        0x5586_e84d_5c45_a400,
+        0x9c65_f5ce_2da9_5d56,
+        0x6a3d_4cfa_271e_98c1,
+        0x3fea_10a7_6e3d_cff6,
+    ]},
+    Scalar{ ls: [
+        0x54ed_9603_9e1d_c7b9,
+        0xa4e8_a055_e417_a05a,
+        0x5a39_6ba3_e504_4ee6,
+        0x53ab_d17e_904e_dd77,
+    ]},
+    Scalar{ ls: [
+        0x84a5_d6d4_a159_8af3,
+        0x9768_07be_f703_bdb9,
+        0x5359_0d23_ddaf_809e,
+        0x1d07_a298_e370_3c3f,
+    ]},
+    Scalar{ ls: [
+        0x10f5_93e5_d2b6_c5c9,
+        0x7804_e6ae_416d_18db,
+        0x6571_25a8_9970_6403,
+        0x2e53_5681_06f6_047d,
+    ]},
+    Scalar{ ls: [
+        0x8a21_d834_b140_3201,
+        0x580d_751f_846a_a9cc,
+        0xb599_3b0b_17b0_c464,
+        0x68a4_ef7f_c6fc_50e3,
+    ]},
+    Scalar{ ls: [
+        0x5441_a6d0_482e_8df9,
+        0x15ed_5c93_26d2_fe38,
+        0x9772_7393_2697_e44a,
+        0x24ef_f902_3ab2_484b,
+    ]},
+    Scalar{ ls: [
+        0xa441_1064_3978_7cc6,
+        0x66f1_f9aa_89a1_0f46,
+        0x7d02_d36d_0d08_7716,
+        0x1738_a325_cbb1_88ca,
+    ]},
+    Scalar{ ls: [
+        0x3bcc_42f6_c273_bc80,
+        0x68bc_7b10_9d04_ff93,
+        0xb787_9be8_966b_f103,
+        0x63eb_82a2_c833_997b,
+    ]},
+    Scalar{ ls: [
+        0xa969_be83_b3f7_99f8,
+        0x1341_85d2_7ae2_daa0,
+        0x4714_815b_22f3_8c96,
+        0x4c72_fbf9_0291_c9f8,
+    ]},
+    Scalar{ ls: [
+        0x69f8_311c_abbb_8ce8,
+        0x096e_716a_337e_1b35,
+        0xebdb_484a_d31c_2a25,
+        0x4423_bab1_b495_b2ce,
+    ]},
+    Scalar{ ls: [
+        0x0813_5e97_db19_a143,
+        0x4eb0_fd0a_c3f3_7aab,
+        0x54ab_6eb5_f051_66d0,
+        0x1229_fe58_4d95_c146,
+    ]},
+    Scalar{ ls: [
+        0xdaee_356e_bbb4_9026,
+        0x2dad_9eb4_dea6_ec8d,
+        0x1534_31b8_c240_68f5,
+        0x055f_3d0c_ef80_e01b,
+    ]},
+    Scalar{ ls: [
+        0x85ef_4c05_5ac6_b1eb,
+        0x920d_53c7_4ca8_74d9,
+        0x6401_161f_2f5c_1d4e,
+        0x5262_d519_46b1_4055,
+    ]},
+    Scalar{ ls: [
+        0x1f35_6471_8e6b_8aaa,
+        0xcec9_1edd_10a6_f0d0,
+        0x85af_d964_689d_28f9,
+        0x2e48_c1d0_c20a_bdd1,
+    ]},
+    Scalar{ ls: [
+        0xb10a_11f5_15d7_de2e,
+        0xd13f_c7df_1500_da9c,
+        0x950f_8345_d2a4_ae13,
+        0x3b50_8ee6_1daf_0540,
+    ]},
+    Scalar{ ls: [
+        0x00d3_9fa1_4ac0_3856,
+        0x53ff_aa48_ee06_d712,
+        0x7afb_0a27_94a8_8f03,
+        0x314c_e135_bb63_9a72,
+    ]},
+    Scalar{ ls: [
+        0x9a4d_4acd_4fc3_8404,
+        0xd4fb_fa68_1d0f_107e,
+        0x29ca_a895_11bb_ba80,
+        0x7037_aa85_dbd5_4d00,
+    ]},
+    Scalar{ ls: [
+        0x70a7_087e_d281_3a7f,
+        0xba84_fb44_9266_0ec6,
+        0xd9bb_0b56_c14e_826a,
+        0x0289_378b_62cf_2157,
+    ]},
+    Scalar{ ls: [
+        0xfdfe_9697_fc5a_8c63,
+        0x8e40_66ae_b598_93ed,
+        0x0268_5f29_224c_12c6,
+        0x1cb1_ae62_8121_6034,
+    ]},
+    Scalar{ ls: [
+        0xf292_edea_a401_4fd9,
+        0x7ee6_2574_ba9b_0732,
+        0xdbe8_08da_b5ad_a0a8,
+        0x16cc_80d2_8eb7_bde0,
+    ]},
+    Scalar{ ls: [
+        0x9e40_e559_fbe0_4ae2,
+        0x13ec_3fa4_21ab_b15e,
+        0x1bc0_4a2c_059f_288e,
+        0x4b91_c685_e749_fc05,
+    ]},
+    Scalar{ ls: [
+        0xdcba_a9ad_d969_eadd,
+        0xf0b8_f2d4_b4b8_0f2e,
+        0x5036_7944_86bc_b9a7,
+        0x0c3c_12da_de56_a777,
+    ]},
+    Scalar{ ls: [
+        0xd628_057f_8e8e_cb20,
+        0x7e64_7f09_a1c0_e560,
+        0x4936_d3f5_4287_a26c,
+        0x3a72_7b4a_7d7c_b8b2,
+    ]},
+    Scalar{ ls: [
+        0x6a65_ebd8_bcbe_87c5,
+        0xde4b_da4f_8030_ab62,
+        0x81dc_f47b_eeb4_34f5,
+        0x1d98_84f3_5b05_25d9,
+    ]},
+    Scalar{ ls: [
+        0x0b70_ccb1_2155_0784,
+        0xf4f9_c80f_faea_c8e8,
+        0x6f86_c51d_4fe9_7522,
+        0x2ade_5a8f_b8e1_fecf,
+    ]},
+    Scalar{ ls: [
+        0x3dcb_eae6_4e94_5f7e,
+        0x3aea_cdb4_82ab_60c5,
+        0x6f14_c619_3f8e_a53b,
+        0x17c6_54bc_a130_a037,
+    ]},
+    Scalar{ ls: [
+        0xd6c9_9913_afe9_50a5,
+        0xde9b_e7a3_d782_4554,
+        0x347e_13bf_fefb_9dea,
+        0x600d_9c3b_81a7_d788,
+    ]},
+    Scalar{ ls: [
+        0xf1b5_eae2_e9af_fadd,
+        0xb50e_5ee9_3654_c7a3,
+        0x7aa2_1c62_d6e6_2dcd,
+        0x59d8_c178_6e38_c69c,
+    ]},
+    Scalar{ ls: [
+        0xebba_dd98_10a6_906a,
+        0x2e20_af7c_4564_f25e,
+        0xcee3_9070_c938_21fe,
+        0x5991_93db_6061_3387,
+    ]},
+    Scalar{ ls: [
+        0x035c_a741_16cf_143c,
+        0x2459_ec26_b28e_4432,
+        0xffe8_3134_9a34_db4f,
+        0x5fc6_7ede_ad4a_1b7b,
+    ]},
+    Scalar{ ls: [
+        0x39a6_2026_0441_490e,
+        0x0ba0_3c6a_0717_9750,
+        0xcb53_9971_ebc9_2af5,
+        0x504c_dbc2_648d_add3,
+    ]},
+    Scalar{ ls: [
+        0xb2a2_7b4e_c95e_066c,
+        0xd843_0652_ff5b_44ed,
+        0x9445_c946_397f_52d0,
+        0x3992_00a9_5a0f_b678,
+    ]},
+    Scalar{ ls: [
+        0xcb0f_968c_1e3e_2bd2,
+        0x3fb3_2e47_4da8_fe6c,
+        0xaac4_994f_587b_46c5,
+        0x179c_b7df_c977_d676,
+    ]},
+    Scalar{ ls: [
+        0x4888_a8f4_534c_3996,
+        0xf80f_2edf_a52f_b139,
+        0x6c45_7024_87dd_0ca9,
+        0x3d68_9a50_bd7b_6eb6,
+    ]},
+    Scalar{ ls: [
+        0xc1e7_f3f8_bd5c_9bcd,
+        0xd80a_fcd2_a02d_b2db,
+        0x83c4_e9f5_1e95_d42d,
+        0x1271_5c68_eeea_d957,
+    ]},
+    Scalar{ ls: [
+        0xd15e_6fed_9358_dc9f,
+        0xaeb6_0e31_2882_233d,
+        0x1aa3_d0bf_26ad_7200,
+        0x1e0c_650b_06de_17a2,
+    ]},
+    Scalar{ ls: [
+        0x0459_8ec3_14e1_8346,
+        0xc00d_8cad_293f_c4a1,
+        0x448d_9182_cdd5_edbd,
+        0x6793_2648_1be5_70c6,
+    ]},
+    Scalar{ ls: [
+        0x71db_eee7_3fec_977b,
+        0x8d03_72bb_3f66_777b,
+        0x17fc_9dfe_a3d3_6e26,
+        0x7307_51da_17c2_97fc,
+    ]},
+    Scalar{ ls: [
+        0x114e_1541_8dc8_bf69,
+        0xf8e7_43dc_f821_c960,
+        0x95f7_b3e7_6110_cf6a,
+        0x3e77_9f42_ff5f_5076,
+    ]},
+    Scalar{ ls: [
+        0x6b02_00f1_4f47_70c3,
+        0xaf12_a4e1_ee81_3f11,
+        0x581d_4a2e_efb7_bbd5,
+        0x7317_4e3a_918d_cd9b,
+    ]},
+    Scalar{ ls: [
+        0x2cc1_3780_83c4_d851,
+        0x2f8d_78d0_dd7f_dc27,
+        0x234c_9c9d_3c0c_4581,
+        0x6d7f_6d29_7d78_3925,
+    ]},
+    Scalar{ ls: [
+        0xa1f4_514a_39f7_d0ba,
+        0x0baf_67e8_19ff_e2d5,
+        0x7334_5220_f5a7_03a6,
+        0x1c1f_a191_a3df_dc04,
+    ]},
+    Scalar{ ls: [
+        0x304c_d8de_d59e_95e8,
+        0x1181_a1ae_0c49_1da9,
+        0xfd17_c83e_db4f_5314,
+        0x25b7_5ef0_d600_eb97,
+    ]},
+    Scalar{ ls: [
+        0x7d91_b61e_1e39_47f2,
+        0x954a_0942_6e34_6002,
+        0xb3f1_0179_2fd3_9f82,
+        0x1dbf_a1a8_bf45_c2c4,
+    ]},
+    Scalar{ ls: [
+        0x2ac3_7f8b_5152_48e9,
+        0xe424_fd0d_2c0b_91e1,
+        0x6118_a70b_ecee_60fd,
+        0x7374_6ba6_9e29_e5d2,
+    ]},
+    Scalar{ ls: [
+        0x3351_8846_cff6_0301,
+        0x959c_7bd7_c65c_25a5,
+        0x158d_f013_b9d2_5253,
+        0x60dc_d93f_7bc6_9dac,
+    ]},
+    Scalar{ ls: [
+        0x0b3d_7685_1233_9746,
+        0xcfed_8f36_409a_6739,
+        0xe4fa_2b28_f44f_cc01,
+        0x0eb5_79e6_227d_53a2,
+    ]},
+    Scalar{ ls: [
+        0xf84a_e6bd_e533_e312,
+        0xd21b_687a_f278_574e,
+        0xc2a7_95f9_ae4c_fc66,
+        0x14e7_6d69_b7a6_ecc6,
+    ]},
+    Scalar{ ls: [
+        0x6a21_0f99_f986_04d8,
+        0xc4c9_2923_a8f6_b909,
+        0xfe21_78f2_8b02_6b92,
+        0x4b71_98dd_f2a9_9884,
+    ]},
+    Scalar{ ls: [
+        0xbe82_d7b9_baae_e9d8,
+        0xf6cf_af7e_a761_ffc8,
+        0x6668_8a9b_09ff_9a5b,
+        0x1a23_8d84_2cbd_a746,
+    ]},
+    Scalar{ ls: [
+        0x3b11_78b4_f871_74b9,
+        0x645a_ea51_1c36_0ba5,
+        0xb309_caa3_fd4f_cef8,
+        0x7359_e671_da71_2d94,
+    ]},
+    Scalar{ ls: [
+        0x34b6_c8c3_df77_7363,
+        0x1824_ba96_9388_0a89,
+        0xb1cb_dc01_3b6d_7b72,
+        0x3419_b464_8abd_866c,
+    ]},
+    Scalar{ ls: [
+        0x9e87_6f67_7d6d_0fdf,
+        0xe6c8_935e_d7f5_29f7,
+        0x7134_60ff_c883_edbf,
+        0x07f0_d316_bbe9_8f68,
+    ]},
+    Scalar{ ls: [
+        0x517e_ea51_ff38_0c2c,
+        0x2ace_904d_cc26_ac63,
+        0xe7d3_bba2_5b00_3cd9,
+        0x6b2e_6200_c01d_5568,
+    ]},
+    Scalar{ ls: [
+        0x4049_2c83_7ab1_239b,
+        0x52c4_1137_2b0c_1b82,
+        0x65b5_f765_68b7_dd08,
+        0x469e_944f_3b8d_cc7a,
+    ]},
+    Scalar{ ls: [
+        0x2373_7810_b543_fca5,
+        0x4148_65aa_b19a_7fcb,
+        0xefa2_fc81_e71f_d7d0,
+        0x2976_b951_75f2_d144,
+    ]},
+    Scalar{ ls: [
+        0x08fd_5742_2bc0_9481,
+        0x3678_5c44_de9c_5207,
+        0x8cfe_df12_112e_f94b,
+        0x53c9_d6b9_c95a_9876,
+    ]},
+    Scalar{ ls: [
+        0x93d9_11e6_7e8b_8806,
+        0xb8e3_71d1_c295_c590,
+        0x3717_b88a_94aa_eff1,
+        0x1f53_f33b_bb90_0221,
+    ]},
+    Scalar{ ls: [
+        0x0aeb_aa68_7996_85eb,
+        0x14dd_393b_91ba_7b86,
+        0x1793_373f_c79c_4a65,
+        0x2478_dee4_a949_9652,
+    ]},
+    Scalar{ ls: [
+        0x07f5_4c48_176a_4f5a,
+        0x965b_7823_7c86_3a04,
+        0x2823_b180_bb55_97a7,
+        0x4171_9027_d958_f892,
+    ]},
+    Scalar{ ls: [
+        0x8ab0_a432_de0f_9d70,
+        0x2ac2_0691_37ac_6eb6,
+        0xad47_81a7_383f_5ad0,
+        0x5878_70a4_d44d_b2e9,
+    ]},
+    Scalar{ ls: [
+        0x4e96_9cab_8597_2765,
+        0xd3f8_1fb9_9aea_60ee,
+        0x7e53_466f_1a93_16db,
+        0x133b_035d_dcd5_f9c3,
+    ]},
+    Scalar{ ls: [
+        0x3fa9_a7ea_66af_a98b,
+        0x1dbb_d218_b001_5143,
+        0xfc17_4563_e8cb_2d78,
+        0x60b4_5c36_3e84_97f4,
+    ]},
+    Scalar{ ls: [
+        0x97fe_88e8_9dbb_3488,
+        0x3144_0129_586d_82c6,
+        0xb953_632a_5277_7149,
+        0x1d5e_ebf9_bfd1_42e5,
+    ]},
+    Scalar{ ls: [
+        0xaf56_cd3c_c926_87cc,
+        0x844d_1a91_b551_c72d,
+        0xc29a_8b55_9161_a4da,
+        0x46f8_8246_d4c9_8296,
+    ]},
+    Scalar{ ls: [
+        0x747e_a49c_9855_4a5a,
+        0xc2fa_744b_b1c3_8ab6,
+        0x0a13_3f74_6505_93a7,
+        0x45d1_9263_4a2f_544b,
+    ]},
+    Scalar{ ls: [
+        0x047c_b5e3_e213_dad7,
+        0xba8e_c99d_6169_963a,
+        0x116a_9712_6795_e401,
+        0x0334_af51_6957_0d4c,
+    ]},
+    Scalar{ ls: [
+        0x3674_9043_2d1d_f087,
+        0xbb97_518d_405e_cc0b,
+        0x888e_2aed_6ec9_d1d8,
+        0x22fb_3649_6b22_0761,
+    ]},
+    Scalar{ ls: [
+        0x09a9_287f_3996_6928,
+        0xf1af_59db_3690_a94f,
+        0xc2b1_8cbf_4b48_66c1,
+        0x6c8f_b643_5ffe_91c0,
+    ]},
+    Scalar{ ls: [
+        0x1ff8_2538_31f1_643c,
+        0x8db8_a44b_a81e_61a4,
+        0x6f3c_6065_f6f1_70b9,
+        0x6fd1_4ac2_7791_b19e,
+    ]},
+    Scalar{ ls: [
+        0x609f_a256_4352_a71b,
+        0x3bdd_00d3_602f_dc35,
+        0xff3a_72db_f838_7191,
+        0x444f_5f7d_3208_c65f,
+    ]},
+    Scalar{ ls: [
+        0xac34_73a2_10a0_decf,
+        0x710e_9fb0_88ee_efab,
+        0x7a85_6d22_4bac_96ae,
+        0x69f0_ff4e_4471_cd6c,
+    ]},
+    Scalar{ ls: [
+        0x5af1_5143_21a2_21a6,
+        0x4b2b_1d49_8bd7_8305,
+        0x5de7_7fd7_41ea_6dcd,
+        0x04c5_693c_9e85_e1fe,
+    ]},
+    Scalar{ ls: [
+        0xb2b2_22c3_73a3_5dc8,
+        0xcd64_cdd6_bfd4_d7c0,
+        0xd98f_adea_86f4_256a,
+        0x4c2f_1388_5472_b538,
+    ]},
+    Scalar{ ls: [
+        0x68bf_545e_3eef_6f5f,
+        0xf2cb_9d7f_a124_27ee,
+        0xa2ba_fdd9_01b3_1015,
+        0x364b_52f5_5123_dfba,
+    ]},
+    Scalar{ ls: [
+        0x5e33_42e7_1d7c_9de1,
+        0xd863_e8b7_edd9_d792,
+        0x5aad_a289_cd22_2578,
+        0x2678_5f82_b6fd_a047,
+    ]},
+    Scalar{ ls: [
+        0x7e0f_926b_95d5_e502,
+        0x5ed8_e3f2_e92f_de6e,
+        0x8dbc_f55a_a836_5fe0,
+        0x0f70_d8c6_c373_e0b0,
+    ]},
+    Scalar{ ls: [
+        0x1b9c_ab83_b37d_fb0c,
+        0xcdcd_b231_8b38_e1f5,
+        0x7e5d_7154_1957_ed2b,
+        0x4aaf_a9ed_7095_fa73,
+    ]},
+    Scalar{ ls: [
+        0xae97_8cab_4b11_81c9,
+        0xf905_086f_ca76_825f,
+        0x9bcc_a831_83cb_9a5a,
+        0x4e32_8055_14de_8325,
+    ]},
+    Scalar{ ls: [
+        0x4335_3e35_c86d_7481,
+        0xae3b_66aa_7ae1_32b5,
+        0x09f8_cd78_00ba_9e45,
+        0x715d_f00c_d85b_8603,
+    ]},
+    Scalar{ ls: [
+        0xd5cf_da3b_3cae_deed,
+        0x3eb9_2abf_00ae_26df,
+        0x72b8_d61e_9fc2_98ae,
+        0x56b7_c0d1_3742_e9c6,
+    ]},
+    Scalar{ ls: [
+        0x0bc9_1bb3_e8bb_ef19,
+        0x95e3_5c16_948c_c570,
+        0x487b_7d36_bb96_1882,
+        0x48ae_1e9f_336c_5e76,
+    ]},
+    Scalar{ ls: [
+        0x9551_71bf_1be8_5d17,
+        0x455a_fe00_ed8b_16e7,
+        0xa155_2da4_ea9f_eebe,
+        0x1ded_f5b0_52e5_17c7,
+    ]},
+    Scalar{ ls: [
+        0x17e7_c34d_77d4_6e42,
+        0xe1e8_fb14_26bb_3dbb,
+        0xf162_5adf_5d61_461e,
+        0x0605_3183_317a_285a,
+    ]},
+    Scalar{ ls: [
+        0xedcf_16a6_e165_0978,
+        0xfaaf_6f4e_f7a3_3818,
+        0xb3c7_5744_6262_b651,
+        0x0351_8966_25e9_0606,
+    ]},
+    Scalar{ ls: [
+        0xeb62_904a_332c_3226,
+        0x5c89_3776_98fc_a89b,
+        0x2bcc_cb20_9d03_c798,
+        0x33a0_3d71_ea00_7db0,
+    ]},
+    Scalar{ ls: [
+        0xad42_acb8_92fb_908a,
+        0x0117_80c5_456e_60c9,
+        0x9055_8f1d_7bf5_9fbd,
+        0x53ce_b641_a626_1eda,
+    ]},
+    Scalar{ ls: [
+        0x3955_9c41_460a_7564,
+        0x8f55_6edb_b523_7561,
+        0xd83c_5feb_d214_e77a,
+        0x41f5_bb81_be03_0b46,
+    ]},
+    Scalar{ ls: [
+        0xbceb_737c_3445_3a0e,
+        0x7151_ef24_8f49_b60e,
+        0xb0c5_2728_b452_a224,
+        0x0d58_1452_bf6e_2881,
+    ]},
+    Scalar{ ls: [
+        0xd40d_0883_5801_0e67,
+        0xb8cc_c499_8338_9ae0,
+        0xf3fe_005d_a865_50b8,
+        0x4e48_45bc_2320_cba6,
+    ]},
+    Scalar{ ls: [
+        0xe489_2e64_88f4_27f3,
+        0xf1cc_6a55_7724_043e,
+        0xd41f_b9b0_6ecd_326f,
+        0x464c_33ae_5451_56f6,
+    ]},
+    Scalar{ ls: [
+        0x01ef_05ad_6986_cf59,
+        0xdf68_36f8_6f64_9c4e,
+        0xc693_4638_3415_d169,
+        0x3872_b364_4c2e_e6ab,
+    ]},
+    Scalar{ ls: [
+        0x2004_d360_4ace_ac02,
+        0xfef1_6b62_a33e_3fa7,
+        0xc998_fc9b_8df1_3233,
+        0x1415_4cd0_e850_653c,
+    ]},
+    Scalar{ ls: [
+        0x3811_596f_c161_a1b9,
+        0xaff7_c514_d6be_46ac,
+        0x8366_a434_b3ea_432a,
+        0x524d_49bc_9511_ca32,
+    ]},
+    Scalar{ ls: [
+        0x5ed9_d862_c743_fa07,
+        0x5ddb_6319_4db1_0fa1,
+        0x91f8_1e72_02bd_3173,
+        0x54a8_8eb6_af6f_e5ed,
+    ]},
+    Scalar{ ls: [
+        0x1712_60ec_a2bf_ac65,
+        0x9d6e_7532_dee1_f9c5,
+        0x5edb_a067_3e79_33b9,
+        0x64ad_24c1_8b4a_67ec,
+    ]},
+    Scalar{ ls: [
+        0x2b75_ed9b_20f1_5f6c,
+        0x8dd5_b34e_3560_71cb,
+        0x0e9d_8982_c90b_aa65,
+        0x626d_6689_0f2d_9b9c,
+    ]},
+    Scalar{ ls: [
+        0x60f9_72f7_8b96_8d04,
+        0x3b4f_bda7_d037_fa92,
+        0xccf4_7837_f333_be53,
+        0x604d_cf0f_879c_3b00,
+    ]},
+    Scalar{ ls: [
+        0xadde_ebf2_e0c0_6c32,
+        0x69de_2c22_bbfd_71c8,
+        0x0eb6_60e3_d8f9_02f6,
+        0x20f7_83cf_774b_40fa,
+    ]},
+    Scalar{ ls: [
+        0xcb6a_5921_661f_6331,
+        0xb43c_d01f_ffab_8b5a,
+        0x5763_e397_7aac_4cb6,
+        0x6444_172d_5b40_e154,
+    ]},
+    Scalar{ ls: [
+        0xbed7_ccfb_a21a_b41f,
+        0x0608_a364_824b_aaa3,
+        0xdcdf_842a_8bea_ba4c,
+        0x386a_d6ca_f997_cac3,
+    ]},
+    Scalar{ ls: [
+        0x7c74_4785_423f_20a2,
+        0x814f_8c29_450c_bc18,
+        0x4084_34af_49b4_d426,
+        0x101c_b467_12bd_65dc,
+    ]},
+    Scalar{ ls: [
+        0x34a2_ce5c_4b79_36a0,
+        0x08f8_862d_63cb_debe,
+        0x7caa_7407_9193_e20c,
+        0x64f6_b60b_54f9_5a09,
+    ]},
+    Scalar{ ls: [
+        0x4de5_1e59_35d4_fd90,
+        0x9488_1941_c7f8_aabd,
+        0xcb3f_98c7_e272_c77b,
+        0x3444_18ac_c467_9ef3,
+    ]},
+    Scalar{ ls: [
+        0x9177_a3e3_7042_11bd,
+        0x9e4f_c385_6352_79ee,
+        0x16a3_2c9a_1c53_3d60,
+        0x6ed8_3585_f0e9_5c90,
+    ]},
+    Scalar{ ls: [
+        0x9963_a92c_75aa_31e8,
+        0x4002_490d_accd_b52f,
+        0x1858_4d21_9191_06a0,
+        0x6d1f_9095_c8b8_600b,
+    ]},
+    Scalar{ ls: [
+        0xfc1a_891c_a694_cccc,
+        0x533f_c5e2_c47e_af00,
+        0xdd04_3e29_8a35_4f05,
+        0x4919_e72f_febb_4084,
+    ]},
+    Scalar{ ls: [
+        0x939a_3781_43a8_e08e,
+        0x58cf_e323_adb9_ddc0,
+        0xfae7_cf60_f06d_2446,
+        0x27e2_6a03_7184_4c74,
+    ]},
+    Scalar{ ls: [
+        0xe44b_a63b_5b36_e050,
+        0x73e2_a922_a24c_48d4,
+        0x353b_74d5_e1ff_799b,
+        0x669a_c9b8_beeb_287d,
+    ]},
+    Scalar{ ls: [
+        0xfb71_3336_c682_f0ed,
+        0xaa32_dd71_6de0_884f,
+        0x9d0e_1ce1_e2ec_f786,
+        0x4953_1a9d_25a2_2cee,
+    ]},
+    Scalar{ ls: [
+        0x3719_bc93_8193_131e,
+        0x44d0_675f_774a_8734,
+        0xcfa8_fd83_aa0c_8750,
+        0x5264_4e0a_9725_2b58,
+    ]},
+    Scalar{ ls: [
+        0xf12e_a03f_d259_9e75,
+        0xdb9a_6149_fec1_379b,
+        0x7945_ae29_ae0b_a9b6,
+        0x43ea_fb26_5d81_530b,
+    ]},
+    Scalar{ ls: [
+        0xe1b3_edbc_d0d2_2125,
+        0x9b18_42b1_e303_096a,
+        0x5dd0_a33d_665c_3af6,
+        0x5b19_8edf_5c2c_dc55,
+    ]},
+    Scalar{ ls: [
+        0x34a5_0d12_4d3e_47fd,
+        0x534f_262d_bbbe_734f,
+        0x3d3b_c86b_6621_8c49,
+        0x0fd6_617a_18e3_eee2,
+    ]},
+    Scalar{ ls: [
+        0x6061_2cfa_d326_e80c,
+        0xeecc_6b35_7bf0_841d,
+        0x6580_4b7c_0c8b_30ac,
+        0x1ac7_eada_1aae_6a71,
+    ]},
+    Scalar{ ls: [
+        0x5399_39f2_e500_8b07,
+        0x5966_2a9e_15a9_386c,
+        0x95f1_aa10_5eaf_8b57,
+        0x73e9_c6e5_03ac_42b0,
+    ]},
+    Scalar{ ls: [
+        0xecfa_3bc3_1a79_0a4d,
+        0x231d_972b_fa1b_9a75,
+        0x61b8_3560_0de6_3e06,
+        0x0af9_412d_02a0_35e3,
+    ]},
+    Scalar{ ls: [
+        0x4702_3c27_f6b9_c9b9,
+        0xc0ad_0565_14bb_228c,
+        0x1d1d_2da6_67f0_e8b6,
+        0x3e48_db3e_197e_4c4d,
+    ]},
+    Scalar{ ls: [
+        0x53ca_a119_4ddb_6467,
+        0x8d01_256a_27c8_10fa,
+        0xba88_8bd2_1066_5665,
+        0x0126_af89_1036_3c34,
+    ]},
+    Scalar{ ls: [
+        0xa6f4_5bc7_f89b_51d7,
+        0x2806_c9a5_8b78_5594,
+        0xa316_dd21_3738_6335,
+        0x4d48_572d_86e2_bf31,
+    ]},
+    Scalar{ ls: [
+        0x5a9e_501f_eabb_6580,
+        0xec09_191b_4411_3486,
+        0x10a8_0cbb_ff22_44d1,
+        0x0ad9_8f2a_0a7f_e0d6,
+    ]},
+    Scalar{ ls: [
+        0x6ca4_861f_c02b_6531,
+        0xc23a_bc93_6773_80a8,
+        0xb9b4_6326_dbaa_6e1a,
+        0x536f_8a77_b649_c393,
+    ]},
+    Scalar{ ls: [
+        0xc9f9_5afa_63b2_1e1a,
+        0x9eed_9b7e_3bdf_a384,
+        0xfcc0_48c3_1cac_032d,
+        0x6be5_9fad_96ac_f2b5,
+    ]},
+    Scalar{ ls: [
+        0x6619_3978_8974_5316,
+        0x8f05_1587_fbd3_6f54,
+        0x3eb2_63fc_aaa4_bac1,
+        0x5004_b3d6_43ee_32ae,
+    ]},
+    Scalar{ ls: [
+        0x802c_dbbf_37b3_66ad,
+        0xef06_18c0_6b81_9992,
+        0xf434_6219_42ef_1cab,
+        0x355b_69f3_71a3_c521,
+    ]},
+    Scalar{ ls: [
+        0xcf5e_c990_4067_fc48,
+        0xf63c_6dad_6136_586d,
+        0x7718_7c8c_e985_50d8,
+        0x1635_7487_9ebb_5bc9,
+    ]},
+    Scalar{ ls: [
+        0x9437_ee23_9045_92e3,
+        0x035a_764d_257c_561e,
+        0xc4be_8080_68cb_c237,
+        0x6d47_433d_3c22_8301,
+    ]},
+    Scalar{ ls: [
+        0xfd67_a3c4_2f3b_75b0,
+        0x5132_d514_cdc0_7de4,
+        0x4427_4e31_9b26_6cb4,
+        0x11fa_1b1a_7375_dda2,
+    ]},
+    Scalar{ ls: [
+        0xebb2_4c92_b587_96d1,
+        0xf6b1_b137_a3ce_e60f,
+        0xcb02_d040_4418_8d7d,
+        0x34be_b83c_be21_d05e,
+    ]},
+    Scalar{ ls: [
+        0x537b_d901_1d49_b18c,
+        0x6fed_311f_e575_f3bd,
+        0x3346_1514_3e3d_a783,
+        0x4135_d1c3_c165_5006,
+    ]},
+    Scalar{ ls: [
+        0x6180_1a2a_81df_b4d2,
+        0x02bc_f7cf_3265_e499,
+        0xff6f_78f5_a3ab_b168,
+        0x69a6_135a_ba3a_bfb0,
+    ]},
+    Scalar{ ls: [
+        0xc556_857e_0e4f_ad5c,
+        0xfaa1_5618_fb91_1093,
+        0x6948_930e_d0b0_a360,
+        0x15b5_abb5_9bf2_c9ec,
+    ]},
+    Scalar{ ls: [
+        0xb35d_1e64_5cf5_a3b1,
+        0x708a_8b85_f22d_e645,
+        0x5e0a_d6c2_a9c8_16c8,
+        0x10ee_e69a_24ce_541f,
+    ]},
+    Scalar{ ls: [
+        0xd56f_d6f6_f54a_5b1f,
+        0x7f5e_3a00_cb74_21de,
+        0x40d4_db7a_e0d0_f0b9,
+        0x3848_79a3_0b53_5435,
+    ]},
+    Scalar{ ls: [
+        0x7fb3_6752_9574_7d48,
+        0x8a0b_6ac1_e4d5_58c0,
+        0x506c_1630_0885_4bf2,
+        0x4832_24d1_519f_0726,
+    ]},
+    Scalar{ ls: [
+        0xbcab_1d9e_08db_f090,
+        0xdfa7_c214_1789_6105,
+        0x2985_83c8_84fa_b612,
+        0x539d_47bf_04a6_740b,
+    ]},
+    Scalar{ ls: [
+        0x488b_4c88_14ef_d9b1,
+        0x8bfd_9cba_f9b2_638b,
+        0x18bf_c501_704a_c5f1,
+        0x72a8_8e6d_4ac4_e989,
+    ]},
+    Scalar{ ls: [
+        0xc5f3_3eef_4b73_390c,
+        0xfcdd_44fc_387f_9cd7,
+        0xaf0a_c235_0148_988d,
+        0x18ae_c200_45ff_c338,
+    ]},
+    Scalar{ ls: [
+        0x9cee_0527_a760_1d82,
+        0x3d45_bdd8_892c_0779,
+        0x181d_8dfe_24df_5107,
+        0x203b_efb1_3cb8_15a7,
+    ]},
+    Scalar{ ls: [
+        0x23c2_4dd5_5e4d_3fb8,
+        0x83ae_67cb_2681_a408,
+        0xa35a_cbe9_6d32_c9dd,
+        0x0d5a_6129_33f1_0941,
+    ]},
+    Scalar{ ls: [
+        0xdc17_333a_2ab3_0822,
+        0xaea0_1036_f231_3f06,
+        0xe44a_78cc_26e2_7233,
+        0x277b_5189_0acb_58c7,
+    ]},
+    Scalar{ ls: [
+        0xcd49_fc1e_d84d_2212,
+        0xdf63_5d37_d9fd_a0a6,
+        0xd110_165a_14c7_3a5c,
+        0x2443_0bf4_9dcf_b313,
+    ]},
+    Scalar{ ls: [
+        0x71d3_111c_e04c_4d3a,
+        0xc094_fdd3_c3c6_3fa8,
+        0x72f0_add2_e0d6_b39f,
+        0x64fd_a9c6_f8a8_32a2,
+    ]},
+    Scalar{ ls: [
+        0x79c4_7168_fb21_a907,
+        0xc274_b65c_0a24_db69,
+        0xdb54_b48f_a48f_efda,
+        0x5fad_33a9_2d80_d5a4,
+    ]},
+    Scalar{ ls: [
+        0x3f7e_4e63_7f16_baea,
+        0x4b11_4c97_077f_259d,
+        0xbd88_34a9_1150_174f,
+        0x223f_b80f_cfc1_5db6,
+    ]},
+    Scalar{ ls: [
+        0x1bd9_605a_fb6b_f4aa,
+        0x282c_305e_8322_72af,
+        0xfb71_ba07_e1a2_8548,
+        0x635f_298a_8ca9_1892,
+    ]},
+    Scalar{ ls: [
+        0x3f2c_067a_8a56_566b,
+        0xf09b_4b44_e19e_667d,
+        0x436f_069c_e030_d83c,
+        0x722e_9634_f087_9a37,
+    ]},
+    Scalar{ ls: [
+        0x34b6_4c91_7e32_8ffd,
+        0x52c8_7531_369a_ad3a,
+        0xc818_8581_b489_a401,
+        0x63db_b998_03fd_dbb7,
+    ]},
+    Scalar{ ls: [
+        0x0eb1_e3eb_0f2b_3d8d,
+        0x27f9_1980_9252_1853,
+        0x4bd5_30a2_b285_e6eb,
+        0x16a7_e76e_3f3f_af65,
+    ]},
+    Scalar{ ls: [
+        0x1ff0_98cd_f530_31c6,
+        0xb347_e815_a310_1a89,
+        0x9cca_4849_e070_e137,
+        0x337c_eeae_e36b_13d4,
+    ]},
+];
+
+/// MDS matrix for width 3
+pub const MDS_3: [[Scalar; 3]; 3] = [
+    [
+        Scalar{ ls: [
+            0x0000_0000_5555_5555,
+            0xe416_1ea9_aaab_36ab,
+            0xeeec_b7fd_521f_62a8,
+            0x2eb0_c839_9ccb_80e7,
+        ]},
+        Scalar{ ls: [
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+            0x4000_0000_0000_0000,
+        ]},
+        Scalar{ ls: [
+            0x0000_0000_6666_6666,
+            0x11b4_24cb_999a_419a,
+            0x51e8_dcc9_95bf_4331,
+            0x04d4_2378_55c1_0116,
+        ]},
+    ],
+    [
+        Scalar{ ls: [
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+            0x4000_0000_0000_0000,
+        ]},
+        Scalar{ ls: [
+            0x0000_0000_6666_6666,
+            0x11b4_24cb_999a_419a,
+            0x51e8_dcc9_95bf_4331,
+            0x04d4_2378_55c1_0116,
+        ]},
+        Scalar{ ls: [
+            0xffff_ffff_aaaa_aaab,
+            0x1be9_e156_5554_c954,
+            0x1113_4802_ade0_9d57,
+            0x514f_37c6_6334_7f18,
+        ]},
+    ],
+    [
+        Scalar{ ls: [
+            0x0000_0000_6666_6666,
+            0x11b4_24cb_999a_419a,
+            0x51e8_dcc9_95bf_4331,
+            0x04d4_2378_55c1_0116,
+        ]},
+        Scalar{ ls: [
+            0xffff_ffff_aaaa_aaab,
+            0x1be9_e156_5554_c954,
+            0x1113_4802_ade0_9d57,
+            0x514f_37c6_6334_7f18,
+        ]},
+        Scalar{ ls: [
+            0xdb6d_b6da_db6d_b6dc,
+            0xe6b5_824a_db6c_c6da,
+            0xf8b3_56e0_0581_0db9,
+            0x66d0_f1e6_60ec_4796,
+        ]},
+    ],
+];
+
+/// Round constants for width 5, 5 for each of the 64 rounds
+pub const ROUND_CONSTANTS_5: [Scalar; 320] = [
+    Scalar{ ls: [
+        0x3d0a_4729_368c_fb9f,
+        0x3056_05b2_cb53_223d,
+        0x8f64_4337_a000_8dcf,
+        0x33ff_809e_7dbd_ebbf,
+    ]},
+    Scalar{ ls: [
+        0x9e52_ce58_a418_2072,
+        0x5031_70b7_2050_9067,
+        0x2572_ea25_6be8_85d0,
+        0x4998_feed_41b4_cddf,
+    ]},
+    Scalar{ ls: [
+        0x578b_2978_864d_447c,
+        0x0344_1057_4ac6_4bd6,
+        0x2302_dadf_7a5f_a9c5,
+        0x27ae_1668_3e3a_7acc,
+    ]},
+    Scalar{ ls: [
+        0x6d3a_7acd_0bbd_8dd7,
+        0x307d_b01f_2309_2d8c,
+        0x4fbe_333d_330c_fb35,
+        0x5ec0_e948_c78b_c2c2,
+    ]},
+    Scalar{ ls: [
+        0x2cb4_d18b_3637_e7e5,
+        0x67d9_01cf_3025_2eee,
+        0x0b7c_1572_fafa_5f30,
+        0x73b3_3c1d_097f_e58c,
+    ]},
+    Scalar{ ls: [
+        0xfa8f_c21a_61ef_6392,
+        0x4cde_0f8e_1360_40af,
+        0x8f40_8c49_3b20_b947,
+        0x0a63_a551_5f9a_8776,
+    ]},
+    Scalar{ ls: [
+        0xfd20_fe09_e2b7_bcc6,
+        0x7469_ffae_39f6_0a03,
+        0xf2d0_03d8_eba2_8762,
+        0x5792_b30f_3d1f_0ae1,
+    ]},
+    Scalar{ ls: [
+        0x10ca_1e1e_9ccc_3453,
+        0x29e2_a246_166f_11d1,
+        0x6c7b_3a50_4c9d_fbfc,
+        0x6fa8_f1e3_66ca_caae,
+    ]},
+    Scalar{ ls: [
+        0x4564_cbc3_8276_b222,
+        0x68b3_5665_dfd4_5579,
+        0x713d_7ab5_8419_4810,
+        0x2700_d191_77d0_a473,
+    ]},
+    Scalar{ ls: [
+        0x094b_2c45_f543_9010,
+        0x1ca2_3f18_ac8f_3ceb,
+        0x5c1a_38e2_df88_07e2,
+        0x3e88_3c78_7506_5d0f,
+    ]},
+    Scalar{ ls: [
+        0xa171_b33f_698c_6ec4,
+        0xfa71_9366_c189_5f87,
+        0x9d60_fee7_4e2d_ed28,
+        0x641a_4222_7360_70d6,
+    ]},
+    Scalar{ ls: [
+        0x3c28_0652_f2b9_4092,
+        0x254f_5bd6_ec59_0298,
+        0x09f1_3e8c_0930_9949,
+        0x0678_a5e6_9165_fc50,
+    ]},
+    Scalar{ ls: [
+        0x4be2_e297_afcd_b048,
+        0x519d_f0d9_3f78_2afd,
+        0x693f_34fe_1ecf_e927,
+        0x5f97_86f5_95c4_d074,
+    ]},
+    Scalar{ ls: [
+        0xbea3_d972_d2dc_d033,
+        0x0fe0_ef8a_c582_2df0,
+        0xf934_84ec_d886_86ff,
+        0x14a8_00ea_839b_968a,
+    ]},
+    Scalar{ ls: [
+        0xc58d_3fae_96e3_3ed2,
+        0x8b0d_6613_7d25_1f51,
+        0x60be_3804_aade_9405,
+        0x3020_47ef_7652_9f40,
+    ]},
+    Scalar{ ls: [
+        0x459f_4d5c_c130_5f05,
+        0x2aae_2aa9_ca31_d4ab,
+        0xf500_43e3_c3c2_4a7d,
+        0x2167_8ba9_b065_be28,
+    ]},
+    Scalar{ ls: [
+        0x01e8_b170_8365_fcf0,
+        0x6b55_619f_4b67_eca1,
+        0x6dfa_0d5c_9bd8_75f8,
+        0x19de_848e_d7d3_5632,
+    ]},
+    Scalar{ ls: [
+        0xad2c_6d7a_1543_eff1,
+        0x79b0_fcea_809f_3b20,
+        0x52b0_aa13_68d9_ec40,
+        0x525e_eeb5_9ac3_7c75,
+    ]},
+    Scalar{ ls: [
+        0x001e_3305_48c7_ac6a,
+        0x0a9f_3871_faf2_06dd,
+        0x3c26_7814_0bf4_06a9,
+        0x720a_f141_1330_a661,
+    ]},
+    Scalar{ ls: [
+        0xffdc_b72e_be6e_386d,
+        0x44ff_7e44_94ad_6dd5,
+        0x3c95_f5b5_0dd1_5bbd,
+        0x0b85_11f4_d9a9_c087,
+    ]},
+    Scalar{ ls: [
+        0xae1e_6cd6_6f0e_b4e4,
+        0x0361_8235_c5b2_aa63,
+        0xbb0e_3da7_d428_8fa8,
+        0x71ab_fce9_36ab_df7b,
+    ]},
+    Scalar{ ls: [
+        0x10f2_801c_97e9_5cde,
+        0x809a_d0e1_1f35_ead9,
+        0x1e56_6ab6_3423_5df0,
+        0x3cb0_9525_269b_fe4d,
+    ]},
+    Scalar{ ls: [
+        0x63cc_8eb1_dc2e_681f,
+        0xd0f9_2587_286e_fe69,
+        0x3f3a_d1ba_b7a7_3cea,
+        0x5873_9e3b_9716_8d92,
+    ]},
+    Scalar{ ls: [
+        0x426d_aac7_8763_d686,
+        0x6086_adf1_3540_0264,
+        0x1500_216f_7471_572f,
+        0x4607_d895_138a_ce9f,
+    ]},
+    Scalar{ ls: [
+        0xf70c_c576_c613_89e3,
+        0x46e4_ef33_5f07_114d,
+        0x70e0_0056_a958_bc0e,
+        0x4fe5_b716_5519_76f5,
+    ]},
+    Scalar{ ls: [
+        0x8c1c_9951_0a17_822c,
+        0x0d34_6f02_4f2a_715c,
+        0x8e69_fc51_30bc_3ba6,
+        0x0a87_7b2a_3e34_0e19,
+    ]},
+    Scalar{ ls: [
+        0xd9bd_54d3_88a6_d76c,
+        0xbef7_2866_32d2_daf0,
+        0x75af_5903_efc0_98df,
+        0x291c_bbe9_88c5_547a,
+    ]},
+    Scalar{ ls: [
+        0x9713_2b64_99d7_1f4e,
+        0xf0d3_a357_ec4c_da78,
+        0xbea1_75ed_9e6d_949a,
+        0x2a18_59cd_7c72_07c2,
+    ]},
+    Scalar{ ls: [
+        0xe861_22ce_3464_d639,
+        0x5c51_131f_962b_77ea,
+        0xe049_d5d4_815c_a1b0,
+        0x309f_72b4_8ad9_06db,
+    ]},
+    Scalar{ ls: [
+        0xfc15_4038_e49c_3f24,
+        0xeb40_c9c3_7906_c5f0,
+        0x2f1a_a7b4_1f9b_621e,
+        0x0ab2_a26b_25f4_d076,
+    ]},
+    Scalar{ ls: [
+        0xb077_70c6_0fa1_f0c9,
+        0xb994_6011_7139_e9a2,
+        0xf336_cf54_87e7_b65d,
+        0x133f_f869_b4e9_f9ac,
+    ]},
+    Scalar{ ls: [
+        0x89c9_e05b_f761_54ca,
+        0xd24a_6117_0e44_f7b9,
+        0x4d56_2ea3_2765_79b0,
+        0x7168_d025_0240_bdfd,
+    ]},
+    Scalar{ ls: [
+        0xc8f8_0fc4_fbcd_ea41,
+        0xb386_ef4f_bdf4_62fa,
+        0x80f5_5290_5dc3_b5e2,
+        0x3960_ad4b_63c6_acc7,
+    ]},
+    Scalar{ ls: [
+        0x3d41_6d96_6b46_6931,
+        0x585a_abb9_b026_98c3,
+        0xfdea_253d_a2e5_2ff5,
+        0x6793_7a2b_9e4b_3d94,
+    ]},
+    Scalar{ ls: [
+        0x521b_08f8_7d3a_62a2,
+        0x504c_9225_94ac_604e,
+        0xcb6c_085d_4393_045f,
+        0x67a1_7645_f5fe_6f12,
+    ]},
+    Scalar{ ls: [
+        0x1553_5a4b_be0a_59cf,
+        0x12e7_8603_63af_f0f3,
+        0xe820_c74d_a29d_530c,
+        0x07fc_6a08_94e5_d880,
+    ]},
+    Scalar{ ls: [
+        0xab97_c5ed_0920_dfab,
+        0xf673_bbbd_4e14_3ec3,
+        0x6448_3938_2cc2_962f,
+        0x313d_9b3d_4e4e_edb0,
+    ]},
+    Scalar{ ls: [
+        0x4b4d_4dfa_eb6e_be50,
+        0x170b_774e_9e61_a7b8,
+        0xcad4_4b19_5d05_8e62,
+        0x6f8e_f493_8e83_44a3,
+    ]},
+    Scalar{ ls: [
+        0x0d49_0957_7ee1_aa0a,
+        0x821f_e8a5_4067_097d,
+        0x4d2b_ba13_bef7_509e,
+        0x676f_8b88_4b82_02e1,
+    ]},
+    Scalar{ ls: [
+        0x559e_c089_4ae1_b84f,
+        0x78b4_ab6e_858a_28e4,
+        0xcc9b_5bcf_a69c_08e0,
+        0x527a_9ce2_83bd_bae1,
+    ]},
+    Scalar{ ls: [
+        0xc22e_f78a_be95_0575,
+        0x8912_37e2_bd89_70a7,
+        0x9b27_8e26_8188_b2f9,
+        0x3275_64e5_60a0_4b8a,
+    ]},
+    Scalar{ ls: [
+        0x3245_7ad7_0023_eb5c,
+        0x2f27_d317_986a_e15e,
+        0x0049_c4e7_67a3_2537,
+        0x68b5_8b67_bb7c_cc70,
+    ]},
+    Scalar{ ls: [
+        0x53a6_5784_8aff_9bff,
+        0x5b6a_485f_d7c2_3204,
+        0x0e54_a792_c412_66af,
+        0x35be_2816_84de_cd4f,
+    ]},
+    Scalar{ ls: [
+        0x9e84_6768_34c5_7093,
+        0x484f_560a_a3b4_ead6,
+        0x2406_2b29_1958_12dc,
+        0x5e25_0537_b974_0b38,
+    ]},
+    Scalar{ ls: [
+        0xe27b_36df_2b45_b241,
+        0x5429_af31_5c6b_1da8,
+        0xf813_d7f9_5476_5a1a,
+        0x5639_204f_69f5_4fb3,
+    ]},
+    Scalar{ ls: [
+        0x7d10_a536_03d7_01a5,
+        0x0ff4_a650_68f0_024f,
+        0xc5d7_41b5_f5cc_b5ba,
+        0x4ff5_3e49_8890_8779,
+    ]},
+    Scalar{ ls: [
+        0x4425_44b6_b0c9_daf6,
+        0x75fb_4a66_fc9a_a916,
+        0x6734_932b_0782_4297,
+        0x6db4_867f_0a6a_dca8,
+    ]},
+    Scalar{ ls: [
+        0x4f00_6ffb_727c_3abb,
+        0x8a7a_7979_5168_bfcb,
+        0x3f9b_9139_0949_394a,
+        0x5550_a311_e043_909d,
+    ]},
+    Scalar{ ls: [
+        0x21b4_57cd_f268_bde3,
+        0x01ab_c2f5_7863_b0e1,
+        0xb05b_ce74_f6c9_c9f1,
+        0x4b43_3527_593c_785e,
+    ]},
+    Scalar{ ls: [
+        0x4150_a3bd_13da_840a,
+        0x2925_3fa8_116a_866d,
+        0x5f11_76ff_e74c_eb11,
+        0x3047_dd89_ae43_d82c,
+    ]},
+    Scalar{ ls: [
+        0x89a7_c197_cbc3_1da3,
+        0xfa8a_13a5_da97_1fb6,
+        0x3966_64e9_58f0_f01f,
+        0x3b66_b0aa_5109_d3be,
+    ]},
+    Scalar{ ls: [
+        0xbe5a_60f6_4293_ab36,
+        0x3f84_c3f8_d395_91d5,
+        0x4cdb_adc0_8c91_f11c,
+        0x7254_6f91_8cb5_862d,
+    ]},
+    Scalar{ ls: [
+        0xd19c_0f2f_9575_2035,
+        0xc13c_2523_f615_96d7,
+        0xee25_f21e_8bcb_d6c2,
+        0x16da_633e_4bcf_a122,
+    ]},
+    Scalar{ ls: [
+        0xcc2e_f009_c331_cfb1,
+        0x3d7e_8266_48a8_76fc,
+        0x7442_b045_f93f_9b4e,
+        0x380f_a74e_5a41_12de,
+    ]},
+    Scalar{ ls: [
+        0x0f8f_8374_da17_5b62,
+        0x6e2c_1954_9294_f34f,
+        0x2071_e3ab_b803_88b8,
+        0x257c_9612_03f4_7d0f,
+    ]},
+    Scalar{ ls: [
+        0x3400_c2e7_03ec_93fc,
+        0xbe2c_ac08_ecbb_764a,
+        0x11c1_b1ce_23a7_187c,
+        0x2325_a541_93c5_d5e6,
+    ]},
+    Scalar{ ls: [
+        0x58f3_18be_0a26_f3f5,
+        0x3ff5_ba05_862c_2055,
+        0xb61b_bdd7_de0e_dd2d,
+        0x68a8_9fd2_cd97_38c4,
+    ]},
+    Scalar{ ls: [
+        0x5f9d_63f1_bcaa_8762,
+        0x6163_2423_a037_d7a8,
+        0x4514_7d97_4cbf_cf63,
+        0x132a_ce05_9e6b_d1af,
+    ]},
+    Scalar{ ls: [
+        0xfdf1_35b0_08f8_99d2,
+        0x7a80_5ed3_a2ec_f22b,
+        0x1a9a_7d0a_bd13_3faa,
+        0x0595_943e_4fce_9b63,
+    ]},
+    Scalar{ ls: [
+        0x4b51_0e7c_7235_2b3d,
+        0xb825_6e8f_f576_4bbe,
+        0xd9a8_f53d_077a_eb3e,
+        0x02cc_32c2_6ef7_e8f8,
+    ]},
+    Scalar{ ls: [
+        0xf92e_d5a0_4ad3_c55b,
+        0xc2f5_bc4a_6300_8a86,
+        0x9277_00c9_64ef_9a4e,
+        0x556d_1423_8894_679c,
+    ]},
+    Scalar{ ls: [
+        0x183d_11fe_f811_fd7c,
+        0xd1d8_1758_5cb8_2d87,
+        0x5948_f15d_50c1_b3d5,
+        0x3b2d_245b_a5b9_c430,
+    ]},
+    Scalar{ ls: [
+        0x404d_9a41_8db5_53de,
+        0xf7ec_0906_fb79_5857,
+        0x8f29_ee88_e97e_5eec,
+        0x1d34_aa7f_93e4_8be0,
+    ]},
+    Scalar{ ls: [
+        0xb891_2bad_da3b_bc66,
+        0x50a2_15ce_03ac_d19c,
+        0x02b7_9765_bbd6_5e9e,
+        0x3c44_c93a_1ad7_f8c6,
+    ]},
+    Scalar{ ls: [
+        0x2ea7_727b_059b_d797,
+        0x7c7d_6f46_2871_1277,
+        0x37b7_29d7_798c_63e4,
+        0x5b18_a981_9798_7c32,
+    ]},
+    Scalar{ ls: [
+        0x873d_2110_2878_2198,
+        0xb8dd_9aa4_1ce1_46f9,
+        0xfe8c_9f40_100f_6e69,
+        0x4dc1_a8ce_d27d_6693,
+    ]},
+    Scalar{ ls: [
+        0x0a9d_8a27_3e82_0b29,
+        0x13c6_7659_6915_8284,
+        0xf44a_d5fa_bb48_735e,
+        0x268d_eb31_487a_5f96,
+    ]},
+    Scalar{ ls: [
+        0xcdf2_6e73_eebf_6952,
+        0x0f82_efe7_df57_c4c3,
+        0x6ed7_b1e4_2040_d3de,
+        0x50a9_a0d7_f55d_9a7d,
+    ]},
+    Scalar{ ls: [
+        0x55e8_1f57_c298_4eab,
+        0x7e1e_1358_42c5_dc76,
+        0x83df_8400_451e_72ad,
+        0x27cd_4f0b_7729_8459,
+    ]},
+    Scalar{ ls: [
+        0x83a9_d4be_4ce2_d226,
+        0xa7ac_e1db_18f3_4ff0,
+        0x63d0_532c_8043_ca01,
+        0x66ad_4898_a86e_8498,
+    ]},
+    Scalar{ ls: [
+        0xc222_1d65_9fb8_d5a0,
+        0xb47e_728e_24e8_4a06,
+        0x59d0_4239_4974_7a73,
+        0x49da_01c4_9046_000c,
+    ]},
+    Scalar{ ls: [
+        0xe7a8_04a6_7a58_69af,
+        0x836d_38ef_a41b_11db,
+        0x5c81_76f8_2d3e_39c3,
+        0x2cd8_9887_9efa_b5c7,
+    ]},
+    Scalar{ ls: [
+        0xa3ff_9ff1_3945_d131,
+        0xd352_e89a_5f84_0d72,
+        0xf882_1146_f0c6_ca68,
+        0x3995_3120_48d4_35ba,
+    ]},
+    Scalar{ ls: [
+        0x4454_4f41_1bea_5078,
+        0xd3c5_f472_9816_a79c,
+        0x6a55_ed45_f7a0_5ed8,
+        0x18c0_492c_306d_8113,
+    ]},
+    Scalar{ ls: [
+        0x1cdf_0d88_7814_e9a2,
+        0xc982_b48a_e814_61a4,
+        0x8b6f_d114_3bc2_bdfc,
+        0x7236_fcf8_06aa_9d6a,
+    ]},
+    Scalar{ ls: [
+        0x4017_c06a_88b1_c415,
+        0x69b6_635e_2889_fd7c,
+        0xbf57_a53f_c8ae_c52a,
+        0x2ef4_c28c_a5c8_d5c6,
+    ]},
+    Scalar{ ls: [
+        0xda07_60f2_b8fb_3cc6,
+        0xed9c_e271_5ee2_0928,
+        0xdf14_8ac5_d7c4_67b9,
+        0x6744_d68b_6b76_e56a,
+    ]},
+    Scalar{ ls: [
+        0x7b68_0747_2701_b4bb,
+        0xd4c5_e2f9_eec6_360d,
+        0x7f4f_8ea8_f05d_ae42,
+        0x433b_1869_3ac6_a01d,
+    ]},
+    Scalar{ ls: [
+        0x4967_25e4_0e88_9e5c,
+        0x70a1_8dc4_5014_e803,
+        0xc734_db95_5108_2c95,
+        0x45b7_7081_2826_210b,
+    ]},
+    Scalar{ ls: [
+        0x2b76_3b4f_f96b_d022,
+        0x9471_849a_26e1_63eb,
+        0x4508_98a7_deb9_1c17,
+        0x0097_d853_030b_7b23,
+    ]},
+    Scalar{ ls: [
+        0xb468_bad0_f8ec_d8c8,
+        0xba5b_079b_4e92_31c4,
+        0xca03_d594_5752_58f7,
+        0x6bba_12ae_f758_c4f6,
+    ]},
+    Scalar{ ls: [
+        0xa120_0649_a0b1_47e1,
+        0x48e0_50ab_b530_004a,
+        0x0bb5_fe08_7009_c9a0,
+        0x662f_1671_a667_528d,
+    ]},
+    Scalar{ ls: [
+        0x8dfc_0eb0_5dd4_49da,
+        0xf13c_2d9c_b975_9ff1,
+        0x9bdd_cf9f_ff79_785c,
+        0x1c12_446d_f6cd_e292,
+    ]},
+    Scalar{ ls: [
+        0xafce_4f71_f4a0_0065,
+        0xd3c1_4214_d5b2_80b5,
+        0x1584_fe6f_5d3a_b3fa,
+        0x38c4_c5a7_133a_4627,
+    ]},
+    Scalar{ ls: [
+        0x4e9f_4828_3888_1936,
+        0x441a_50e5_899b_00b5,
+        0x39b1_f527_ee78_0d10,
+        0x1a84_3cb4_07c2_1dd4,
+    ]},
+    Scalar{ ls: [
+        0x98c9_2d2f_801b_26e6,
+        0xabc4_1c92_0f86_6616,
+        0xf9e4_9388_4058_0f80,
+        0x6288_74c7_bb6a_9da0,
+    ]},
+    Scalar{ ls: [
+        0xe1c2_abbe_deec_ac7a,
+        0xea70_6869_8a84_8017,
+        0xfff2_5f15_f1a6_6866,
+        0x1a99_262a_a87c_a263,
+    ]},
+    Scalar{ ls: [
+        0x8e61_2673_34a5_3b19,
+        0x3012_1cfe_affa_82e7,
+        0xe381_2779_a1de_d600,
+        0x3ad1_4f45_17d6_033b,
+    ]},
+    Scalar{ ls: [
+        0x263f_332b_633b_393d,
+        0xbd77_1ab8_2864_adaa,
+        0x83d3_36fa_dc4a_ec45,
+        0x3d76_d579_a693_0801,
+    ]},
+    Scalar{ ls: [
+        0xf694_3be6_679f_efc4,
+        0x8c6d_45f6_c37c_1d4a,
+        0x0aa6_4b22_6ebf_1a50,
+        0x3774_89b8_fa67_4b26,
+    ]},
+    Scalar{ ls: [
+        0x60e3_6da9_d141_d6d5,
+        0x9dd2_1b9e_452a_47dd,
+        0x54c4_4d8f_0dd9_2710,
+        0x033f_c9fd_dcce_fdc8,
+    ]},
+    Scalar{ ls: [
+        0x9160_be11_3c5b_7204,
+        0x3e2b_0d23_92fa_51ce,
+        0x1ca8_dd54_262e_4145,
+        0x37bf_7723_c410_ae7a,
+    ]},
+    Scalar{ ls: [
+        0x45d6_1cb4_3105_776a,
+        0xfdb4_a2bb_966d_e617,
+        0xcd43_57e4_8a8c_0d14,
+        0x4e74_90f3_90fe_ba3a,
+    ]},
+    Scalar{ ls: [
+        0xf767_e44f_c4d8_41be,
+        0x667d_fa56_2214_fec2,
+        0x61e7_1e98_1ac4_eaae,
+        0x4cbe_1713_9b12_72f0,
+    ]},
+    Scalar{ ls: [
+        0xe09d_064d_942c_4ce7,
+        0xd518_1a0d_4ad3_497a,
+        0xcf92_488c_bd83_4d43,
+        0x52f3_3b02_95d4_94c6,
+    ]},
+    Scalar{ ls: [
+        0xe1fa_c72d_8064_61b6,
+        0x2577_b09c_796b_e7da,
+        0x7140_aeda_fe04_f65d,
+        0x602d_a849_e047_fc33,
+    ]},
+    Scalar{ ls: [
+        0x9705_2feb_b4f3_b21b,
+        0x02d4_5853_5cbf_921c,
+        0xcb11_5943_320e_bf11,
+        0x493d_1f2d_fe02_7932,
+    ]},
+    Scalar{ ls: [
+        0xc8f5_047a_b564_0ba5,
+        0xc82f_18b3_5efb_19f9,
+        0x789f_ea29_65bd_e496,
+        0x283a_2ad6_2961_2559,
+    ]},
+    Scalar{ ls: [
+        0x4971_23ce_16d1_ceb2,
+        0x5da8_743c_21dd_47a9,
+        0x690f_12d0_fed2_122c,
+        0x1a1b_436b_0a9a_67cf,
+    ]},
+    Scalar{ ls: [
+        0x9933_e696_3f2f_f447,
+        0xc4ea_43b1_2186_c3c6,
+        0xd327_08f1_bcb8_c608,
+        0x2421_af00_06f3_902a,
+    ]},
+    Scalar{ ls: [
+        0x830b_6d4e_03d3_43b6,
+        0xc16c_264f_ca02_ba93,
+        0x6a27_9386_814a_7123,
+        0x2425_d152_3d18_7713,
+    ]},
+    Scalar{ ls: [
+        0x4a2c_ada3_5880_9889,
+        0xa4a5_1bad_f61c_43ac,
+        0xc981_5175_db39_ed63,
+        0x48b8_408b_194e_9736,
+    ]},
+    Scalar{ ls: [
+        0xcd49_8ea4_9f58_6f72,
+        0x2187_2834_5804_fdc4,
+        0x6a30_df33_63fc_cc7c,
+        0x6d11_4db8_bef7_18eb,
+    ]},
+    Scalar{ ls: [
+        0xe840_e936_89b6_2ab5,
+        0x0873_043f_226f_63be,
+        0x7e70_9389_781e_3e23,
+        0x5068_f56c_2343_ae72,
+    ]},
+    Scalar{ ls: [
+        0xa191_d982_d725_f1e4,
+        0x26d5_c430_4cf4_5f45,
+        0x6fc8_5fac_f4de_20af,
+        0x4757_d766_bb26_72e2,
+    ]},
+    Scalar{ ls: [
+        0xe23e_5203_8d88_11ef,
+        0x2e9b_946f_e682_73ff,
+        0x58fc_2a1d_c30c_a450,
+        0x2b5e_5fa1_2e61_0914,
+    ]},
+    Scalar{ ls: [
+        0x22cf_4d3e_ae62_c912,
+        0xa316_2e31_46af_89b9,
+        0xf4d5_6d2f_2028_26a0,
+        0x1463_c860_b2f5_3d14,
+    ]},
+    Scalar{ ls: [
+        0x3d34_06b9_5db7_bc2d,
+        0x1dbf_d0b2_1175_39c8,
+        0x608a_40c7_1b3c_ce34,
+        0x5f99_09be_5e01_6510,
+    ]},
+    Scalar{ ls: [
+        0x8c96_78e6_e5f4_35b8,
+        0xf381_2d4c_7ce3_cc9e,
+        0xcd5e_f6ac_e845_1b8d,
+        0x1ca5_1691_4609_cb5c,
+    ]},
+    Scalar{ ls: [
+        0x1a1e_332e_b276_970b,
+        0xa500_a898_c6ed_7b3a,
+        0x7643_757d_4382_e93b,
+        0x3021_b442_ecb7_d2e1,
+    ]},
+    Scalar{ ls: [
+        0xcad7_2e3e_d0a5_1a07,
+        0xedae_c49c_f71f_a466,
+        0x093f_c8d6_7d38_b3bf,
+        0x72e8_009b_88a0_9d61,
+    ]},
+    Scalar{ ls: [
+        0x02d1_c3c6_286c_4407,
+        0x0102_0628_f4b3_f326,
+        0xb5d1_ea13_070c_5fe6,
+        0x4da2_17bc_020f_cfdb,
+    ]},
+    Scalar{ ls: [
+        0x0762_3b3a_3a1e_d069,
+        0xd21e_c81c_407b_15df,
+        0xa936_6103_b9a3_cfba,
+        0x04e4_b163_dcbc_031d,
+    ]},
+    Scalar{ ls: [
+        0x710d_768b_171e_3498,
+        0x725f_9201_9bd3_235a,
+        0xc9d0_fd9f_38f1_1d1c,
+        0x690b_065c_8518_bc18,
+    ]},
+    Scalar{ ls: [
+        0xd0a0_7d7b_fba5_0c77,
+        0x4153_6825_944c_e978,
+        0x5d50_ff93_924d_18d2,
+        0x3590_775c_c432_72b4,
+    ]},
+    Scalar{ ls: [
+        0xa7d8_c13e_04c9_4e8b,
+        0x1d4c_ab96_3413_f317,
+        0xdb70_1035_ad8e_aa8f,
+        0x1b62_826c_5620_7a89,
+    ]},
+    Scalar{ ls: [
+        0x6553_2086_a94f_7fc5,
+        0x2fea_fbf5_c9d5_f04f,
+        0x4908_e3eb_99e8_d1b4,
+        0x4fff_3115_7c23_7b77,
+    ]},
+    Scalar{ ls: [
+        0x0725_b686_ab0d_8fa9,
+        0x00f0_a906_d3cd_f1e7,
+        0x4973_3c27_66d3_21d5,
+        0x0180_29af_72f5_5a76,
+    ]},
+    Scalar{ ls: [
+        0xcd0d_1408_9fcd_24e4,
+        0x4041_5dd1_a267_feb8,
+        0x615b_2337_929d_b5a7,
+        0x0a3f_67b9_db85_780c,
+    ]},
+    Scalar{ ls: [
+        0x8fe5_01c8_c837_672d,
+        0x34aa_443e_d072_159e,
+        0x8115_cdd6_c697_e69b,
+        0x1ac1_a6b7_ae93_6638,
+    ]},
+    Scalar{ ls: [
+        0x35f8_224d_1f65_fa45,
+        0xf3ef_a0b0_062f_9efa,
+        0xa359_1ccf_1a9e_f67b,
+        0x1567_32d9_b049_dd8a,
+    ]},
+    Scalar{ ls: [
+        0x3cfc_8c61_895c_46e3,
+        0xa746_04b1_6815_fed1,
+        0x91c7_9827_5a77_5b43,
+        0x56ec_5410_90c0_f631,
+    ]},
+    Scalar{ ls: [
+        0xa833_f14e_5b0a_f8a7,
+        0x42e2_d311_dec3_230e,
+        0x2b55_9231_4444_dfba,
+        0x54b2_b632_7c32_9328,
+    ]},
+    Scalar{ ls: [
+        0x181b_4ee9_ada8_00eb,
+        0x5a46_2f79_c27a_4ada,
+        0xa747_1bae_84e0_0f99,
+        0x06cc_d894_79e1_086b,
+    ]},
+    Scalar{ ls: [
+        0xf10f_a793_3d79_0bcc,
+        0x5193_af0d_b164_264f,
+        0x1ac4_e973_201c_5771,
+        0x592d_dad4_1dab_04ac,
+    ]},
+    Scalar{ ls: [
+        0xbc2c_6052_e6c3_a1bd,
+        0xbf7e_54cd_6028_fd75,
+        0xfda1_f412_a52f_25db,
+        0x4bd1_c125_7e3a_4325,
+    ]},
+    Scalar{ ls: [
+        0x24f4_03de_f8b5_d21a,
+        0x7adb_18a3_6f2f_9704,
+        0x329d_be80_9868_232f,
+        0x2e00_df39_07c4_b888,
+    ]},
+    Scalar{ ls: [
+        0x1c3f_90e6_2924_1852,
+        0xcfb5_873e_7874_1fee,
+        0xebf3_f656_685a_9720,
+        0x0191_0214_71e7_3978,
+    ]},
+    Scalar{ ls: [
+        0x8f4b_93e2_c7d3_41b4,
+        0xe4da_756b_565b_0163,
+        0x42e5_0c36_4782_5970,
+        0x1141_58b1_0fae_d579,
+    ]},
+    Scalar{ ls: [
+        0x60b8_a7ed_4761_02d2,
+        0x91c8_7ea1_f531_6cff,
+        0x6446_cbfb_5c3e_bd35,
+        0x3b23_f44a_6edd_19d6,
+    ]},
+    Scalar{ ls: [
+        0x65a0_cbe6_4288_827d,
+        0x035c_8744_2bc0_dfa7,
+        0x232c_f48c_ad49_5281,
+        0x04d1_bf95_1ff6_8b3e,
+    ]},
+    Scalar{ ls: [
+        0x601d_b881_9238_94e0,
+        0x8108_4e9b_8ff9_8b82,
+        0x2f9b_a06b_e019_3fb8,
+        0x1fb0_c03e_c513_2368,
+    ]},
+    Scalar{ ls: [
+        0xcb0c_6e48_03b1_9f94,
+        0x3975_b3b4_9937_6298,
+        0x9158_1051_5256_2174,
+        0x0c9c_b511_82d2_d755,
+    ]},
+    Scalar{ ls: [
+        0x3e78_2971_8814_31ab,
+        0x1a31_a100_e69d_dc93,
+        0x0aed_7128_2f88_144e,
+        0x6d70_16a9_ba65_0652,
+    ]},
+    Scalar{ ls: [
+        0x2a3c_5ebc_e46a_d3a9,
+        0x0df2_f5b2_34df_22f8,
+        0xfb75_f48f_1124_e2ee,
+        0x1104_046a_7167_5545,
+    ]},
+    Scalar{ ls: [
+        0x564d_f1c5_34dc_b750,
+        0x42c7_ac57_ef72_75e5,
+        0xa120_eba2_154e_a131,
+        0x3f11_baff_1719_f6a8,
+    ]},
+    Scalar{ ls: [
+        0x9719_412f_8974_62be,
+        0x4e19_302d_1459_becc,
+        0x552d_1a62_18fc_600e,
+        0x3129_d1e2_7fbf_20d9,
+    ]},
+    Scalar{ ls: [
+        0x2705_5082_91b9_7b70,
+        0xfd06_5346_4186_c8fe,
+        0xfbe8_33ca_81df_b4bc,
+        0x5b6f_f16c_dee6_34c5,
+    ]},
+    Scalar{ ls: [
+        0x46dd_8276_ee64_ffec,
+        0xdd1d_5f0a_55ef_16fb,
+        0x6c63_50ea_7d49_107f,
+        0x1708_36bd_c3a3_020f,
+    ]},
+    Scalar{ ls: [
+        0x9d3d_7a60_bffa_d113,
+        0x5f11_f90a_d820_4e46,
+        0x683e_9a75_0408_4fc6,
+        0x2c0e_92ac_b78c_4b7f,
+    ]},
+    Scalar{ ls: [
+        0x5532_5baf_fa5e_8b7e,
+        0xba04_5e15_c021_665b,
+        0x4c6f_034d_4570_cf9a,
+        0x55db_891d_b53b_5860,
+    ]},
+    Scalar{ ls: [
+        0x5997_1247_0c04_e939,
+        0xf0f9_4305_3c0a_a1a4,
+        0xbe50_dfe2_5869_b148,
+        0x4d8c_7ef3_6c71_dcfb,
+    ]},
+    Scalar{ ls: [
+        0x28a4_677e_76b7_5cab,
+        0x171f_22bf_a27e_be27,
+        0x3be2_4a0e_52c9_6c78,
+        0x57d1_a4ad_843e_6380,
+    ]},
+    Scalar{ ls: [
+        0xfa5a_d871_07d9_0e8b,
+        0x00b9_1585_6a20_be5a,
+        0x781f_40a0_7c3d_4fa0,
+        0x1af5_d3c8_0c39_421f,
+    ]},
+    Scalar{ ls: [
+        0xf0ed_5a89_06ee_6ccf,
+        0x2d6a_fed1_1ece_c6f7,
+        0xa0ce_11ef_b406_ee8b,
+        0x3536_92d0_4090_3fd5,
+    ]},
+    Scalar{ ls: [
+        0x12aa_64a7_abbb_8af8,
+        0x4478_5e84_e76f_7099,
+        0xe6b6_8186_1192_cd14,
+        0x3571_bf96_05ea_aa3b,
+    ]},
+    Scalar{ ls: [
+        0x8ed0_ac78_4653_b817,
+        0x21dd_a559_2416_4f09,
+        0x0caf_ccb8_dffb_a835,
+        0x4c55_6b9c_edaa_a9dc,
+    ]},
+    Scalar{ ls: [
+        0x8ebc_fa3a_064d_edef,
+        0x5fc6_ff1f_f611_5c42,
+        0x8407_4d6e_0818_334f,
+        0x3e28_bfb3_294d_781c,
+    ]},
+    Scalar{ ls: [
+        0x09bc_6f56_5899_c430,
+        0xd855_3dc6_f5db_23b0,
+        0x61af_e86b_1ebc_b706,
+        0x162f_1b12_dd98_89bd,
+    ]},
+    Scalar{ ls: [
+        0xa92d_3808_082a_072e,
+        0x8f7e_8523_ee6b_0c13,
+        0x7f21_60c4_e292_36a2,
+        0x047d_1202_6334_fe7c,
+    ]},
+    Scalar{ ls: [
+        0xe5de_684c_354d_a18d,
+        0x4070_c15e_1023_0bac,
+        0x1a40_cc22_7846_6f48,
+        0x05bc_91c4_44bf_13f7,
+    ]},
+    Scalar{ ls: [
+        0x8bb9_6fcf_5427_a823,
+        0xe2cb_1df9_85c3_0c03,
+        0x3b0f_b8f8_eb7e_df41,
+        0x6c19_c42f_d784_8bc9,
+    ]},
+    Scalar{ ls: [
+        0x091b_9d44_1c44_d01f,
+        0x9443_84dd_6ce8_114d,
+        0x64fc_cb77_3bbd_29ee,
+        0x238d_835b_8929_121f,
+    ]},
+    Scalar{ ls: [
+        0x0b22_e107_3444_20a0,
+        0x8792_89ca_2e4c_3151,
+        0xb40f_f7eb_d988_5dc5,
+        0x536d_c9d0_300b_6cd2,
+    ]},
+    Scalar{ ls: [
+        0x0b91_90b8_4fbe_5409,
+        0x4964_d7bb_b58d_811c,
+        0x7a56_58b2_9af8_7d67,
+        0x4632_953d_f7ad_c348,
+    ]},
+    Scalar{ ls: [
+        0x3331_de07_71c4_c4a7,
+        0xd495_4158_c137_61a4,
+        0xf1b1_f1b3_6998_e8ef,
+        0x36dd_e17f_14e5_cf86,
+    ]},
+    Scalar{ ls: [
+        0x5998_22a0_d935_947b,
+        0x8a1a_bfd7_dc2a_4799,
+        0xeaa9_6179_b66d_a6ca,
+        0x2304_48e4_4e3a_8f35,
+    ]},
+    Scalar{ ls: [
+        0x3211_a924_44c6_e0d5,
+        0x904f_219a_73ca_d9ce,
+        0x5978_59f9_3122_c466,
+        0x5dc0_aee7_b3f6_d7c6,
+    ]},
+    Scalar{ ls: [
+        0x686c_5317_70ed_ab9b,
+        0xc0f0_67d7_f83b_7f35,
+        0x759a_d573_dd17_1aac,
+        0x1556_c54c_5915_63f5,
+    ]},
+    Scalar{ ls: [
+        0xcf9b_6056_0e52_aaaf,
+        0x8bfb_0412_6187_a19c,
+        0xf28c_94f5_3064_babc,
+        0x2598_cb75_4edc_4b65,
+    ]},
+    Scalar{ ls: [
+        0x0d27_a771_466b_687b,
+        0xdcd2_ceda_4cd5_832e,
+        0xd2ce_9158_1186_151e,
+        0x2f4b_67fc_4260_d625,
+    ]},
+    Scalar{ ls: [
+        0xa794_cc64_3269_cfce,
+        0xf0b6_280d_c810_1af4,
+        0xf36c_daf1_b7bd_ea0d,
+        0x644c_4116_08fb_df3f,
+    ]},
+    Scalar{ ls: [
+        0x3244_9ab9_903b_3edc,
+        0x7fd4_d3ee_77a5_de28,
+        0x7796_a8da_d688_10c2,
+        0x0462_ba01_f62a_71fd,
+    ]},
+    Scalar{ ls: [
+        0xc661_32db_0ce2_cd8a,
+        0xd8c9_0983_5acb_e0f1,
+        0xfaca_c0d6_ac43_69af,
+        0x194d_609e_4ad8_e043,
+    ]},
+    Scalar{ ls: [
+        0x3906_1853_c870_24ed,
+        0xe8bb_dd51_915d_1f36,
+        0xdf79_67e7_8d47_f199,
+        0x387a_cbd9_b7bb_5608,
+    ]},
+    Scalar{ ls: [
+        0x0424_8366_c897_d14b,
+        0x7d22_8e93_7a90_4148,
+        0x7154_63fe_662c_5f41,
+        0x23a1_fb0e_0fc6_98f6,
+    ]},
+    Scalar{ ls: [
+        0xc8d2_2273_6b86_8731,
+        0x2bab_cb89_6857_c286,
+        0xee24_bfc9_011d_9c0d,
+        0x41ec_567b_2c28_0111,
+    ]},
+    Scalar{ ls: [
+        0xd4bf_d0fe_47d2_ea17,
+        0x1c31_02b6_33d8_3763,
+        0x2b91_19e5_3313_e705,
+        0x47f5_6c54_ca64_06dc,
+    ]},
+    Scalar{ ls: [
+        0x2f80_3038_b26f_0166,
+        0xb782_2367_b602_440b,
+        0xf236_3e78_b944_66f1,
+        0x7101_7783_6f91_0398,
+    ]},
+    Scalar{ ls: [
+        0xa8b3_d463_fb62_9ad7,
+        0xa41d_e45d_f0f0_f61e,
+        0xfced_6ae4_5585_ecaa,
+        0x1dea_fd0d_7e92_c10c,
+    ]},
+    Scalar{ ls: [
+        0x9b30_cd4c_7f1e_a982,
+        0xca7c_da01_60e5_319b,
+        0xeb49_f82b_d706_cf1a,
+        0x27a0_186a_a674_9b51,
+    ]},
+    Scalar{ ls: [
+        0x40f6_51cd_0eef_37ea,
+        0x5ca7_dbd6_4710_0c88,
+        0x2144_a512_6e68_c8a8,
+        0x5987_d80c_bf82_f17b,
+    ]},
+    Scalar{ ls: [
+        0xce96_a252_177f_58af,
+        0xae7f_8de3_9faa_8e7a,
+        0x1b7a_efe4_de54_8f51,
+        0x0569_bce1_84ce_5c79,
+    ]},
+    Scalar{ ls: [
+        0x7f9e_09f1_497e_2e31,
+        0x551c_4942_0f4b_3474,
+        0x654a_169c_50a8_6310,
+        0x6127_f1ca_d53b_ccc0,
+    ]},
+    Scalar{ ls: [
+        0x2cb6_8fa8_329e_53d4,
+        0xf62b_ff5c_0e4c_b6ea,
+        0xd178_0eff_e34e_6232,
+        0x3b35_24b1_c369_0466,
+    ]},
+    Scalar{ ls: [
+        0xba38_2bf8_5f43_1ab3,
+        0xa4e5_2fdf_3bcf_d7f6,
+        0x7d13_2ca5_c953_b89c,
+        0x15f5_a1c0_d783_9e3b,
+    ]},
+    Scalar{ ls: [
+        0xc6a6_2bfb_116c_706b,
+        0xc8a6_7861_f708_0c34,
+        0xc798_ec6e_011f_8c62,
+        0x08d8_551e_a403_a02f,
+    ]},
+    Scalar{ ls: [
+        0x39ee_8e12_dc08_7d89,
+        0x312f_86c8_9ade_0472,
+        0xb6b3_abd9_9d7f_0359,
+        0x3f3f_4e62_cf63_039e,
+    ]},
+    Scalar{ ls: [
+        0xd3ba_72cd_8bce_0d36,
+        0x7ec9_b361_c8b7_70e5,
+        0xb8f9_f4b9_258d_0afc,
+        0x42d0_f463_0ef2_ea66,
+    ]},
+    Scalar{ ls: [
+        0x69cb_d645_318c_c778,
+        0x822e_a53f_9002_d2fa,
+        0xf0c3_c7f8_6592_fd5c,
+        0x4dd6_18f0_7753_0297,
+    ]},
+    Scalar{ ls: [
+        0x34bc_ff3e_ce7c_94a6,
+        0xd765_7c71_1511_4f6f,
+        0xa516_39d8_7ad1_c583,
+        0x07e4_e906_7ae1_4b72,
+    ]},
+    Scalar{ ls: [
+        0xc9d7_d680_4d8b_589b,
+        0x41ce_6229_15d2_2f09,
+        0x8219_bf81_dd3b_ee04,
+        0x697e_f1ec_f1b9_77df,
+    ]},
+    Scalar{ ls: [
+        0x586f_fa88_1b64_02b2,
+        0xc92d_db75_594a_d351,
+        0xadff_f39f_590e_9f23,
+        0x6d39_21b2_5235_7a56,
+    ]},
+    Scalar{ ls: [
+        0xf3b5_ce5f_b7f6_3d17,
+        0xbe3d_6b29_8356_a377,
+        0x67e9_ea57_826c_31f4,
+        0x1230_8dfc_d4f3_3cd4,
+    ]},
+    Scalar{ ls: [
+        0x0b99_66a8_5b5f_7d03,
+        0x68ed_d96e_6bee_6b0d,
+        0x4c22_3cf5_a6ac_d85a,
+        0x2385_8d44_94ab_4f0b,
+    ]},
+    Scalar{ ls: [
+        0xe45f_c5e9_760a_fd88,
+        0xf8b3_cea1_93d3_db0e,
+        0x7486_6ad1_d21a_4e8b,
+        0x3780_3394_079f_3bba,
+    ]},
+    Scalar{ ls: [
+        0x1d38_2004_0010_85d4,
+        0x2773_ad9f_b6a8_ca66,
+        0x65d8_1cab_3419_f5d5,
+        0x199a_a0de_9d76_2c80,
+    ]},
+    Scalar{ ls: [
+        0xef21_fdc6_610c_2a93,
+        0x5347_f068_6451_1688,
+        0xd1ee_0f58_4ffd_4295,
+        0x09cd_c95d_2853_0a6e,
+    ]},
+    Scalar{ ls: [
+        0xcdda_532b_b2fe_bb2a,
+        0xe4c8_b00a_4d98_a179,
+        0x0aca_d82e_480d_330a,
+        0x180f_1610_5a3d_a4b1,
+    ]},
+    Scalar{ ls: [
+        0xe6cf_8eea_4ed2_f571,
+        0x7323_6eb9_9c4a_6ffc,
+        0x636e_325f_82aa_9325,
+        0x3fee_7145_ccc7_bd94,
+    ]},
+    Scalar{ ls: [
+        0x63a9_b59d_30fb_338d,
+        0x66c5_a96b_a76b_7cee,
+        0x4cfc_186f_6175_30cc,
+        0x4904_e060_09a2_f6e3,
+    ]},
+    Scalar{ ls: [
+        0x1668_a1af_08db_137e,
+        0xb780_9201_5f17_bbb7,
+        0x7f74_21e5_9b6d_4c41,
+        0x66df_34f3_590d_5e46,
+    ]},
+    Scalar{ ls: [
+        0xd74e_6ca1_c783_3429,
+        0x8c68_8ea9_16c0_0a49,
+        0x549c_3b99_ace2_5903,
+        0x0628_61d8_ccc1_784e,
+    ]},
+    Scalar{ ls: [
+        0x9704_3051_5f36_fb64,
+        0x8f0c_d52f_79fe_d123,
+        0xa913_8863_bea7_1de1,
+        0x5aad_3e8d_aea0_f821,
+    ]},
+    Scalar{ ls: [
+        0xfbf6_1edd_73ad_393b,
+        0xc41c_caf3_bc52_388a,
+        0x8e55_0167_5670_8e09,
+        0x6aa9_bda1_dcdc_8653,
+    ]},
+    Scalar{ ls: [
+        0x9dc7_fac5_ddaf_f4fd,
+        0x51c2_38d6_1d25_d388,
+        0x4dea_da58_e1dc_2219,
+        0x0e64_e646_5fa9_4614,
+    ]},
+    Scalar{ ls: [
+        0xa7c5_631e_dcbb_7f1a,
+        0x65b3_6fe3_1078_28d1,
+        0xd6b9_15cd_12a8_e017,
+        0x147a_31d7_ef97_56c0,
+    ]},
+    Scalar{ ls: [
+        0x63b0_9a66_ad97_bdd4,
+        0x192d_f6c4_c406_3467,
+        0xcd46_0d16_e570_7ebf,
+        0x3d35_2ac2_6e4e_30f9,
+    ]},
+    Scalar{ ls: [
+        0xaf2a_5424_18bb_77a3,
+        0x3e07_6e3c_fc02_7697,
+        0xdffa_8881_6642_2c0b,
+        0x0af2_7c79_cc69_3fe1,
+    ]},
+    Scalar{ ls: [
+        0x12af_49fa_6983_a18f,
+        0xc284_b530_6493_a850,
+        0xf22c_fbdd_2465_aacd,
+        0x265f_6667_3058_d5a7,
+    ]},
+    Scalar{ ls: [
+        0xaf09_1fea_e509_41c7,
+        0x8202_9c35_3bf5_293f,
+        0xae73_8c68_1d52_aa3e,
+        0x60bc_31e7_185f_1e26,
+    ]},
+    Scalar{ ls: [
+        0x7b53_438a_c676_1e9f,
+        0xda09_a547_3422_b7c7,
+        0x2244_90f2_2ff0_9c1c,
+        0x4979_3353_95a4_66e4,
+    ]},
+    Scalar{ ls: [
+        0x515c_d836_94fc_ec15,
+        0x55ac_c039_cc55_9a72,
+        0x7d06_92ec_641c_58a8,
+        0x64bd_75b6_e0c5_1ca6,
+    ]},
+    Scalar{ ls: [
+        0x1769_0a8d_b634_2229,
+        0x2b1b_eb16_86c1_dca8,
+        0xa778_22b3_2e3b_db51,
+        0x61ba_4251_bc68_c52b,
+    ]},
+    Scalar{ ls: [
+        0x0bfb_bc79_0a52_99a7,
+        0xc543_a5da_16c3_3f05,
+        0x2a55_cd72_5ad0_12ff,
+        0x576a_6928_cb77_8846,
+    ]},
+    Scalar{ ls: [
+        0x98b6_378a_441e_2a56,
+        0xf339_651e_fb68_4c5d,
+        0x7424_7b9a_473c_b3a4,
+        0x70be_707e_6b78_ba54,
+    ]},
+    Scalar{ ls: [
+        0x5777_fee1_5f31_54ae,
+        0xabb8_9239_afdf_b98e,
+        0x232d_526f_7355_0380,
+        0x329f_e7df_8f11_4537,
+    ]},
+    Scalar{ ls: [
+        0xea90_ccc6_b3c1_40e8,
+        0xed1c_16ac_0fee_abcb,
+        0xbed5_a758_b7d2_f4c3,
+        0x15ca_f6b7_9fa1_b6b9,
+    ]},
+    Scalar{ ls: [
+        0x2f0b_8828_10e1_eee8,
+        0x474a_f027_a3de_0624,
+        0x2d4e_ec9b_3335_d6ce,
+        0x143d_e704_f10c_f28b,
+    ]},
+    Scalar{ ls: [
+        0x77ad_0ff4_b89b_7199,
+        0x0395_64fd_bdb6_3c19,
+        0x9c8d_d022_debd_23ce,
+        0x22f9_edcb_30b7_1156,
+    ]},
+    Scalar{ ls: [
+        0x537d_95d7_2d0d_ea62,
+        0xb368_da37_c9dc_baa8,
+        0x2af2_2e9e_9d50_20a4,
+        0x2a5f_441a_d73b_feb7,
+    ]},
+    Scalar{ ls: [
+        0xe583_9e09_b937_f377,
+        0xc63d_75cc_1636_0eda,
+        0xd113_ecac_b097_bc99,
+        0x2f5c_68c8_2dc1_f5ef,
+    ]},
+    Scalar{ ls: [
+        0x8791_d4ad_b903_59b7,
+        0x9bef_5f1e_9619_7a3d,
+        0xa8dc_cf9e_9313_9c0c,
+        0x210a_3ee8_ef8a_0ca2,
+    ]},
+    Scalar{ ls: [
+        0xd506_fe38_c82a_d95b,
+        0x5e91_d8bd_161b_0c92,
+        0x083d_8611_1883_97e2,
+        0x3c35_cf74_f282_f055,
+    ]},
+    Scalar{ ls: [
+        0xd9c4_04f1_ffce_f90a,
+        0xa5f0_cadc_0c85_107e,
+        0xc260_1a77_1725_e25b,
+        0x4f06_6f03_e80e_34d2,
+    ]},
+    Scalar{ ls: [
+        0x1722_e899_0898_94ed,
+        0x1726_08df_620d_08c8,
+        0x8bbe_da62_6b16_6b59,
+        0x41b9_1f5b_7115_7da8,
+    ]},
+    Scalar{ ls: [
+        0x3169_7a85_50c1_ea39,
+        0xbe18_4e66_27c6_e8c7,
+        0xb952_a2aa_c755_611f,
+        0x62e2_6706_154c_8395,
+    ]},
+    Scalar{ ls: [
+        0x041c_ed20_0288_69e1,
+        0x06a9_6ecd_1968_e79c,
+        0x1d9e_90f8_493b_8c8c,
+        0x39a7_4e51_fe29_e9d0,
+    ]},
+    Scalar{ ls: [
+        0x996b_44aa_cb08_a7b7,
+        0x8fd7_e6c7_4642_536c,
+        0x4a98_1e6c_282d_8c82,
+        0x5522_7a99_1ea1_ac16,
+    ]},
+    Scalar{ ls: [
+        0x3346_065b_54f6_f64b,
+        0x8e76_0c60_061b_9836,
+        0x57cc_e1d1_61d7_5551,
+        0x6c3a_13e1_0f22_959c,
+    ]},
+    Scalar{ ls: [
+        0xb7f6_3048_b967_7649,
+        0x60e6_f56f_dc86_7f55,
+        0xeccb_6c44_0e49_f1e5,
+        0x302a_4838_976a_e3ac,
+    ]},
+    Scalar{ ls: [
+        0x334b_e401_7dc7_c74c,
+        0xf2a4_fb22_37fd_edc0,
+        0x09b0_be5e_ee38_ce56,
+        0x58cf_907e_3daf_3d4d,
+    ]},
+    Scalar{ ls: [
+        0xfb8f_6726_906b_49fd,
+        0x1186_a88d_fded_4e1d,
+        0x96d2_104a_3bf4_7044,
+        0x0d83_4d75_47be_34e0,
+    ]},
+    Scalar{ ls: [
+        0x55ba_3594_ba48_ec5e,
+        0x388d_80fe_b111_14ad,
+        0x5ddf_ce13_c673_b551,
+        0x455f_8b68_76bf_1726,
+    ]},
+    Scalar{ ls: [
+        0x8b0c_ca4a_dda9_e1b4,
+        0xebf5_61fd_768d_393b,
+        0x7436_2742_06a3_1d25,
+        0x05f2_55c1_d46d_e16e,
+    ]},
+    Scalar{ ls: [
+        0x2fa0_10ea_6c69_70a3,
+        0x4167_ee1f_5870_2d29,
+        0x7638_fad7_0767_b398,
+        0x17d2_21d0_0b71_3ed2,
+    ]},
+    Scalar{ ls: [
+        0xc74e_c4bf_66f0_d2e5,
+        0x8b6d_b0a9_7392_cdce,
+        0x84bc_e556_69c0_7494,
+        0x342f_70aa_ac43_bd61,
+    ]},
+    Scalar{ ls: [
+        0xce83_6422_8d2b_4cdf,
+        0x3a97_b04e_524f_4dec,
+        0xa6e3_70bd_7993_61f1,
+        0x734a_41b5_13b5_b42e,
+    ]},
+    Scalar{ ls: [
+        0x8419_7292_6c17_50ef,
+        0xe6ac_02dc_b888_740e,
+        0x0c62_f676_a81d_5531,
+        0x281f_24d6_61dd_e137,
+    ]},
+    Scalar{ ls: [
+        0x8a6c_368a_3fb9_33ad,
+        0x9eaa_fdf0_532d_4ef4,
+        0x9207_adfd_584e_44da,
+        0x41f9_cb2f_81e2_4522,
+    ]},
+    Scalar{ ls: [
+        0x6cf9_11e7_7148_b775,
+        0x43a8_13a6_77d4_0d03,
+        0xc1c1_be4e_ef8b_9ec0,
+        0x3b2e_72e7_1fe0_a9eb,
+    ]},
+    Scalar{ ls: [
+        0xe8ad_7ff1_0ed1_481e,
+        0xa4a0_b467_7813_1929,
+        0xf5d3_4ce3_076e_cebf,
+        0x50a9_fe7d_efd6_dd6c,
+    ]},
+    Scalar{ ls: [
+        0x562f_2621_c2f0_09cb,
+        0xcada_e0bb_d7aa_629e,
+        0xa6d6_9427_c3f6_6e58,
+        0x6dba_a0a9_e6a5_e714,
+    ]},
+    Scalar{ ls: [
+        0xf4d3_1028_df2e_3228,
+        0x2360_d268_16fa_9e68,
+        0xc08a_5959_63d9_8bca,
+        0x513c_2d66_0778_e957,
+    ]},
+    Scalar{ ls: [
+        0xc507_0034_75bb_6692,
+        0x8496_caaf_34fd_b085,
+        0x451f_e393_2dee_c837,
+        0x219a_a8d3_750e_057e,
+    ]},
+    Scalar{ ls: [
+        0x8c0c_265a_9166_dfe4,
+        0x952e_99d4_02f7_f426,
+        0x65b9_a308_d0c2_f8a7,
+        0x0fb5_e273_d3b0_2c4f,
+    ]},
+    Scalar{ ls: [
+        0x7b1c_574e_1e1f_afa4,
+        0x3dba_ffa8_29fd_d8b3,
+        0x6123_7809_f130_e79f,
+        0x16ec_0563_a1ed_1cd9,
+    ]},
+    Scalar{ ls: [
+        0x9336_c091_0268_2479,
+        0x56f7_44a3_b12b_2ca7,
+        0x4a3c_a1d9_c82e_d16c,
+        0x2bbf_d359_df2b_623a,
+    ]},
+    Scalar{ ls: [
+        0xd0e5_81a9_7cda_7da0,
+        0x3a78_a9de_f70e_1d53,
+        0x704b_8580_5d57_cd8b,
+        0x2bb2_dbb3_59f5_152f,
+    ]},
+    Scalar{ ls: [
+        0x75a9_e665_f9c3_6e27,
+        0x48fe_b6e0_e597_6640,
+        0x517f_ce81_8a1c_0398,
+        0x73a4_a362_a556_31e7,
+    ]},
+    Scalar{ ls: [
+        0x96a1_cf0c_3b33_1320,
+        0xef90_7974_7a60_8471,
+        0x6bf8_61d3_11c0_8442,
+        0x7063_b3c7_0212_9611,
+    ]},
+    Scalar{ ls: [
+        0xf892_8be2_0f37_d0e2,
+        0x1eed_f1b9_3b06_d1a2,
+        0x6bb9_4e64_9b56_1b6e,
+        0x3198_5df0_d04c_a40e,
+    ]},
+    Scalar{ ls: [
+        0x0021_fa4f_1c02_7c35,
+        0xee10_80aa_02e0_fc46,
+        0x03d8_619d_f7a4_8c9e,
+        0x600e_f9a5_191b_cdda,
+    ]},
+    Scalar{ ls: [
+        0x8ae1_d3c3_758d_cd5d,
+        0x75d0_a0e0_47aa_a203,
+        0x495e_0faf_d3c4_1dc0,
+        0x23b3_92fd_11da_d25a,
+    ]},
+    Scalar{ ls: [
+        0xe5e8_6351_3c1a_2f3a,
+        0xbdbd_075c_748a_2d84,
+        0x08b9_1a66_afc4_1587,
+        0x218b_d407_0a9f_e6fb,
+    ]},
+    Scalar{ ls: [
+        0x5cdc_2ada_836c_22e9,
+        0x2458_1600_d9d3_8e19,
+        0x633a_4d62_8b68_314c,
+        0x5976_06ab_a6df_edaf,
+    ]},
+    Scalar{ ls: [
+        0x6a8a_3ce3_a6ec_51ec,
+        0x9a2e_2579_aa49_6942,
+        0xb6a7_9c35_8d18_fbe3,
+        0x13e4_87d5_b525_e12a,
+    ]},
+    Scalar{ ls: [
+        0xcfcf_aa40_26c4_e2f2,
+        0xe28f_6a91_3d46_7e0e,
+        0x1b17_90f4_d9b8_4dd4,
+        0x6021_affc_1058_3eac,
+    ]},
+    Scalar{ ls: [
+        0x9e3f_dd9a_437e_75d2,
+        0xc9c9_8656_4168_967d,
+        0x2fc9_a3ea_f841_9132,
+        0x2eba_7279_1a87_919b,
+    ]},
+    Scalar{ ls: [
+        0x92ab_59c8_4399_baef,
+        0x17a2_4c3a_68cc_6349,
+        0x0937_7339_d9ba_ccb1,
+        0x0c0e_765a_9570_61d7,
+    ]},
+    Scalar{ ls: [
+        0xdd2a_bc4a_9f81_0c7c,
+        0x52b9_9d5b_beb2_7d24,
+        0xf62b_0257_73ca_a13f,
+        0x6217_f4a8_6252_a402,
+    ]},
+    Scalar{ ls: [
+        0xff92_2403_d97f_5136,
+        0x2579_880f_5f04_5c4e,
+        0x36cb_6c9c_a174_939f,
+        0x1120_8ad3_fe75_3e6d,
+    ]},
+    Scalar{ ls: [
+        0x47ad_bbd9_d8dd_c939,
+        0x5348_7cf6_1101_d7e4,
+        0xd45c_6c6d_f4a7_e064,
+        0x4144_2da1_d8b7_09f0,
+    ]},
+    Scalar{ ls: [
+        0x480f_7e83_278f_6e54,
+        0x649e_1cce_41dc_fa4c,
+        0x894d_a97e_10c7_89d2,
+        0x6c9e_b5d7_c480_07b8,
+    ]},
+    Scalar{ ls: [
+        0xbe14_3308_7335_2c62,
+        0xcf55_4daa_8ed6_7ee7,
+        0x6720_13aa_d47e_69e5,
+        0x2ba1_c407_da3b_a70b,
+    ]},
+    Scalar{ ls: [
+        0x06de_1617_cf46_6ca2,
+        0x5d78_08b6_463e_cf65,
+        0x99ac_33ea_df98_07d7,
+        0x5d29_ac74_8c4a_e3eb,
+    ]},
+    Scalar{ ls: [
+        0x8377_0321_19a6_25f3,
+        0xf55e_d0b7_565c_da7d,
+        0xcbc4_9653_cb60_2df6,
+        0x6d00_378d_91bd_a8d6,
+    ]},
+    Scalar{ ls: [
+        0x95a1_8643_b09f_03c1,
+        0xa7c6_360f_9a3a_df4b,
+        0x9562_b285_affb_649e,
+        0x10f6_a297_c31f_81a7,
+    ]},
+    Scalar{ ls: [
+        0x1279_6183_b2fa_eb03,
+        0x7876_3e48_f097_1307,
+        0x60e2_ec96_7f07_ec49,
+        0x337f_93cf_e417_4ef6,
+    ]},
+    Scalar{ ls: [
+        0x5c69_4a82_d6b7_7a8e,
+        0x6b82_7f6d_e3a2_3812,
+        0x8653_109c_dbfb_dd02,
+        0x1d62_c713_10e7_370c,
+    ]},
+    Scalar{ ls: [
+        0x21a0_fac8_0883_33e4,
+        0x4665_2697_38f5_ec83,
+        0x6971_f4e8_b5bd_2f1f,
+        0x5e13_aea3_ad97_1950,
+    ]},
+    Scalar{ ls: [
+        0xf8a1_7d42_fc4b_355f,
+        0x3bad_8891_527a_1d80,
+        0x65d6_a23e_5a46_efb0,
+        0x6e6b_603f_2056_071f,
+    ]},
+    Scalar{ ls: [
+        0x947d_be4e_3b97_e66e,
+        0x81f5_8792_f9e3_c75d,
+        0x6e24_003f_187e_41e3,
+        0x6a5b_ceea_094d_566c,
+    ]},
+    Scalar{ ls: [
+        0x5e6d_28ee_8a3b_3731,
+        0xea20_99a9_8d93_27e3,
+        0x587c_df77_63c1_2b95,
+        0x1f75_90f2_0d32_3551,
+    ]},
+    Scalar{ ls: [
+        0x4cac_9ba4_8778_dc25,
+        0x2638_9bba_9d2a_5e09,
+        0xd2eb_75ba_907c_1f4e,
+        0x3312_a35c_2dce_b398,
+    ]},
+    Scalar{ ls: [
+        0x288a_da16_796d_73f5,
+        0x0f4a_95f9_ddbc_cc25,
+        0x9b8a_6dbb_6a6f_8f63,
+        0x2f69_c716_21c4_5105,
+    ]},
+    Scalar{ ls: [
+        0x2216_cfe7_fb9c_9535,
+        0xd286_9a75_0c72_18e7,
+        0x47a2_9576_64c5_8510,
+        0x1543_dfeb_9afb_6a31,
+    ]},
+    Scalar{ ls: [
+        0x4ace_5122_f9a2_ebca,
+        0x7244_0b4e_941f_6c1b,
+        0x3263_a701_b70f_8e17,
+        0x0ca8_bea0_6660_e9a4,
+    ]},
+    Scalar{ ls: [
+        0x414a_0142_6f8e_ca29,
+        0x5547_3fb3_b9ec_2568,
+        0x51a6_3b65_5896_1ce1,
+        0x61fe_8138_2f4b_6b7b,
+    ]},
+    Scalar{ ls: [
+        0xcd93_bd8b_76f9_7a33,
+        0x0922_133b_89ed_d5da,
+        0x1ba0_dfea_f7eb_83e7,
+        0x0b28_56a3_6d7b_5ff9,
+    ]},
+    Scalar{ ls: [
+        0x5d3c_937f_0bec_c1a8,
+        0xb41a_e2b2_2897_cae6,
+        0x5e29_00f5_b89e_8314,
+        0x15f7_4946_27e9_0505,
+    ]},
+    Scalar{ ls: [
+        0xe755_dd4d_1936_a9d2,
+        0x53b0_8ad7_19c1_6f21,
+        0xdfa9_c1c4_5f5a_a90f,
+        0x077b_73d3_eea7_2b05,
+    ]},
+    Scalar{ ls: [
+        0xdfcf_7a16_8d2b_3d7f,
+        0x5235_a431_09e3_c38a,
+        0x3ab5_061c_b9ba_3538,
+        0x359c_627f_34be_4685,
+    ]},
+    Scalar{ ls: [
+        0x5b2f_8684_9158_bd00,
+        0x01a6_ef04_af90_4931,
+        0x823f_ded0_cd0a_d7e7,
+        0x28de_4c1c_8ba9_a65f,
+    ]},
+    Scalar{ ls: [
+        0xb1c6_b62f_87d7_a001,
+        0x7c24_6842_a7c0_a4f7,
+        0xd88a_f012_8c64_0b1a,
+        0x0210_ebc5_72d2_6066,
+    ]},
+    Scalar{ ls: [
+        0x3cdb_1301_0273_5406,
+        0x867c_7863_f408_6e7b,
+        0x3505_970f_1e96_dacb,
+        0x02c9_4558_d291_4e6a,
+    ]},
+    Scalar{ ls: [
+        0xa516_139a_c188_34ae,
+        0xe031_57e8_1ee0_5f7d,
+        0xbcd9_bc20_03f0_df39,
+        0x2c52_7d57_a4ae_867e,
+    ]},
+    Scalar{ ls: [
+        0xb948_435c_7c8b_11e8,
+        0x8c26_aebd_6c19_3129,
+        0x630e_2542_8787_1c6b,
+        0x51e8_3011_66e5_7e1b,
+    ]},
+    Scalar{ ls: [
+        0x4c23_c878_2e24_9a88,
+        0x6b9b_a0e8_e81a_d4de,
+        0x295f_097d_47b9_f989,
+        0x49b8_a18c_38f2_693f,
+    ]},
+    Scalar{ ls: [
+        0x07af_16dc_ef16_8f21,
+        0x38a5_6461_2346_e8c4,
+        0xf5ba_6486_450b_898f,
+        0x3433_e100_afd3_95c2,
+    ]},
+    Scalar{ ls: [
+        0xb4d4_0639_2895_59c4,
+        0x9510_ea17_f07b_ebfb,
+        0xf7e0_8e67_2246_3a8f,
+        0x60a2_c5fb_fabf_e200,
+    ]},
+    Scalar{ ls: [
+        0xd71b_5056_a8ca_1d3e,
+        0xeee8_8752_1f84_eb88,
+        0x64c9_0e54_4d47_9065,
+        0x4f4a_673d_bb3f_d704,
+    ]},
+    Scalar{ ls: [
+        0xd716_2b11_3966_1aec,
+        0x4b73_f484_aa61_ccb3,
+        0xd390_cf03_e72e_e51c,
+        0x2964_db2e_b902_0c71,
+    ]},
+    Scalar{ ls: [
+        0x8f30_02ae_a794_bc0c,
+        0x7c60_7bdd_b1fb_532d,
+        0xb122_a7ab_8ba7_6716,
+        0x71cd_c2ca_476c_a22b,
+    ]},
+    Scalar{ ls: [
+        0xd0bb_f7bc_f83b_703d,
+        0x0f04_857a_c1d9_843b,
+        0x9654_51a1_3797_58e0,
+        0x4c1d_9502_b18f_9322,
+    ]},
+    Scalar{ ls: [
+        0xdcba_fa79_2afb_aaa9,
+        0xccb1_5705_d7ab_4438,
+        0x0657_08aa_2b33_3ae4,
+        0x3d77_e9f0_ab1a_96bf,
+    ]},
+    Scalar{ ls: [
+        0x459d_1df2_c254_e6dd,
+        0x9559_01c5_3e0b_c476,
+        0xd92e_2e2b_357c_b990,
+        0x36b3_145e_4b4a_5d9f,
+    ]},
+    Scalar{ ls: [
+        0x3e90_b48a_b009_a67c,
+        0xed89_8691_7dd0_7b13,
+        0x5d19_a20a_7b2f_aace,
+        0x0f4d_a665_f9a0_3383,
+    ]},
+    Scalar{ ls: [
+        0xb3e8_e7c0_928c_98a7,
+        0x873a_5af9_d81f_ff2a,
+        0xaa9a_2b90_e9aa_0b7e,
+        0x1cd2_16b3_0d0e_d819,
+    ]},
+    Scalar{ ls: [
+        0xaa28_3321_758f_4df8,
+        0xee01_7c56_148a_fc33,
+        0xce1b_c1e8_7c25_5952,
+        0x58e4_8cba_bcae_b8a6,
+    ]},
+    Scalar{ ls: [
+        0x4e45_51c6_36ec_9886,
+        0x4116_e239_ba92_cf37,
+        0x9f31_179b_1401_9299,
+        0x66be_a3d2_2bb1_8c1b,
+    ]},
+    Scalar{ ls: [
+        0xd7a9_adfe_d40e_eab4,
+        0xcf17_ea85_60de_5225,
+        0x50b8_666b_da7d_a6f2,
+        0x45b9_82c3_19b9_a3e2,
+    ]},
+    Scalar{ ls: [
+        0x2b7d_d85f_ecc9_724d,
+        0xdfac_028e_18af_198e,
+        0xf117_a0e9_881b_f28f,
+        0x4762_0111_6650_0884,
+    ]},
+    Scalar{ ls: [
+        0xcca6_d8a5_c91b_77fc,
+        0xd9fa_e861_0b5c_aeef,
+        0x571f_eff3_11ec_0026,
+        0x1dd3_8dc7_729b_5aa3,
+    ]},
+    Scalar{ ls: [
+        0x7038_d7c9_5023_5fa2,
+        0x2b91_f21f_8779_893c,
+        0x1324_ec86_6268_1af2,
+        0x5c70_7770_fa05_b83d,
+    ]},
+    Scalar{ ls: [
+        0x1675_d979_4d78_82ce,
+        0x1ae3_ca86_1c30_f89d,
+        0x8025_3d41_f4a9_4043,
+        0x5a7b_15c5_59b0_b615,
+    ]},
+    Scalar{ ls: [
+        0x0806_6d02_59f7_2c9b,
+        0x90be_55a7_cda7_4b45,
+        0x091e_763d_65cf_09e3,
+        0x5c36_1aa0_7abe_e306,
+    ]},
+    Scalar{ ls: [
+        0x5721_d447_3a0b_a103,
+        0xd253_85d4_3c73_66c9,
+        0xe66e_8dfd_2421_2b2d,
+        0x45bb_0f4f_ddab_1568,
+    ]},
+    Scalar{ ls: [
+        0x7fa8_c0b8_6376_3703,
+        0x8a1f_c524_814e_9e52,
+        0x81a2_7f2c_5827_ae58,
+        0x3d31_aeb4_38c0_bb62,
+    ]},
+    Scalar{ ls: [
+        0xc367_0bc8_6bd8_5906,
+        0x8c50_bc12_cb1c_e537,
+        0x9196_b534_6611_0383,
+        0x5a65_08d7_200f_fc4a,
+    ]},
+    Scalar{ ls: [
+        0x15c4_ba71_331a_1833,
+        0x6d63_c1af_f21e_97d6,
+        0x6f0e_2a80_0b86_25de,
+        0x009a_f333_d5df_279f,
+    ]},
+    Scalar{ ls: [
+        0xa4c6_9dae_96d2_8248,
+        0xad76_2047_afd1_0946,
+        0x2111_b22b_7a32_ed08,
+        0x49a8_0e95_cab5_10a3,
+    ]},
+    Scalar{ ls: [
+        0x1cd0_48ff_c54c_4315,
+        0x9791_d710_9bd9_157d,
+        0x2a63_f7fb_af1e_d499,
+        0x13d1_15c6_357e_b7b5,
+    ]},
+    Scalar{ ls: [
+        0x6506_4da1_c53d_8d43,
+        0x6f39_8bfd_0c14_2d97,
+        0x0b7e_d306_c330_79cc,
+        0x6c7c_af10_e247_1913,
+    ]},
+    Scalar{ ls: [
+        0x21ec_9b0a_9ebe_fafe,
+        0x4bdb_72a1_31e1_6e36,
+        0x9f9f_83d9_fe54_c20d,
+        0x0046_7056_a0be_11a3,
+    ]},
+    Scalar{ ls: [
+        0xc1d6_8cce_231f_fe5a,
+        0x8195_8839_9cee_b927,
+        0x7bb2_9579_4541_6e6d,
+        0x1fff_2d5a_ff45_098b,
+    ]},
+    Scalar{ ls: [
+        0x4772_3d76_70b3_72f8,
+        0x7e0b_9641_0b61_58a3,
+        0x7d8f_6847_46b6_9f52,
+        0x21fb_08d3_bdb9_b2cf,
+    ]},
+    Scalar{ ls: [
+        0x63f9_daa7_1d62_5c81,
+        0x0918_ac2c_d122_a801,
+        0x6e4c_034c_fc85_fd3c,
+        0x31be_dee3_c977_825c,
+    ]},
+    Scalar{ ls: [
+        0x12f3_563e_146e_8caa,
+        0xd244_a2c6_769a_0edd,
+        0x77a6_2119_159b_7954,
+        0x4fd6_cf94_8ef7_ac5a,
+    ]},
+    Scalar{ ls: [
+        0x41f5_0e46_551c_2004,
+        0x9acb_a8a2_9b1f_bcc3,
+        0xd92a_9008_23fb_79a0,
+        0x1cc7_2c50_4e2d_924f,
+    ]},
+    Scalar{ ls: [
+        0xa78b_cc7d_3955_f806,
+        0x2d90_1f8d_5b90_8dbd,
+        0xea3c_55ec_c9c4_43ac,
+        0x560f_da55_d9f3_427b,
+    ]},
+    Scalar{ ls: [
+        0x64b7_79f2_64bf_44f7,
+        0x82cc_c772_ea66_2bcb,
+        0xe71e_5300_e4f2_ac30,
+        0x4860_2f7d_0902_27aa,
+    ]},
+    Scalar{ ls: [
+        0x9bc2_878a_c546_c697,
+        0x566a_5db7_d064_fea8,
+        0xab23_2380_a6da_2bc0,
+        0x366f_f065_5f7d_c851,
+    ]},
+    Scalar{ ls: [
+        0x6a6e_36ef_3bba_af79,
+        0x9013_b722_bbc9_f7d7,
+        0x469f_87f9_924f_8229,
+        0x143c_5d31_cb05_cb7f,
+    ]},
+    Scalar{ ls: [
+        0x4388_5bde_29b5_22a3,
+        0x0b11_783a_948d_7958,
+        0xc44c_da28_cd40_3b43,
+        0x3405_1e58_2130_4fe6,
+    ]},
+    Scalar{ ls: [
+        0x07b2_e173_0408_61d0,
+        0x42b6_1109_9b2f_e090,
+        0xd478_f4f9_ca06_7225,
+        0x0e0c_993a_913a_126d,
+    ]},
+    Scalar{ ls: [
+        0x242c_8eab_a7aa_1bd6,
+        0xe173_b31c_5a85_0dea,
+        0xc55a_a58b_e78d_bfac,
+        0x3df5_a317_9ad4_406a,
+    ]},
+    Scalar{ ls: [
+        0x2179_5081_855f_6305,
+        0x211b_1d15_83c9_bdf5,
+        0x8ae7_8337_a90a_20c5,
+        0x1b7c_72b0_34ef_5586,
+    ]},
+    Scalar{ ls: [
+        0x4b26_49cd_680a_76d1,
+        0xbce7_f09e_31ad_71da,
+        0xf7b3_737d_d309_7754,
+        0x5b80_27a7_a231_79df,
+    ]},
+    Scalar{ ls: [
+        0x65dc_13b8_35cc_678c,
+        0x35d4_87cb_b4d2_e9a2,
+        0x9918_37f6_547c_a089,
+        0x3a07_345c_9ef9_9d55,
+    ]},
+];
+
+/// MDS matrix for width 5
+pub const MDS_5: [[Scalar; 5]; 5] = [
+    [
+        Scalar{ ls: [
+            0x0000_0000_6666_6666,
+            0x11b4_24cb_999a_419a,
+            0x51e8_dcc9_95bf_4331,
+            0x04d4_2378_55c1_0116,
+        ]},
+        Scalar{ ls: [
+            0xffff_ffff_aaaa_aaab,
+            0x1be9_e156_5554_c954,
+            0x1113_4802_ade0_9d57,
+            0x514f_37c6_6334_7f18,
+        ]},
+        Scalar{ ls: [
+            0xdb6d_b6da_db6d_b6dc,
+            0xe6b5_824a_db6c_c6da,
+            0xf8b3_56e0_0581_0db9,
+            0x66d0_f1e6_60ec_4796,
+        ]},
+        Scalar{ ls: [
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+            0x2000_0000_0000_0000,
+        ]},
+        Scalar{ ls: [
+            0xaaaa_aaaa_1c71_c71d,
+            0xd930_778f_e38d_4f8d,
+            0x1c75_7804_7721_063b,
+            0x5cd9_5cf5_5002_2928,
+        ]},
+    ],
+    [
+        Scalar{ ls: [
+            0xffff_ffff_aaaa_aaab,
+            0x1be9_e156_5554_c954,
+            0x1113_4802_ade0_9d57,
+            0x514f_37c6_6334_7f18,
+        ]},
+        Scalar{ ls: [
+            0xdb6d_b6da_db6d_b6dc,
+            0xe6b5_824a_db6c_c6da,
+            0xf8b3_56e0_0581_0db9,
+            0x66d0_f1e6_60ec_4796,
+        ]},
+        Scalar{ ls: [
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+            0x2000_0000_0000_0000,
+        ]},
+        Scalar{ ls: [
+            0xaaaa_aaaa_1c71_c71d,
+            0xd930_778f_e38d_4f8d,
+            0x1c75_7804_7721_063b,
+            0x5cd9_5cf5_5002_2928,
+        ]},
+        Scalar{ ls: [
+            0x0000_0000_3333_3333,
+            0x88da_1265_cccd_20cd,
+            0x28f4_6e64_cadf_a198,
+            0x026a_11bc_2ae0_808b,
+        ]},
+    ],
+    [
+        Scalar{ ls: [
+            0xdb6d_b6da_db6d_b6dc,
+            0xe6b5_824a_db6c_c6da,
+            0xf8b3_56e0_0581_0db9,
+            0x66d0_f1e6_60ec_4796,
+        ]},
+        Scalar{ ls: [
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+            0x2000_0000_0000_0000,
+        ]},
+        Scalar{ ls: [
+            0xaaaa_aaaa_1c71_c71d,
+            0xd930_778f_e38d_4f8d,
+            0x1c75_7804_7721_063b,
+            0x5cd9_5cf5_5002_2928,
+        ]},
+        Scalar{ ls: [
+            0x0000_0000_3333_3333,
+            0x88da_1265_cccd_20cd,
+            0x28f4_6e64_cadf_a198,
+            0x026a_11bc_2ae0_808b,
+        ]},
+        Scalar{ ls: [
+            0xe8ba_2e8b_745d_1746,
+            0x8396_d800_8ba2_9c5c,
+            0x2096_2747_4791_ca2f,
+            0x2c59_c154_f04b_2e0d,
+        ]},
+    ],
+    [
+        Scalar{ ls: [
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+            0x2000_0000_0000_0000,
+        ]},
+        Scalar{ ls: [
+            0xaaaa_aaaa_1c71_c71d,
+            0xd930_778f_e38d_4f8d,
+            0x1c75_7804_7721_063b,
+            0x5cd9_5cf5_5002_2928,
+        ]},
+        Scalar{ ls: [
+            0x0000_0000_3333_3333,
+            0x88da_1265_cccd_20cd,
+            0x28f4_6e64_cadf_a198,
+            0x026a_11bc_2ae0_808b,
+        ]},
+        Scalar{ ls: [
+            0xe8ba_2e8b_745d_1746,
+            0x8396_d800_8ba2_9c5c,
+            0x2096_2747_4791_ca2f,
+            0x2c59_c154_f04b_2e0d,
+        ]},
+        Scalar{ ls: [
+            0xffff_ffff_5555_5556,
+            0x37d3_c2ac_aaa9_92a9,
+            0x2226_9005_5bc1_3aae,
+            0x629e_6f8c_c668_fe30,
+        ]},
+    ],
+    [
+        Scalar{ ls: [
+            0xaaaa_aaaa_1c71_c71d,
+            0xd930_778f_e38d_4f8d,
+            0x1c75_7804_7721_063b,
+            0x5cd9_5cf5_5002_2928,
+        ]},
+        Scalar{ ls: [
+            0x0000_0000_3333_3333,
+            0x88da_1265_cccd_20cd,
+            0x28f4_6e64_cadf_a198,
+            0x026a_11bc_2ae0_808b,
+        ]},
+        Scalar{ ls: [
+            0xe8ba_2e8b_745d_1746,
+            0x8396_d800_8ba2_9c5c,
+            0x2096_2747_4791_ca2f,
+            0x2c59_c154_f04b_2e0d,
+        ]},
+        Scalar{ ls: [
+            0xffff_ffff_5555_5556,
+            0x37d3_c2ac_aaa9_92a9,
+            0x2226_9005_5bc1_3aae,
+            0x629e_6f8c_c668_fe30,
+        ]},
+        Scalar{ ls: [
+            0xc4ec_4ec4_6276_2763,
+            0x40c8_9364_13b0_58eb,
+            0x7e0b_7453_18e0_fe2a,
+            0x521d_817b_8c8f_e0ff,
+        ]},
+    ],
+];
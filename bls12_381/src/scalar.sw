@@ -1,9 +1,13 @@
-library scalar;
+library;
 
-dep util;
-
-use utils::{choice::*, integer_utils::adc}; 
-use util::*;
+use utils::{
+    choice::*,
+    integer_utils::adc,
+    ff::{Field, PrimeField},
+    bigint::U256,
+    monty::{MontyField, MontyParams, montgomery_reduce as monty_reduce},
+};
+use ::util::*;
 
 use core::ops::{Eq, Add, Subtract, Multiply};
 
@@ -72,7 +76,7 @@ const R3: Scalar = Scalar{ ls: [
 ]};
 
 // 2^S * t = MODULUS - 1 with t odd
-const S: u32 = 32;
+pub const S: u32 = 32;
 
 // Explanation from zkcrypto:
 /// GENERATOR^t where t * 2^s + 1 = q
@@ -82,32 +86,74 @@ const S: u32 = 32;
 /// `GENERATOR = 7 mod q` is a generator
 /// of the q - 1 order multiplicative
 /// subgroup.
-const ROOT_OF_UNITY: Scalar = Scalar{ ls: [
+pub const ROOT_OF_UNITY: Scalar = Scalar{ ls: [
     0xb9b5_8d8c_5f0e_466a,
     0x5b1b_4c80_1819_d7ec,
     0x0af5_3ae3_52a3_1e64,
     0x5bf3_adda_19e9_b27b,
 ]};
 
+// Montgomery arithmetic modulo q
+pub struct ScalarParams {}
+
+impl MontyParams for ScalarParams {
+    fn modulus() -> U256 {
+        U256 { ls: MODULUS_SCALAR.ls }
+    }
+
+    fn r() -> U256 {
+        U256 { ls: R.ls }
+    }
+
+    fn r2() -> U256 {
+        U256 { ls: R2.ls }
+    }
+
+    fn r3() -> U256 {
+        U256 { ls: R3.ls }
+    }
+
+    fn inv() -> u64 {
+        INV
+    }
+}
+
+impl Scalar {
+    fn monty(self) -> MontyField<ScalarParams> {
+        MontyField::from_montgomery(U256 { ls: self.ls }, ScalarParams {})
+    }
+
+    fn from_monty(m: MontyField<ScalarParams>) -> Self {
+        Scalar { ls: m.montgomery_form.ls }
+    }
+}
+
 impl ConditionallySelectable for Scalar {
     // Select a if choice == 1 or select b if choice == 0, in constant time.
     fn conditional_select(a: Self, b: Self, choice: Choice) -> Self {
         Scalar{ ls: [
-            ~u64::conditional_select(a.ls[0], b.ls[0], choice),
-            ~u64::conditional_select(a.ls[1], b.ls[1], choice),
-            ~u64::conditional_select(a.ls[2], b.ls[2], choice),
-            ~u64::conditional_select(a.ls[3], b.ls[3], choice),
+            u64::conditional_select(a.ls[0], b.ls[0], choice),
+            u64::conditional_select(a.ls[1], b.ls[1], choice),
+            u64::conditional_select(a.ls[2], b.ls[2], choice),
+            u64::conditional_select(a.ls[3], b.ls[3], choice),
         ]}
     }
 }
 
+impl ConditionallyNegatable for Scalar {
+    // Negate self if choice == 1, leave it unchanged if choice == 0
+    fn conditional_negate(ref mut self, choice: Choice) {
+        self = Scalar::conditional_select(self.neg(), self, choice);
+    }
+}
+
 impl ConstantTimeEq for Scalar {
     // returns (self == other), as a choice
     fn ct_eq(self, other: Self) -> Choice {
-        ~u64::ct_eq(self.ls[0], other.ls[0])
-        & ~u64::ct_eq(self.ls[1], other.ls[1])
-        & ~u64::ct_eq(self.ls[2], other.ls[2])
-        & ~u64::ct_eq(self.ls[3], other.ls[3])
+        u64::ct_eq(self.ls[0], other.ls[0])
+        & u64::ct_eq(self.ls[1], other.ls[1])
+        & u64::ct_eq(self.ls[2], other.ls[2])
+        & u64::ct_eq(self.ls[3], other.ls[3])
     }
 }
 
@@ -122,62 +168,23 @@ impl Scalar {
 
     // returns self - rhs mod q
     fn sub(self, rhs: Self) -> Self {
-        let (d0, borrow) = sbb(self.ls[0], rhs.ls[0], 0);
-        let (d1, borrow) = sbb(self.ls[1], rhs.ls[1], borrow);
-        let (d2, borrow) = sbb(self.ls[2], rhs.ls[2], borrow);
-        let (d3, borrow) = sbb(self.ls[3], rhs.ls[3], borrow);
-
-        // The final borrow is 11..11 if there was underflow. Otherwise 0. Therefore, it's used as a mask
-        let (d0, carry) = adc(d0, MODULUS_SCALAR.ls[0] & borrow, 0);
-        let (d1, carry) = adc(d1, MODULUS_SCALAR.ls[1] & borrow, carry);
-        let (d2, carry) = adc(d2, MODULUS_SCALAR.ls[2] & borrow, carry);
-        let (d3, _) = adc(d3, MODULUS_SCALAR.ls[3] & borrow, carry);
-
-        Scalar{ ls: [d0, d1, d2, d3]}
+        Scalar::from_monty(self.monty().sub(rhs.monty()))
     }
 
     // returns -self mod q
     fn neg(self) -> Self {
-        // Explanation from zkcrypto repo
-        // Subtract `self` from `MODULUS` to negate. Ignore the final
-        // borrow because it cannot underflow; self is guaranteed to
-        // be in the field.
-        let (d0, borrow) = sbb(MODULUS_SCALAR.ls[0], self.ls[0], 0);
-        let (d1, borrow) = sbb(MODULUS_SCALAR.ls[1], self.ls[1], borrow);
-        let (d2, borrow) = sbb(MODULUS_SCALAR.ls[2], self.ls[2], borrow);
-        let (d3, _) = sbb(MODULUS_SCALAR.ls[3], self.ls[3], borrow);
-
-        // The mask should be 0 when a==p and 2^65-1 otherwise        
-        // limbs = 0 when self = 0
-        let limbs = self.ls[0] | self.ls[1] | self.ls[2] | self.ls[3];
-        // p mod p = 0, so this checks whether self is p
-        let scalar_is_0_mod_p = is_zero_u64(limbs);
-        // mask = a_is_p - 1. This will give either 1-1 (=0) or 0-1 (wrap around to 2^64-1)
-        let mask = subtract_1_wrap(scalar_is_0_mod_p);
-
-        Scalar{ ls: [d0 & mask, d1 & mask, d2 & mask, d3 & mask]}
+        Scalar::from_monty(self.monty().neg())
     }
 }
 
 impl Scalar {
     // returns self + rhs mod q
     fn add(self, rhs: Self) -> Self {
-        let (d0, carry) = adc(self.ls[0], rhs.ls[0], 0);
-        let (d1, carry) = adc(self.ls[1], rhs.ls[1], carry);
-        let (d2, carry) = adc(self.ls[2], rhs.ls[2], carry);
-        let (d3, _) = adc(self.ls[3], rhs.ls[3], carry);
-
-        // Subtract q to ensure the element is always mod q
-        (Scalar{ls:[d0, d1, d2, d3]}).sub(MODULUS_SCALAR)
+        Scalar::from_monty(self.monty().add(rhs.monty()))
     }
 
-
-    /*
-    returns t mod q (as Scalar)
-
-    according to zkcrypto repo this is based on Algorithm 14.32 in Handbook of Applied Cryptography
-    <http://cacr.uwaterloo.ca/hac/about/chap14.pdf>
-    */
+    // returns t / R mod q (as Scalar), where t = r0 + r1 * 2^64 + ... + r7 * 2^448
+    // The reduction itself is done by the generic Montgomery backend in utils.
     fn montgomery_reduce(
         r0: u64,
         r1: u64,
@@ -188,64 +195,19 @@ impl Scalar {
         r6: u64,
         r7: u64,
     ) -> Self {
-        let k = wrapping_mul(r0, INV);
-        let (_, carry) = mac(r0, k, MODULUS_SCALAR.ls[0], 0);
-        let (r1, carry) = mac(r1, k, MODULUS_SCALAR.ls[1], carry);
-        let (r2, carry) = mac(r2, k, MODULUS_SCALAR.ls[2], carry);
-        let (r3, carry) = mac(r3, k, MODULUS_SCALAR.ls[3], carry);
-        let (r4, carry2) = adc(r4, 0, carry);
-
-        let k = wrapping_mul(r1, INV);
-        let (_, carry) = mac(r1, k, MODULUS_SCALAR.ls[0], 0);
-        let (r2, carry) = mac(r2, k, MODULUS_SCALAR.ls[1], carry);
-        let (r3, carry) = mac(r3, k, MODULUS_SCALAR.ls[2], carry);
-        let (r4, carry) = mac(r4, k, MODULUS_SCALAR.ls[3], carry);
-        let (r5, carry2) = adc(r5, carry2, carry);
-
-        let k = wrapping_mul(r2, INV);
-        let (_, carry) = mac(r2, k, MODULUS_SCALAR.ls[0], 0);
-        let (r3, carry) = mac(r3, k, MODULUS_SCALAR.ls[1], carry);
-        let (r4, carry) = mac(r4, k, MODULUS_SCALAR.ls[2], carry);
-        let (r5, carry) = mac(r5, k, MODULUS_SCALAR.ls[3], carry);
-        let (r6, carry2) = adc(r6, carry2, carry);
-
-        let k = wrapping_mul(r3, INV);
-        let (_, carry) = mac(r3, k, MODULUS_SCALAR.ls[0], 0);
-        let (r4, carry) = mac(r4, k, MODULUS_SCALAR.ls[1], carry);
-        let (r5, carry) = mac(r5, k, MODULUS_SCALAR.ls[2], carry);
-        let (r6, carry) = mac(r6, k, MODULUS_SCALAR.ls[3], carry);
-        let (r7, _) = adc(r7, carry2, carry);
-
-        // Subtract q to ensure the element is always mod q
-        (Scalar{ ls:[r4, r5, r6, r7]}).sub(MODULUS_SCALAR)
+        Scalar{ ls: monty_reduce(
+            U256 { ls: [r0, r1, r2, r3] },
+            U256 { ls: [r4, r5, r6, r7] },
+            ScalarParams::modulus(),
+            ScalarParams::inv(),
+        ).ls }
     }
 }
 
 impl Scalar {
     // returns self * rhs mod q
     fn mul(self, rhs: Self) -> Self {
-        // Schoolbook multiplication
-        let (r0, carry) = mac(0, self.ls[0], rhs.ls[0], 0);
-        let (r1, carry) = mac(0, self.ls[0], rhs.ls[1], carry);
-        let (r2, carry) = mac(0, self.ls[0], rhs.ls[2], carry);
-        let (r3, r4) = mac(0, self.ls[0], rhs.ls[3], carry);
-
-        let (r1, carry) = mac(r1, self.ls[1], rhs.ls[0], 0);
-        let (r2, carry) = mac(r2, self.ls[1], rhs.ls[1], carry);
-        let (r3, carry) = mac(r3, self.ls[1], rhs.ls[2], carry);
-        let (r4, r5) = mac(r4, self.ls[1], rhs.ls[3], carry);
-
-        let (r2, carry) = mac(r2, self.ls[2], rhs.ls[0], 0);
-        let (r3, carry) = mac(r3, self.ls[2], rhs.ls[1], carry);
-        let (r4, carry) = mac(r4, self.ls[2], rhs.ls[2], carry);
-        let (r5, r6) = mac(r5, self.ls[2], rhs.ls[3], carry);
-
-        let (r3, carry) = mac(r3, self.ls[3], rhs.ls[0], 0);
-        let (r4, carry) = mac(r4, self.ls[3], rhs.ls[1], carry);
-        let (r5, carry) = mac(r5, self.ls[3], rhs.ls[2], carry);
-        let (r6, r7) = mac(r6, self.ls[3], rhs.ls[3], carry);
-
-        ~Scalar::montgomery_reduce(r0, r1, r2, r3, r4, r5, r6, r7)
+        Scalar::from_monty(self.monty().mul(rhs.monty()))
     }
 
     // returns self ^ 2 mod q
@@ -276,7 +238,7 @@ impl Scalar {
         let (r6, carry) = mac(r6, self.ls[3], self.ls[3], carry);
         let (r7, _) = adc(0, r7, carry);
 
-        ~Scalar::montgomery_reduce(r0, r1, r2, r3, r4, r5, r6, r7)
+        Scalar::montgomery_reduce(r0, r1, r2, r3, r4, r5, r6, r7)
     }
 
     // returns self + self mod q
@@ -324,49 +286,36 @@ impl Scalar {
     /// **This operation is variable time with respect
     /// to the exponent.** If the exponent is fixed,
     /// this operation is effectively constant time.
-    pub fn pow_vartime(self, by: [u64; 4]) -> Scalar {//TODO implement when possible, this gives an error when called from sqrt
-        let mut res = ~Self::one();
+    pub fn pow_vartime(self, by: [u64; 4]) -> Scalar {
+        let mut res = Self::one();
         let mut i = 4;
         while i > 0 {
-            let e = by[i -1];
-            let mut j = 65;
+            i -= 1;
+            let mut j = 64;
             while j > 0 {
+                j -= 1;
                 res = res.square();
 
-                if ((e >> (j-1)) & 1) == 1 {
-                    res *= self;
-                    // res.mul_assign(self);
+                if ((by[i] >> j) & 1) == 1 {
+                    res = res.mul(self);
                 }
-                j -= 1;
             }
-            i -= 1;
         }
         res
     }
-} 
+}
 
 impl Scalar {
-/*
-functions
-- pow_vartime
-- sqrt
-
-will give Immediate18TooLarge
-
-(It originally gave the error
-error: Internal compiler error: Verification failed: Function anon_11103 return type must match its RET instructions.
-Please file an issue on the repository and include the code that triggered this error.
-
-Sept 12: new error = 
-error: Internal compiler error: Verification failed: Function one_1 return type must match its RET instructions.
-Please file an issue on the repository and include the code that triggered this error.
-)
-*/
+    // This goes in a separate impl, because if we use previously defined functions in Scalar impl,
+    // Sway will not recognize them from inside the same impl
 
     /// Computes the square root of this element, if it exists.
     pub fn sqrt(self) -> CtOption<Scalar> {
-        // Tonelli-Shank's algorithm for q mod 16 = 1
-        // https://eprint.iacr.org/2012/685.pdf (page 12, algorithm 5)
+        // Comment from zkcrypto
+        // This is a constant-time version of https://eprint.iacr.org/2012/685.pdf (page 12,
+        // algorithm 5). Steps 2-5 of the algorithm are omitted because they are only needed
+        // to detect non-square input; it is more efficient to do that by checking at the end
+        // whether the square of the result is the input.
 
         // w = self^((t - 1) // 2)
         //   = self^6104339283789297388802252303364915521546564123189034618274734669823
@@ -377,47 +326,189 @@ Please file an issue on the repository and include the code that triggered this
             0x0000_0000_39f6_d3a9,
         ]);
 
-        // let mut v = S;
-        // let mut x = self * w;
-        // let mut b = x * w;
-
-        // // Initialize z as the 2^S root of unity.
-        // let mut z = ROOT_OF_UNITY;
-
-        // let mut max_v = S;
-
-        // while max_v > 0 {
-        //     let mut k = 1;
-        //     let mut tmp = b.square();
-        //     let mut j_less_than_v: Choice = ~Choice::from(1u8);
-
-        //     let mut j = 2; // j in 2..max_v
-        //     while j <= max_v {
-        //         let tmp_is_one = ~Choice::from_bool(tmp.eq(~Scalar::one()));
-        //         let squared = ~Scalar::conditional_select(tmp, z, tmp_is_one).square();
-        //         tmp = ~Scalar::conditional_select(squared, tmp, tmp_is_one);
-        //         let new_z = ~Scalar::conditional_select(z, squared, tmp_is_one);
-        //         let j_less_than_v_bool = ~Choice::unwrap_as_bool(j_less_than_v) && j != v;
-        //         j_less_than_v = ~Choice::from_bool(j_less_than_v_bool);
-        //         k = ~u32::conditional_select(j, k, tmp_is_one);
-        //         z = ~Scalar::conditional_select(z, new_z, j_less_than_v);
-
-        //         j += 1;
-        //     }
-
-        //     let result = x * z;
-        //     x = ~Scalar::conditional_select(result, x, ~Choice::from_bool(b.eq(~Scalar::one())));
-        //     z = z.square();
-        //     b *= z;
-        //     v = k;
-
-        //     max_v -= 1;
-        // }
-
-        // ~CtOption::new(
-        //     x,
-        //     (x * x).ct(self), // Only return Some if it's the square root.
-        // )
-        ~CtOption::new(self, ~Choice::from(1))
+        let mut v: u64 = S;
+        let mut x = w * self;
+        let mut b = x * w;
+
+        // Initialize z as the 2^S root of unity.
+        let mut z = ROOT_OF_UNITY;
+
+        let mut max_v: u64 = S;
+        while max_v > 0 {
+            let mut k: u64 = 1;
+            let mut b2k = b.square();
+            let mut j_less_than_v = Choice::from(1u8);
+
+            // Comment from zkcrypto
+            // This loop has three phases based on the value of k for algorithm 5:
+            // - for j <= k, we square b2k in order to calculate b^{2^k}.
+            // - for k < j <= v, we square z in order to calculate ω.
+            // - for j > v, we do nothing.
+            let mut j: u64 = 2;
+            while j < max_v {
+                let b2k_is_one = b2k.ct_eq(Scalar::one());
+                let squared = Scalar::conditional_select(z, b2k, b2k_is_one).square();
+                b2k = Scalar::conditional_select(b2k, squared, b2k_is_one);
+                let new_z = Scalar::conditional_select(squared, z, b2k_is_one);
+                j_less_than_v = j_less_than_v.binary_and(u64::ct_eq(j, v).not());
+                k = u64::conditional_select(k, j, b2k_is_one);
+                z = Scalar::conditional_select(new_z, z, j_less_than_v);
+
+                j += 1;
+            }
+
+            let result = x * z;
+            x = Scalar::conditional_select(x, result, b.ct_eq(Scalar::one()));
+            z = z.square();
+            b = b * z;
+            v = k;
+
+            max_v -= 1;
+        }
+
+        CtOption::new(
+            x,
+            (x * x).ct_eq(self), // Only return Some if it's the square root.
+        )
+    }
+}
+
+impl Scalar {
+    // This goes in a separate impl, because if we use previously defined functions in Scalar impl,
+    // Sway will not recognize them from inside the same impl
+
+    // returns Some(self^-1 mod q) or None if self == 0
+    pub fn invert(self) -> CtOption<Scalar> {
+        // Exponentiate by q - 2
+        let t = self.pow_vartime([
+            0xffff_fffe_ffff_ffff,
+            0x53bd_a402_fffe_5bfe,
+            0x3339_d808_09a1_d805,
+            0x73ed_a753_299d_7d48,
+        ]);
+
+        CtOption::new(t, self.ct_eq(Scalar::zero()).not())
+    }
+
+    /// Attempts to convert a little-endian byte representation of
+    /// a scalar into a `Scalar`, failing if the input is not canonical.
+    pub fn from_bytes(bytes: [u8; 32]) -> CtOption<Scalar> {
+        let mut tmp = Scalar{ ls: [0, 0, 0, 0] };
+        let mut i = 0;
+        while i < 32 {
+            // byte i is in limb i/8, at position i%8 from the least significant byte
+            let byte: u64 = bytes[i];
+            tmp.ls[i / 8] = tmp.ls[i / 8] | (byte << (8 * (i % 8)));
+            i += 1;
+        }
+
+        // Try to subtract the modulus
+        let (_, borrow) = sbb(tmp.ls[0], MODULUS_SCALAR.ls[0], 0);
+        let (_, borrow) = sbb(tmp.ls[1], MODULUS_SCALAR.ls[1], borrow);
+        let (_, borrow) = sbb(tmp.ls[2], MODULUS_SCALAR.ls[2], borrow);
+        let (_, borrow) = sbb(tmp.ls[3], MODULUS_SCALAR.ls[3], borrow);
+
+        // If the element is smaller than MODULUS then the
+        // subtraction will underflow, producing a borrow value
+        // of 0xffff...ffff. Otherwise, it'll be zero.
+        let is_some = Choice::from(borrow & 1);
+
+        // Convert to Montgomery form by computing
+        // (a.R^0 * R^2) / R = a.R
+        CtOption::new(tmp * R2, is_some)
+    }
+
+    /// Converts an element of `Scalar` into a byte representation in
+    /// little-endian byte order.
+    pub fn to_bytes(self) -> [u8; 32] {
+        // Turn into canonical form by computing
+        // (a.R) / R = a
+        let tmp = Scalar::montgomery_reduce(self.ls[0], self.ls[1], self.ls[2], self.ls[3], 0, 0, 0, 0);
+
+        let mut res: [u8; 32] = [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        let mut i = 0;
+        while i < 32 {
+            res[i] = (tmp.ls[i / 8] >> (8 * (i % 8))) & 0xff;
+            i += 1;
+        }
+        res
+    }
+
+    /// Converts a 512-bit little endian integer into
+    /// a `Scalar` by reducing by the modulus.
+    pub fn from_bytes_wide(bytes: [u8; 64]) -> Scalar {
+        let mut limbs: [u64; 8] = [0, 0, 0, 0, 0, 0, 0, 0];
+        let mut i = 0;
+        while i < 64 {
+            let byte: u64 = bytes[i];
+            limbs[i / 8] = limbs[i / 8] | (byte << (8 * (i % 8)));
+            i += 1;
+        }
+
+        // Comment from zkcrypto
+        // We reduce an arbitrary 512-bit number by decomposing it into two 256-bit digits
+        // with the higher bits multiplied by 2^256. Thus, we perform two reductions
+        //
+        // 1. the lower bits are multiplied by R^2, as normal
+        // 2. the upper bits are multiplied by R^2 * 2^256 = R^3
+        //
+        // and computing their sum in the field. It remains to see that arbitrary 256-bit
+        // numbers can be placed into Montgomery form safely using the reduction. The
+        // reduction works so long as the product is less than R=2^256 multiplied by
+        // the modulus. This holds because for any `c` smaller than the modulus, we have
+        // that (2^256 - 1)*c is an acceptable product for the reduction. Therefore, the
+        // reduction always works so long as `c` is in the field; in this case it is either the
+        // constant `R2` or `R3`.
+        let d0 = Scalar{ ls: [limbs[0], limbs[1], limbs[2], limbs[3]] };
+        let d1 = Scalar{ ls: [limbs[4], limbs[5], limbs[6], limbs[7]] };
+        // Convert to Montgomery form
+        d0 * R2 + d1 * R3
+    }
+}
+
+impl Field for Scalar {
+    fn zero() -> Self {
+        Scalar::zero()
+    }
+
+    fn one() -> Self {
+        Scalar::one()
+    }
+
+    fn is_zero(self) -> Choice {
+        self.ct_eq(Scalar::zero())
+    }
+
+    fn square(self) -> Self {
+        self.square()
+    }
+
+    fn double(self) -> Self {
+        self.double()
+    }
+
+    fn invert(self) -> CtOption<Self> {
+        self.invert()
+    }
+
+    fn sqrt(self) -> CtOption<Self> {
+        self.sqrt()
+    }
+}
+
+impl PrimeField for Scalar {
+    fn from_repr(bytes: [u8; 32]) -> CtOption<Self> {
+        Scalar::from_bytes(bytes)
+    }
+
+    fn to_repr(self) -> [u8; 32] {
+        self.to_bytes()
+    }
+
+    fn is_odd(self) -> Choice {
+        Choice::from(self.to_bytes()[0] & 1)
     }
 }
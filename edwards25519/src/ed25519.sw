@@ -0,0 +1,72 @@
+library;
+
+use ::scalar::Scalar;
+use ::ge25519::{ge25519, ge25519_double, ge25519_identity, ge25519_scalarmult, ge25519_scalarmult_base, unpack};
+use ::sha512::sha512;
+use std::vec::Vec;
+
+/*
+Ed25519 signature verification, as specified in RFC 8032 section 5.1.7
+https://www.rfc-editor.org/rfc/rfc8032#section-5.1.7
+*/
+
+// returns whether [8]p is the identity, i.e. whether p is one of the 8 points of small order
+fn is_small_order(p: ge25519) -> bool {
+    ge25519_double(ge25519_double(ge25519_double(p))) == ge25519_identity()
+}
+
+/// Returns whether `sig` is a valid Ed25519 signature on `msg` for the public key `pubkey`.
+/// The signature is the encoding of a point R followed by the encoding of a scalar S.
+/// Verification fails if the public key or R don't decode to a point, if one of them has small order,
+/// or if S isn't reduced modulo ℓ.
+/// Otherwise the signature is valid iff [S]B = R + [k]A, where A is the public key and k = SHA-512(R || A || msg) mod ℓ.
+/// This is the cofactorless check with the small order checks of ed25519-dalek's `verify_strict`.
+pub fn ed25519_verify(pubkey: [u8; 32], msg: Vec<u8>, sig: [u8; 64]) -> bool {
+    let mut r_bytes: [u8; 32] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    let mut s_bytes: [u8; 32] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    let mut i = 0;
+    while i < 32 {
+        r_bytes[i] = sig[i];
+        s_bytes[i] = sig[32 + i];
+        i += 1;
+    }
+
+    // All inputs are public, so there's no need to continue in constant time when decoding fails
+    let a = unpack(pubkey);
+    let r = unpack(r_bytes);
+    let s = Scalar::from_canonical_bytes(s_bytes);
+    if a.is_none() || r.is_none() || s.is_none() {
+        return false;
+    }
+    // With a public key of small order, signatures can be valid for many messages at once
+    if is_small_order(a.unwrap()) || is_small_order(r.unwrap()) {
+        return false;
+    }
+
+    // k = SHA-512(R || A || msg) mod ℓ
+    let mut hash_input = Vec::new();
+    i = 0;
+    while i < 32 {
+        hash_input.push(r_bytes[i]);
+        i += 1;
+    }
+    i = 0;
+    while i < 32 {
+        hash_input.push(pubkey[i]);
+        i += 1;
+    }
+    i = 0;
+    while i < msg.len() {
+        hash_input.push(msg.get(i).unwrap());
+        i += 1;
+    }
+    let k = Scalar::from_bytes_mod_order_wide(sha512(hash_input));
+
+    ge25519_scalarmult_base(s.unwrap()) == r.unwrap() + ge25519_scalarmult(a.unwrap(), k)
+}
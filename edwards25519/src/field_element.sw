@@ -1,9 +1,10 @@
-library field_element;
+library;
 
 use std::u128::*;
 //This wildcard import is needed because of importing ConstantTimeEq for u64 (since it's a trait for a primitive type)
 use utils::choice::*; 
 use utils::integer_utils::adc; 
+use utils::ff::{Field, PrimeField};
 use core::ops::{Eq, Add, Subtract, Multiply};
 
 /*
@@ -35,6 +36,20 @@ pub const ZERO: Element = Element {
     l0: 0, l1: 0, l2: 0, l3: 0, l4: 0
 };
 
+// one element in GF(2^255-19)
+pub const ONE: Element = Element {
+    l0: 1, l1: 0, l2: 0, l3: 0, l4: 0
+};
+
+/* sqrt(-1) = 19681161376707505956807079304988542015446066515923890162744021073123829784752 */
+pub const SQRT_M1: Element = Element {
+    l0: 1718705420411056, 
+    l1: 234908883556509, 
+    l2: 2233514472574048, 
+    l3: 2117202627021982, 
+    l4: 765476049583133
+};
+
 // returns x*19 using a bitshift implementation
 // from NaCl impl https://cr.yp.to/ecdh.html#use
 fn times19(x: u64) -> u64 {
@@ -89,15 +104,15 @@ impl Element {
 
     //returns self with all limbs multiplied by scalar x, reduced p
     fn scalar_mult(self, x: u32) -> Element {
-        let scalar_u128: U128 = ~U128::from(0, x);
+        let scalar_u128: U128 = U128::from((0, x));
 
         // e is radix 51, so all limbs have max 51 bits. The scalar has max 32 bits.
         // Their multiplication has max 84 bits and is stored as (upper, lower) in U128
-        let l0_temp: U128 = ~U128::from(0, self.l0) * scalar_u128;
-        let l1_temp: U128 = ~U128::from(0, self.l1) * scalar_u128;
-        let l2_temp: U128 = ~U128::from(0, self.l2) * scalar_u128;
-        let l3_temp: U128 = ~U128::from(0, self.l3) * scalar_u128;
-        let l4_temp: U128 = ~U128::from(0, self.l4) * scalar_u128;
+        let l0_temp: U128 = U128::from((0, self.l0)) * scalar_u128;
+        let l1_temp: U128 = U128::from((0, self.l1)) * scalar_u128;
+        let l2_temp: U128 = U128::from((0, self.l2)) * scalar_u128;
+        let l3_temp: U128 = U128::from((0, self.l3)) * scalar_u128;
+        let l4_temp: U128 = U128::from((0, self.l4)) * scalar_u128;
 
         let (coeff0, carry0) = get_coeff_and_carry(l0_temp);
         let (coeff1, carry1) = get_coeff_and_carry(l1_temp);
@@ -172,6 +187,9 @@ impl Element {
     }
 
     //returns self*rhs mod 2^255 - 19
+    // Not inlined: every inlined copy adds its U128 temporaries to the caller's stack frame,
+    // and formulas with several multiplications then hit Immediate18TooLarge in contracts
+    #[inline(never)]
     fn multiply(self, rhs: Element) -> Element {
         //https://cs.opensource.google/go/go/+/master:src/crypto/internal/edwards25519/field/fe_generic.go;l=34;bpv=0?q=feMul&sq=&ss=go%2Fgo
 
@@ -283,104 +301,161 @@ impl Element {
     fn negate(self) -> Element {
         ZERO.subtract(self)
     }
+}
 
-    //Testing not done because of error Immediate18TooLarge
-    // returns self^-1 mod p
-    //self^(-1) mod p = self^(p-2) mod p by  Fermat's theorem, hence we calculate self^(p-2) mod p
-    fn inverse(self) -> Element {
-        let mut i = 0;
-        let z2 = self.square();                 //2
-        // let mut t = z2.square();             //4
-        // t = t.square();                      //8
-
-        // let z9 = t*self;             //9
-        // let z11 = z9*z2;         //11
-        // t = z11.square();                    //22
-
-        // let z2_5_0 = t*z9;       //31
-        // t = z2_5_0.square();                 // 62 = 2^6 - 2^1
-        // while i < 4 {
-        //     t = t.square();                  // 2^10 - 2^5
-        //     i+=1;                            
-        // }
-
-        // let z2_10_0 = t * z2_5_0;  // 2^10 - 2^0
-
-        // t = square(z2_10_0);                //2^11 - 2^1
-        // i = 0;
-        // while i < 9 {
-        //     t = square(t);                  //2^20 - 2^10
-        //     i += 1;
-        // }
-
-        // let z2_20_0 = t * z2_10_0; //2^20 - 2^0
-
-        // t = z2_20_0.square();                //2^21 - 2^1
-        // i = 0;
-        // while i < 19 {
-        //     t = t.square();                  //2^40 - 2^20
-        //     i += 1;
-        // }
-
-        // t = t * z2_20_0;           //2^40 - 2^0
+impl Element {
+    // This goes in a separate impl, because if we use previously defined functions in Fp impl, 
+    // Sway will not recognize them from inside the same impl
 
-        // t = t.square(); // 2^41 - 2^1
-        
-        // i = 0;
-        // while i < 10 {
-        //     t = t.square();                  //2^50 - 2^10
-        // }
+    // returns self^(2^k), by squaring k times
+    fn pow2k(self, k: u64) -> Element {
+        let mut res = self;
+        let mut i = 0;
+        while i < k {
+            res = res.square();
+            i += 1;
+        }
+        res
+    }
 
-        // let z2_50_0 = t * z2_10_0; //2^50 - 2^0
+    // returns the element with the given 32-byte little-endian encoding.
+    // The most significant bit is ignored. As in the Go impl, non-canonical encodings
+    // of values in [p, 2^255) are accepted, and the result isn't reduced.
+    fn from_bytes(bytes: [u8; 32]) -> Element {
+        let mut words: [u64; 4] = [0, 0, 0, 0];
+        let mut i = 0;
+        while i < 32 {
+            // byte i is in word i/8, at position i%8 from the least significant byte
+            let byte: u64 = bytes[i];
+            words[i / 8] = words[i / 8] | (byte << (8 * (i % 8)));
+            i += 1;
+        }
 
-        // t = z2_50_0.square();                //2^51 - 2^1
+        Element {
+            l0: words[0] & MASK_LOW_51_BITS,
+            l1: ((words[0] >> 51) | (words[1] << 13)) & MASK_LOW_51_BITS,
+            l2: ((words[1] >> 38) | (words[2] << 26)) & MASK_LOW_51_BITS,
+            l3: ((words[2] >> 25) | (words[3] << 39)) & MASK_LOW_51_BITS,
+            l4: (words[3] >> 12) & MASK_LOW_51_BITS,
+        }
+    }
 
-        // i = 0;
-        // while i < 49 {
-        //     t = t.square();                  //2^100 - 2^50
-        //     i += 1;
-        // }
-        
-        // let z2_100_0 = t * z2_50_0;//2^100 - 2^0
+    // returns the canonical 32-byte little-endian encoding of self
+    fn to_bytes(self) -> [u8; 32] {
+        let red = self.reduce();
+        let words: [u64; 4] = [
+            red.l0 | (red.l1 << 51),
+            (red.l1 >> 13) | (red.l2 << 38),
+            (red.l2 >> 26) | (red.l3 << 25),
+            (red.l3 >> 39) | (red.l4 << 12),
+        ];
+
+        let mut res: [u8; 32] = [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        let mut i = 0;
+        while i < 32 {
+            res[i] = (words[i / 8] >> (8 * (i % 8))) & 0xff;
+            i += 1;
+        }
+        res
+    }
 
-        // t = z2_100_0.square();               //2^101 - 2^1
+    // returns whether self is negative, meaning that its reduced value is odd (RFC 8032)
+    fn is_negative(self) -> Choice {
+        Choice::from(self.reduce().l0 & 1)
+    }
 
-        // i = 0;
-        // while i < 99 {
-        //     t = t.square();                  //2^200 - 2^100
-        //     i += 1;
-        // }
+    // returns whether self is 0 mod p, in constant time
+    fn is_zero(self) -> Choice {
+        self.reduce().ct_eq(ZERO)
+    }
+}
 
-        // t = t * z2_100_0;          //2^200 - 2^0
+impl Element {
+    // This goes in a separate impl, because if we use previously defined functions in Fp impl, 
+    // Sway will not recognize them from inside the same impl
 
-        // t = t.square();                      //2^201 - 2^1
+    // returns self^((p-5)/8) = self^(2^252-3), which is used for computing square roots
+    // The addition chain is the same as in the Go impl
+    fn pow22523(self) -> Element {
+        let mut t0 = self.square();             // 2
+        let mut t1 = t0.pow2k(2);               // 8
+        t1 = self.multiply(t1);                 // 9
+        t0 = t0.multiply(t1);                   // 11
+        t0 = t0.square();                       // 22
+        t0 = t1.multiply(t0);                   // 31 = 2^5 - 2^0
+        t1 = t0.pow2k(5);                       // 2^10 - 2^5
+        t0 = t1.multiply(t0);                   // 2^10 - 2^0
+        t1 = t0.pow2k(10);                      // 2^20 - 2^10
+        t1 = t1.multiply(t0);                   // 2^20 - 2^0
+        let mut t2 = t1.pow2k(20);              // 2^40 - 2^20
+        t1 = t2.multiply(t1);                   // 2^40 - 2^0
+        t1 = t1.pow2k(10);                      // 2^50 - 2^10
+        t0 = t1.multiply(t0);                   // 2^50 - 2^0
+        t1 = t0.pow2k(50);                      // 2^100 - 2^50
+        t1 = t1.multiply(t0);                   // 2^100 - 2^0
+        t2 = t1.pow2k(100);                     // 2^200 - 2^100
+        t1 = t2.multiply(t1);                   // 2^200 - 2^0
+        t1 = t1.pow2k(50);                      // 2^250 - 2^50
+        t0 = t1.multiply(t0);                   // 2^250 - 2^0
+        t0 = t0.pow2k(2);                       // 2^252 - 2^2
+        t0.multiply(self)                       // 2^252 - 3
+    }
+}
 
-        // i = 0;
-        // while i < 49 {
-        //     t = t.square();                  //2^250 - 2^50
-        //     i += 1;
-        // }
+impl Element {
+    // This goes in a separate impl, because if we use previously defined functions in Fp impl, 
+    // Sway will not recognize them from inside the same impl
 
-        // t = t * z2_50_0;           //2^250 - 2^0
+    // returns self^-1 mod p
+    // self^(-1) mod p = self^(p-2) mod p by Fermat's theorem, and p-2 = 8*(2^252-3) + 3,
+    // hence we calculate (self^(2^252-3))^8 * self^3 using the addition chain of pow22523
+    fn inverse(self) -> Element {
+        let self3 = self.square().multiply(self);
+        self.pow22523().pow2k(3).multiply(self3)
+    }
 
-        // i = 0;
-        // while i < 5 {
-        //     t = t.square();                  //2^255 - 2^5
-        //     i += 1;
-        // }
+    // returns |self|, i.e. -self if self is negative and self otherwise, in constant time
+    fn abs(self) -> Element {
+        Element::conditional_select(self.negate(), self.reduce(), self.is_negative())
+    }
+}
 
-        // t = t * z11;               //2^255 - 21 = p - 2
+impl Element {
+    // This goes in a separate impl, because if we use previously defined functions in Fp impl, 
+    // Sway will not recognize them from inside the same impl
 
-        // return t;
-        self
+    /*
+    returns (was_square, r) as in SQRT_RATIO_M1 of RFC 9496 https://www.rfc-editor.org/rfc/rfc9496#section-4.2
+    - if u/v is square, was_square = 1 and r = sqrt(u/v)
+    - if u/v isn't square, was_square = 0 and r = sqrt(i*u/v), where i = sqrt(-1)
+    - if u = 0 or v = 0, was_square = 1 if u = 0, and r = 0
+    r is always the nonnegative root
+    */
+    fn sqrt_ratio_i(u: Element, v: Element) -> (Choice, Element) {
+        let u = u.reduce();
+        let v3 = v.square().multiply(v);
+        let v7 = v3.square().multiply(v);
+        let mut r = u.multiply(v3).multiply(u.multiply(v7).pow22523());
+        let check = v.multiply(r.square()).reduce();
+
+        let u_neg = u.negate();
+        let correct_sign_sqrt = check.ct_eq(u);
+        let flipped_sign_sqrt = check.ct_eq(u_neg);
+        let flipped_sign_sqrt_i = check.ct_eq(u_neg.multiply(SQRT_M1).reduce());
+
+        let r_prime = r.multiply(SQRT_M1);
+        r = Element::conditional_select(r_prime, r, flipped_sign_sqrt | flipped_sign_sqrt_i);
+        (correct_sign_sqrt | flipped_sign_sqrt, r.abs())
     }
-
 }
 
 //returns a*b as u128
 pub fn multiply64(a: u64, b: u64) -> U128 {
-    let a_128: U128 = ~U128::from(0, a);
-    let b_128: U128 = ~U128::from(0, b);
+    let a_128: U128 = U128::from((0, a));
+    let b_128: U128 = U128::from((0, b));
     a_128 * b_128
 }
 
@@ -390,7 +465,7 @@ pub fn add_multiply64(res: U128, a: u64, b: u64) -> U128 {
     let add_res: (u64, u64) = adc(mul_res.lower, res.lower, 0);
     let add_res2: (u64, u64) = adc(mul_res.upper, res.upper, add_res.1);
 
-    ~U128::from(add_res2.0, add_res.0)
+    U128::from((add_res2.0, add_res.0))
 }
 
 //returns a>>51 as u64
@@ -409,11 +484,31 @@ pub fn get_coeff_and_carry(y: U128) -> (u64, u64) {
 impl ConstantTimeEq for Element {
     // returns (self == other), as a choice
     fn ct_eq(self, other: Element) -> Choice {
-        ~u64::ct_eq(self.l0, other.l0)
-        & ~u64::ct_eq(self.l1, other.l1)
-        & ~u64::ct_eq(self.l2, other.l2)
-        & ~u64::ct_eq(self.l3, other.l3)
-        & ~u64::ct_eq(self.l4, other.l4)
+        u64::ct_eq(self.l0, other.l0)
+        & u64::ct_eq(self.l1, other.l1)
+        & u64::ct_eq(self.l2, other.l2)
+        & u64::ct_eq(self.l3, other.l3)
+        & u64::ct_eq(self.l4, other.l4)
+    }
+}
+
+impl ConditionallySelectable for Element {
+    // Select a if choice == 1 or select b if choice == 0, in constant time.
+    fn conditional_select(a: Self, b: Self, choice: Choice) -> Self {
+        Element {
+            l0: u64::conditional_select(a.l0, b.l0, choice),
+            l1: u64::conditional_select(a.l1, b.l1, choice),
+            l2: u64::conditional_select(a.l2, b.l2, choice),
+            l3: u64::conditional_select(a.l3, b.l3, choice),
+            l4: u64::conditional_select(a.l4, b.l4, choice),
+        }
+    }
+}
+
+impl ConditionallyNegatable for Element {
+    // Negate self if choice == 1, leave it unchanged if choice == 0
+    fn conditional_negate(ref mut self, choice: Choice) {
+        self = Element::conditional_select(self.negate(), self, choice);
     }
 }
 
@@ -443,3 +538,50 @@ impl Multiply for Element {
         self.multiply(other)
     }
 }
+
+impl Field for Element {
+    fn zero() -> Self {
+        ZERO
+    }
+
+    fn one() -> Self {
+        ONE
+    }
+
+    fn is_zero(self) -> Choice {
+        self.is_zero()
+    }
+
+    fn square(self) -> Self {
+        self.square()
+    }
+
+    fn double(self) -> Self {
+        self.add(self)
+    }
+
+    fn invert(self) -> CtOption<Self> {
+        CtOption::new(self.inverse(), self.is_zero().not())
+    }
+
+    fn sqrt(self) -> CtOption<Self> {
+        let (was_square, root) = Element::sqrt_ratio_i(self, ONE);
+        CtOption::new(root, was_square)
+    }
+}
+
+impl PrimeField for Element {
+    // The encoding is little-endian, as in RFC 8032. Encodings of values >= p are not canonical
+    fn from_repr(bytes: [u8; 32]) -> CtOption<Self> {
+        let res = Element::from_bytes(bytes);
+        CtOption::new(res, res.to_bytes().ct_eq(bytes))
+    }
+
+    fn to_repr(self) -> [u8; 32] {
+        self.to_bytes()
+    }
+
+    fn is_odd(self) -> Choice {
+        self.is_negative()
+    }
+}
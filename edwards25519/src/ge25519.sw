@@ -1,15 +1,11 @@
-library ge25519;
+library;
 
-// #define ge25519 
-// #define ge25519_unpack_vartime
-// #define ge25519_pack 
-// #define ge25519_add 
-// #define ge25519_double
-// #define ge25519_scalarmult
-// #define ge25519_scalarmult_base
-
-dep field_element;
-use field_element::*;
+use ::field_element::*;
+use ::scalar::Scalar;
+//This wildcard import is needed because of importing ConstantTimeEq for u64 (since it's a trait for a primitive type)
+use utils::choice::*;
+use utils::group::{Group, CurveAffine, Neg};
+use core::ops::{Eq, Add, Subtract};
 
 /* 
  * Arithmetic on the twisted Edwards curve -x^2 + y^2 = 1 + dx^2y^2 
@@ -69,7 +65,41 @@ pub struct ge25519_p2 {
     z: Element,
 }
 
-// TODO test
+/* Base point in extended coordinates:
+ x = 15112221349535400772501151409588531511454012693041857206046113283949847762202
+ y = 46316835694926478169428394003475163141307993866256225615783033603165251855960
+ z = 1
+ t = x*y
+*/
+pub const GE25519_BASE: ge25519 = ge25519 {
+    x: Element{ l0: 1738742601995546, 
+        l1: 1146398526822698, 
+        l2: 2070867633025821, 
+        l3: 562264141797630, 
+        l4: 587772402128613 },
+    y: Element{ l0: 1801439850948184, 
+        l1: 1351079888211148, 
+        l2: 450359962737049, 
+        l3: 900719925474099, 
+        l4: 1801439850948198 },
+    z: Element{ l0: 1, l1: 0, l2: 0, l3: 0, l4: 0 },
+    t: Element{ l0: 1841354044333475, 
+        l1: 16398895984059, 
+        l2: 755974180946558, 
+        l3: 900171276175154, 
+        l4: 1821297809914039 },
+};
+
+// returns the neutral element (0, 1)
+pub fn ge25519_identity() -> ge25519 {
+    ge25519 {
+        x: ZERO,
+        y: ONE,
+        z: ONE,
+        t: ZERO,
+    }
+}
+
 pub fn p1p1_to_p2(p: ge25519_p1p1) -> ge25519_p2 {
     ge25519_p2 {
         x: p.x * p.t,
@@ -78,18 +108,25 @@ pub fn p1p1_to_p2(p: ge25519_p1p1) -> ge25519_p2 {
     }
 }
 
-// TODO test
 pub fn p1p1_to_p3(p: ge25519_p1p1) -> ge25519 {
     let p2 = p1p1_to_p2(p);
     ge25519 {
         x: p2.x,
         y: p2.y,
         z: p2.z,
-        t: p2.x * p2.y
+        t: p.x * p.y
+    }
+}
+
+// drops the t coordinate, which isn't needed for doubling
+pub fn p3_to_p2(p: ge25519) -> ge25519_p2 {
+    ge25519_p2 {
+        x: p.x,
+        y: p.y,
+        z: p.z,
     }
 }
 
-// TODO test
 pub fn ge25519_mixadd2(q: ge25519_aff, r: ge25519) -> ge25519 {
     let qt = q.y * q.x;
     let mut a = r.y - r.x; /* A = (Y1-X1)*(Y2-X2) */
@@ -117,29 +154,235 @@ pub fn ge25519_mixadd2(q: ge25519_aff, r: ge25519) -> ge25519 {
 
 /*
 http://www.hyperelliptic.org/EFD/g1p/auto-twisted-extended-1.html#doubling-dbl-2008-hwcd
+Written as in curve25519-dalek: the y and t coordinates of the completed point are negated
+compared to the EFD formula, which gives the same point and saves the negation of x^2.
 */
-// Can't be tested because errors to Immediate18TooLarge
-
 pub fn dbl_p1p1(p: ge25519_p2) -> ge25519_p1p1 {
-    let a = p.x.square();
-    let b = p.y.square();
-    let mut c = p.z.square();
-    c = c + c;
-    let d = a.negate();
-
-    let mut res_x = p.x + p.y;
-    res_x = res_x.square();
-    res_x = res_x - a;
-    res_x = res_x - b;
-    let res_z = d + b;
-    let res_t = res_z - c;
-    let res_y = d - b;
+    let xx = p.x.square();
+    let yy = p.y.square();
+    let zz = p.z.square();
+    let x_plus_y_sq = (p.x + p.y).square();
+    let yy_plus_xx = yy + xx;
+    let yy_minus_xx = yy - xx;
 
     ge25519_p1p1 {
-        x: res_x,
-        y: res_y,
-        z: res_z,
-        t: res_t,
+        x: x_plus_y_sq - yy_plus_xx,
+        y: yy_plus_xx,
+        z: yy_minus_xx,
+        t: (zz + zz) - yy_minus_xx,
+    }
+}
+
+/*
+http://www.hyperelliptic.org/EFD/g1p/auto-twisted-extended-1.html#addition-add-2008-hwcd-3
+*/
+// returns p + q
+pub fn ge25519_add(p: ge25519, q: ge25519) -> ge25519 {
+    let a = (p.y - p.x) * (q.y - q.x); /* A = (Y1-X1)*(Y2-X2) */
+    let b = (p.y + p.x) * (q.y + q.x); /* B = (Y1+X1)*(Y2+X2) */
+    let c = p.t * GE25519_EC2D * q.t; /* C = T1*2*d*T2 */
+    let mut d = p.z * q.z;
+    d = d + d; /* D = Z1*2*Z2 */
+    let e = b - a; /* E = B-A */
+    let f = d - c; /* F = D-C */
+    let g = d + c; /* G = D+C */
+    let h = b + a; /* H = B+A */
+
+    ge25519 {
+        x: e * f,
+        y: h * g,
+        z: g * f,
+        t: e * h
+    }
+}
+
+// returns 2p
+pub fn ge25519_double(p: ge25519) -> ge25519 {
+    p1p1_to_p3(dbl_p1p1(p3_to_p2(p)))
+}
+
+// returns -p
+pub fn ge25519_neg(p: ge25519) -> ge25519 {
+    ge25519 {
+        x: p.x.negate(),
+        y: p.y,
+        z: p.z,
+        t: p.t.negate(),
+    }
+}
+
+impl ConditionallySelectable for ge25519 {
+    // Select a if choice == 1 or select b if choice == 0, in constant time.
+    fn conditional_select(a: Self, b: Self, choice: Choice) -> Self {
+        ge25519 {
+            x: Element::conditional_select(a.x, b.x, choice),
+            y: Element::conditional_select(a.y, b.y, choice),
+            z: Element::conditional_select(a.z, b.z, choice),
+            t: Element::conditional_select(a.t, b.t, choice),
+        }
     }
 }
 
+impl ConditionallyNegatable for ge25519 {
+    // Negate self if choice == 1, leave it unchanged if choice == 0
+    fn conditional_negate(ref mut self, choice: Choice) {
+        self = ge25519::conditional_select(ge25519_neg(self), self, choice);
+    }
+}
+
+impl ConstantTimeEq for ge25519 {
+    // returns (p == q), as a choice
+    fn ct_eq(self, other: Self) -> Choice {
+        // (X1/Z1, Y1/Z1) == (X2/Z2, Y2/Z2) iff X1*Z2 == X2*Z1 and Y1*Z2 == Y2*Z1
+        // The products are reduced, because ct_eq of Element compares the limbs
+        let x1 = (self.x * other.z).reduce();
+        let x2 = (other.x * self.z).reduce();
+        let y1 = (self.y * other.z).reduce();
+        let y2 = (other.y * self.z).reduce();
+        x1.ct_eq(x2) & y1.ct_eq(y2)
+    }
+}
+
+impl Eq for ge25519 {
+    fn eq(self, other: Self) -> bool {
+        self.ct_eq(other).unwrap_as_bool()
+    }
+}
+
+impl Add for ge25519 {
+    fn add(self, other: Self) -> Self {
+        ge25519_add(self, other)
+    }
+}
+
+impl Subtract for ge25519 {
+    fn subtract(self, other: Self) -> Self {
+        ge25519_add(self, ge25519_neg(other))
+    }
+}
+
+impl Neg for ge25519 {
+    fn neg(self) -> Self {
+        ge25519_neg(self)
+    }
+}
+
+impl Group<Scalar> for ge25519 {
+    fn identity() -> Self {
+        ge25519_identity()
+    }
+
+    fn generator() -> Self {
+        GE25519_BASE
+    }
+
+    fn is_identity(self) -> Choice {
+        self.ct_eq(ge25519_identity())
+    }
+
+    fn double(self) -> Self {
+        ge25519_double(self)
+    }
+
+    fn mul_scalar(self, scalar: Scalar) -> Self {
+        ge25519_scalarmult(self, scalar)
+    }
+}
+
+impl CurveAffine for ge25519_aff {
+    fn identity() -> Self {
+        ge25519_aff { x: ZERO, y: ONE }
+    }
+
+    fn generator() -> Self {
+        // the base point has z = 1
+        ge25519_aff { x: GE25519_BASE.x, y: GE25519_BASE.y }
+    }
+
+    fn is_identity(self) -> Choice {
+        self.x.is_zero() & self.y.reduce().ct_eq(ONE)
+    }
+}
+
+// returns s*p in constant time, using double-and-add from the most significant bit
+// All 256 bits of s are used, so s doesn't have to be reduced modulo ℓ
+pub fn ge25519_scalarmult(p: ge25519, s: Scalar) -> ge25519 {
+    let mut acc = ge25519_identity();
+    let mut i = 256;
+    while i > 0 {
+        i -= 1;
+        acc = ge25519_double(acc);
+        acc = ge25519::conditional_select(ge25519_add(acc, p), acc, Choice::from(s.bit(i)));
+    }
+    acc
+}
+
+// returns s*B, where B is the base point
+pub fn ge25519_scalarmult_base(s: Scalar) -> ge25519 {
+    ge25519_scalarmult(GE25519_BASE, s)
+}
+
+/*
+Decoding as in RFC 8032, section 5.1.3. The encoding is the 32-byte little-endian encoding of y,
+with the most significant bit set to the sign (lowest bit) of x.
+*/
+// returns the point with encoding `bytes`, which is none if y isn't canonical (y >= p),
+// if there is no point with this y, or if x = 0 and the sign bit is set
+pub fn unpack(bytes: [u8; 32]) -> CtOption<ge25519> {
+    let y = Element::from_bytes(bytes);
+    let top_byte: u64 = bytes[31];
+    let x_sign = Choice::from(top_byte >> 7);
+
+    let mut y_bytes = bytes;
+    y_bytes[31] = top_byte & 0x7f;
+    let y_is_canonical = y.to_bytes().ct_eq(y_bytes);
+
+    // x^2 = u/v, with u = y^2 - 1 and v = d*y^2 + 1
+    let yy = y.square();
+    let u = yy - ONE;
+    let v = (yy * GE25519_ECD + ONE).reduce();
+
+    // The candidate root is x = u*v^3 * (u*v^7)^((p-5)/8)
+    let v3 = v.square() * v;
+    let v7 = v3.square() * v;
+    let mut x = u * v3 * (u * v7).pow22523();
+
+    // If v*x^2 = u, x is a root. If v*x^2 = -u, x*sqrt(-1) is a root. Otherwise there is no root
+    let vxx = (v * x.square()).reduce();
+    let correct_sign = vxx.ct_eq(u);
+    let flipped_sign = vxx.ct_eq(u.negate());
+    x = Element::conditional_select(x * SQRT_M1, x, flipped_sign);
+    let has_root = correct_sign | flipped_sign;
+
+    // x = 0 has no negative root
+    let x_is_invalid_zero = x.reduce().ct_eq(ZERO) & x_sign;
+
+    // Take the root with the sign that's encoded
+    let negate_x = Choice::from(x.is_negative().unwrap_u8() ^ x_sign.unwrap_u8());
+    x = Element::conditional_select(x.negate(), x, negate_x).reduce();
+
+    let point = ge25519 {
+        x: x,
+        y: y,
+        z: ONE,
+        t: x * y,
+    };
+    CtOption::new(point, y_is_canonical & has_root & x_is_invalid_zero.not())
+}
+
+/*
+Encoding as in RFC 8032, section 5.1.2
+*/
+// returns the 32-byte little-endian encoding of the affine y-coordinate of p,
+// with the most significant bit set to the sign (lowest bit) of the affine x-coordinate
+pub fn pack(p: ge25519) -> [u8; 32] {
+    let z_inv = p.z.inverse();
+    let x = p.x * z_inv;
+    let y = p.y * z_inv;
+
+    let mut res = y.to_bytes();
+    let top_byte: u64 = res[31];
+    let x_sign: u64 = x.is_negative().unwrap_u8();
+    res[31] = top_byte | (x_sign << 7);
+    res
+}
@@ -0,0 +1,88 @@
+library;
+
+/*
+The Keccak-f[1600] permutation, as specified in FIPS 202 https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.202.pdf
+The state consists of 25 lanes of 64 bits, where lane x + 5*y holds A[x, y].
+The rho and pi steps are combined as in the reference implementation of the Keccak team (and the keccak crate).
+*/
+
+// Round constants for the iota step
+const RC: [u64; 24] = [
+    0x0000000000000001, 0x0000000000008082, 0x800000000000808A, 0x8000000080008000,
+    0x000000000000808B, 0x0000000080000001, 0x8000000080008081, 0x8000000000008009,
+    0x000000000000008A, 0x0000000000000088, 0x0000000080008009, 0x000000008000000A,
+    0x000000008000808B, 0x800000000000008B, 0x8000000000008089, 0x8000000000008003,
+    0x8000000000008002, 0x8000000000000080, 0x000000000000800A, 0x800000008000000A,
+    0x8000000080008081, 0x8000000000008080, 0x0000000080000001, 0x8000000080008008,
+];
+
+// Rotation offsets of the rho step, in the order in which the lanes are visited by the pi step
+const RHO: [u64; 24] = [
+    1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+];
+
+// The order in which the pi step visits the lanes, starting from lane 1
+const PI: [u64; 24] = [
+    10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+];
+
+// returns x rotated left by n bits, for 0 < n < 64
+fn rotl(x: u64, n: u64) -> u64 {
+    (x << n) | (x >> (64 - n))
+}
+
+// returns the bitwise complement of x
+fn not(x: u64) -> u64 {
+    u64::max() - x
+}
+
+/// Applies the 24 rounds of Keccak-f[1600] to `state`.
+pub fn keccak_f1600(ref mut state: [u64; 25]) {
+    let mut round = 0;
+    while round < 24 {
+        // theta
+        let mut c: [u64; 5] = [0, 0, 0, 0, 0];
+        let mut x = 0;
+        while x < 5 {
+            c[x] = state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20];
+            x += 1;
+        }
+        x = 0;
+        while x < 5 {
+            let d = c[(x + 4) % 5] ^ rotl(c[(x + 1) % 5], 1);
+            let mut y = 0;
+            while y < 25 {
+                state[x + y] = state[x + y] ^ d;
+                y += 5;
+            }
+            x += 1;
+        }
+
+        // rho and pi
+        let mut last = state[1];
+        let mut i = 0;
+        while i < 24 {
+            let j = PI[i];
+            let tmp = state[j];
+            state[j] = rotl(last, RHO[i]);
+            last = tmp;
+            i += 1;
+        }
+
+        // chi
+        let mut y = 0;
+        while y < 25 {
+            let row: [u64; 5] = [state[y], state[y + 1], state[y + 2], state[y + 3], state[y + 4]];
+            x = 0;
+            while x < 5 {
+                state[y + x] = row[x] ^ (not(row[(x + 1) % 5]) & row[(x + 2) % 5]);
+                x += 1;
+            }
+            y += 5;
+        }
+
+        // iota
+        state[0] = state[0] ^ RC[round];
+        round += 1;
+    }
+}
@@ -1,4 +1,13 @@
-library edwards25519;
+library;
 
-dep field_element;
-dep ge25519;
\ No newline at end of file
+mod field_element;
+mod scalar;
+mod ge25519;
+mod sha512;
+mod ed25519;
+mod x25519;
+mod ristretto;
+mod keccak;
+mod strobe;
+mod merlin;
+mod sr25519;
\ No newline at end of file
@@ -0,0 +1,68 @@
+library;
+
+use ::strobe::*;
+use std::vec::Vec;
+
+/*
+Merlin transcripts, as implemented in the merlin crate https://merlin.cool
+A transcript absorbs labeled messages into a STROBE-128 state, and produces labeled challenges from it.
+*/
+
+pub struct Transcript {
+    strobe: Strobe128,
+}
+
+/// Returns the bytes of an ASCII string of at most 16 characters, that is given as 2 words.
+/// The string consists of the first `len` bytes of `hi || lo`, in big-endian order.
+/// For example, "sign:pk" is ascii(0x7369676e3a706b00, 0, 7).
+pub fn ascii(hi: u64, lo: u64, len: u64) -> Vec<u8> {
+    let mut res = Vec::new();
+    let mut i = 0;
+    while i < len {
+        let word = if i < 8 { hi } else { lo };
+        res.push((word >> (8 * (7 - (i % 8)))) & 0xff);
+        i += 1;
+    }
+    res
+}
+
+// returns the 4-byte little-endian encoding of x, which must be < 2^32
+fn le32(x: u64) -> Vec<u8> {
+    let mut res = Vec::new();
+    let mut i = 0;
+    while i < 4 {
+        res.push((x >> (8 * i)) & 0xff);
+        i += 1;
+    }
+    res
+}
+
+/// Returns a new transcript with the domain separator `label`.
+pub fn transcript_new(label: Vec<u8>) -> Transcript {
+    // "Merlin v1.0"
+    let mut t = Transcript {
+        strobe: strobe_new(ascii(0x4d65726c696e2076, 0x312e300000000000, 11)),
+    };
+    // "dom-sep"
+    append_message(t, ascii(0x646f6d2d73657000, 0, 7), label);
+    t
+}
+
+/// Appends `message` to the transcript, with the label `label`.
+pub fn append_message(ref mut t: Transcript, label: Vec<u8>, message: Vec<u8>) {
+    let mut strobe = t.strobe;
+    meta_ad(strobe, label, false);
+    meta_ad(strobe, le32(message.len()), true);
+    ad(strobe, message, false);
+    t.strobe = strobe;
+}
+
+/// Returns `len` challenge bytes with the label `label`, which depend on all messages appended so far.
+pub fn challenge_bytes(ref mut t: Transcript, label: Vec<u8>, len: u64) -> Vec<u8> {
+    let mut strobe = t.strobe;
+    meta_ad(strobe, label, false);
+    meta_ad(strobe, le32(len), true);
+    let res = prf(strobe, len, false);
+    t.strobe = strobe;
+    res
+}
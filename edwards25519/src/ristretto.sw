@@ -0,0 +1,237 @@
+library;
+
+use ::field_element::*;
+use ::ge25519::*;
+use ::scalar::Scalar;
+//This wildcard import is needed because of importing ConstantTimeEq for u64 (since it's a trait for a primitive type)
+use utils::choice::*;
+use utils::group::{Group, Neg};
+use core::ops::{Eq, Add, Subtract};
+
+/*
+The ristretto255 prime-order group, as specified in RFC 9496 https://www.rfc-editor.org/rfc/rfc9496
+Elements are represented by edwards25519 points in extended coordinates. Points that differ
+by a point of order 4 (or 2) represent the same element.
+Reference implementation: curve25519-dalek https://github.com/dalek-cryptography/curve25519-dalek
+*/
+
+/* 1/sqrt(a-d) = 54469307008909316920995813868745141605393597292927456921205312896311721017578 */
+const INVSQRT_A_MINUS_D: Element = Element{ l0: 278908739862762, 
+        l1: 821645201101625, 
+        l2: 8113234426968, 
+        l3: 1777959178193151, 
+        l4: 2118520810568447 };
+
+/* sqrt(a*d-1) = 25063068953384623474111414158702152701244531502492656460079210482610430750235 */
+const SQRT_AD_MINUS_ONE: Element = Element{ l0: 2241493124984347, 
+        l1: 425987919032274, 
+        l2: 2207028919301688, 
+        l3: 1220490630685848, 
+        l4: 974799131293748 };
+
+/* 1-d^2 = 1159843021668779879193775521855586647937357759715417654439879720876111806838 */
+const ONE_MINUS_D_SQ: Element = Element{ l0: 1136626929484150, 
+        l1: 1998550399581263, 
+        l2: 496427632559748, 
+        l3: 118527312129759, 
+        l4: 45110755273534 };
+
+/* (d-1)^2 = 40440834346308536858101042469323190826248399146238708352240133220865137265952 */
+const D_MINUS_ONE_SQ: Element = Element{ l0: 1507062230895904, 
+        l1: 1572317787530805, 
+        l2: 683053064812840, 
+        l3: 317374165784489, 
+        l4: 1572899562415810 };
+
+pub struct RistrettoPoint {
+    point: ge25519,
+}
+
+/*
+The Elligator map of RFC 9496 section 4.3.4, from a field element to a point
+*/
+fn elligator(t: Element) -> ge25519 {
+    let r = SQRT_M1 * t.square();
+    let u = (r + ONE) * ONE_MINUS_D_SQ;
+    let v = (ZERO - ONE - r * GE25519_ECD) * (r + GE25519_ECD);
+
+    let (was_square, s) = Element::sqrt_ratio_i(u, v);
+    let s_prime = (s * t).abs().negate();
+    let s = Element::conditional_select(s, s_prime, was_square);
+    let c = Element::conditional_select(ONE.negate(), r, was_square);
+
+    let n = c * (r - ONE) * D_MINUS_ONE_SQ - v;
+    let ss = s.square();
+
+    // the affine coordinates are x = X/Z and y = Y/T, which is exactly the ge25519_p1p1 representation
+    p1p1_to_p3(ge25519_p1p1 {
+        x: (s + s) * v,
+        y: ONE - ss,
+        z: n * SQRT_AD_MINUS_ONE,
+        t: ONE + ss,
+    })
+}
+
+impl RistrettoPoint {
+    pub fn identity() -> RistrettoPoint {
+        RistrettoPoint { point: ge25519_identity() }
+    }
+
+    /// The generator of ristretto255, which is represented by the edwards25519 base point
+    pub fn basepoint() -> RistrettoPoint {
+        RistrettoPoint { point: GE25519_BASE }
+    }
+
+    /// Returns the canonical 32-byte encoding of this element (RFC 9496 section 4.3.2).
+    pub fn encode(self) -> [u8; 32] {
+        let x0 = self.point.x;
+        let y0 = self.point.y;
+        let z0 = self.point.z;
+        let t0 = self.point.t;
+
+        let u1 = (z0 + y0) * (z0 - y0);
+        let u2 = x0 * y0;
+        // Ignore was_square since this is always square
+        let (_, invsqrt) = Element::sqrt_ratio_i(ONE, u1 * u2.square());
+        let den1 = invsqrt * u1;
+        let den2 = invsqrt * u2;
+        let z_inv = den1 * den2 * t0;
+
+        let ix0 = x0 * SQRT_M1;
+        let iy0 = y0 * SQRT_M1;
+        let enchanted_denominator = den1 * INVSQRT_A_MINUS_D;
+
+        let rotate = (t0 * z_inv).is_negative();
+        let x = Element::conditional_select(iy0, x0, rotate);
+        let mut y = Element::conditional_select(ix0, y0, rotate);
+        let den_inv = Element::conditional_select(enchanted_denominator, den2, rotate);
+
+        y = Element::conditional_select(y.negate(), y, (x * z_inv).is_negative());
+
+        let s = (den_inv * (z0 - y)).abs();
+        s.to_bytes()
+    }
+
+    /// Returns the element with encoding `bytes` (RFC 9496 section 4.3.1).
+    /// Is none if `bytes` is not the canonical encoding of an element.
+    pub fn decode(bytes: [u8; 32]) -> CtOption<RistrettoPoint> {
+        let s = Element::from_bytes(bytes);
+        // s must be canonical (which includes the top bit being 0) and nonnegative
+        let s_is_canonical = s.to_bytes().ct_eq(bytes);
+        let s_is_negative = s.is_negative();
+
+        let ss = s.square();
+        let u1 = ONE - ss;
+        let u2 = ONE + ss;
+        let u2_sqr = u2.square();
+
+        let v = (GE25519_ECD * u1.square()).negate() - u2_sqr;
+
+        let (was_square, invsqrt) = Element::sqrt_ratio_i(ONE, v * u2_sqr);
+
+        let den_x = invsqrt * u2;
+        let den_y = invsqrt * den_x * v;
+
+        let x = (s + s) * den_x;
+        let x = x.abs();
+        let y = u1 * den_y;
+        let t = x * y;
+
+        let point = RistrettoPoint {
+            point: ge25519 {
+                x: x,
+                y: y,
+                z: ONE,
+                t: t,
+            }
+        };
+        let is_valid = s_is_canonical 
+            & s_is_negative.not() 
+            & was_square 
+            & t.is_negative().not() 
+            & y.is_zero().not();
+        CtOption::new(point, is_valid)
+    }
+
+    /// Returns the element that `bytes` maps to, with the one-way map of RFC 9496 section 4.3.4.
+    /// The result is uniformly distributed if `bytes` is, so this can be used for hashing to the group.
+    pub fn from_uniform_bytes(bytes: [u8; 64]) -> RistrettoPoint {
+        let mut r0_bytes: [u8; 32] = [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        let mut r1_bytes: [u8; 32] = [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        let mut i = 0;
+        while i < 32 {
+            r0_bytes[i] = bytes[i];
+            r1_bytes[i] = bytes[32 + i];
+            i += 1;
+        }
+
+        // from_bytes ignores the most significant bit, as required
+        let p1 = elligator(Element::from_bytes(r0_bytes));
+        let p2 = elligator(Element::from_bytes(r1_bytes));
+        RistrettoPoint { point: ge25519_add(p1, p2) }
+    }
+}
+
+impl ConstantTimeEq for RistrettoPoint {
+    // returns (self == other), as a choice (RFC 9496 section 4.3.3)
+    fn ct_eq(self, other: Self) -> Choice {
+        // x1*y2 == y1*x2 or y1*y2 == x1*x2
+        let x1y2 = (self.point.x * other.point.y).reduce();
+        let y1x2 = (self.point.y * other.point.x).reduce();
+        let y1y2 = (self.point.y * other.point.y).reduce();
+        let x1x2 = (self.point.x * other.point.x).reduce();
+        x1y2.ct_eq(y1x2) | y1y2.ct_eq(x1x2)
+    }
+}
+
+impl Eq for RistrettoPoint {
+    fn eq(self, other: Self) -> bool {
+        self.ct_eq(other).unwrap_as_bool()
+    }
+}
+
+impl Add for RistrettoPoint {
+    fn add(self, other: Self) -> Self {
+        RistrettoPoint { point: ge25519_add(self.point, other.point) }
+    }
+}
+
+impl Subtract for RistrettoPoint {
+    fn subtract(self, other: Self) -> Self {
+        RistrettoPoint { point: ge25519_add(self.point, ge25519_neg(other.point)) }
+    }
+}
+
+impl Neg for RistrettoPoint {
+    fn neg(self) -> Self {
+        RistrettoPoint { point: ge25519_neg(self.point) }
+    }
+}
+
+impl Group<Scalar> for RistrettoPoint {
+    fn identity() -> Self {
+        RistrettoPoint::identity()
+    }
+
+    fn generator() -> Self {
+        RistrettoPoint::basepoint()
+    }
+
+    fn is_identity(self) -> Choice {
+        self.ct_eq(RistrettoPoint::identity())
+    }
+
+    fn double(self) -> Self {
+        RistrettoPoint { point: ge25519_double(self.point) }
+    }
+
+    fn mul_scalar(self, scalar: Scalar) -> Self {
+        RistrettoPoint { point: ge25519_scalarmult(self.point, scalar) }
+    }
+}
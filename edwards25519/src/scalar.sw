@@ -0,0 +1,377 @@
+library;
+
+//This wildcard import is needed because of importing ConditionallySelectable for u64 (since it's a trait for a primitive type)
+use utils::choice::*;
+use utils::integer_utils::adc;
+use utils::ff::{Field, PrimeField};
+use std::u128::U128;
+use core::ops::{Eq, Add, Multiply};
+
+/*
+Scalars for the edwards25519 group, i.e. integers modulo the order of the prime-order subgroup
+ℓ = 2^252 + 27742317777372353535851937790883648493
+Reference implementation: curve25519-dalek https://github.com/dalek-cryptography/curve25519-dalek
+*/
+
+// A 256-bit integer, stored as 4 little-endian 64-bit limbs: ls[0] + ls[1]*2^64 + ls[2]*2^128 + ls[3]*2^192
+// Unless constructed with `from_bits`, the value is reduced modulo ℓ
+pub struct Scalar {
+    ls: [u64; 4],
+}
+
+/// The order of the prime-order subgroup
+/// ℓ = 0x1000000000000000000000000000000014def9dea2f79cd65812631a5cf5d3ed
+pub const L: Scalar = Scalar {
+    ls: [
+        0x5812_631a_5cf5_d3ed,
+        0x14de_f9de_a2f7_9cd6,
+        0x0000_0000_0000_0000,
+        0x1000_0000_0000_0000,
+    ],
+};
+
+//returns the result and new carry of a + b*c + carry as (result, carry)
+fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let a_128: U128 = U128::from((0, a));
+    let b_128: U128 = U128::from((0, b));
+    let c_128: U128 = U128::from((0, c));
+    let carry_128: U128 = U128::from((0, carry));
+
+    let res: U128 = a_128 + (b_128 * c_128) + carry_128;
+    (
+        res.lower,
+        res.upper,
+    )
+}
+
+impl Scalar {
+    pub fn zero() -> Scalar {
+        Scalar { ls: [0, 0, 0, 0] }
+    }
+
+    pub fn one() -> Scalar {
+        Scalar { ls: [1, 0, 0, 0] }
+    }
+
+    /// Returns the 256-bit integer with little-endian encoding `bytes`, without reducing it modulo ℓ.
+    /// This is only meant for scalar multiplication by integers that are not reduced,
+    /// such as clamped X25519 secret keys.
+    pub fn from_bits(bytes: [u8; 32]) -> Scalar {
+        let mut res = Scalar { ls: [0, 0, 0, 0] };
+        let mut i = 0;
+        while i < 32 {
+            // byte i is in limb i/8, at position i%8 from the least significant byte
+            let byte: u64 = bytes[i];
+            res.ls[i / 8] = res.ls[i / 8] | (byte << (8 * (i % 8)));
+            i += 1;
+        }
+        res
+    }
+
+    /// Returns the little-endian encoding of this scalar.
+    pub fn to_bytes(self) -> [u8; 32] {
+        let mut res: [u8; 32] = [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        let mut i = 0;
+        while i < 32 {
+            res[i] = (self.ls[i / 8] >> (8 * (i % 8))) & 0xff;
+            i += 1;
+        }
+        res
+    }
+
+    // returns bit i of this scalar, where bit 0 is the least significant bit
+    pub fn bit(self, i: u64) -> u8 {
+        (self.ls[i / 64] >> (i % 64)) & 1
+    }
+}
+
+impl Scalar {
+    // This goes in a separate impl, because if we use previously defined functions in Scalar impl, 
+    // Sway will not recognize them from inside the same impl
+
+    // returns (self - ℓ mod 2^256, carry), where carry is 1 if self >= ℓ and 0 otherwise
+    // The subtraction is computed as self + !ℓ + 1, which has a carry exactly when there's no borrow
+    fn sub_order(self) -> (Scalar, u64) {
+        let (d0, carry) = adc(self.ls[0], u64::max() - L.ls[0], 1);
+        let (d1, carry) = adc(self.ls[1], u64::max() - L.ls[1], carry);
+        let (d2, carry) = adc(self.ls[2], u64::max() - L.ls[2], carry);
+        let (d3, carry) = adc(self.ls[3], u64::max() - L.ls[3], carry);
+        (Scalar { ls: [d0, d1, d2, d3] }, carry)
+    }
+}
+
+impl Scalar {
+    // This goes in a separate impl, because if we use previously defined functions in Scalar impl, 
+    // Sway will not recognize them from inside the same impl
+
+    // returns self - ℓ if self >= ℓ and self otherwise, in constant time
+    // This fully reduces self if self < 2ℓ
+    fn reduce_once(self) -> Scalar {
+        let (diff, carry) = self.sub_order();
+        let choice = Choice::from(carry);
+        Scalar {
+            ls: [
+                u64::conditional_select(diff.ls[0], self.ls[0], choice),
+                u64::conditional_select(diff.ls[1], self.ls[1], choice),
+                u64::conditional_select(diff.ls[2], self.ls[2], choice),
+                u64::conditional_select(diff.ls[3], self.ls[3], choice),
+            ]
+        }
+    }
+}
+
+impl Scalar {
+    // This goes in a separate impl, because if we use previously defined functions in Scalar impl, 
+    // Sway will not recognize them from inside the same impl
+
+    // returns the 512-bit integer limbs[0] + limbs[1]*2^64 + ... + limbs[7]*2^448, reduced modulo ℓ
+    fn reduce_wide(limbs: [u64; 8]) -> Scalar {
+        let mut res = Scalar::zero();
+        // Double-and-add over the bits, from the most significant bit.
+        // Since res < ℓ before each step, 2*res + bit < 2ℓ < 2^254, so reducing once suffices.
+        let mut i = 512;
+        while i > 0 {
+            i -= 1;
+            let bit = (limbs[i / 64] >> (i % 64)) & 1;
+            let doubled = Scalar {
+                ls: [
+                    (res.ls[0] << 1) | bit,
+                    (res.ls[1] << 1) | (res.ls[0] >> 63),
+                    (res.ls[2] << 1) | (res.ls[1] >> 63),
+                    (res.ls[3] << 1) | (res.ls[2] >> 63),
+                ]
+            };
+            res = doubled.reduce_once();
+        }
+        res
+    }
+
+    /// Returns whether `bytes` is the canonical encoding of a scalar, i.e. the encoded integer is < ℓ.
+    pub fn is_canonical(bytes: [u8; 32]) -> Choice {
+        let (_, carry) = Scalar::from_bits(bytes).sub_order();
+        u64::ct_eq(carry, 0)
+    }
+
+    // returns self + rhs mod ℓ
+    fn add(self, rhs: Scalar) -> Scalar {
+        // Both are < ℓ < 2^253, so the sum doesn't overflow and is < 2ℓ
+        let (d0, carry) = adc(self.ls[0], rhs.ls[0], 0);
+        let (d1, carry) = adc(self.ls[1], rhs.ls[1], carry);
+        let (d2, carry) = adc(self.ls[2], rhs.ls[2], carry);
+        let (d3, _) = adc(self.ls[3], rhs.ls[3], carry);
+        Scalar { ls: [d0, d1, d2, d3] }.reduce_once()
+    }
+}
+
+impl Scalar {
+    // This goes in a separate impl, because if we use previously defined functions in Scalar impl, 
+    // Sway will not recognize them from inside the same impl
+
+    /// Returns the 512-bit integer with little-endian encoding `bytes`, reduced modulo ℓ.
+    /// This is used to turn a SHA-512 output into a scalar.
+    pub fn from_bytes_mod_order_wide(bytes: [u8; 64]) -> Scalar {
+        let mut limbs: [u64; 8] = [0, 0, 0, 0, 0, 0, 0, 0];
+        let mut i = 0;
+        while i < 64 {
+            // byte i is in limb i/8, at position i%8 from the least significant byte
+            let byte: u64 = bytes[i];
+            limbs[i / 8] = limbs[i / 8] | (byte << (8 * (i % 8)));
+            i += 1;
+        }
+        Scalar::reduce_wide(limbs)
+    }
+
+    /// Returns the 256-bit integer with little-endian encoding `bytes`, reduced modulo ℓ.
+    pub fn from_bytes_mod_order(bytes: [u8; 32]) -> Scalar {
+        let s = Scalar::from_bits(bytes);
+        Scalar::reduce_wide([s.ls[0], s.ls[1], s.ls[2], s.ls[3], 0, 0, 0, 0])
+    }
+
+    /// Returns the scalar with little-endian encoding `bytes`, if it is canonical (i.e. < ℓ).
+    /// This is the check RFC 8032 requires for the S part of a signature.
+    pub fn from_canonical_bytes(bytes: [u8; 32]) -> CtOption<Scalar> {
+        CtOption::new(Scalar::from_bits(bytes), Scalar::is_canonical(bytes))
+    }
+
+    // returns self * rhs mod ℓ
+    fn mul(self, rhs: Scalar) -> Scalar {
+        // Schoolbook multiplication into 8 limbs, followed by a wide reduction
+        let mut t: [u64; 8] = [0, 0, 0, 0, 0, 0, 0, 0];
+        let mut i = 0;
+        while i < 4 {
+            let mut carry = 0;
+            let mut j = 0;
+            while j < 4 {
+                let (res, new_carry) = mac(t[i + j], self.ls[i], rhs.ls[j], carry);
+                t[i + j] = res;
+                carry = new_carry;
+                j += 1;
+            }
+            t[i + 4] = carry;
+            i += 1;
+        }
+        Scalar::reduce_wide(t)
+    }
+}
+
+impl Scalar {
+    // This goes in a separate impl, because if we use previously defined functions in Scalar impl, 
+    // Sway will not recognize them from inside the same impl
+
+    // returns -self mod ℓ
+    fn neg(self) -> Scalar {
+        // ℓ - self = ℓ + !self + 1, which is ℓ for self = 0, so it's reduced once more
+        let (d0, carry) = adc(L.ls[0], u64::max() - self.ls[0], 1);
+        let (d1, carry) = adc(L.ls[1], u64::max() - self.ls[1], carry);
+        let (d2, carry) = adc(L.ls[2], u64::max() - self.ls[2], carry);
+        let (d3, _) = adc(L.ls[3], u64::max() - self.ls[3], carry);
+        Scalar { ls: [d0, d1, d2, d3] }.reduce_once()
+    }
+
+    // returns self^exp mod ℓ, where exp is given as 4 little-endian limbs
+    // The running time depends on exp, so it should only be used with public exponents
+    pub fn pow_vartime(self, exp: [u64; 4]) -> Scalar {
+        let mut res = Scalar::one();
+        let mut i = 4;
+        while i > 0 {
+            i -= 1;
+            let mut j = 64;
+            while j > 0 {
+                j -= 1;
+                res = res.mul(res);
+                if ((exp[i] >> j) & 1) == 1 {
+                    res = res.mul(self);
+                }
+            }
+        }
+        res
+    }
+}
+
+impl Scalar {
+    // This goes in a separate impl, because if we use previously defined functions in Scalar impl, 
+    // Sway will not recognize them from inside the same impl
+
+    // returns self^-1 mod ℓ = self^(ℓ-2) by Fermat's theorem. Returns 0 for 0
+    fn invert_unchecked(self) -> Scalar {
+        self.pow_vartime([
+            0x5812_631a_5cf5_d3eb,
+            0x14de_f9de_a2f7_9cd6,
+            0x0000_0000_0000_0000,
+            0x1000_0000_0000_0000,
+        ])
+    }
+
+    // returns a square root of self, which is only correct if self is a square
+    // Since ℓ = 5 mod 8, this is Atkin's algorithm:
+    // b = (2*self)^((ℓ-5)/8), i = 2*self*b^2 and the root is self*b*(i-1)
+    fn sqrt_unchecked(self) -> Scalar {
+        let two_a = self.add(self);
+        let b = two_a.pow_vartime([
+            0xcb02_4c63_4b9e_ba7d,
+            0x029b_df3b_d45e_f39a,
+            0x0000_0000_0000_0000,
+            0x0200_0000_0000_0000,
+        ]);
+        let i = two_a.mul(b.mul(b));
+        self.mul(b).mul(i.add(Scalar::one().neg()))
+    }
+}
+
+impl ConstantTimeEq for Scalar {
+    // returns (self == other), as a choice
+    fn ct_eq(self, other: Scalar) -> Choice {
+        u64::ct_eq(self.ls[0], other.ls[0])
+        & u64::ct_eq(self.ls[1], other.ls[1])
+        & u64::ct_eq(self.ls[2], other.ls[2])
+        & u64::ct_eq(self.ls[3], other.ls[3])
+    }
+}
+
+impl ConditionallySelectable for Scalar {
+    // Select a if choice == 1 or select b if choice == 0, in constant time.
+    fn conditional_select(a: Self, b: Self, choice: Choice) -> Self {
+        Scalar {
+            ls: [
+                u64::conditional_select(a.ls[0], b.ls[0], choice),
+                u64::conditional_select(a.ls[1], b.ls[1], choice),
+                u64::conditional_select(a.ls[2], b.ls[2], choice),
+                u64::conditional_select(a.ls[3], b.ls[3], choice),
+            ]
+        }
+    }
+}
+
+impl ConditionallyNegatable for Scalar {
+    // Negate self if choice == 1, leave it unchanged if choice == 0
+    fn conditional_negate(ref mut self, choice: Choice) {
+        self = Scalar::conditional_select(self.neg(), self, choice);
+    }
+}
+
+impl Eq for Scalar {
+    fn eq(self, other: Self) -> bool {
+        self.ct_eq(other).unwrap_as_bool()
+    }
+}
+
+impl Add for Scalar {
+    fn add(self, other: Self) -> Self {
+        self.add(other)
+    }
+}
+
+impl Multiply for Scalar {
+    fn multiply(self, other: Self) -> Self {
+        self.mul(other)
+    }
+}
+
+impl Field for Scalar {
+    fn zero() -> Self {
+        Scalar::zero()
+    }
+
+    fn one() -> Self {
+        Scalar::one()
+    }
+
+    fn is_zero(self) -> Choice {
+        self.ct_eq(Scalar::zero())
+    }
+
+    fn square(self) -> Self {
+        self.mul(self)
+    }
+
+    fn double(self) -> Self {
+        self.add(self)
+    }
+
+    fn invert(self) -> CtOption<Self> {
+        CtOption::new(self.invert_unchecked(), self.ct_eq(Scalar::zero()).not())
+    }
+
+    fn sqrt(self) -> CtOption<Self> {
+        let root = self.sqrt_unchecked();
+        CtOption::new(root, root.mul(root).ct_eq(self))
+    }
+}
+
+impl PrimeField for Scalar {
+    // The encoding is little-endian, as in RFC 8032
+    fn from_repr(bytes: [u8; 32]) -> CtOption<Self> {
+        Scalar::from_canonical_bytes(bytes)
+    }
+
+    fn to_repr(self) -> [u8; 32] {
+        self.to_bytes()
+    }
+
+    fn is_odd(self) -> Choice {
+        Choice::from(self.ls[0] & 1)
+    }
+}
@@ -0,0 +1,174 @@
+library;
+
+use std::vec::Vec;
+use utils::integer_utils::adc;
+
+// Implementation of SHA-512, as specified in FIPS 180-4 https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.180-4.pdf
+// The FuelVM only has instructions for SHA-256 and Keccak-256, but Ed25519 needs SHA-512.
+
+// The first 64 bits of the fractional parts of the cube roots of the first 80 primes
+const K: [u64; 80] = [
+    0x428a_2f98_d728_ae22, 0x7137_4491_23ef_65cd, 0xb5c0_fbcf_ec4d_3b2f, 0xe9b5_dba5_8189_dbbc,
+    0x3956_c25b_f348_b538, 0x59f1_11f1_b605_d019, 0x923f_82a4_af19_4f9b, 0xab1c_5ed5_da6d_8118,
+    0xd807_aa98_a303_0242, 0x1283_5b01_4570_6fbe, 0x2431_85be_4ee4_b28c, 0x550c_7dc3_d5ff_b4e2,
+    0x72be_5d74_f27b_896f, 0x80de_b1fe_3b16_96b1, 0x9bdc_06a7_25c7_1235, 0xc19b_f174_cf69_2694,
+    0xe49b_69c1_9ef1_4ad2, 0xefbe_4786_384f_25e3, 0x0fc1_9dc6_8b8c_d5b5, 0x240c_a1cc_77ac_9c65,
+    0x2de9_2c6f_592b_0275, 0x4a74_84aa_6ea6_e483, 0x5cb0_a9dc_bd41_fbd4, 0x76f9_88da_8311_53b5,
+    0x983e_5152_ee66_dfab, 0xa831_c66d_2db4_3210, 0xb003_27c8_98fb_213f, 0xbf59_7fc7_beef_0ee4,
+    0xc6e0_0bf3_3da8_8fc2, 0xd5a7_9147_930a_a725, 0x06ca_6351_e003_826f, 0x1429_2967_0a0e_6e70,
+    0x27b7_0a85_46d2_2ffc, 0x2e1b_2138_5c26_c926, 0x4d2c_6dfc_5ac4_2aed, 0x5338_0d13_9d95_b3df,
+    0x650a_7354_8baf_63de, 0x766a_0abb_3c77_b2a8, 0x81c2_c92e_47ed_aee6, 0x9272_2c85_1482_353b,
+    0xa2bf_e8a1_4cf1_0364, 0xa81a_664b_bc42_3001, 0xc24b_8b70_d0f8_9791, 0xc76c_51a3_0654_be30,
+    0xd192_e819_d6ef_5218, 0xd699_0624_5565_a910, 0xf40e_3585_5771_202a, 0x106a_a070_32bb_d1b8,
+    0x19a4_c116_b8d2_d0c8, 0x1e37_6c08_5141_ab53, 0x2748_774c_df8e_eb99, 0x34b0_bcb5_e19b_48a8,
+    0x391c_0cb3_c5c9_5a63, 0x4ed8_aa4a_e341_8acb, 0x5b9c_ca4f_7763_e373, 0x682e_6ff3_d6b2_b8a3,
+    0x748f_82ee_5def_b2fc, 0x78a5_636f_4317_2f60, 0x84c8_7814_a1f0_ab72, 0x8cc7_0208_1a64_39ec,
+    0x90be_fffa_2363_1e28, 0xa450_6ceb_de82_bde9, 0xbef9_a3f7_b2c6_7915, 0xc671_78f2_e372_532b,
+    0xca27_3ece_ea26_619c, 0xd186_b8c7_21c0_c207, 0xeada_7dd6_cde0_eb1e, 0xf57d_4f7f_ee6e_d178,
+    0x06f0_67aa_7217_6fba, 0x0a63_7dc5_a2c8_98a6, 0x113f_9804_bef9_0dae, 0x1b71_0b35_131c_471b,
+    0x28db_77f5_2304_7d84, 0x32ca_ab7b_40c7_2493, 0x3c9e_be0a_15c9_bebc, 0x431d_67c4_9c10_0d4c,
+    0x4cc5_d4be_cb3e_42b6, 0x597f_299c_fc65_7e2a, 0x5fcb_6fab_3ad6_faec, 0x6c44_198c_4a47_5817,
+];
+
+// The first 64 bits of the fractional parts of the square roots of the first 8 primes
+const H: [u64; 8] = [
+    0x6a09_e667_f3bc_c908, 0xbb67_ae85_84ca_a73b, 0x3c6e_f372_fe94_f82b, 0xa54f_f53a_5f1d_36f1,
+    0x510e_527f_ade6_82d1, 0x9b05_688c_2b3e_6c1f, 0x1f83_d9ab_fb41_bd6b, 0x5be0_cd19_137e_2179,
+];
+
+// Block size of SHA-512 in bytes
+const SHA512_BLOCK_SIZE: u64 = 128;
+
+// returns a + b mod 2^64
+fn wrapping_add(a: u64, b: u64) -> u64 {
+    let (res, _) = adc(a, b, 0);
+    res
+}
+
+// returns x rotated right by n bits, for 0 < n < 64
+fn rotr(x: u64, n: u64) -> u64 {
+    (x >> n) | (x << (64 - n))
+}
+
+// returns the big-endian word at position `start` of `bytes`
+fn read_word(bytes: Vec<u8>, start: u64) -> u64 {
+    let mut res = 0;
+    let mut i = 0;
+    while i < 8 {
+        let byte: u64 = bytes.get(start + i).unwrap();
+        res = (res << 8) | byte;
+        i += 1;
+    }
+    res
+}
+
+// Applies the compression function to `state` for the 128-byte block of `bytes` at position `start`
+fn compress(ref mut state: [u64; 8], bytes: Vec<u8>, start: u64) {
+    // The message schedule is kept in a window of 16 words, w[t % 16] holds W_t
+    let mut w: [u64; 16] = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+    let mut t = 0;
+    while t < 16 {
+        w[t] = read_word(bytes, start + 8 * t);
+        t += 1;
+    }
+
+    let mut a = state[0];
+    let mut b = state[1];
+    let mut c = state[2];
+    let mut d = state[3];
+    let mut e = state[4];
+    let mut f = state[5];
+    let mut g = state[6];
+    let mut h = state[7];
+
+    t = 0;
+    while t < 80 {
+        if t >= 16 {
+            // W_t = σ1(W_(t-2)) + W_(t-7) + σ0(W_(t-15)) + W_(t-16)
+            let w2 = w[(t - 2) % 16];
+            let w15 = w[(t - 15) % 16];
+            let sigma1 = rotr(w2, 19) ^ rotr(w2, 61) ^ (w2 >> 6);
+            let sigma0 = rotr(w15, 1) ^ rotr(w15, 8) ^ (w15 >> 7);
+            w[t % 16] = wrapping_add(wrapping_add(sigma1, w[(t - 7) % 16]), wrapping_add(sigma0, w[t % 16]));
+        }
+
+        // T1 = h + Σ1(e) + Ch(e, f, g) + K_t + W_t
+        let big_sigma1 = rotr(e, 14) ^ rotr(e, 18) ^ rotr(e, 41);
+        let ch = (e & f) ^ ((u64::max() - e) & g);
+        let t1 = wrapping_add(wrapping_add(wrapping_add(h, big_sigma1), wrapping_add(ch, K[t])), w[t % 16]);
+        // T2 = Σ0(a) + Maj(a, b, c)
+        let big_sigma0 = rotr(a, 28) ^ rotr(a, 34) ^ rotr(a, 39);
+        let maj = (a & b) ^ (a & c) ^ (b & c);
+        let t2 = wrapping_add(big_sigma0, maj);
+
+        h = g;
+        g = f;
+        f = e;
+        e = wrapping_add(d, t1);
+        d = c;
+        c = b;
+        b = a;
+        a = wrapping_add(t1, t2);
+        t += 1;
+    }
+
+    state[0] = wrapping_add(state[0], a);
+    state[1] = wrapping_add(state[1], b);
+    state[2] = wrapping_add(state[2], c);
+    state[3] = wrapping_add(state[3], d);
+    state[4] = wrapping_add(state[4], e);
+    state[5] = wrapping_add(state[5], f);
+    state[6] = wrapping_add(state[6], g);
+    state[7] = wrapping_add(state[7], h);
+}
+
+/// Returns the SHA-512 hash of `input`.
+pub fn sha512(input: Vec<u8>) -> [u8; 64] {
+    let len = input.len();
+
+    // Padding: the input, the byte 0x80, zeros, and the length in bits as a 128-bit big-endian integer,
+    // such that the total length is a multiple of the block size
+    let mut padded = Vec::new();
+    let mut i = 0;
+    while i < len {
+        padded.push(input.get(i).unwrap());
+        i += 1;
+    }
+    padded.push(0x80);
+    while padded.len() % SHA512_BLOCK_SIZE != SHA512_BLOCK_SIZE - 16 {
+        padded.push(0);
+    }
+    // The upper 64 bits of the length are 0 for any input that fits in memory
+    i = 0;
+    while i < 8 {
+        padded.push(0);
+        i += 1;
+    }
+    let len_bits = len * 8;
+    let mut j = 8;
+    while j > 0 {
+        j -= 1;
+        padded.push((len_bits >> (8 * j)) & 0xff);
+    }
+
+    let mut state = H;
+    let mut start = 0;
+    while start < padded.len() {
+        compress(state, padded, start);
+        start += SHA512_BLOCK_SIZE;
+    }
+
+    let mut res: [u8; 64] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    i = 0;
+    while i < 64 {
+        // the words of the state are output in big-endian order
+        res[i] = (state[i / 8] >> (8 * (7 - (i % 8)))) & 0xff;
+        i += 1;
+    }
+    res
+}
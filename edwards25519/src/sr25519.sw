@@ -0,0 +1,92 @@
+library;
+
+use ::scalar::Scalar;
+use ::ge25519::{ge25519_neg, ge25519_scalarmult, ge25519_scalarmult_base};
+use ::ristretto::RistrettoPoint;
+use ::merlin::*;
+use utils::choice::ConstantTimeEq;
+use std::vec::Vec;
+
+/*
+Sr25519 signature verification: Schnorr signatures over ristretto255 with Merlin transcripts,
+as implemented in the schnorrkel crate https://github.com/w3f/schnorrkel (verify_simple)
+*/
+
+// returns the bytes of `bytes` as a Vec
+fn to_vec(bytes: [u8; 32]) -> Vec<u8> {
+    let mut res = Vec::new();
+    let mut i = 0;
+    while i < 32 {
+        res.push(bytes[i]);
+        i += 1;
+    }
+    res
+}
+
+/// Returns whether `sig` is a valid Sr25519 signature on `msg` in the signing context `context`,
+/// for the public key `pubkey`.
+/// The signature is the encoding of a point R followed by a scalar s, where the most significant bit
+/// of the last byte is set to distinguish it from an Ed25519 signature.
+/// Verification fails if that bit isn't set, if s isn't reduced or if the public key doesn't decode.
+/// Otherwise the signature is valid iff the encoding of [s]B - [k]A equals R,
+/// where A is the public key and k is the challenge derived from the transcript.
+pub fn sr25519_verify(pubkey: [u8; 32], context: Vec<u8>, msg: Vec<u8>, sig: [u8; 64]) -> bool {
+    if (sig[63] & 128) == 0 {
+        return false;
+    }
+
+    let mut r_bytes: [u8; 32] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    let mut s_bytes: [u8; 32] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    let mut i = 0;
+    while i < 32 {
+        r_bytes[i] = sig[i];
+        s_bytes[i] = sig[32 + i];
+        i += 1;
+    }
+    s_bytes[31] = s_bytes[31] & 127;
+
+    // All inputs are public, so there's no need to continue in constant time when decoding fails
+    let a = RistrettoPoint::decode(pubkey);
+    let s = Scalar::from_canonical_bytes(s_bytes);
+    if a.is_none() || s.is_none() {
+        return false;
+    }
+
+    // "SigningContext"
+    let mut t = transcript_new(ascii(0x5369676e696e6743, 0x6f6e746578740000, 14));
+    append_message(t, ascii(0, 0, 0), context);
+    // "sign-bytes"
+    append_message(t, ascii(0x7369676e2d627974, 0x6573000000000000, 10), msg);
+    // "proto-name", "Schnorr-sig"
+    append_message(t, ascii(0x70726f746f2d6e61, 0x6d65000000000000, 10), ascii(0x5363686e6f72722d, 0x7369670000000000, 11));
+    // "sign:pk"
+    append_message(t, ascii(0x7369676e3a706b00, 0, 7), to_vec(pubkey));
+    // "sign:R"
+    append_message(t, ascii(0x7369676e3a520000, 0, 6), to_vec(r_bytes));
+
+    // "sign:c"
+    let challenge = challenge_bytes(t, ascii(0x7369676e3a630000, 0, 6), 64);
+    let mut k_bytes: [u8; 64] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    i = 0;
+    while i < 64 {
+        k_bytes[i] = challenge.get(i).unwrap();
+        i += 1;
+    }
+    let k = Scalar::from_bytes_mod_order_wide(k_bytes);
+
+    // R' = [s]B - [k]A
+    let minus_ka = ge25519_scalarmult(ge25519_neg(a.unwrap().point), k);
+    let r_prime = RistrettoPoint { point: ge25519_scalarmult_base(s.unwrap()) + minus_ka };
+    r_prime.encode().ct_eq(r_bytes).unwrap_as_bool()
+}
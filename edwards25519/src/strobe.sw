@@ -0,0 +1,139 @@
+library;
+
+use ::keccak::keccak_f1600;
+use std::vec::Vec;
+
+/*
+STROBE-128 on Keccak-f[1600], restricted to the operations that Merlin transcripts use.
+This follows the minimal implementation in the merlin crate https://github.com/dalek-cryptography/merlin/blob/master/src/strobe.rs
+See https://strobe.sourceforge.io/specs/ for the full specification.
+*/
+
+// The rate of STROBE-128 in bytes, which is 200 - 2*128/8 - 2
+const STROBE_R: u64 = 166;
+
+const FLAG_I: u64 = 1;
+const FLAG_A: u64 = 2;
+const FLAG_C: u64 = 4;
+const FLAG_M: u64 = 16;
+const FLAG_K: u64 = 32;
+
+pub struct Strobe128 {
+    state: [u64; 25],
+    pos: u64,
+    pos_begin: u64,
+    cur_flags: u64,
+}
+
+// returns the byte at position `pos` of the state, where lanes are little-endian
+fn get_byte(state: [u64; 25], pos: u64) -> u64 {
+    (state[pos / 8] >> (8 * (pos % 8))) & 0xff
+}
+
+// xors `byte` into the byte at position `pos` of the state
+fn xor_byte(ref mut state: [u64; 25], pos: u64, byte: u64) {
+    state[pos / 8] = state[pos / 8] ^ (byte << (8 * (pos % 8)));
+}
+
+fn run_f(ref mut s: Strobe128) {
+    let mut state = s.state;
+    xor_byte(state, s.pos, s.pos_begin);
+    xor_byte(state, s.pos + 1, 0x04);
+    xor_byte(state, STROBE_R + 1, 0x80);
+    keccak_f1600(state);
+    s.state = state;
+    s.pos = 0;
+    s.pos_begin = 0;
+}
+
+// xors a single byte into the state, and applies the permutation when the rate is reached
+fn absorb_byte(ref mut s: Strobe128, byte: u64) {
+    let mut state = s.state;
+    xor_byte(state, s.pos, byte);
+    s.state = state;
+    s.pos += 1;
+    if s.pos == STROBE_R {
+        run_f(s);
+    }
+}
+
+fn absorb(ref mut s: Strobe128, data: Vec<u8>) {
+    let mut i = 0;
+    while i < data.len() {
+        absorb_byte(s, data.get(i).unwrap());
+        i += 1;
+    }
+}
+
+// reads `len` bytes from the state, setting them to 0
+fn squeeze(ref mut s: Strobe128, len: u64) -> Vec<u8> {
+    let mut res = Vec::new();
+    let mut i = 0;
+    while i < len {
+        let byte = get_byte(s.state, s.pos);
+        res.push(byte);
+        // xoring the byte with itself clears it
+        absorb_byte(s, byte);
+        i += 1;
+    }
+    res
+}
+
+fn begin_op(ref mut s: Strobe128, flags: u64, more: bool) {
+    if more {
+        // continuing an operation with the same flags
+        return;
+    }
+
+    let old_begin = s.pos_begin;
+    s.pos_begin = s.pos + 1;
+    s.cur_flags = flags;
+
+    absorb_byte(s, old_begin);
+    absorb_byte(s, flags);
+
+    let force_f = (flags & (FLAG_C | FLAG_K)) != 0;
+    if force_f && s.pos != 0 {
+        run_f(s);
+    }
+}
+
+/// Returns the STROBE-128 state initialized with `protocol_label`.
+pub fn strobe_new(protocol_label: Vec<u8>) -> Strobe128 {
+    // The first 18 bytes of the state are [1, STROBE_R + 2, 1, 0, 1, 96] || "STROBEv1.0.2", in little-endian lanes
+    let mut state: [u64; 25] = [
+        0x545360010001a801, 0x302e317645424f52, 0x322e, 0, 0,
+        0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0,
+    ];
+    keccak_f1600(state);
+
+    let mut s = Strobe128 {
+        state: state,
+        pos: 0,
+        pos_begin: 0,
+        cur_flags: 0,
+    };
+    meta_ad(s, protocol_label, false);
+    s
+}
+
+/// Absorbs `data` as metadata.
+pub fn meta_ad(ref mut s: Strobe128, data: Vec<u8>, more: bool) {
+    begin_op(s, FLAG_M | FLAG_A, more);
+    absorb(s, data);
+}
+
+/// Absorbs `data` as associated data.
+pub fn ad(ref mut s: Strobe128, data: Vec<u8>, more: bool) {
+    begin_op(s, FLAG_A, more);
+    absorb(s, data);
+}
+
+/// Returns `len` pseudorandom bytes that depend on everything absorbed so far.
+pub fn prf(ref mut s: Strobe128, len: u64, more: bool) -> Vec<u8> {
+    begin_op(s, FLAG_I | FLAG_A | FLAG_C, more);
+    squeeze(s, len)
+}
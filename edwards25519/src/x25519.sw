@@ -0,0 +1,79 @@
+library;
+
+use ::field_element::*;
+use ::scalar::Scalar;
+//This wildcard import is needed because of importing ConditionallySelectable for u64 (since it's a trait for a primitive type)
+use utils::choice::*;
+
+/*
+X25519 Diffie-Hellman, as specified in RFC 7748 https://www.rfc-editor.org/rfc/rfc7748
+The function works on u-coordinates of the Montgomery curve v^2 = u^3 + 486662u^2 + u,
+which is birationally equivalent to edwards25519.
+*/
+
+/// The u-coordinate of the base point
+pub const BASEPOINT: [u8; 32] = [
+    9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+];
+
+// returns the scalar with the 3 lowest bits and the highest bit cleared, and the second highest bit set
+fn clamp(scalar: [u8; 32]) -> [u8; 32] {
+    let mut res = scalar;
+    let first_byte: u64 = res[0];
+    let last_byte: u64 = res[31];
+    res[0] = first_byte & 248;
+    res[31] = (last_byte & 127) | 64;
+    res
+}
+
+// swaps a and b if choice == 1, in constant time
+fn conditional_swap(ref mut a: Element, ref mut b: Element, choice: Choice) {
+    let new_a = Element::conditional_select(b, a, choice);
+    let new_b = Element::conditional_select(a, b, choice);
+    a = new_a;
+    b = new_b;
+}
+
+/// Returns the u-coordinate of [k]P, where k is `scalar` after clamping and P is the point with u-coordinate `u`.
+/// The most significant bit of `u` is ignored. The computation is done with the
+/// constant-time Montgomery ladder of RFC 7748, section 5.
+pub fn x25519(scalar: [u8; 32], u: [u8; 32]) -> [u8; 32] {
+    let k = Scalar::from_bits(clamp(scalar));
+    let x1 = Element::from_bytes(u);
+    let mut x2 = ONE;
+    let mut z2 = ZERO;
+    let mut x3 = x1;
+    let mut z3 = ONE;
+    let mut swap: u8 = 0;
+
+    // bit 255 of a clamped scalar is 0
+    let mut t = 255;
+    while t > 0 {
+        t -= 1;
+        let k_t = k.bit(t);
+        swap = swap ^ k_t;
+        conditional_swap(x2, x3, Choice::from(swap));
+        conditional_swap(z2, z3, Choice::from(swap));
+        swap = k_t;
+
+        let a = x2 + z2;
+        let aa = a.square();
+        let b = x2 - z2;
+        let bb = b.square();
+        let e = aa - bb;
+        let c = x3 + z3;
+        let d = x3 - z3;
+        let da = d * a;
+        let cb = c * b;
+        x3 = (da + cb).square();
+        z3 = x1 * (da - cb).square();
+        x2 = aa * bb;
+        // z2 = E * (AA + a24*E) with a24 = 121665, and AA = BB + E
+        z2 = e * (bb + e.scalar_mult(121666));
+    }
+    conditional_swap(x2, x3, Choice::from(swap));
+    conditional_swap(z2, z3, Choice::from(swap));
+
+    (x2 * z2.inverse()).to_bytes()
+}
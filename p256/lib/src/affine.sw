@@ -1,7 +1,7 @@
 library;
 
 use ::field::FieldElement;
-use utils::{choice::*};
+use utils::{choice::*, group::CurveAffine};
 
 // a = -3 mod p
 pub const EQUATION_A: FieldElement = FieldElement {
@@ -73,4 +73,18 @@ impl AffinePoint {
       infinity: self.infinity,
     }
   }
+}
+
+impl CurveAffine for AffinePoint {
+  fn identity() -> Self {
+    Self::identity()
+  }
+
+  fn generator() -> Self {
+    Self::generator()
+  }
+
+  fn is_identity(self) -> Choice {
+    self.is_identity()
+  }
 }
\ No newline at end of file
@@ -1,8 +1,7 @@
 library;
 
 use ::scalar::Scalar;
-use ::modular_helper::ct_eq; 
-use ::utils::choice::{Choice, CtOption};
+use ::utils::choice::{Choice, CtOption, ConstantTimeEq};
 use ::affine::AffinePoint;
 use ::projective::ProjectivePoint;
 use ::field::FieldElement;
@@ -30,10 +29,10 @@ pub fn verify_prehashed(a: AffinePoint, bytes: [u8;32], sig: Signature) -> bool
   
   // checking if r != 0 and mod_r == r implies r is in the interval [1, n − 1]
   // same check for s.
-  assert(ct_eq(sig.r.ls, [0,0,0,0]).unwrap_as_bool() == false);
-  assert(ct_eq(sig.s.ls, [0,0,0,0]).unwrap_as_bool() == false);
-  assert(ct_eq(mod_r.ls, sig.r.ls).unwrap_as_bool() == true);
-  assert(ct_eq(mod_s.ls, sig.s.ls).unwrap_as_bool() == true);
+  assert(sig.r.ls.ct_eq([0,0,0,0]).unwrap_as_bool() == false);
+  assert(sig.s.ls.ct_eq([0,0,0,0]).unwrap_as_bool() == false);
+  assert(mod_r.ls.ct_eq(sig.r.ls).unwrap_as_bool() == true);
+  assert(mod_s.ls.ct_eq(sig.s.ls).unwrap_as_bool() == true);
 
     //converting bytes hash into [u64;4]
     let mut i = 0;
@@ -53,11 +52,11 @@ pub fn verify_prehashed(a: AffinePoint, bytes: [u8;32], sig: Signature) -> bool
     }
 
   //cheks if bytes hash is non-zero
-  assert(ct_eq(u64s, [0,0,0,0]).unwrap_as_bool() == false);
+  assert(u64s.ct_eq([0,0,0,0]).unwrap_as_bool() == false);
 
   // checks if public key is non-zero
-  assert(ct_eq(a.x.ls, [0,0,0,0]).unwrap_as_bool() == false);
-  assert(ct_eq(a.y.ls, [0,0,0,0]).unwrap_as_bool() == false);
+  assert(a.x.ls.ct_eq([0,0,0,0]).unwrap_as_bool() == false);
+  assert(a.y.ls.ct_eq([0,0,0,0]).unwrap_as_bool() == false);
 
   let z = Scalar::from_bytes(bytes);
   let s_inv: Scalar = sig.s.scalar_invert().unwrap();
@@ -73,7 +72,7 @@ pub fn verify_prehashed(a: AffinePoint, bytes: [u8;32], sig: Signature) -> bool
   let x: ProjectivePoint = u1_g.add(u2_a);
 
   //checking if x is point at infinity 
-  assert(ct_eq(x.z.ls, [0,0,0,0]).unwrap_as_bool() == false);
+  assert(x.z.ls.ct_eq([0,0,0,0]).unwrap_as_bool() == false);
   
   let res: FieldElement = x.into().x.fe_from_montgomery();
   let res_scalar: Scalar = Scalar::from_bytes(res.to_bytes());
@@ -1,11 +1,12 @@
 library;
 
 use utils::{
-  integer_utils::{adc, sbb, mac},
-  choice::{Choice, CtOption, ConditionallySelectable, ConstantTimeEq}
+  choice::{Choice, CtOption, ConditionallySelectable, ConditionallyNegatable, ConstantTimeEq, ConstantTimeLess},
+  ff::{Field, PrimeField},
+  bigint::U256,
+  monty::{MontyField, MontyParams}
 };
 use core::ops::{Add, Subtract, Multiply};
-use ::modular_helper::{sub_inner, mul_wide, add, ct_eq, conditional_select, to_bytes};
 
 // Little endian
 // ls[0] + ls[1] * 2^64 + ls[2] * 2^128 + ls[3] * 2^192
@@ -16,10 +17,46 @@ pub struct FieldElement {
 
 // 115792089210356248762697446949407573530086143415290314195533631308867097853951
 // 18446744073709551615 + 4294967295 * 2ˆ64 + 18446744069414584321 * 2ˆ192
-const MODULUS_FE: [u64; 4] = [18446744073709551615, 4294967295, 0, 18446744069414584321];
+const MODULUS_FE: U256 = U256 { ls: [18446744073709551615, 4294967295, 0, 18446744069414584321] };
 
-// R^2 = 2^512 mod p = 134799733323198995502561713907086292154532538166959272814710328655875
-const R_2: [u64; 4] = [3, 18446744056529682431, 18446744073709551614, 21474836477];
+// Montgomery arithmetic modulo p
+pub struct P256FieldParams {}
+
+impl MontyParams for P256FieldParams {
+  fn modulus() -> U256 {
+    MODULUS_FE
+  }
+
+  // R = 2^256 mod p
+  fn r() -> U256 {
+    U256 { ls: [1, 18446744069414584320, 18446744073709551615, 4294967294] }
+  }
+
+  // R^2 = 2^512 mod p = 134799733323198995502561713907086292154532538166959272814710328655875
+  fn r2() -> U256 {
+    U256 { ls: [3, 18446744056529682431, 18446744073709551614, 21474836477] }
+  }
+
+  // R^3 = 2^768 mod p
+  fn r3() -> U256 {
+    U256 { ls: [18446744060824649738, 18446743996400140279, 25769803772, 103079215105] }
+  }
+
+  // -(p^{-1}) mod 2^64, which is 1 because p = -1 mod 2^64
+  fn inv() -> u64 {
+    1
+  }
+}
+
+impl FieldElement {
+  fn monty(self) -> MontyField<P256FieldParams> {
+    MontyField::from_montgomery(U256 { ls: self.ls }, P256FieldParams {})
+  }
+
+  fn from_monty(m: MontyField<P256FieldParams>) -> Self {
+    FieldElement { ls: m.montgomery_form.ls }
+  }
+}
 
 impl FieldElement {
 
@@ -43,68 +80,31 @@ impl FieldElement {
 
   // Returns `a + b mod p`.
   fn fe_add(self, b: Self) -> Self {
-    FieldElement {ls: add(self.ls, b.ls, MODULUS_FE)}
+    Self::from_monty(self.monty() + b.monty())
   }
 
   // Returns `a - b mod p`.
   fn fe_sub(self, b: Self) -> Self {
-    FieldElement{ls: sub_inner([self.ls[0], self.ls[1], self.ls[2], self.ls[3], 0], [b.ls[0], b.ls[1], b.ls[2], b.ls[3], 0], MODULUS_FE)}
+    Self::from_monty(self.monty() - b.monty())
   }
 
   // normalize and convert to bytes
   pub fn to_bytes(self) -> [u8;32] {
-    to_bytes([self.ls[0], self.ls[1], self.ls[2], self.ls[3], 0], MODULUS_FE)
+    // subtract p if self is not smaller than p
+    U256 { ls: self.ls }.sub_mod(MODULUS_FE, MODULUS_FE).to_be_bytes()
   }
 }
 
-fn montgomery_reduce(r: [u64; 8]) -> FieldElement {
-  // Ref: https://github.com/RustCrypto/elliptic-curves/blob/81cb7e11afbde1b8753d56fa27238369209b2e65/p256/src/arithmetic/field/field64.rs#L118
-  let r0 = r[0];
-  let r1 = r[1];
-  let r2 = r[2];
-  let r3 = r[3];
-  let r4 = r[4];
-  let r5 = r[5];
-  let r6 = r[6];
-  let r7 = r[7];
-
-  let (r1, carry) = mac(r1, r0, MODULUS_FE[1], r0);
-  let (r2, carry) = adc(r2, 0, carry);
-  let (r3, carry) = mac(r3, r0, MODULUS_FE[3], carry);
-  let (r4, carry2) = adc(r4, 0, carry);
-
-  let (r2, carry) = mac(r2, r1, MODULUS_FE[1], r1);
-  let (r3, carry) = adc(r3, 0, carry);
-  let (r4, carry) = mac(r4, r1, MODULUS_FE[3], carry);
-  let (r5, carry2) = adc(r5, carry2, carry);
-
-  let (r3, carry) = mac(r3, r2, MODULUS_FE[1], r2);
-  let (r4, carry) = adc(r4, 0, carry);
-  let (r5, carry) = mac(r5, r2, MODULUS_FE[3], carry);
-  let (r6, carry2) = adc(r6, carry2, carry);
-
-  let (r4, carry) = mac(r4, r3, MODULUS_FE[1], r3);
-  let (r5, carry) = adc(r5, 0, carry);
-  let (r6, carry) = mac(r6, r3, MODULUS_FE[3], carry);
-  let (r7, r8) = adc(r7, carry2, carry);
-
-  FieldElement{ls: sub_inner(
-    [r4, r5, r6, r7, r8],
-    [MODULUS_FE[0], MODULUS_FE[1], MODULUS_FE[2], MODULUS_FE[3], 0],
-    MODULUS_FE
-  )}
-}
-
 impl FieldElement {
 
   /// Returns `a * b mod p`.
   pub fn fe_mul(self, b: Self) -> Self {
-    montgomery_reduce(mul_wide(self.ls, b.ls))
+    Self::from_monty(self.monty() * b.monty())
   }
 
   // Translate a field element out of the Montgomery domain.
   pub fn fe_from_montgomery(self) -> Self {
-    montgomery_reduce([self.ls[0], self.ls[1], self.ls[2], self.ls[3], 0, 0, 0, 0])
+    FieldElement { ls: self.monty().retrieve().ls }
   }
 
 }
@@ -137,7 +137,7 @@ impl FieldElement {
 
   // Translate a field element into the Montgomery domain.
   pub fn fe_to_montgomery(self) -> Self {
-    self * FieldElement{ls: R_2}
+    Self::from_monty(MontyField::new(U256 { ls: self.ls }, P256FieldParams {}))
   }
 
   pub fn square(self) -> Self {
@@ -149,7 +149,7 @@ impl FieldElement {
 impl ConstantTimeEq for FieldElement {
   // returns (self == other), as a choice
   fn ct_eq(self, other: FieldElement) -> Choice {
-    ct_eq(self.ls, other.ls)
+    self.ls.ct_eq(other.ls)
   }
 }
 
@@ -257,6 +257,62 @@ impl FieldElement {
 impl ConditionallySelectable for FieldElement {
   // Select a if choice == 1 or select b if choice == 0, in constant time.
   fn conditional_select(self, b: Self, choice: Choice) -> Self {
-    FieldElement{ ls: conditional_select(self.ls, b.ls, choice)}
+    FieldElement{ ls: U256::conditional_select(U256 { ls: self.ls }, U256 { ls: b.ls }, choice).ls }
+  }
+}
+
+impl ConditionallyNegatable for FieldElement {
+  // Negate self if choice == 1, leave it unchanged if choice == 0
+  fn conditional_negate(ref mut self, choice: Choice) {
+    self = FieldElement::conditional_select(self.negate(), self, choice);
+  }
+}
+
+impl Field for FieldElement {
+  fn zero() -> Self {
+    Self::zero()
+  }
+
+  // Multiplicative identity in Montgomery form.
+  fn one() -> Self {
+    Self::one_montgomery_form()
+  }
+
+  fn is_zero(self) -> Choice {
+    self.is_zero()
+  }
+
+  fn square(self) -> Self {
+    self.square()
+  }
+
+  fn double(self) -> Self {
+    self.double()
+  }
+
+  fn invert(self) -> CtOption<Self> {
+    self.invert()
+  }
+
+  fn sqrt(self) -> CtOption<Self> {
+    self.sqrt()
+  }
+}
+
+impl PrimeField for FieldElement {
+  // Attempts to parse a big endian byte array into a field element in Montgomery form,
+  // failing if the input is not smaller than p.
+  fn from_repr(bytes: [u8; 32]) -> CtOption<Self> {
+    let value = U256::from_be_bytes(bytes);
+    CtOption::new(FieldElement { ls: value.ls }.fe_to_montgomery(), value.ct_lt(MODULUS_FE))
+  }
+
+  // Returns the big endian encoding of the canonical (non-Montgomery) value.
+  fn to_repr(self) -> [u8; 32] {
+    self.fe_from_montgomery().to_bytes()
+  }
+
+  fn is_odd(self) -> Choice {
+    self.fe_from_montgomery().is_odd()
   }
 }
\ No newline at end of file
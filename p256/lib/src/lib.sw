@@ -1,6 +1,5 @@
 library;
 
-mod modular_helper;
 mod field;
 mod scalar;
 mod affine;
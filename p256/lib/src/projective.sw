@@ -4,7 +4,9 @@ use ::affine::AffinePoint;
 use ::field::FieldElement;
 use ::scalar::Scalar;
 use ::std::convert::From;
-use ::utils::choice::{ConditionallySelectable, Choice, CtOption};
+use ::utils::choice::{ConditionallySelectable, ConditionallyNegatable, Choice, CtOption};
+use ::utils::group::{Group, Neg};
+use core::ops::{Add, Subtract};
 use std::logging::log;
 
 pub struct ProjectivePoint {
@@ -61,6 +63,13 @@ impl ConditionallySelectable for ProjectivePoint {
   }
 }
 
+impl ConditionallyNegatable for ProjectivePoint {
+  // Negate self if choice == 1, leave it unchanged if choice == 0
+  fn conditional_negate(ref mut self, choice: Choice) {
+    self = ProjectivePoint::conditional_select(self.neg(), self, choice);
+  }
+}
+
 impl From<AffinePoint> for ProjectivePoint {
     fn from(p: AffinePoint) -> Self {
       let projective = ProjectivePoint {
@@ -276,3 +285,44 @@ impl ProjectivePoint {
     q
   }
 }
+
+impl Add for ProjectivePoint {
+  fn add(self, other: Self) -> Self {
+    self.add(other)
+  }
+}
+
+impl Subtract for ProjectivePoint {
+  fn subtract(self, other: Self) -> Self {
+    self.sub(other)
+  }
+}
+
+impl Neg for ProjectivePoint {
+  fn neg(self) -> Self {
+    self.neg()
+  }
+}
+
+impl Group<Scalar> for ProjectivePoint {
+  // The identity with its y coordinate in Montgomery form, as used by the point arithmetic.
+  fn identity() -> Self {
+    Self::identity_montgomery()
+  }
+
+  fn generator() -> Self {
+    Self::generator()
+  }
+
+  fn is_identity(self) -> Choice {
+    self.z.is_zero()
+  }
+
+  fn double(self) -> Self {
+    self.double()
+  }
+
+  fn mul_scalar(self, scalar: Scalar) -> Self {
+    self.mul(scalar)
+  }
+}
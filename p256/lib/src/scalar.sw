@@ -3,7 +3,8 @@ library;
 use utils::integer_utils::{adc, sbb, mac}; 
 use utils::choice::{Choice, ConstantTimeEq, ConditionallySelectable, CtOption};
 use core::ops::{Add, Subtract, Multiply};
-use ::modular_helper::{sub_inner, mul_wide, add, ct_eq, conditional_select, to_bytes};
+use utils::bigint::U256;
+use utils::ff::{Field, PrimeField};
 
 pub struct Scalar { 
   ls: [u64; 4] 
@@ -17,6 +18,12 @@ pub const MODULUS_SCALAR: [u64;4] = [17562291160714782033, 13611842547513532036,
 // MU = floor(2^512 / n)
 //    = 115792089264276142090721624801893421302707618245269942344307673200490803338238
 //    = 0x100000000fffffffffffffffeffffffff43190552df1a6c21012ffd85eedf9bfe
+// 2^S * t = n - 1 with t odd
+const S: u64 = 4;
+
+// ROOT_OF_UNITY = 7^t mod n, a primitive 2^S-th root of unity
+const ROOT_OF_UNITY: [u64; 4] = [401620792848049666, 1533135717938990511, 13438876315472772604, 18431402614449441170];
+
 pub const MU: [u64; 5] = [85565669623438334, 4834901528447446049, 18446744069414584319, 4294967295, 1];
 
 fn q1_times_mu_shift_five(q1: [u64; 5]) -> [u64; 5] {
@@ -128,12 +135,12 @@ impl Scalar {
 
   // Returns a + b mod n
   pub fn scalar_add(self, b: Self) -> Self {
-    Scalar{ls: add(self.ls, b.ls, MODULUS_SCALAR)}
+    Scalar { ls: U256 { ls: self.ls }.add_mod(U256 { ls: b.ls }, U256 { ls: MODULUS_SCALAR }).ls }
   }
 
   // Returns `a - b mod n`.
   pub fn scalar_sub(self, b: Self) -> Self {
-    Scalar{ls: sub_inner([self.ls[0], self.ls[1], self.ls[2], self.ls[3], 0], [b.ls[0], b.ls[1], b.ls[2], b.ls[3], 0], MODULUS_SCALAR)}
+    Scalar { ls: U256 { ls: self.ls }.sub_mod(U256 { ls: b.ls }, U256 { ls: MODULUS_SCALAR }).ls }
   }
 
   pub fn barrett_reduce(self, h: Self) -> Self {
@@ -168,35 +175,20 @@ impl Scalar {
 
   // Returns self * rhs mod n
   pub fn scalar_mul(self, b: Self) -> Self {
-    let t: [u64; 8] = mul_wide(self.ls, b.ls);
-    (Scalar{ls:[t[0], t[1], t[2], t[3]]}).barrett_reduce(Scalar{ls: [t[4], t[5], t[6], t[7]]})
+    let (lo, hi) = U256 { ls: self.ls }.mul_wide(U256 { ls: b.ls });
+    (Scalar { ls: lo.ls }).barrett_reduce(Scalar { ls: hi.ls })
   }
 
   // returns scalar from big endian byte array (32 bytes)
   pub fn from_bytes(bytes: [u8; 32]) -> Self {
-    // Scalar: ls: [u64; 4] is in little endian
-    let mut i = 0;
-    let mut j = 4;
-    let mut u64s: [u64;4] = [0;4];
-    while i < 32 {
-      u64s[j-1] = (bytes[i + 0] << 56)
-        .binary_or(bytes[i + 1] << 48)
-        .binary_or(bytes[i + 2] << 40)
-        .binary_or(bytes[i + 3] << 32)
-        .binary_or(bytes[i + 4] << 24)
-        .binary_or(bytes[i + 5] << 16)
-        .binary_or(bytes[i + 6] << 8)
-        .binary_or(bytes[i + 7]);
-      j -= 1;
-      i += 8;
-    }
-    Scalar { ls: u64s}.scalar_add(Self::zero()) // trigger the mod q
+    Scalar { ls: U256::from_be_bytes(bytes).ls }.scalar_add(Self::zero()) // trigger the mod q
   }
 
   // return big endian byte array
   // normalize and convert to bytes
   pub fn to_bytes(self) -> [u8;32] {
-    to_bytes([self.ls[0], self.ls[1], self.ls[2], self.ls[3], 0], MODULUS_SCALAR)
+    // subtract n if self is not smaller than n
+    U256 { ls: self.ls }.sub_mod(U256 { ls: MODULUS_SCALAR }, U256 { ls: MODULUS_SCALAR }).to_be_bytes()
   }
   
   // returns little endian byte array
@@ -245,7 +237,7 @@ impl Scalar {
 impl ConstantTimeEq for Scalar {
   // returns (self == other), as a choice
   fn ct_eq(self, other: Scalar) -> Choice {
-    ct_eq(self.ls, other.ls)
+    self.ls.ct_eq(other.ls)
   }
 }
 
@@ -342,6 +334,106 @@ impl Scalar {
 impl ConditionallySelectable for Scalar {
   // Select a if choice == 1 or select b if choice == 0, in constant time.
   fn conditional_select(self, b: Self, choice: Choice) -> Self {
-    Scalar{ ls: conditional_select(self.ls, b.ls, choice)}
+    Scalar{ ls: U256::conditional_select(U256 { ls: self.ls }, U256 { ls: b.ls }, choice).ls }
+  }
+}
+
+impl Scalar {
+  pub fn is_zero(self) -> Choice {
+    self.ct_eq(Self::zero())
+  }
+}
+
+impl Scalar {
+  // returns square root of self mod n in the form CtOption(value: square_root, is_some: true)
+  // If there is no such element, the result is CtOption(value: xxx, is_some: false)
+  pub fn sqrt(self) -> CtOption<Self> {
+    // Tonelli-Shanks, constant time as in https://github.com/zkcrypto/ff/blob/main/ff_derive/src/lib.rs
+    // w = self^((t - 1) // 2)
+    let w = self.pow_vartime([
+      0x279d_ce56_17e3_192a,
+      0xfde7_37d5_6d38_bcf4,
+      0x07ff_ffff_ffff_ffff,
+      0x07ff_ffff_f800_0000,
+    ]);
+
+    let mut v = S;
+    let mut x = self * w;
+    let mut b = x * w;
+    let mut z = Scalar { ls: ROOT_OF_UNITY };
+
+    let mut max_v = S;
+    while max_v >= 1 {
+      let mut k = 1;
+      let mut tmp = b.square();
+      let mut j_less_than_v = Choice::from(1u8);
+
+      let mut j = 2;
+      while j < max_v {
+        let tmp_is_one = tmp.ct_eq(Self::one());
+        let squared = Scalar::conditional_select(z, tmp, tmp_is_one).square();
+        tmp = Scalar::conditional_select(tmp, squared, tmp_is_one);
+        let new_z = Scalar::conditional_select(squared, z, tmp_is_one);
+        j_less_than_v = j_less_than_v & !j.ct_eq(v);
+        k = u64::conditional_select(k, j, tmp_is_one);
+        z = Scalar::conditional_select(new_z, z, j_less_than_v);
+        j += 1;
+      }
+
+      let result = x * z;
+      x = Scalar::conditional_select(x, result, b.ct_eq(Self::one()));
+      z = z.square();
+      b = b * z;
+      v = k;
+      max_v -= 1;
+    }
+
+    CtOption::new(x, (x * x).ct_eq(self))
+  }
+}
+
+impl Field for Scalar {
+  fn zero() -> Self {
+    Self::zero()
+  }
+
+  fn one() -> Self {
+    Self::one()
+  }
+
+  fn is_zero(self) -> Choice {
+    self.is_zero()
+  }
+
+  fn square(self) -> Self {
+    self.square()
+  }
+
+  fn double(self) -> Self {
+    self.double()
+  }
+
+  fn invert(self) -> CtOption<Self> {
+    self.scalar_invert()
+  }
+
+  fn sqrt(self) -> CtOption<Self> {
+    self.sqrt()
+  }
+}
+
+impl PrimeField for Scalar {
+  // `from_bytes` reduces its input, so non-canonical encodings are rejected here
+  fn from_repr(bytes: [u8; 32]) -> CtOption<Self> {
+    let s = Self::from_bytes(bytes);
+    CtOption::new(s, s.to_bytes().ct_eq(bytes))
+  }
+
+  fn to_repr(self) -> [u8; 32] {
+    self.to_bytes()
+  }
+
+  fn is_odd(self) -> Choice {
+    Choice::from(self.ls[0] & 1)
   }
 }
\ No newline at end of file
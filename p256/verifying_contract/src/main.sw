@@ -1,7 +1,6 @@
 contract;
 
 use p256::{
-    modular_helper::to_bytes,
     field::FieldElement,
     scalar::Scalar,
     affine::AffinePoint,
@@ -6,7 +6,7 @@ use p256::{
   affine::AffinePoint,
   projective::ProjectivePoint,
 };
-use utils::choice::CtOption;
+use utils::{choice::CtOption, group::Group};
 
 abi MyContract {
 
@@ -21,6 +21,49 @@ abi MyContract {
     fn proj_add(p1: ProjectivePoint, p2: ProjectivePoint) -> ProjectivePoint;
     fn proj_aff_add(p1_proj: ProjectivePoint, p2_aff: AffinePoint) -> ProjectivePoint;
     fn proj_mul(p: ProjectivePoint, k: Scalar) -> ProjectivePoint;
+
+  // Group trait
+    fn group_generator() -> ProjectivePoint;
+    fn group_identity() -> ProjectivePoint;
+    fn group_is_identity(p: ProjectivePoint) -> bool;
+    fn group_double(p: ProjectivePoint) -> ProjectivePoint;
+    fn group_add(p1: ProjectivePoint, p2: ProjectivePoint) -> ProjectivePoint;
+    fn group_sub(p1: ProjectivePoint, p2: ProjectivePoint) -> ProjectivePoint;
+    fn group_neg(p: ProjectivePoint) -> ProjectivePoint;
+    fn group_mul_scalar(p: ProjectivePoint, k: Scalar) -> ProjectivePoint;
+}
+
+// The Group endpoints go through these generic functions, so they test the Group impl and its supertraits
+fn generator<G, S>() -> G where G: Group<S> {
+  G::generator()
+}
+
+fn identity<G, S>() -> G where G: Group<S> {
+  G::identity()
+}
+
+fn is_identity<G, S>(p: G) -> bool where G: Group<S> {
+  p.is_identity().unwrap_as_bool()
+}
+
+fn double<G, S>(p: G) -> G where G: Group<S> {
+  p.double()
+}
+
+fn add<G, S>(p1: G, p2: G) -> G where G: Group<S> {
+  p1 + p2
+}
+
+fn sub<G, S>(p1: G, p2: G) -> G where G: Group<S> {
+  p1 - p2
+}
+
+fn neg<G, S>(p: G) -> G where G: Group<S> {
+  p.neg()
+}
+
+fn mul_scalar<G, S>(p: G, k: S) -> G where G: Group<S> {
+  p.mul_scalar(k)
 }
 
 impl MyContract for Contract {
@@ -58,4 +101,37 @@ impl MyContract for Contract {
     fn proj_mul(p: ProjectivePoint, k: Scalar) -> ProjectivePoint {
       p.mul(k)
     }
+
+    // Group trait
+    fn group_generator() -> ProjectivePoint {
+      generator::<ProjectivePoint, Scalar>()
+    }
+
+    fn group_identity() -> ProjectivePoint {
+      identity::<ProjectivePoint, Scalar>()
+    }
+
+    fn group_is_identity(p: ProjectivePoint) -> bool {
+      is_identity::<ProjectivePoint, Scalar>(p)
+    }
+
+    fn group_double(p: ProjectivePoint) -> ProjectivePoint {
+      double::<ProjectivePoint, Scalar>(p)
+    }
+
+    fn group_add(p1: ProjectivePoint, p2: ProjectivePoint) -> ProjectivePoint {
+      add::<ProjectivePoint, Scalar>(p1, p2)
+    }
+
+    fn group_sub(p1: ProjectivePoint, p2: ProjectivePoint) -> ProjectivePoint {
+      sub::<ProjectivePoint, Scalar>(p1, p2)
+    }
+
+    fn group_neg(p: ProjectivePoint) -> ProjectivePoint {
+      neg::<ProjectivePoint, Scalar>(p)
+    }
+
+    fn group_mul_scalar(p: ProjectivePoint, k: Scalar) -> ProjectivePoint {
+      mul_scalar(p, k)
+    }
 }
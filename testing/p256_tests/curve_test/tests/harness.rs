@@ -371,4 +371,105 @@ async fn test_proj_mul_2g() {
     [8797506388050518575, 5381390155001572521, 14210276306527660856, 11433769691616765559],
     [18243921637092895352, 3362778627141179829, 4574725413093469409, 1998945958994053561]
   );
-}
\ No newline at end of file
+}
+
+#[tokio::test]
+async fn test_group_generator_identity() {
+  let (_methods, _id) = get_contract_methods().await;
+
+  let generator = _methods
+    .group_generator()
+    .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+    .call().await.unwrap();
+  let (x_converted, y_converted) = proj_to_resulting_coordinates(&_methods, &generator.value).await;
+  assert_xy(x_converted, y_converted, g.x.ls, g.y.ls);
+
+  let identity = _methods.group_identity().call().await.unwrap();
+  assert!(_methods.group_is_identity(identity.value.clone()).call().await.unwrap().value);
+  assert!(!_methods.group_is_identity(generator.value.clone()).call().await.unwrap().value);
+
+  // identity + G = G
+  let sum = _methods
+    .group_add(identity.value, generator.value)
+    .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+    .call().await.unwrap();
+  let (x_converted, y_converted) = proj_to_resulting_coordinates(&_methods, &sum.value).await;
+  assert_xy(x_converted, y_converted, g.x.ls, g.y.ls);
+}
+
+#[tokio::test]
+async fn test_group_sub_neg() {
+  let (_methods, _id) = get_contract_methods().await;
+
+  let g_converted_projective = affine_to_proj(&_methods, &g).await;
+
+  // G - G = 0
+  let diff = _methods
+    .group_sub(g_converted_projective.clone(), g_converted_projective.clone())
+    .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+    .call().await.unwrap();
+  assert!(_methods.group_is_identity(diff.value).call().await.unwrap().value);
+
+  // G + (-G) = 0
+  let neg_g = _methods.group_neg(g_converted_projective.clone()).call().await.unwrap();
+  let sum = _methods
+    .group_add(g_converted_projective.clone(), neg_g.value.clone())
+    .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+    .call().await.unwrap();
+  assert!(_methods.group_is_identity(sum.value).call().await.unwrap().value);
+
+  // -G = (x, p - y)
+  let (x_converted, y_converted) = proj_to_resulting_coordinates(&_methods, &neg_g.value).await;
+  assert_xy(x_converted, y_converted,
+    g.x.ls,
+    [3767753221892779530, 15290227238617653553, 8149286295562117609, 12690225778011766885]
+  );
+}
+
+#[tokio::test]
+async fn test_group_double_mul_scalar() {
+  let (_methods, _id) = get_contract_methods().await;
+
+  let g_converted_projective = affine_to_proj(&_methods, &g).await;
+
+  // double(G) and [2]G both give 2G
+  let g_double = _methods
+    .group_double(g_converted_projective.clone())
+    .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+    .call().await.unwrap();
+  let (x_converted, y_converted) = proj_to_resulting_coordinates(&_methods, &g_double.value).await;
+  assert_xy(x_converted, y_converted,
+    [11964737083406719352, 13873736548487404341, 9967090510939364035, 9003393950442278782],
+    [11386427643415524305, 13438088067519447593, 2971701507003789531, 537992211385471040]
+  );
+
+  let g_mul_2 = _methods
+    .group_mul_scalar(g_converted_projective.clone(), Scalar{ls: [2, 0, 0, 0]})
+    .tx_params(TxParameters::default().set_gas_limit(100_000_000_000))
+    .call().await.unwrap();
+  let (x_converted, y_converted) = proj_to_resulting_coordinates(&_methods, &g_mul_2.value).await;
+  assert_xy(x_converted, y_converted,
+    [11964737083406719352, 13873736548487404341, 9967090510939364035, 9003393950442278782],
+    [11386427643415524305, 13438088067519447593, 2971701507003789531, 537992211385471040]
+  );
+
+  // [0]G = 0
+  let g_mul_0 = _methods
+    .group_mul_scalar(g_converted_projective.clone(), Scalar{ls: [0, 0, 0, 0]})
+    .tx_params(TxParameters::default().set_gas_limit(100_000_000_000))
+    .call().await.unwrap();
+  assert!(_methods.group_is_identity(g_mul_0.value).call().await.unwrap().value);
+
+  // [n-1]G = -G
+  let n_minus_1 = Scalar{ls: [17562291160714782032, 13611842547513532036, 18446744073709551615, 18446744069414584320]};
+  let g_mul_n_minus_1 = _methods
+    .group_mul_scalar(g_converted_projective.clone(), n_minus_1)
+    .tx_params(TxParameters::default().set_gas_limit(100_000_000_000))
+    .call().await.unwrap();
+  let (x_converted, y_converted) = proj_to_resulting_coordinates(&_methods, &g_mul_n_minus_1.value).await;
+  assert_xy(x_converted, y_converted,
+    g.x.ls,
+    [3767753221892779530, 15290227238617653553, 8149286295562117609, 12690225778011766885]
+  );
+}
+
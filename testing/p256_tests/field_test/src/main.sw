@@ -5,7 +5,7 @@ use p256::{
   scalar::{Scalar, *},
 };
 
-use utils::choice::CtOption;
+use utils::{choice::CtOption, ff::{Field, PrimeField}};
 
 abi MyContract {
 
@@ -24,6 +24,46 @@ abi MyContract {
     fn scalar_mul(a: Scalar, b: Scalar) -> Scalar;
     fn scalar_invert(a: Scalar) -> CtOption<Scalar>;
     fn scalar_from_bytes(in: [u8; 32]) -> Scalar;
+
+  // Field and PrimeField traits
+    fn scalar_square(a: Scalar) -> Scalar;
+    fn scalar_double(a: Scalar) -> Scalar;
+    fn scalar_is_zero(a: Scalar) -> bool;
+    fn scalar_field_invert(a: Scalar) -> CtOption<Scalar>;
+    fn scalar_sqrt(a: Scalar) -> CtOption<Scalar>;
+    fn scalar_from_repr(bytes: [u8; 32]) -> CtOption<Scalar>;
+    fn scalar_to_repr(a: Scalar) -> [u8; 32];
+    fn fe_from_repr(bytes: [u8; 32]) -> CtOption<FieldElement>;
+    fn fe_to_repr(a: FieldElement) -> [u8; 32];
+}
+
+// The trait endpoints go through these generic functions, so they test the Field and PrimeField impls
+fn field_square<F>(a: F) -> F where F: Field {
+  a.square()
+}
+
+fn field_double<F>(a: F) -> F where F: Field {
+  a.double()
+}
+
+fn field_is_zero<F>(a: F) -> bool where F: Field {
+  a.is_zero().unwrap_as_bool()
+}
+
+fn field_invert<F>(a: F) -> CtOption<F> where F: Field {
+  a.invert()
+}
+
+fn field_sqrt<F>(a: F) -> CtOption<F> where F: Field {
+  a.sqrt()
+}
+
+fn prime_field_from_repr<F>(bytes: [u8; 32]) -> CtOption<F> where F: PrimeField {
+  F::from_repr(bytes)
+}
+
+fn prime_field_to_repr<F>(a: F) -> [u8; 32] where F: PrimeField {
+  a.to_repr()
 }
 
 impl MyContract for Contract {
@@ -78,4 +118,40 @@ impl MyContract for Contract {
       Scalar::from_bytes(in)
     }
 
+  // Field and PrimeField traits
+    fn scalar_square(a: Scalar) -> Scalar {
+      field_square(a)
+    }
+
+    fn scalar_double(a: Scalar) -> Scalar {
+      field_double(a)
+    }
+
+    fn scalar_is_zero(a: Scalar) -> bool {
+      field_is_zero(a)
+    }
+
+    fn scalar_field_invert(a: Scalar) -> CtOption<Scalar> {
+      field_invert(a)
+    }
+
+    fn scalar_sqrt(a: Scalar) -> CtOption<Scalar> {
+      field_sqrt(a)
+    }
+
+    fn scalar_from_repr(bytes: [u8; 32]) -> CtOption<Scalar> {
+      prime_field_from_repr::<Scalar>(bytes)
+    }
+
+    fn scalar_to_repr(a: Scalar) -> [u8; 32] {
+      prime_field_to_repr(a)
+    }
+
+    fn fe_from_repr(bytes: [u8; 32]) -> CtOption<FieldElement> {
+      prime_field_from_repr::<FieldElement>(bytes)
+    }
+
+    fn fe_to_repr(a: FieldElement) -> [u8; 32] {
+      prime_field_to_repr(a)
+    }
 }
@@ -2,6 +2,7 @@ use fuels::{prelude::*,
   tx::{ConsensusParameters, ContractId}, 
 };
 use fuel_core_chain_config::ChainConfig;
+use num_bigint::BigUint;
 
 // Load abi from json
 abigen!(Contract(
@@ -315,4 +316,173 @@ async fn test_scalar_invert() {
   // result should be 84801081494837761602111676842516221872243864255054144073280115004536303842931
   let expected_res = Scalar{ ls: [9530314696573515379, 1325056620123427311, 7698614219480972011, 13509591698470992260]};
   assert_scalar(invert_x.value.value, expected_res);
-}
\ No newline at end of file
+}
+
+// n, the order of the P-256 group
+fn scalar_modulus() -> BigUint {
+  BigUint::parse_bytes(b"115792089210356248762697446949407573529996955224135760342422259061068512044369", 10).unwrap()
+}
+
+// p, the modulus of the P-256 base field
+fn fe_modulus() -> BigUint {
+  BigUint::parse_bytes(b"115792089210356248762697446949407573530086143415290314195533631308867097853951", 10).unwrap()
+}
+
+fn to_biguint(ls: [u64; 4]) -> BigUint {
+  ls.iter().rev().fold(BigUint::from(0u64), |acc, l| (acc << 64) + *l)
+}
+
+fn to_ls(n: &BigUint) -> [u64; 4] {
+  let digits = n.to_u64_digits();
+  let mut ls = [0u64; 4];
+  ls[..digits.len()].copy_from_slice(&digits);
+  ls
+}
+
+fn to_be_bytes(n: &BigUint) -> [u8; 32] {
+  let bytes = n.to_bytes_be();
+  let mut res = [0u8; 32];
+  res[32 - bytes.len()..].copy_from_slice(&bytes);
+  res
+}
+
+fn test_scalars() -> Vec<BigUint> {
+  let n = scalar_modulus();
+  vec![
+    BigUint::from(1u64),
+    BigUint::from(2u64),
+    to_biguint(X_SCALAR.ls),
+    to_biguint(Y_SCALAR.ls),
+    &n - 1u64,
+    &n - 2u64,
+  ]
+}
+
+#[tokio::test]
+async fn test_scalar_field_trait() {
+  let (_methods, _id) = get_contract_methods().await;
+  let n = scalar_modulus();
+
+  for a in test_scalars() {
+    let s = Scalar{ ls: to_ls(&a) };
+
+    let square = _methods.scalar_square(s.clone()).call().await.unwrap();
+    assert_scalar(square.value, Scalar{ ls: to_ls(&(&a * &a % &n)) });
+
+    let double = _methods.scalar_double(s.clone()).call().await.unwrap();
+    assert_scalar(double.value, Scalar{ ls: to_ls(&(&a * 2u64 % &n)) });
+
+    let is_zero = _methods.scalar_is_zero(s.clone()).call().await.unwrap();
+    assert!(!is_zero.value);
+
+    // a^(n-2) = a^-1
+    let inv = _methods
+      .scalar_field_invert(s)
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap();
+    assert_eq!(inv.value.is_some.c, 1);
+    assert_scalar(inv.value.value, Scalar{ ls: to_ls(&a.modpow(&(&n - 2u64), &n)) });
+  }
+
+  let zero = Scalar{ ls: [0, 0, 0, 0] };
+  let is_zero = _methods.scalar_is_zero(zero.clone()).call().await.unwrap();
+  assert!(is_zero.value);
+
+  let inv = _methods
+    .scalar_field_invert(zero)
+    .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+    .call().await.unwrap();
+  assert_eq!(inv.value.is_some.c, 0);
+}
+
+#[tokio::test]
+async fn test_scalar_sqrt() {
+  let (_methods, _id) = get_contract_methods().await;
+  let n = scalar_modulus();
+
+  for a in test_scalars() {
+    let square = &a * &a % &n;
+    let sqrt = _methods
+      .scalar_sqrt(Scalar{ ls: to_ls(&square) })
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap();
+
+    // the root is either a or -a
+    assert_eq!(sqrt.value.is_some.c, 1);
+    let root = to_biguint(sqrt.value.value.ls);
+    assert!(root == a || root == &n - &a);
+  }
+
+  let sqrt_zero = _methods
+    .scalar_sqrt(Scalar{ ls: [0, 0, 0, 0] })
+    .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+    .call().await.unwrap();
+  assert_eq!(sqrt_zero.value.is_some.c, 1);
+  assert_scalar(sqrt_zero.value.value, Scalar{ ls: [0, 0, 0, 0] });
+
+  // 7 is the smallest quadratic non-residue mod n
+  let sqrt_7 = _methods
+    .scalar_sqrt(Scalar{ ls: [7, 0, 0, 0] })
+    .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+    .call().await.unwrap();
+  assert_eq!(sqrt_7.value.is_some.c, 0);
+}
+
+#[tokio::test]
+async fn test_scalar_repr() {
+  let (_methods, _id) = get_contract_methods().await;
+  let n = scalar_modulus();
+
+  for a in test_scalars() {
+    let bytes = to_be_bytes(&a);
+
+    let repr = _methods.scalar_to_repr(Scalar{ ls: to_ls(&a) }).call().await.unwrap();
+    assert_eq!(repr.value, bytes);
+
+    let from_repr = _methods
+      .scalar_from_repr(bytes)
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap();
+    assert_eq!(from_repr.value.is_some.c, 1);
+    assert_scalar(from_repr.value.value, Scalar{ ls: to_ls(&a) });
+  }
+
+  // encodings of n, n+1 and 2^256-1 are not canonical
+  for a in [n.clone(), &n + 1u64, (BigUint::from(1u64) << 256) - 1u64] {
+    let from_repr = _methods
+      .scalar_from_repr(to_be_bytes(&a))
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap();
+    assert_eq!(from_repr.value.is_some.c, 0);
+  }
+}
+
+#[tokio::test]
+async fn test_fe_repr() {
+  let (_methods, _id) = get_contract_methods().await;
+  let p = fe_modulus();
+
+  for a in [BigUint::from(1u64), to_biguint(X_SCALAR.ls), &p - 1u64] {
+    let bytes = to_be_bytes(&a);
+
+    // from_repr returns the element in Montgomery form
+    let from_repr = _methods
+      .fe_from_repr(bytes)
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap();
+    assert_eq!(from_repr.value.is_some.c, 1);
+    let converted = from_montgomery(&_methods, from_repr.value.value.clone()).await;
+    assert_eq!(converted, FieldElement{ ls: to_ls(&a) });
+
+    let repr = _methods.fe_to_repr(from_repr.value.value).call().await.unwrap();
+    assert_eq!(repr.value, bytes);
+  }
+
+  for a in [p.clone(), &p + 1u64, (BigUint::from(1u64) << 256) - 1u64] {
+    let from_repr = _methods
+      .fe_from_repr(to_be_bytes(&a))
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap();
+    assert_eq!(from_repr.value.is_some.c, 0);
+  }
+}
@@ -1,7 +1,20 @@
 contract;
 
-use bls12_381::{fp::Fp, fp2::Fp2, scalar::Scalar};
-use utils::choice::{CtOption, Choice};
+use bls12_381::{
+    fp::{Fp, LegendreSymbol},
+    fp2::Fp2,
+    scalar::Scalar,
+    g1::{G1Affine, G1Projective, FROM_AFF, FROM_PROJ},
+    g2::{G2Affine, G2Projective, FROM_AFF2, FROM_PROJ2},
+    expand_msg::expand_message_xmd,
+    hash_to_curve::{hash_to_curve_g1, hash_to_curve_g2},
+    kzg::{verify_kzg_proof, verify_kzg_proof_batch},
+    groth16::{Proof, VerifyingKey, verify},
+    ntt::{EvaluationDomain, fft_8, ifft_8, fft_16, ifft_16},
+    poseidon::hash,
+};
+use utils::{choice::{CtOption, Choice}, ff::{Field, PrimeField}, group::Group};
+use std::{vec::Vec, option::Option};
 
 abi BlsTestContract {
     // Works
@@ -16,27 +29,180 @@ abi BlsTestContract {
     // works if ran by itself
     // #[storage(read, write)]fn square_fp(a: Fp) -> Fp;
 
+    #[storage(read, write)]fn sqrt_fp(a: Fp) -> Fp;
+    #[storage(read, write)]fn fp_from_bytes(bytes: [u8; 48]) -> Fp;
+    #[storage(read, write)]fn fp_to_bytes(a: Fp) -> [u8; 48];
+    #[storage(read, write)]fn legendre_fp(a: Fp) -> LegendreSymbol;
+
     // Works
     #[storage(read, write)]fn add_fp2(a: Fp2, b: Fp2) -> Fp2;
     #[storage(read, write)]fn sub_fp2(a: Fp2, b: Fp2) -> Fp2;
     #[storage(read, write)]fn neg_fp2(a: Fp2) -> Fp2;
-    // Running this one will give Immediate18TooLarge
-    // #[storage(read, write)]fn lexicographically_largest_fp2(a: Fp2) -> Choice;
-    
-    // // not tested, still gives Immediate18TooLarge error
-    // #[storage(read, write)]fn square_fp2(a: Fp2) -> Fp2;
+    #[storage(read, write)]fn lexicographically_largest_fp2(a: Fp2) -> Choice;
+    #[storage(read, write)]fn square_fp2(a: Fp2) -> Fp2;
+    #[storage(read, write)]fn invert_fp2(a: Fp2) -> Fp2;
+    #[storage(read, write)]fn sqrt_fp2(a: Fp2) -> Fp2;
+    #[storage(read, write)]fn pow_vartime_fp2(a: Fp2, by: [u64; 6]) -> Fp2;
 
     #[storage(read, write)]fn mul_fp2(a: Fp2, b: Fp2) -> Fp2;
 
     #[storage(read, write)]fn add_scalar(a: Scalar, b: Scalar) -> Scalar;
-  
-//This function gives an error
-    // #[storage(read, write)]fn scalar_sqrt(a: Scalar) -> CtOption<Scalar>;
+    // reverts if a is not a square
+    #[storage(read, write)]fn sqrt_scalar(a: Scalar) -> Scalar;
+    #[storage(read, write)]fn pow_vartime_scalar(a: Scalar, by: [u64; 4]) -> Scalar;
+    // reverts if a is zero
+    #[storage(read, write)]fn invert_scalar(a: Scalar) -> Scalar;
+    // reverts if the encoding is not canonical
+    #[storage(read, write)]fn scalar_from_bytes(bytes: [u8; 32]) -> Scalar;
+    #[storage(read, write)]fn scalar_to_bytes(a: Scalar) -> [u8; 32];
+    #[storage(read, write)]fn scalar_from_bytes_wide(bytes: [u8; 64]) -> Scalar;
 
 // These can't be compiled yet.. 
     // #[storage(read, write)]fn mul_fp6(a: Fp6, b: Fp6) -> Fp6;
     // #[storage(read, write)]fn square_fp6(a: Fp6) -> Fp6;
 
+    // msg and dst are given as the first msg_len resp. dst_len bytes of the input arrays
+    #[storage(read, write)]fn expand_message_xmd_32(msg: [u8; 64], msg_len: u64, dst: [u8; 64], dst_len: u64) -> b256;
+    #[storage(read, write)]fn hash_to_g1(msg: [u8; 64], msg_len: u64, dst: [u8; 64], dst_len: u64) -> G1Affine;
+    #[storage(read, write)]fn hash_to_g2(msg: [u8; 64], msg_len: u64, dst: [u8; 64], dst_len: u64) -> G2Affine;
+
+    // The from_ functions revert if the encoding is invalid
+    #[storage(read, write)]fn g1_to_compressed(p: G1Affine) -> [u8; 48];
+    #[storage(read, write)]fn g1_to_uncompressed(p: G1Affine) -> [u8; 96];
+    #[storage(read, write)]fn g1_from_compressed(bytes: [u8; 48]) -> G1Affine;
+    #[storage(read, write)]fn g1_from_uncompressed(bytes: [u8; 96]) -> G1Affine;
+    #[storage(read, write)]fn g2_to_compressed(p: G2Affine) -> [u8; 96];
+    #[storage(read, write)]fn g2_to_uncompressed(p: G2Affine) -> [u8; 192];
+    #[storage(read, write)]fn g2_from_compressed(bytes: [u8; 96]) -> G2Affine;
+    #[storage(read, write)]fn g2_from_uncompressed(bytes: [u8; 192]) -> G2Affine;
+
+    // The points are deserialized without any checks, so that the checks themselves can be tested
+    #[storage(read, write)]fn g1_is_on_curve(bytes: [u8; 96]) -> bool;
+    #[storage(read, write)]fn g1_projective_is_on_curve(bytes: [u8; 96]) -> bool;
+    #[storage(read, write)]fn g1_is_torsion_free(bytes: [u8; 96]) -> bool;
+    #[storage(read, write)]fn g2_is_on_curve(bytes: [u8; 192]) -> bool;
+    #[storage(read, write)]fn g2_is_torsion_free(bytes: [u8; 192]) -> bool;
+
+    // The kzg functions revert if a point is invalid
+    #[storage(read, write)]fn kzg_verify_proof(commitment: [u8; 48], z: Scalar, y: Scalar, proof: [u8; 48], setup_g2: [u8; 96]) -> bool;
+    #[storage(read, write)]fn kzg_verify_proof_batch_2(commitments: [[u8; 48]; 2], zs: [Scalar; 2], ys: [Scalar; 2], proofs: [[u8; 48]; 2], setup_g2: [u8; 96]) -> bool;
+
+    // Reverts if a point is invalid
+    #[storage(read, write)]fn groth16_verify_1(alpha_g1: [u8; 48], beta_g2: [u8; 96], gamma_g2: [u8; 96], delta_g2: [u8; 96], ic: [[u8; 48]; 2], a: [u8; 48], b: [u8; 96], c: [u8; 48], public_input: Scalar) -> bool;
+
+    // Reverts if size is not a power of two
+    #[storage(read, write)]fn evaluation_domain_generator(size: u64) -> Scalar;
+    #[storage(read, write)]fn ntt_8(coeffs: [Scalar; 8]) -> [Scalar; 8];
+    #[storage(read, write)]fn intt_8(evals: [Scalar; 8]) -> [Scalar; 8];
+    #[storage(read, write)]fn ntt_16(coeffs: [Scalar; 16]) -> [Scalar; 16];
+    #[storage(read, write)]fn intt_16(evals: [Scalar; 16]) -> [Scalar; 16];
+    #[storage(read, write)]fn lagrange_coefficients_8(tau: Scalar) -> [Scalar; 8];
+
+    #[storage(read, write)]fn poseidon_hash_2(inputs: [Scalar; 2]) -> Scalar;
+    #[storage(read, write)]fn poseidon_hash_4(inputs: [Scalar; 4]) -> Scalar;
+
+    // Both return \sum scalars[i] * points[i], compressed. The naive version is for gas comparison
+    #[storage(read, write)]fn g1_multi_exp_8(points: [[u8; 48]; 8], scalars: [Scalar; 8]) -> [u8; 48];
+    #[storage(read, write)]fn g1_naive_multi_exp_8(points: [[u8; 48]; 8], scalars: [Scalar; 8]) -> [u8; 48];
+
+    // These go through the generic functions below, so they test the Group, Field and PrimeField impls.
+    // Points are given and returned compressed
+    #[storage(read, write)]fn g1_group_generator() -> [u8; 48];
+    #[storage(read, write)]fn g1_group_is_identity(p: [u8; 48]) -> bool;
+    #[storage(read, write)]fn g1_group_double(p: [u8; 48]) -> [u8; 48];
+    #[storage(read, write)]fn g1_group_sub(p1: [u8; 48], p2: [u8; 48]) -> [u8; 48];
+    #[storage(read, write)]fn g1_group_neg(p: [u8; 48]) -> [u8; 48];
+    #[storage(read, write)]fn g1_group_mul_scalar(p: [u8; 48], k: Scalar) -> [u8; 48];
+    #[storage(read, write)]fn g2_group_generator() -> [u8; 96];
+    #[storage(read, write)]fn g2_group_is_identity(p: [u8; 96]) -> bool;
+    #[storage(read, write)]fn g2_group_double(p: [u8; 96]) -> [u8; 96];
+    #[storage(read, write)]fn g2_group_sub(p1: [u8; 96], p2: [u8; 96]) -> [u8; 96];
+    #[storage(read, write)]fn g2_group_neg(p: [u8; 96]) -> [u8; 96];
+    #[storage(read, write)]fn g2_group_mul_scalar(p: [u8; 96], k: Scalar) -> [u8; 96];
+    #[storage(read, write)]fn scalar_field_square(a: Scalar) -> Scalar;
+    #[storage(read, write)]fn scalar_field_double(a: Scalar) -> Scalar;
+    // reverts if a is zero
+    #[storage(read, write)]fn scalar_field_invert(a: Scalar) -> Scalar;
+    // reverts if a is not a square
+    #[storage(read, write)]fn scalar_field_sqrt(a: Scalar) -> Scalar;
+    // reverts if the encoding is not canonical
+    #[storage(read, write)]fn scalar_from_repr(bytes: [u8; 32]) -> Scalar;
+    #[storage(read, write)]fn scalar_to_repr(a: Scalar) -> [u8; 32];
+}
+
+// returns the first len bytes of input as a Vec
+fn to_vec(input: [u8; 64], len: u64) -> Vec<u8> {
+    let mut res = Vec::new();
+    let mut i = 0;
+    while i < len {
+        res.push(input[i]);
+        i += 1;
+    }
+    res
+}
+
+fn group_generator<G, S>() -> G where G: Group<S> {
+    G::generator()
+}
+
+fn group_is_identity<G, S>(p: G) -> bool where G: Group<S> {
+    p.is_identity().unwrap_as_bool()
+}
+
+fn group_double<G, S>(p: G) -> G where G: Group<S> {
+    p.double()
+}
+
+fn group_sub<G, S>(p1: G, p2: G) -> G where G: Group<S> {
+    p1 - p2
+}
+
+fn group_neg<G, S>(p: G) -> G where G: Group<S> {
+    p.neg()
+}
+
+fn group_mul_scalar<G, S>(p: G, k: S) -> G where G: Group<S> {
+    p.mul_scalar(k)
+}
+
+fn field_square<F>(a: F) -> F where F: Field {
+    a.square()
+}
+
+fn field_double<F>(a: F) -> F where F: Field {
+    a.double()
+}
+
+fn field_invert<F>(a: F) -> CtOption<F> where F: Field {
+    a.invert()
+}
+
+fn field_sqrt<F>(a: F) -> CtOption<F> where F: Field {
+    a.sqrt()
+}
+
+fn prime_field_from_repr<F>(bytes: [u8; 32]) -> CtOption<F> where F: PrimeField {
+    F::from_repr(bytes)
+}
+
+fn prime_field_to_repr<F>(a: F) -> [u8; 32] where F: PrimeField {
+    a.to_repr()
+}
+
+fn g1(bytes: [u8; 48]) -> G1Projective {
+    G1Projective::from(G1Affine::from_compressed(bytes).unwrap())
+}
+
+fn g1_compressed(p: G1Projective) -> [u8; 48] {
+    G1Affine::from(p).to_compressed()
+}
+
+fn g2(bytes: [u8; 96]) -> G2Projective {
+    G2Projective::from(G2Affine::from_compressed(bytes).unwrap())
+}
+
+fn g2_compressed(p: G2Projective) -> [u8; 96] {
+    G2Affine::from(p).to_compressed()
 }
 
 impl BlsTestContract for Contract {
@@ -72,25 +238,57 @@ impl BlsTestContract for Contract {
         a.neg()
     }
 
-    // #[storage(read, write)]fn lexicographically_largest_fp2(a: Fp2) -> Choice {
-    //     a.lexicographically_largest()
-    // }
+    #[storage(read, write)]fn lexicographically_largest_fp2(a: Fp2) -> Choice {
+        a.lexicographically_largest()
+    }
 
     #[storage(read, write)]fn add_scalar(a: Scalar, b: Scalar) -> Scalar {
         a + b
     }
 
-    // #[storage(read, write)]fn square_fp2(a: Fp2) -> Fp2 {
-    //     a.square()
-    // }
+    #[storage(read, write)]fn square_fp2(a: Fp2) -> Fp2 {
+        a.square()
+    }
+
+    #[storage(read, write)]fn invert_fp2(a: Fp2) -> Fp2 {
+        a.invert().unwrap()
+    }
+
+    #[storage(read, write)]fn sqrt_fp2(a: Fp2) -> Fp2 {
+        a.sqrt().unwrap()
+    }
+
+    #[storage(read, write)]fn pow_vartime_fp2(a: Fp2, by: [u64; 6]) -> Fp2 {
+        a.pow_vartime(by)
+    }
 
     #[storage(read, write)]fn mul_fp2(a: Fp2, b: Fp2) -> Fp2 {
         a * b
     }
 
-    // #[storage(read, write)]fn scalar_sqrt(a: Scalar) -> CtOption<Scalar> {
-    //     a.sqrt()
-    // }
+    #[storage(read, write)]fn sqrt_scalar(a: Scalar) -> Scalar {
+        a.sqrt().unwrap()
+    }
+
+    #[storage(read, write)]fn pow_vartime_scalar(a: Scalar, by: [u64; 4]) -> Scalar {
+        a.pow_vartime(by)
+    }
+
+    #[storage(read, write)]fn invert_scalar(a: Scalar) -> Scalar {
+        a.invert().unwrap()
+    }
+
+    #[storage(read, write)]fn scalar_from_bytes(bytes: [u8; 32]) -> Scalar {
+        Scalar::from_bytes(bytes).unwrap()
+    }
+
+    #[storage(read, write)]fn scalar_to_bytes(a: Scalar) -> [u8; 32] {
+        a.to_bytes()
+    }
+
+    #[storage(read, write)]fn scalar_from_bytes_wide(bytes: [u8; 64]) -> Scalar {
+        Scalar::from_bytes_wide(bytes)
+    }
 
     // #[storage(read, write)]fn mul_fp6(a: Fp6, b: Fp6) -> Fp6 {
     //     a * b
@@ -99,4 +297,280 @@ impl BlsTestContract for Contract {
     // #[storage(read, write)]fn square_fp6(a: Fp6) -> Fp6 {
     //     a.square()
     // }
+
+    #[storage(read, write)]fn expand_message_xmd_32(msg: [u8; 64], msg_len: u64, dst: [u8; 64], dst_len: u64) -> b256 {
+        let bytes = expand_message_xmd(to_vec(msg, msg_len), to_vec(dst, dst_len), 32);
+        let mut words = [0, 0, 0, 0];
+        let mut i = 0;
+        while i < 32 {
+            words[i / 8] = (words[i / 8] << 8) | bytes.get(i).unwrap();
+            i += 1;
+        }
+        asm(r1: (words[0], words[1], words[2], words[3])) { r1: b256 }
+    }
+
+    #[storage(read, write)]fn hash_to_g1(msg: [u8; 64], msg_len: u64, dst: [u8; 64], dst_len: u64) -> G1Affine {
+        G1Affine::from(hash_to_curve_g1(to_vec(msg, msg_len), to_vec(dst, dst_len)))
+    }
+
+    #[storage(read, write)]fn hash_to_g2(msg: [u8; 64], msg_len: u64, dst: [u8; 64], dst_len: u64) -> G2Affine {
+        G2Affine::from(hash_to_curve_g2(to_vec(msg, msg_len), to_vec(dst, dst_len)))
+    }
+
+    #[storage(read, write)]fn g1_to_compressed(p: G1Affine) -> [u8; 48] {
+        p.to_compressed()
+    }
+
+    #[storage(read, write)]fn g1_to_uncompressed(p: G1Affine) -> [u8; 96] {
+        p.to_uncompressed()
+    }
+
+    #[storage(read, write)]fn g1_from_compressed(bytes: [u8; 48]) -> G1Affine {
+        G1Affine::from_compressed(bytes).unwrap()
+    }
+
+    #[storage(read, write)]fn g1_from_uncompressed(bytes: [u8; 96]) -> G1Affine {
+        G1Affine::from_uncompressed(bytes).unwrap()
+    }
+
+    #[storage(read, write)]fn g2_to_compressed(p: G2Affine) -> [u8; 96] {
+        p.to_compressed()
+    }
+
+    #[storage(read, write)]fn g2_to_uncompressed(p: G2Affine) -> [u8; 192] {
+        p.to_uncompressed()
+    }
+
+    #[storage(read, write)]fn g2_from_compressed(bytes: [u8; 96]) -> G2Affine {
+        G2Affine::from_compressed(bytes).unwrap()
+    }
+
+    #[storage(read, write)]fn g2_from_uncompressed(bytes: [u8; 192]) -> G2Affine {
+        G2Affine::from_uncompressed(bytes).unwrap()
+    }
+
+    #[storage(read, write)]fn g1_is_on_curve(bytes: [u8; 96]) -> bool {
+        G1Affine::from_uncompressed_unchecked(bytes).unwrap().is_on_curve().unwrap_as_bool()
+    }
+
+    #[storage(read, write)]fn g1_projective_is_on_curve(bytes: [u8; 96]) -> bool {
+        G1Projective::from(G1Affine::from_uncompressed_unchecked(bytes).unwrap()).is_on_curve().unwrap_as_bool()
+    }
+
+    #[storage(read, write)]fn g1_is_torsion_free(bytes: [u8; 96]) -> bool {
+        G1Affine::from_uncompressed_unchecked(bytes).unwrap().is_torsion_free().unwrap_as_bool()
+    }
+
+    #[storage(read, write)]fn g2_is_on_curve(bytes: [u8; 192]) -> bool {
+        G2Affine::from_uncompressed_unchecked(bytes).unwrap().is_on_curve().unwrap_as_bool()
+    }
+
+    #[storage(read, write)]fn g2_is_torsion_free(bytes: [u8; 192]) -> bool {
+        G2Affine::from_uncompressed_unchecked(bytes).unwrap().is_torsion_free().unwrap_as_bool()
+    }
+
+    #[storage(read, write)]fn sqrt_fp(a: Fp) -> Fp {
+        a.sqrt().unwrap()
+    }
+
+    #[storage(read, write)]fn fp_from_bytes(bytes: [u8; 48]) -> Fp {
+        Fp::from_bytes(bytes).unwrap()
+    }
+
+    #[storage(read, write)]fn fp_to_bytes(a: Fp) -> [u8; 48] {
+        a.to_bytes()
+    }
+
+    #[storage(read, write)]fn legendre_fp(a: Fp) -> LegendreSymbol {
+        a.legendre()
+    }
+
+    #[storage(read, write)]fn kzg_verify_proof(commitment: [u8; 48], z: Scalar, y: Scalar, proof: [u8; 48], setup_g2: [u8; 96]) -> bool {
+        verify_kzg_proof(commitment, z, y, proof, setup_g2)
+    }
+
+    #[storage(read, write)]fn kzg_verify_proof_batch_2(commitments: [[u8; 48]; 2], zs: [Scalar; 2], ys: [Scalar; 2], proofs: [[u8; 48]; 2], setup_g2: [u8; 96]) -> bool {
+        let mut commitments_vec = Vec::new();
+        let mut zs_vec = Vec::new();
+        let mut ys_vec = Vec::new();
+        let mut proofs_vec = Vec::new();
+        let mut i = 0;
+        while i < 2 {
+            commitments_vec.push(commitments[i]);
+            zs_vec.push(zs[i]);
+            ys_vec.push(ys[i]);
+            proofs_vec.push(proofs[i]);
+            i += 1;
+        }
+        verify_kzg_proof_batch(commitments_vec, zs_vec, ys_vec, proofs_vec, setup_g2)
+    }
+
+    #[storage(read, write)]fn groth16_verify_1(alpha_g1: [u8; 48], beta_g2: [u8; 96], gamma_g2: [u8; 96], delta_g2: [u8; 96], ic: [[u8; 48]; 2], a: [u8; 48], b: [u8; 96], c: [u8; 48], public_input: Scalar) -> bool {
+        let mut ic_vec = Vec::new();
+        ic_vec.push(ic[0]);
+        ic_vec.push(ic[1]);
+
+        let vk = VerifyingKey::from_compressed(alpha_g1, beta_g2, gamma_g2, delta_g2, ic_vec).unwrap();
+        let proof = Proof::from_compressed(a, b, c).unwrap();
+
+        let mut public_inputs = Vec::new();
+        public_inputs.push(public_input);
+        verify(vk, proof, public_inputs)
+    }
+
+    #[storage(read, write)]fn evaluation_domain_generator(size: u64) -> Scalar {
+        EvaluationDomain::new(size).generator
+    }
+
+    #[storage(read, write)]fn ntt_8(coeffs: [Scalar; 8]) -> [Scalar; 8] {
+        fft_8(coeffs)
+    }
+
+    #[storage(read, write)]fn intt_8(evals: [Scalar; 8]) -> [Scalar; 8] {
+        ifft_8(evals)
+    }
+
+    #[storage(read, write)]fn ntt_16(coeffs: [Scalar; 16]) -> [Scalar; 16] {
+        fft_16(coeffs)
+    }
+
+    #[storage(read, write)]fn intt_16(evals: [Scalar; 16]) -> [Scalar; 16] {
+        ifft_16(evals)
+    }
+
+    #[storage(read, write)]fn lagrange_coefficients_8(tau: Scalar) -> [Scalar; 8] {
+        to_array_8(EvaluationDomain::new(8).evaluate_all_lagrange_coefficients(tau))
+    }
+
+    #[storage(read, write)]fn poseidon_hash_2(inputs: [Scalar; 2]) -> Scalar {
+        let mut inputs_vec = Vec::new();
+        inputs_vec.push(inputs[0]);
+        inputs_vec.push(inputs[1]);
+        hash(inputs_vec)
+    }
+
+    #[storage(read, write)]fn poseidon_hash_4(inputs: [Scalar; 4]) -> Scalar {
+        let mut inputs_vec = Vec::new();
+        let mut i = 0;
+        while i < 4 {
+            inputs_vec.push(inputs[i]);
+            i += 1;
+        }
+        hash(inputs_vec)
+    }
+
+    #[storage(read, write)]fn g1_multi_exp_8(points: [[u8; 48]; 8], scalars: [Scalar; 8]) -> [u8; 48] {
+        let mut points_vec = Vec::new();
+        let mut i = 0;
+        while i < 8 {
+            points_vec.push(G1Affine::from_compressed(points[i]).unwrap());
+            i += 1;
+        }
+        G1Affine::from(G1Projective::multi_exp(points_vec, to_vec_8(scalars))).to_compressed()
+    }
+
+    #[storage(read, write)]fn g1_naive_multi_exp_8(points: [[u8; 48]; 8], scalars: [Scalar; 8]) -> [u8; 48] {
+        let mut acc = G1Projective::identity();
+        let mut i = 0;
+        while i < 8 {
+            acc = acc + G1Projective::from(G1Affine::from_compressed(points[i]).unwrap()).mul(scalars[i]);
+            i += 1;
+        }
+        G1Affine::from(acc).to_compressed()
+    }
+
+    #[storage(read, write)]fn g1_group_generator() -> [u8; 48] {
+        g1_compressed(group_generator::<G1Projective, Scalar>())
+    }
+
+    #[storage(read, write)]fn g1_group_is_identity(p: [u8; 48]) -> bool {
+        group_is_identity::<G1Projective, Scalar>(g1(p))
+    }
+
+    #[storage(read, write)]fn g1_group_double(p: [u8; 48]) -> [u8; 48] {
+        g1_compressed(group_double::<G1Projective, Scalar>(g1(p)))
+    }
+
+    #[storage(read, write)]fn g1_group_sub(p1: [u8; 48], p2: [u8; 48]) -> [u8; 48] {
+        g1_compressed(group_sub::<G1Projective, Scalar>(g1(p1), g1(p2)))
+    }
+
+    #[storage(read, write)]fn g1_group_neg(p: [u8; 48]) -> [u8; 48] {
+        g1_compressed(group_neg::<G1Projective, Scalar>(g1(p)))
+    }
+
+    #[storage(read, write)]fn g1_group_mul_scalar(p: [u8; 48], k: Scalar) -> [u8; 48] {
+        g1_compressed(group_mul_scalar(g1(p), k))
+    }
+
+    #[storage(read, write)]fn g2_group_generator() -> [u8; 96] {
+        g2_compressed(group_generator::<G2Projective, Scalar>())
+    }
+
+    #[storage(read, write)]fn g2_group_is_identity(p: [u8; 96]) -> bool {
+        group_is_identity::<G2Projective, Scalar>(g2(p))
+    }
+
+    #[storage(read, write)]fn g2_group_double(p: [u8; 96]) -> [u8; 96] {
+        g2_compressed(group_double::<G2Projective, Scalar>(g2(p)))
+    }
+
+    #[storage(read, write)]fn g2_group_sub(p1: [u8; 96], p2: [u8; 96]) -> [u8; 96] {
+        g2_compressed(group_sub::<G2Projective, Scalar>(g2(p1), g2(p2)))
+    }
+
+    #[storage(read, write)]fn g2_group_neg(p: [u8; 96]) -> [u8; 96] {
+        g2_compressed(group_neg::<G2Projective, Scalar>(g2(p)))
+    }
+
+    #[storage(read, write)]fn g2_group_mul_scalar(p: [u8; 96], k: Scalar) -> [u8; 96] {
+        g2_compressed(group_mul_scalar(g2(p), k))
+    }
+
+    #[storage(read, write)]fn scalar_field_square(a: Scalar) -> Scalar {
+        field_square(a)
+    }
+
+    #[storage(read, write)]fn scalar_field_double(a: Scalar) -> Scalar {
+        field_double(a)
+    }
+
+    #[storage(read, write)]fn scalar_field_invert(a: Scalar) -> Scalar {
+        field_invert(a).unwrap()
+    }
+
+    #[storage(read, write)]fn scalar_field_sqrt(a: Scalar) -> Scalar {
+        field_sqrt(a).unwrap()
+    }
+
+    #[storage(read, write)]fn scalar_from_repr(bytes: [u8; 32]) -> Scalar {
+        prime_field_from_repr::<Scalar>(bytes).unwrap()
+    }
+
+    #[storage(read, write)]fn scalar_to_repr(a: Scalar) -> [u8; 32] {
+        prime_field_to_repr(a)
+    }
+}
+
+fn to_vec_8(a: [Scalar; 8]) -> Vec<Scalar> {
+    let mut res = Vec::new();
+    let mut i = 0;
+    while i < 8 {
+        res.push(a[i]);
+        i += 1;
+    }
+    res
+}
+
+fn to_array_8(v: Vec<Scalar>) -> [Scalar; 8] {
+    [
+        v.get(0).unwrap(),
+        v.get(1).unwrap(),
+        v.get(2).unwrap(),
+        v.get(3).unwrap(),
+        v.get(4).unwrap(),
+        v.get(5).unwrap(),
+        v.get(6).unwrap(),
+        v.get(7).unwrap(),
+    ]
 }
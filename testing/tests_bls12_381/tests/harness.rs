@@ -2,4 +2,14 @@ mod utils;
 mod tests_fp;
 mod tests_fp2;
 // mod tests_fp6;
-mod tests_scalar;
\ No newline at end of file
+mod tests_scalar;
+mod tests_hash_to_curve;
+mod tests_serialization;
+mod tests_g1;
+mod tests_g2;
+mod tests_kzg;
+mod tests_groth16;
+mod tests_ntt;
+mod tests_poseidon;
+mod tests_multi_exp;
+mod tests_group;
\ No newline at end of file
@@ -1,4 +1,4 @@
-use crate::utils::{helpers::get_contract_instance, Fp};
+use crate::utils::{helpers::get_contract_instance, Fp, LegendreSymbol};
 use fuels::{
     prelude::*,
     tx::{ConsensusParameters, ContractId},
@@ -10,20 +10,19 @@ mod success {
   #[tokio::test] //works
   async fn test_add_fp() {
       let small = Fp{ 
-          ls: [1, 2, 3, 4, 5, 6].to_vec()
+          ls: [1, 2, 3, 4, 5, 6]
       };
       let random = Fp{ 
-          ls: [0x3e2528903ca1ef86, 0x270fd67a03bf9e0a, 0xdc70c19599cb699e, 0xebefda8057d5747a, 0xcf20e11f0b1c323, 0xe979cbf960fe51d].to_vec()
+          ls: [0x3e2528903ca1ef86, 0x270fd67a03bf9e0a, 0xdc70c19599cb699e, 0xebefda8057d5747a, 0xcf20e11f0b1c323, 0xe979cbf960fe51d]
       };
       let expected_res = Fp{ 
-          ls: [4478030004447473543, 2814704111667093004, 15884408734010272161, 17001047363111187582, 932823543034528552, 1051481384684610851].to_vec()
+          ls: [4478030004447473543, 2814704111667093004, 15884408734010272161, 17001047363111187582, 932823543034528552, 1051481384684610851]
       };
 
       let (contract_instance, _id) = get_contract_instance().await;
 
       let res = contract_instance.add_fp(small, random)
-      .tx_params(TxParameters::new(None, Some(100_000_000), None))
-      .call_params(CallParameters::new(None, None, Some(100_000_000)))
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
       .call().await.unwrap().value;
       
       assert!(res == expected_res);
@@ -32,20 +31,19 @@ mod success {
   #[tokio::test] //works
   async fn test_sub_fp() {
       let a = Fp {
-          ls: [10587454305359941416, 4615625447881587853, 9368308553698906485, 9494054596162055604, 377309137954328098, 766262085408033194].to_vec()
+          ls: [10587454305359941416, 4615625447881587853, 9368308553698906485, 9494054596162055604, 377309137954328098, 766262085408033194]
       };
 
       let b = Fp {
-          ls: [13403040667047958534, 405585388298286396, 7295341050629342949, 1749456428444609784, 1856600841951774635, 296809876162753174].to_vec()
+          ls: [13403040667047958534, 405585388298286396, 7295341050629342949, 1749456428444609784, 1856600841951774635, 296809876162753174]
       };
       let expected_res = Fp { 
-          ls: [15631157712021534498, 4210040059583301456, 2072967503069563536, 7744598167717445820, 16967452369712105079, 469452209245280019].to_vec()
+          ls: [15631157712021534498, 4210040059583301456, 2072967503069563536, 7744598167717445820, 16967452369712105079, 469452209245280019]
       };
       let (_instance, _id) = get_contract_instance().await;
 
       let res = _instance.sub_fp(a, b)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
       assert!(res == expected_res);
     }
@@ -60,7 +58,7 @@ mod success {
           0x095a_3c6b_22a7_fcfc,
           0x2294_ce75_d4e2_6a27,
           0x1333_8bd8_7001_1ebb,
-      ].to_vec()};
+      ]};
       let b = Fp{ ls:[
           0xb9c3_c7c5_b119_6af7,
           0x2580_e208_6ce3_35c1,
@@ -68,7 +66,7 @@ mod success {
           0x41f2_81e4_9846_e878,
           0xe076_2346_c384_52ce,
           0x0652_e893_26e5_7dc0,
-      ].to_vec()};
+      ]};
       let c = Fp{ ls:[
           0xf96e_f3d7_11ab_5355,
           0xe8d4_59ea_00f1_48dd,
@@ -76,12 +74,11 @@ mod success {
           0x9e34_a4f3_125c_5f83,
           0x3fbe_0c47_ca74_c19e,
           0x01b0_6a8b_bd4a_dfe4,
-      ].to_vec()};
+      ]};
       let (_instance, _id) = get_contract_instance().await;
 
       let res = _instance.mul_fp(a, b)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
       assert!(res == c);
   }
@@ -96,7 +93,7 @@ mod success {
           0xce9a_032d_df39_3a56,//14887215013780077142
           0x3e9c_4fff_2ca0_c4bb,//4511568884102382779
           0x6436_b6f7_f4d9_5dfb,//7221160228616232443
-          0x1060_6628_ad4a_4d90].to_vec()//1180055427263122832
+          0x1060_6628_ad4a_4d90]//1180055427263122832
       };
 
       let expected_res: Fp = Fp {
@@ -105,14 +102,13 @@ mod success {
           0xa2f1_44bd_729a_aeba,
           0xd415_0932_be9f_feac,
           0xe27b_c7c4_7d44_ee50,
-          0x14b6_a78d_3ec7_a560].to_vec()
+          0x14b6_a78d_3ec7_a560]
       };
 
       let (_instance, _id) = get_contract_instance().await;
 
       let res = _instance.square_fp(a)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
 
       assert!(res == expected_res);
@@ -122,7 +118,7 @@ mod success {
   ERROR: Running this one will give Immediate18TooLarge
   #[tokio::test]
   async fn lexicographically_largest_fp() {
-      let zero = Fp{ ls: [0,0,0,0,0,0].to_vec()};
+      let zero = Fp{ ls: [0,0,0,0,0,0]};
       let one = Fp{ ls: [ //=R
           0x7609_0000_0002_fffd,
           0xebf4_000b_c40c_0002,
@@ -130,7 +126,7 @@ mod success {
           0x77ce_5853_7052_5745,
           0x5c07_1a97_a256_ec6d,
           0x15f6_5ec3_fa80_e493,
-      ].to_vec()};
+      ]};
       let first = Fp{ ls: [
           0xa1fa_ffff_fffe_5557,
           0x995b_fff9_76a3_fffe,
@@ -138,7 +134,7 @@ mod success {
           0xf654_7998_c199_5dbd,
           0x778a_468f_507a_6034,
           0x0205_5993_1f7f_8103
-      ].to_vec()};
+      ]};
       let second = Fp{ ls: [
           0x1804_0000_0001_5554,
           0x8550_0005_3ab0_0001,
@@ -146,7 +142,7 @@ mod success {
           0x6e22_d1ec_31eb_b502,
           0xd391_6126_f2d1_4ca2,
           0x17fb_b857_1a00_6596,
-      ].to_vec()};
+      ]};
       let third = Fp{ ls: [
           0x43f5_ffff_fffc_aaae,
           0x32b7_fff2_ed47_fffd,
@@ -154,33 +150,28 @@ mod success {
           0xeca8_f331_8332_bb7a,
           0xef14_8d1e_a0f4_c069,
           0x040a_b326_3eff_0206,
-      ].to_vec()};
+      ]};
 
       let (contract_instance, _id) = get_contract_instance().await;
 
       let res_zero = contract_instance.lexicographically_largest_fp(zero)
-          .tx_params(TxParameters::new(None, Some(10_000_000_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(10_000_000_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000_000))
           .call().await.unwrap().value;
 
       let res_one = contract_instance.lexicographically_largest_fp(one)
-          .tx_params(TxParameters::new(None, Some(10_000_000_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(10_000_000_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000_000))
           .call().await.unwrap().value;
 
       let res_first = contract_instance.lexicographically_largest_fp(first)
-          .tx_params(TxParameters::new(None, Some(10_000_000_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(10_000_000_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000_000))
           .call().await.unwrap().value;
 
       let res_second = contract_instance.lexicographically_largest_fp(second)
-          .tx_params(TxParameters::new(None, Some(10_000_000_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(10_000_000_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000_000))
           .call().await.unwrap().value;
 
       let res_third = contract_instance.lexicographically_largest_fp(third)
-          .tx_params(TxParameters::new(None, Some(10_000_000_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(10_000_000_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000_000))
           .call().await.unwrap().value;
       
       assert!(res_zero.c == 0);
@@ -191,4 +182,154 @@ mod success {
   }
   */
 
-}
\ No newline at end of file
+  #[tokio::test]
+  async fn test_sqrt_fp() {
+      // a = 4
+      let a = Fp{ ls: [
+          0xaa27_0000_000c_fff3,
+          0x53cc_0032_fc34_000a,
+          0x478f_e97a_6b0a_807f,
+          0xb1d3_7ebe_e6ba_24d7,
+          0x8ec9_733b_bf78_ab2f,
+          0x09d6_4551_3d83_de7e,
+      ]};
+      // 2
+      let two = Fp{ ls: [
+          0x3213_0000_0006_554f,
+          0xb93c_0018_d6c4_0005,
+          0x5760_5e0d_b0dd_bb51,
+          0x8b25_6521_ed1f_9bcb,
+          0x6cf2_8d79_0162_2c03,
+          0x11eb_ab9d_bb81_e28c,
+      ]};
+      // -2
+      let minus_two = Fp{ ls: [
+          0x87eb_ffff_fff9_555c,
+          0x656f_ffe5_da8f_fffa,
+          0x0fd0_7493_45d3_3ad2,
+          0xd951_e663_0665_76f4,
+          0xde29_1a3d_41e9_80d3,
+          0x0815_664c_7dfe_040d,
+      ]};
+
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.sqrt_fp(a)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+
+      assert!(res == two || res == minus_two);
+  }
+
+  #[tokio::test]
+  async fn test_fp_bytes_roundtrip() {
+      let a = Fp{ ls: [
+          0xdc90_6d9b_e3f9_5dc8,
+          0x8755_caf7_4596_91a1,
+          0xcff1_a7f4_e958_3ab3,
+          0x9b43_821f_849e_2284,
+          0xf575_54f3_a297_4f3f,
+          0x085d_bea8_4ed4_7f79,
+      ]};
+
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let bytes = contract_instance.fp_to_bytes(a.clone())
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+
+      let res = contract_instance.fp_from_bytes(bytes)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+
+      assert!(res == a);
+  }
+
+  #[tokio::test]
+  async fn test_fp_from_bytes_minus_one() {
+      // p - 1 in big-endian
+      let bytes = [
+          26, 1, 17, 234, 57, 127, 230, 154, 75, 27, 167, 182, 67, 75, 172, 215, 100, 119, 75,
+          132, 243, 133, 18, 191, 103, 48, 210, 160, 246, 176, 246, 36, 30, 171, 255, 254, 177,
+          83, 255, 255, 185, 254, 255, 255, 255, 255, 170, 170
+      ];
+      // -1 in Montgomery form
+      let minus_one = Fp{ ls: [
+          0x43f5_ffff_fffc_aaae,
+          0x32b7_fff2_ed47_fffd,
+          0x07e8_3a49_a2e9_9d69,
+          0xeca8_f331_8332_bb7a,
+          0xef14_8d1e_a0f4_c069,
+          0x040a_b326_3eff_0206,
+      ]};
+
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.fp_from_bytes(bytes)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+
+      assert!(res == minus_one);
+  }
+
+  #[tokio::test]
+  async fn test_legendre_fp() {
+      let zero = Fp{ ls: [0, 0, 0, 0, 0, 0] };
+      // 4
+      let square = Fp{ ls: [
+          0xaa27_0000_000c_fff3,
+          0x53cc_0032_fc34_000a,
+          0x478f_e97a_6b0a_807f,
+          0xb1d3_7ebe_e6ba_24d7,
+          0x8ec9_733b_bf78_ab2f,
+          0x09d6_4551_3d83_de7e,
+      ]};
+      // -1 is not a square, since p = 3 mod 4
+      let non_square = Fp{ ls: [
+          0x43f5_ffff_fffc_aaae,
+          0x32b7_fff2_ed47_fffd,
+          0x07e8_3a49_a2e9_9d69,
+          0xeca8_f331_8332_bb7a,
+          0xef14_8d1e_a0f4_c069,
+          0x040a_b326_3eff_0206,
+      ]};
+
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res_zero = contract_instance.legendre_fp(zero)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      let res_square = contract_instance.legendre_fp(square)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      let res_non_square = contract_instance.legendre_fp(non_square)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+
+      assert!(res_zero == LegendreSymbol::Zero);
+      assert!(res_square == LegendreSymbol::QuadraticResidue);
+      assert!(res_non_square == LegendreSymbol::QuadraticNonResidue);
+  }
+}
+
+mod revert {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_fp_from_bytes_non_canonical() {
+      // p in big-endian, which is not a canonical encoding
+      let bytes = [
+          26, 1, 17, 234, 57, 127, 230, 154, 75, 27, 167, 182, 67, 75, 172, 215, 100, 119, 75,
+          132, 243, 133, 18, 191, 103, 48, 210, 160, 246, 176, 246, 36, 30, 171, 255, 254, 177,
+          83, 255, 255, 185, 254, 255, 255, 255, 255, 170, 171
+      ];
+
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.fp_from_bytes(bytes)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await;
+
+      assert!(res.is_err());
+  }
+}
@@ -18,7 +18,7 @@ mod success {
               0xb01f_c2a3_726c_80b5,
               0xe1d2_93e5_bbd9_19c9,
               0x04b7_8e80_020e_f2ca,
-          ].to_vec()},
+          ]},
           c_1: Fp{ls: [
               0x952e_a446_0462_618f,
               0x238d_5edd_f025_c62f,
@@ -26,7 +26,7 @@ mod success {
               0x03ce_24ea_c1c9_3808,
               0x0559_50f9_45da_483c,
               0x010a_768d_0df4_eabc,
-          ].to_vec()},
+          ]},
       };
       let b = Fp2 {
           c_0: Fp{ls: [
@@ -36,7 +36,7 @@ mod success {
               0x61d9_96b1_b6ee_1936,
               0x1164_dbe8_667c_853c,
               0x0788_557a_cc7d_9c79,
-          ].to_vec()},
+          ]},
           c_1: Fp{ls: [
               0xda6a_87cc_6f48_fa36,
               0x0fc7_b488_277c_1903,
@@ -44,7 +44,7 @@ mod success {
               0x0261_6d5b_c909_9209,
               0xdbed_4677_2db5_8d48,
               0x11b9_4d50_76c7_b7b1,
-          ].to_vec()},
+          ]},
       };
       let c = Fp2 {
           c_0: Fp{ls: [
@@ -54,7 +54,7 @@ mod success {
               0x11f9_5955_295a_99ec,
               0xf337_6fce_2255_9f06,
               0x0c3f_e3fa_ce8c_8f43,
-          ].to_vec()},
+          ]},
           c_1: Fp{ls: [
               0x6f99_2c12_73ab_5bc5,
               0x3355_1366_17a1_df33,
@@ -62,14 +62,13 @@ mod success {
               0x062f_9246_8ad2_ca12,
               0xe146_9770_738f_d584,
               0x12c3_c3dd_84bc_a26d,
-          ].to_vec()},
+          ]},
       };
 
       let (contract_instance, _id) = get_contract_instance().await;
 
       let res = contract_instance.add_fp2(a, b)
-      .tx_params(TxParameters::new(None, Some(100_000_000), None))
-      .call_params(CallParameters::new(None, None, Some(100_000_000)))
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
       .call().await.unwrap().value;
       
       assert!(res.c_0 == c.c_0);
@@ -87,7 +86,7 @@ mod success {
               0xb01f_c2a3_726c_80b5,
               0xe1d2_93e5_bbd9_19c9,
               0x04b7_8e80_020e_f2ca,
-          ].to_vec()},
+          ]},
           c_1: Fp{ ls: [
               0x952e_a446_0462_618f,
               0x238d_5edd_f025_c62f,
@@ -95,7 +94,7 @@ mod success {
               0x03ce_24ea_c1c9_3808,
               0x0559_50f9_45da_483c,
               0x010a_768d_0df4_eabc,
-          ].to_vec()},
+          ]},
       };
       let b = Fp2 {
           c_0: Fp{ ls: [
@@ -105,7 +104,7 @@ mod success {
               0x61d9_96b1_b6ee_1936,
               0x1164_dbe8_667c_853c,
               0x0788_557a_cc7d_9c79,
-          ].to_vec()},
+          ]},
           c_1: Fp{ ls: [
               0xda6a_87cc_6f48_fa36,
               0x0fc7_b488_277c_1903,
@@ -113,7 +112,7 @@ mod success {
               0x0261_6d5b_c909_9209,
               0xdbed_4677_2db5_8d48,
               0x11b9_4d50_76c7_b7b1,
-          ].to_vec()},
+          ]},
       };
       let c = Fp2 {
           c_0: Fp{ ls: [
@@ -123,7 +122,7 @@ mod success {
               0xb2bd_7776_af03_7a3e,
               0x1b89_5fb3_98a8_4164,
               0x1730_4aef_6f11_3cec,
-          ].to_vec()},
+          ]},
           c_1: Fp{ ls: [
               0x74c3_1c79_9519_1204,
               0x3271_aa54_79fd_ad2b,
@@ -131,14 +130,13 @@ mod success {
               0x65e4_0313_ec44_b8be,
               0x7487_b238_5b70_67cb,
               0x0952_3b26_d0ad_19a4,
-          ].to_vec()},
+          ]},
       };
 
       let (contract_instance, _id) = get_contract_instance().await;
 
       let res = contract_instance.sub_fp2(a, b)
-      .tx_params(TxParameters::new(None, Some(100_000_000), None))
-      .call_params(CallParameters::new(None, None, Some(100_000_000)))
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
       .call().await.unwrap().value;
       
       assert!(res.c_0 == c.c_0);
@@ -156,7 +154,7 @@ mod success {
               0xb01f_c2a3_726c_80b5,
               0xe1d2_93e5_bbd9_19c9,
               0x04b7_8e80_020e_f2ca,
-          ].to_vec()},
+          ]},
           c_1: Fp{ls: [
               0x952e_a446_0462_618f,
               0x238d_5edd_f025_c62f,
@@ -164,7 +162,7 @@ mod success {
               0x03ce_24ea_c1c9_3808,
               0x0559_50f9_45da_483c,
               0x010a_768d_0df4_eabc,
-          ].to_vec()},
+          ]},
       };
       let b = Fp2 {
           c_0: Fp{ls: [
@@ -174,7 +172,7 @@ mod success {
               0xb457_88e1_8118_9209,
               0x6949_13d0_8772_930d,
               0x1549_836a_3770_f3cf,
-          ].to_vec()},
+          ]},
           c_1: Fp{ls: [
               0x24d0_5bb9_fb9d_491c,
               0xfb1e_a120_c12e_39d0,
@@ -182,15 +180,14 @@ mod success {
               0x60a9_269a_31bb_dab6,
               0x45c2_56bc_fd71_649b,
               0x18f6_9b5d_2b8a_fbde,
-          ].to_vec()},
+          ]},
       };
 
 
       let (contract_instance, _id) = get_contract_instance().await;
 
       let res = contract_instance.neg_fp2(a)
-      .tx_params(TxParameters::new(None, Some(100_000_000), None))
-      .call_params(CallParameters::new(None, None, Some(100_000_000)))
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
       .call().await.unwrap().value;
       
       assert!(res.c_0 == b.c_0);
@@ -207,7 +204,7 @@ mod success {
               0xb01f_c2a3_726c_80b5,
               0xe1d2_93e5_bbd9_19c9,
               0x04b7_8e80_020e_f2ca,
-          ].to_vec()},
+          ]},
           c_1: Fp{ ls:[
               0x952e_a446_0462_618f,
               0x238d_5edd_f025_c62f,
@@ -215,7 +212,7 @@ mod success {
               0x03ce_24ea_c1c9_3808,
               0x0559_50f9_45da_483c,
               0x010a_768d_0df4_eabc,
-          ].to_vec()},
+          ]},
       };
       let b = Fp2 {
           c_0: Fp{ ls:[
@@ -225,7 +222,7 @@ mod success {
               0x61d9_96b1_b6ee_1936,
               0x1164_dbe8_667c_853c,
               0x0788_557a_cc7d_9c79,
-          ].to_vec()},
+          ]},
           c_1: Fp{ ls:[
               0xda6a_87cc_6f48_fa36,
               0x0fc7_b488_277c_1903,
@@ -233,7 +230,7 @@ mod success {
               0x0261_6d5b_c909_9209,
               0xdbed_4677_2db5_8d48,
               0x11b9_4d50_76c7_b7b1,
-          ].to_vec()},
+          ]},
       };
       let c = Fp2 {
           c_0: Fp{ ls:[
@@ -243,7 +240,7 @@ mod success {
               0x6a9a_9603_cf88_f09e,
               0xf05a_7bf8_bad0_eb01,
               0x0954_9131_c003_ffae,
-          ].to_vec()},
+          ]},
           c_1: Fp{ ls:[
               0x963b_02d0_f93d_37cd,
               0xc95c_e1cd_b30a_73d4,
@@ -251,21 +248,18 @@ mod success {
               0x56da_3c16_7fab_0d50,
               0x6b50_86b5_f4b6_d6af,
               0x09c3_9f06_2f18_e9f2,
-          ].to_vec()},
+          ]},
       };
 
       let (contract_instance, _id) = get_contract_instance().await;
 
       let res = contract_instance.mul_fp2(a, b)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
 
       assert!(res == c);
   }
 
-  /*
-  //ERROR still gives Immediate18TooLarge :( (12 sept)
   #[tokio::test]
   async fn test_squaring() {
       let a = Fp2 {
@@ -276,7 +270,7 @@ mod success {
               0xb01f_c2a3_726c_80b5,
               0xe1d2_93e5_bbd9_19c9,
               0x04b7_8e80_020e_f2ca,
-          ].to_vec()},
+          ]},
           c_1: Fp{ ls:[
               0x952e_a446_0462_618f,
               0x238d_5edd_f025_c62f,
@@ -284,7 +278,7 @@ mod success {
               0x03ce_24ea_c1c9_3808,
               0x0559_50f9_45da_483c,
               0x010a_768d_0df4_eabc,
-          ].to_vec()},
+          ]},
       };
       let b = Fp2 {
           c_0: Fp{ ls:[
@@ -294,7 +288,7 @@ mod success {
               0x61d9_96b1_b6ee_1936,
               0x1164_dbe8_667c_853c,
               0x0788_557a_cc7d_9c79,
-          ].to_vec()},
+          ]},
           c_1: Fp{ ls:[
               0xda6a_87cc_6f48_fa36,
               0x0fc7_b488_277c_1903,
@@ -302,40 +296,23 @@ mod success {
               0x0261_6d5b_c909_9209,
               0xdbed_4677_2db5_8d48,
               0x11b9_4d50_76c7_b7b1,
-          ].to_vec()},
+          ]},
       };
       let (contract_instance, _id) = get_contract_instance().await;
 
       let res = contract_instance.square_fp2(a)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
 
-      println!("{}", res.c_0.ls[0]);
-      println!("{}", res.c_0.ls[1]);
-      println!("{}", res.c_0.ls[2]);
-      println!("{}", res.c_0.ls[2]);
-      println!("{}", res.c_0.ls[4]);
-      println!("{}", res.c_0.ls[5]);
-
-      println!("{}", res.c_1.ls[0]);
-      println!("{}", res.c_1.ls[1]);
-      println!("{}", res.c_1.ls[2]);
-      println!("{}", res.c_1.ls[2]);
-      println!("{}", res.c_1.ls[4]);
-      println!("{}", res.c_1.ls[5]);
-
       assert!(res.c_0 == b.c_0);
       assert!(res.c_1 == b.c_1);
   }
-  */
-  /*
-  //ERROR Immediate18TooLarge
+
   #[tokio::test]//stripped down version from zkcrypto impl
   async fn lexicographically_largest_fp2() {
       let zero = Fp2 { 
-          c_0 : Fp{ ls: [0,0,0,0,0,0].to_vec()},
-          c_1 : Fp{ ls: [0,0,0,0,0,0].to_vec()},
+          c_0 : Fp{ ls: [0,0,0,0,0,0]},
+          c_1 : Fp{ ls: [0,0,0,0,0,0]},
       };
       let one = Fp2 {
           c_0: Fp{ ls: [ //=R
@@ -345,8 +322,8 @@ mod success {
               0x77ce_5853_7052_5745,
               0x5c07_1a97_a256_ec6d,
               0x15f6_5ec3_fa80_e493,
-          ].to_vec()},
-          c_1 : Fp{ ls: [0,0,0,0,0,0].to_vec()}
+          ]},
+          c_1 : Fp{ ls: [0,0,0,0,0,0]}
       };
 
       let first = Fp2 {
@@ -357,35 +334,315 @@ mod success {
               0xe98a_d408_11f5_fc2b,
               0x736c_3a59_232d_511d,
               0x10ac_d42d_29cf_cbb6,
-          ].to_vec()},
+          ]},
           c_1 : Fp{ ls: [
               0xd328_e37c_c2f5_8d41,
               0x948d_f085_8a60_5869,
               0x6032_f9d5_6f93_a573,
               0x2be4_83ef_3fff_dc87,
               0x30ef_61f8_8f48_3c2a,
-              0x1333_f55a_3572_5be0].to_vec()}
+              0x1333_f55a_3572_5be0]}
       };
       let (contract_instance, _id) = get_contract_instance().await;
 
       let res_zero = contract_instance.lexicographically_largest_fp2(zero)
-          .tx_params(TxParameters::new(None, Some(10_000_000_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(10_000_000_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
 
       let res_one = contract_instance.lexicographically_largest_fp2(one)
-          .tx_params(TxParameters::new(None, Some(10_000_000_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(10_000_000_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
 
       let res_first = contract_instance.lexicographically_largest_fp2(first)
-          .tx_params(TxParameters::new(None, Some(10_000_000_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(10_000_000_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
 
       assert!(res_zero.c == 0);
       assert!(res_one.c == 0);
       assert!(res_first.c == 1);
   }
-  */
+
+  #[tokio::test]
+  async fn test_invert_fp2() {
+      let a = Fp2 {
+          c_0: Fp{ ls: [
+              0x1128_ecad_6754_9455,
+              0x9e7a_1cff_3a4e_a1a8,
+              0xeb20_8d51_e08b_cf27,
+              0xe98a_d408_11f5_fc2b,
+              0x736c_3a59_232d_511d,
+              0x10ac_d42d_29cf_cbb6,
+          ]},
+          c_1: Fp{ ls: [
+              0xd328_e37c_c2f5_8d41,
+              0x948d_f085_8a60_5869,
+              0x6032_f9d5_6f93_a573,
+              0x2be4_83ef_3fff_dc87,
+              0x30ef_61f8_8f48_3c2a,
+              0x1333_f55a_3572_5be0,
+          ]},
+      };
+      let b = Fp2 {
+          c_0: Fp{ ls: [
+              0x0581_a133_3d4f_48a6,
+              0x5824_2f6e_f074_8500,
+              0x0292_c955_349e_6da5,
+              0xba37_721d_dd95_fcd0,
+              0x70d1_6790_3aa5_dfc5,
+              0x1189_5e11_8b58_a9d5,
+          ]},
+          c_1: Fp{ ls: [
+              0x0eda_09d2_d7a8_5d17,
+              0x8808_e137_a7d1_a2cf,
+              0x43ae_2625_c1ff_21db,
+              0xf85a_c9fd_f7a7_4c64,
+              0x8fcc_dda5_b8da_9738,
+              0x08e8_4f0c_b32c_d17d,
+          ]},
+      };
+
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.invert_fp2(a)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+
+      assert!(res == b);
+  }
+
+  #[tokio::test]
+  async fn test_sqrt_fp2() {
+      let a = Fp2 {
+          c_0: Fp{ ls: [
+              0x2bee_d146_27d7_f9e9,
+              0xb661_4e06_660e_5dce,
+              0x06c4_cc7c_2f91_d42c,
+              0x996d_7847_4b7a_63cc,
+              0xebae_bc4c_820d_574e,
+              0x1886_5e12_d93f_d845,
+          ]},
+          c_1: Fp{ ls: [
+              0x7d82_8664_baf4_f566,
+              0xd17e_6639_96ec_7339,
+              0x679e_ad55_cb40_78d0,
+              0xfe3b_2260_e001_ec28,
+              0x3059_93d0_43d9_1b68,
+              0x0626_f03c_0489_b72d,
+          ]},
+      };
+      // the square roots of a
+      let sqrt_a = Fp2 {
+          c_0: Fp{ ls: [
+              0x1aa3_c21b_9621_ec7f,
+              0x07d4_34b0_949e_3455,
+              0xaeca_5a63_ba6c_e982,
+              0x9be5_f883_8e55_cd32,
+              0xe8eb_cf6b_94d5_d1eb,
+              0x1643_327d_bf1f_f0ca,
+          ]},
+          c_1: Fp{ ls: [
+              0x140e_849b_bf1d_aaaf,
+              0xca7e_854e_5c67_93be,
+              0xdbe9_59d7_44de_473c,
+              0xbfc9_c408_dc76_82d7,
+              0x4c6b_b4b7_cf56_afeb,
+              0x08c7_275f_112f_62da,
+          ]},
+      };
+      let minus_sqrt_a = Fp2 {
+          c_0: Fp{ ls: [
+              0x9f5b_3de4_69dd_be2c,
+              0x16d7_cb4e_1cb5_cbaa,
+              0xb866_783d_3c44_0ca2,
+              0xc891_5301_652f_458c,
+              0x622f_d84a_ae75_daeb,
+              0x03bd_df6c_7a5f_f5cf,
+          ]},
+          c_1: Fp{ ls: [
+              0xa5f0_7b64_40e1_fffc,
+              0x542d_7ab0_54ec_6c41,
+              0x8b47_78c9_b1d2_aee7,
+              0xa4ad_877c_170e_8fe7,
+              0xfeaf_f2fe_73f4_fceb,
+              0x1139_ea8b_2850_83bf,
+          ]},
+      };
+
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.sqrt_fp2(a)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+
+      assert!(res == sqrt_a || res == minus_sqrt_a);
+  }
+
+  #[tokio::test]
+  async fn test_sqrt_fp2_of_subfield_element() {
+      // b = 5, which is a generator of the p - 1 order
+      // multiplicative subgroup. Its square root is not in Fp.
+      let b = Fp2 {
+          c_0: Fp{ ls: [
+              0x6631_0000_0010_5545,
+              0x2114_0040_0eec_000d,
+              0x3fa7_af30_c820_e316,
+              0xc52a_8b8d_6387_695d,
+              0x9fb4_e61d_1e83_eac5,
+              0x05cb_922a_fe84_dc77,
+          ]},
+          c_1: Fp{ ls: [
+              0x0000_0000_0000_0000,
+              0x0000_0000_0000_0000,
+              0x0000_0000_0000_0000,
+              0x0000_0000_0000_0000,
+              0x0000_0000_0000_0000,
+              0x0000_0000_0000_0000,
+          ]},
+      };
+      let sqrt_b = Fp2 {
+          c_0: Fp{ ls: [
+              0x0000_0000_0000_0000,
+              0x0000_0000_0000_0000,
+              0x0000_0000_0000_0000,
+              0x0000_0000_0000_0000,
+              0x0000_0000_0000_0000,
+              0x0000_0000_0000_0000,
+          ]},
+          c_1: Fp{ ls: [
+              0xec6a_08bf_80b5_b9f1,
+              0x39b3_e1ef_0a8f_08d1,
+              0x3649_551b_af17_0748,
+              0xa034_697c_1571_2026,
+              0x3576_aebe_e39f_0218,
+              0x06a5_3f2a_581b_3e45,
+          ]},
+      };
+      let minus_sqrt_b = Fp2 {
+          c_0: Fp{ ls: [
+              0x0000_0000_0000_0000,
+              0x0000_0000_0000_0000,
+              0x0000_0000_0000_0000,
+              0x0000_0000_0000_0000,
+              0x0000_0000_0000_0000,
+              0x0000_0000_0000_0000,
+          ]},
+          c_1: Fp{ ls: [
+              0xcd94_f740_7f49_f0ba,
+              0xe4f8_1e0f_a6c4_f72d,
+              0x30e7_7d85_4799_eedb,
+              0xc442_e208_de13_f299,
+              0x15a4_f8f7_5fac_aabe,
+              0x135b_d2bf_e164_a855,
+          ]},
+      };
+
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.sqrt_fp2(b)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+
+      assert!(res == sqrt_b || res == minus_sqrt_b);
+  }
+
+  #[tokio::test]
+  async fn test_pow_vartime_fp2() {
+      let a = Fp2 {
+          c_0: Fp{ ls: [
+              0x2bee_d146_27d7_f9e9,
+              0xb661_4e06_660e_5dce,
+              0x06c4_cc7c_2f91_d42c,
+              0x996d_7847_4b7a_63cc,
+              0xebae_bc4c_820d_574e,
+              0x1886_5e12_d93f_d845,
+          ]},
+          c_1: Fp{ ls: [
+              0x7d82_8664_baf4_f566,
+              0xd17e_6639_96ec_7339,
+              0x679e_ad55_cb40_78d0,
+              0xfe3b_2260_e001_ec28,
+              0x3059_93d0_43d9_1b68,
+              0x0626_f03c_0489_b72d,
+          ]},
+      };
+      // a^(2^64 + 0x1234_5678_9abc_def0)
+      let expected = Fp2 {
+          c_0: Fp{ ls: [
+              0x7dc6_6f9d_d232_aa3f,
+              0xb007_b51e_f951_1ff5,
+              0xa850_14d4_495a_8fec,
+              0x453d_f563_ee0d_3a06,
+              0x464d_82e3_31be_fedc,
+              0x16a6_32f4_7d6a_178b,
+          ]},
+          c_1: Fp{ ls: [
+              0x9907_ec8b_97b2_b064,
+              0xe90e_bdd5_5370_0b17,
+              0xf849_8951_c82f_03fe,
+              0x096d_6ff1_2f06_1845,
+              0x4575_5558_0c62_c560,
+              0x14dd_72a9_4eaa_7da4,
+          ]},
+      };
+
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.pow_vartime_fp2(a, [0x1234_5678_9abc_def0, 1, 0, 0, 0, 0])
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+
+      assert!(res == expected);
+  }
+}
+
+mod revert {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_invert_zero_fp2() {
+      let zero = Fp2 {
+          c_0: Fp{ ls: [0, 0, 0, 0, 0, 0] },
+          c_1: Fp{ ls: [0, 0, 0, 0, 0, 0] },
+      };
+
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.invert_fp2(zero)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await;
+
+      assert!(res.is_err());
+  }
+
+  #[tokio::test]
+  async fn test_sqrt_fp2_nonsquare() {
+      // 2155129644831861015726826462986972654175647013268275306775721078997042729172900466542651176384766902407257452753362*u + 2796889544896299244102912275102369318775038861758288697415827248356648685135290329705805931514906495247464901062529
+      // is nonsquare.
+      let a = Fp2 {
+          c_0: Fp{ ls: [
+              0xc5fa_1bc8_fd00_d7f6,
+              0x3830_ca45_4606_003b,
+              0x2b28_7f11_04b1_02da,
+              0xa7fb_30f2_8230_f23e,
+              0x339c_db9e_e953_dbf0,
+              0x0d78_ec51_d989_fc57,
+          ]},
+          c_1: Fp{ ls: [
+              0x27ec_4898_cf87_f613,
+              0x9de1_394e_1abb_05a5,
+              0x0947_f85d_c170_fc14,
+              0x586f_bc69_6b61_14b7,
+              0x2b34_75a4_077d_7169,
+              0x13e1_c895_cc4b_6c22,
+          ]},
+      };
+
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.sqrt_fp2(a)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await;
+
+      assert!(res.is_err());
+  }
 }
\ No newline at end of file
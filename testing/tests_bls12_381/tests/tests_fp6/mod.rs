@@ -17,7 +17,7 @@ mod success {
                   0x8100_d27c_c925_9f5b,
                   0xafa2_0b96_7464_0eab,
                   0x09bb_cea7_d8d9_497d,
-              ].to_vec()},
+              ]},
               c_1: Fp{ls: [
                   0x0303_cb98_b166_2daa,
                   0xd931_10aa_0a62_1d5a,
@@ -25,7 +25,7 @@ mod success {
                   0x0ba3_643e_cb05_a348,
                   0xdc35_34bb_1f1c_25a6,
                   0x06c3_05bb_19c0_e1c1,
-              ].to_vec()},
+              ]},
           },
           c_1: Fp2 {
               c_0: Fp{ls: [
@@ -35,7 +35,7 @@ mod success {
                   0xf84c_5770_4e38_5ec2,
                   0xcb49_c1d9_c010_e60f,
                   0x0acd_b8e1_58bf_e3c8,
-              ].to_vec()},
+              ]},
               c_1: Fp{ls: [
                   0x8aef_cb98_b15f_8306,
                   0x3ea1_108f_e4f2_1d54,
@@ -43,7 +43,7 @@ mod success {
                   0xe4f5_4aa1_d16b_1a3c,
                   0xba5e_4ef8_6105_a679,
                   0x0ed8_6c07_97be_e5cf,
-              ].to_vec()},
+              ]},
           },
           c_2: Fp2 {
               c_0: Fp{ls: [
@@ -53,7 +53,7 @@ mod success {
                   0xd19e_3dd3_549d_d5b6,
                   0xa972_dc17_01fa_66e3,
                   0x12e3_1f2d_d6bd_e7d6,
-              ].to_vec()},
+              ]},
               c_1: Fp{ls: [
                   0xad2a_cb98_b173_2d9d,
                   0x2cfd_10dd_0696_1d64,
@@ -61,7 +61,7 @@ mod success {
                   0xbd76_e2fd_b1bf_c820,
                   0x6afe_a7f6_de94_d0d5,
                   0x1099_4b0c_5744_c040,
-              ].to_vec()},
+              ]},
           },
       };
 
@@ -78,7 +78,7 @@ mod success {
                   0xaa1f_d62f_34f2_839a,
                   0x5a13_3515_7f89_913f,
                   0x14a3_fe32_9643_c247,
-              ].to_vec()},
+              ]},
               c_1: Fp{ ls: [
                   0x3516_cb98_b16c_82f9,
                   0x926d_10c2_e126_1d5f,
@@ -86,7 +86,7 @@ mod success {
                   0x96c8_c960_b825_3f14,
                   0x4927_c234_207e_51a9,
                   0x18ae_b158_d542_c44e,
-              ].to_vec()},
+              ]},
           },
           c_1: Fp2 {
               c_0: Fp{ ls: [
@@ -96,7 +96,7 @@ mod success {
                   0x1efa_710d_47d2_e7ce,
                   0xed20_a79c_7e27_653c,
                   0x02b8_5294_dac1_dfba,
-              ].to_vec()},
+              ]},
               c_1: Fp{ ls: [
                   0x9d52_cb98_b180_82e5,
                   0x621d_1111_5176_1d6f,
@@ -104,7 +104,7 @@ mod success {
                   0x0ad3_1637_a4f4_da37,
                   0xaeac_737c_5ac1_cf2e,
                   0x006e_7e73_5b48_b824,
-              ].to_vec()},
+              ]},
           },
           c_2: Fp2 {
               c_0: Fp{ ls: [
@@ -114,7 +114,7 @@ mod success {
                   0xf77c_0969_2827_95b1,
                   0x9dc1_009a_fbb6_8f97,
                   0x0479_3199_9a47_ba2b,
-              ].to_vec()},
+              ]},
               c_1: Fp{ ls: [
                   0x253e_cb98_b179_d841,
                   0xc78d_10f7_2c06_1d6a,
@@ -122,7 +122,7 @@ mod success {
                   0xe424_fc9a_ab5a_512b,
                   0x8cd5_8db9_9cab_5001,
                   0x0883_e4bf_d946_bc32,
-              ].to_vec()},
+              ]},
           },
       };
 
@@ -144,7 +144,7 @@ mod success {
                   0x8100_d27c_c925_9f5b,
                   0xafa2_0b96_7464_0eab,
                   0x09bb_cea7_d8d9_497d,
-              ].to_vec()},
+              ]},
               c_1: Fp{ls: [
                   0x0303_cb98_b166_2daa,
                   0xd931_10aa_0a62_1d5a,
@@ -152,7 +152,7 @@ mod success {
                   0x0ba3_643e_cb05_a348,
                   0xdc35_34bb_1f1c_25a6,
                   0x06c3_05bb_19c0_e1c1,
-              ].to_vec()},
+              ]},
           },
           c_1: Fp2 {
               c_0: Fp{ls: [
@@ -162,7 +162,7 @@ mod success {
                   0xf84c_5770_4e38_5ec2,
                   0xcb49_c1d9_c010_e60f,
                   0x0acd_b8e1_58bf_e3c8,
-              ].to_vec()},
+              ]},
               c_1: Fp{ls: [
                   0x8aef_cb98_b15f_8306,
                   0x3ea1_108f_e4f2_1d54,
@@ -170,7 +170,7 @@ mod success {
                   0xe4f5_4aa1_d16b_1a3c,
                   0xba5e_4ef8_6105_a679,
                   0x0ed8_6c07_97be_e5cf,
-              ].to_vec()},
+              ]},
           },
           c_2: Fp2 {
               c_0: Fp{ls: [
@@ -180,7 +180,7 @@ mod success {
                   0xd19e_3dd3_549d_d5b6,
                   0xa972_dc17_01fa_66e3,
                   0x12e3_1f2d_d6bd_e7d6,
-              ].to_vec()},
+              ]},
               c_1: Fp{ls: [
                   0xad2a_cb98_b173_2d9d,
                   0x2cfd_10dd_0696_1d64,
@@ -188,13 +188,12 @@ mod success {
                   0xbd76_e2fd_b1bf_c820,
                   0x6afe_a7f6_de94_d0d5,
                   0x1099_4b0c_5744_c040,
-              ].to_vec()},
+              ]},
           },
       };
 
       let res = contract_instance.add_fp2(a, b)
-      .tx_params(TxParameters::new(None, Some(100_000_000), None, None))
-      .call_params(CallParameters::new(None, None, Some(100_000_000)))
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
       .call().await.unwrap().value;
       
 
@@ -208,13 +207,11 @@ mod success {
       let (contract_instance, _id) = get_contract_instance().await;
       
       let res_square = contract_instance.square_fp6(get_a())
-      .tx_params(TxParameters::new(None, Some(100_000_000), None))
-      .call_params(CallParameters::new(None, None, Some(100_000_000)))
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
       .call().await.unwrap().value;
       
       let res_expected = contract_instance.mul_fp6(get_a(), get_a())
-      .tx_params(TxParameters::new(None, Some(100_000_000), None))
-      .call_params(CallParameters::new(None, None, Some(100_000_000)))
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
       .call().await.unwrap().value;
       
       assert!(res_square == res_expected);
@@ -0,0 +1,113 @@
+use crate::utils::helpers::get_contract_instance;
+use bls12_381::G1Affine as BlsG1Affine;
+use fuels::{
+    prelude::*,
+    tx::{ConsensusParameters, ContractId},
+};
+
+fn generator_uncompressed() -> [u8; 96] {
+  BlsG1Affine::generator().to_uncompressed()
+}
+
+// The generator with the lowest bit of y flipped, which is not on the curve
+fn off_curve_uncompressed() -> [u8; 96] {
+  let mut bytes = generator_uncompressed();
+  bytes[95] ^= 1;
+  bytes
+}
+
+// returns the point with the smallest x-coordinate on the curve, which is not in the q-order subgroup
+fn non_torsion_free_point() -> BlsG1Affine {
+  let p = (1u8..).find_map(|x| {
+      let mut bytes = [0u8; 48];
+      bytes[0] = 0x80;
+      bytes[47] = x;
+      Option::<BlsG1Affine>::from(BlsG1Affine::from_compressed_unchecked(&bytes))
+  }).unwrap();
+  assert!(!bool::from(p.is_torsion_free()));
+  p
+}
+
+async fn is_on_curve(bytes: [u8; 96]) -> bool {
+  let (contract_instance, _id) = get_contract_instance().await;
+
+  contract_instance.g1_is_on_curve(bytes)
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap().value
+}
+
+async fn projective_is_on_curve(bytes: [u8; 96]) -> bool {
+  let (contract_instance, _id) = get_contract_instance().await;
+
+  contract_instance.g1_projective_is_on_curve(bytes)
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap().value
+}
+
+async fn is_torsion_free(bytes: [u8; 96]) -> bool {
+  let (contract_instance, _id) = get_contract_instance().await;
+
+  contract_instance.g1_is_torsion_free(bytes)
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap().value
+}
+
+mod success {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_is_on_curve() {
+      assert!(is_on_curve(generator_uncompressed()).await);
+      assert!(is_on_curve(BlsG1Affine::identity().to_uncompressed()).await);
+      assert!(is_on_curve(non_torsion_free_point().to_uncompressed()).await);
+      assert!(!is_on_curve(off_curve_uncompressed()).await);
+  }
+
+  #[tokio::test]
+  async fn test_projective_is_on_curve() {
+      assert!(projective_is_on_curve(generator_uncompressed()).await);
+      assert!(projective_is_on_curve(BlsG1Affine::identity().to_uncompressed()).await);
+      assert!(!projective_is_on_curve(off_curve_uncompressed()).await);
+  }
+
+  #[tokio::test]
+  async fn test_is_torsion_free() {
+      assert!(is_torsion_free(generator_uncompressed()).await);
+      assert!(is_torsion_free(BlsG1Affine::identity().to_uncompressed()).await);
+      assert!(!is_torsion_free(non_torsion_free_point().to_uncompressed()).await);
+  }
+}
+
+mod revert {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_from_uncompressed_off_curve() {
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.g1_from_uncompressed(off_curve_uncompressed())
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await;
+      assert!(res.is_err());
+  }
+
+  #[tokio::test]
+  async fn test_from_uncompressed_not_torsion_free() {
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.g1_from_uncompressed(non_torsion_free_point().to_uncompressed())
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await;
+      assert!(res.is_err());
+  }
+
+  #[tokio::test]
+  async fn test_from_compressed_not_torsion_free() {
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.g1_from_compressed(non_torsion_free_point().to_compressed())
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await;
+      assert!(res.is_err());
+  }
+}
@@ -0,0 +1,185 @@
+use crate::utils::{helpers::get_contract_instance, Scalar};
+use bellman::{groth16, Circuit, ConstraintSystem, SynthesisError};
+use bls12_381::{Bls12, G1Affine, G2Affine, Scalar as BlsScalar};
+use ff::Field;
+use fuels::{
+    prelude::*,
+    tx::{ConsensusParameters, ContractId},
+};
+use rand_core::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+// Proves knowledge of x such that x^3 + x + 5 = out, where out is the public input
+struct CubeCircuit {
+  x: Option<BlsScalar>,
+}
+
+impl Circuit<BlsScalar> for CubeCircuit {
+  fn synthesize<CS: ConstraintSystem<BlsScalar>>(self, cs: &mut CS) -> std::result::Result<(), SynthesisError> {
+      let x_val = self.x;
+      let x = cs.alloc(|| "x", || x_val.ok_or(SynthesisError::AssignmentMissing))?;
+
+      let x2_val = x_val.map(|x| x.square());
+      let x2 = cs.alloc(|| "x^2", || x2_val.ok_or(SynthesisError::AssignmentMissing))?;
+      cs.enforce(|| "x * x = x^2", |lc| lc + x, |lc| lc + x, |lc| lc + x2);
+
+      let x3_val = x2_val.zip(x_val).map(|(x2, x)| x2 * x);
+      let x3 = cs.alloc(|| "x^3", || x3_val.ok_or(SynthesisError::AssignmentMissing))?;
+      cs.enforce(|| "x^2 * x = x^3", |lc| lc + x2, |lc| lc + x, |lc| lc + x3);
+
+      let out_val = x3_val.zip(x_val).map(|(x3, x)| x3 + x + BlsScalar::from(5));
+      let out = cs.alloc_input(|| "out", || out_val.ok_or(SynthesisError::AssignmentMissing))?;
+      cs.enforce(
+          || "(x^3 + x + 5) * 1 = out",
+          |lc| lc + x3 + x + (BlsScalar::from(5), CS::one()),
+          |lc| lc + CS::one(),
+          |lc| lc + out,
+      );
+
+      Ok(())
+  }
+}
+
+// returns the Montgomery form of s, which is how the contract represents scalars
+fn to_contract_scalar(s: BlsScalar) -> Scalar {
+  // R = 2^256 mod q
+  let r = BlsScalar::from_raw([
+      0x0000_0001_ffff_fffe,
+      0x5884_b7fa_0003_4802,
+      0x998c_4fef_ecbc_4ff5,
+      0x1824_b159_acc5_056f,
+  ]);
+  let bytes = (s * r).to_bytes();
+  Scalar{ ls: bytes
+      .chunks(8)
+      .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+      .collect::<Vec<u64>>()
+      .try_into()
+      .unwrap()
+  }
+}
+
+// returns a verifying key and a proof that 3^3 + 3 + 5 = 35
+fn setup_and_prove() -> (groth16::VerifyingKey<Bls12>, groth16::Proof<Bls12>) {
+  let mut rng = XorShiftRng::from_seed([
+      0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc, 0xe5,
+  ]);
+  let params = groth16::generate_random_parameters::<Bls12, _, _>(CubeCircuit { x: None }, &mut rng).unwrap();
+  let proof = groth16::create_random_proof(CubeCircuit { x: Some(BlsScalar::from(3)) }, &params, &mut rng).unwrap();
+
+  // Sanity check with the bellman verifier
+  let pvk = groth16::prepare_verifying_key(&params.vk);
+  assert!(groth16::verify_proof(&pvk, &proof, &[BlsScalar::from(35)]).is_ok());
+
+  (params.vk, proof)
+}
+
+// returns None if the call reverts
+async fn call_verify(
+  vk: &groth16::VerifyingKey<Bls12>,
+  proof: &groth16::Proof<Bls12>,
+  public_input: BlsScalar,
+) -> Option<bool> {
+  let (contract_instance, _id) = get_contract_instance().await;
+
+  contract_instance.groth16_verify_1(
+      vk.alpha_g1.to_compressed(),
+      vk.beta_g2.to_compressed(),
+      vk.gamma_g2.to_compressed(),
+      vk.delta_g2.to_compressed(),
+      [vk.ic[0].to_compressed(), vk.ic[1].to_compressed()],
+      proof.a.to_compressed(),
+      proof.b.to_compressed(),
+      proof.c.to_compressed(),
+      to_contract_scalar(public_input),
+  )
+  .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+  .call().await.ok().map(|res| res.value)
+}
+
+async fn verify_on_contract(
+  vk: &groth16::VerifyingKey<Bls12>,
+  proof: &groth16::Proof<Bls12>,
+  public_input: BlsScalar,
+) -> bool {
+  call_verify(vk, proof, public_input).await.unwrap()
+}
+
+// returns the G2 point with the smallest x-coordinate in Fp on the curve, which is not in the q-order subgroup
+fn non_torsion_free_g2() -> G2Affine {
+  let p = (1u8..).find_map(|x| {
+      let mut bytes = [0u8; 96];
+      bytes[0] = 0x80;
+      bytes[95] = x;
+      Option::<G2Affine>::from(G2Affine::from_compressed_unchecked(&bytes))
+  }).unwrap();
+  assert!(!bool::from(p.is_torsion_free()));
+  p
+}
+
+mod success {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_groth16_verify() {
+      let (vk, proof) = setup_and_prove();
+
+      assert!(verify_on_contract(&vk, &proof, BlsScalar::from(35)).await);
+  }
+
+  #[tokio::test]
+  async fn test_groth16_verify_wrong_public_input() {
+      let (vk, proof) = setup_and_prove();
+
+      assert!(!verify_on_contract(&vk, &proof, BlsScalar::from(36)).await);
+  }
+
+  #[tokio::test]
+  async fn test_groth16_verify_wrong_proof() {
+      let (vk, proof) = setup_and_prove();
+      // Swapping A and C gives a proof that doesn't verify
+      let wrong_proof = groth16::Proof::<Bls12> {
+          a: proof.c,
+          b: proof.b,
+          c: proof.a,
+      };
+
+      assert!(!verify_on_contract(&vk, &wrong_proof, BlsScalar::from(35)).await);
+  }
+}
+
+mod revert {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_groth16_proof_b_not_torsion_free() {
+      let (vk, proof) = setup_and_prove();
+      let wrong_proof = groth16::Proof::<Bls12> {
+          a: proof.a,
+          b: non_torsion_free_g2(),
+          c: proof.c,
+      };
+
+      assert!(call_verify(&vk, &wrong_proof, BlsScalar::from(35)).await.is_none());
+  }
+
+  #[tokio::test]
+  async fn test_groth16_proof_a_identity() {
+      let (vk, proof) = setup_and_prove();
+      let wrong_proof = groth16::Proof::<Bls12> {
+          a: G1Affine::identity(),
+          b: proof.b,
+          c: proof.c,
+      };
+
+      assert!(call_verify(&vk, &wrong_proof, BlsScalar::from(35)).await.is_none());
+  }
+
+  #[tokio::test]
+  async fn test_groth16_vk_not_torsion_free() {
+      let (mut vk, proof) = setup_and_prove();
+      vk.gamma_g2 = non_torsion_free_g2();
+
+      assert!(call_verify(&vk, &proof, BlsScalar::from(35)).await.is_none());
+  }
+}
@@ -0,0 +1,236 @@
+use crate::utils::{helpers::get_contract_instance, Scalar};
+use bls12_381::{
+    G1Affine as BlsG1Affine, G1Projective as BlsG1Projective,
+    G2Affine as BlsG2Affine, G2Projective as BlsG2Projective,
+    Scalar as BlsScalar,
+};
+use ff::Field;
+use fuels::{
+    prelude::*,
+    tx::{ConsensusParameters, ContractId},
+};
+use rand_core::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+// returns the Montgomery form of s, which is how the contract represents scalars
+fn to_contract_scalar(s: BlsScalar) -> Scalar {
+  // R = 2^256 mod q
+  let r = BlsScalar::from_raw([
+      0x0000_0001_ffff_fffe,
+      0x5884_b7fa_0003_4802,
+      0x998c_4fef_ecbc_4ff5,
+      0x1824_b159_acc5_056f,
+  ]);
+  let bytes = (s * r).to_bytes();
+  Scalar{ ls: bytes
+      .chunks(8)
+      .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+      .collect::<Vec<u64>>()
+      .try_into()
+      .unwrap()
+  }
+}
+
+fn rng() -> XorShiftRng {
+  XorShiftRng::from_seed([
+      0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc, 0xe5,
+  ])
+}
+
+fn g1_compressed(p: BlsG1Projective) -> [u8; 48] {
+  BlsG1Affine::from(p).to_compressed()
+}
+
+fn g2_compressed(p: BlsG2Projective) -> [u8; 96] {
+  BlsG2Affine::from(p).to_compressed()
+}
+
+mod success {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_g1_group() {
+      let (contract_instance, _id) = get_contract_instance().await;
+      let mut rng = rng();
+      let g = BlsG1Projective::generator();
+      let p = g * BlsScalar::random(&mut rng);
+      let k = BlsScalar::random(&mut rng);
+
+      let generator = contract_instance.g1_group_generator()
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert_eq!(generator, g1_compressed(g));
+
+      let identity = contract_instance.g1_group_is_identity(g1_compressed(BlsG1Projective::identity()))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(identity);
+
+      let identity = contract_instance.g1_group_is_identity(g1_compressed(p))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(!identity);
+
+      let double = contract_instance.g1_group_double(g1_compressed(p))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert_eq!(double, g1_compressed(p.double()));
+
+      let diff = contract_instance.g1_group_sub(g1_compressed(p), g1_compressed(g))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert_eq!(diff, g1_compressed(p - g));
+
+      let diff = contract_instance.g1_group_sub(g1_compressed(p), g1_compressed(p))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert_eq!(diff, g1_compressed(BlsG1Projective::identity()));
+
+      let neg = contract_instance.g1_group_neg(g1_compressed(p))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert_eq!(neg, g1_compressed(-p));
+
+      let mul = contract_instance.g1_group_mul_scalar(g1_compressed(p), to_contract_scalar(k))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert_eq!(mul, g1_compressed(p * k));
+
+      // [q - 1]P = -P
+      let mul = contract_instance.g1_group_mul_scalar(g1_compressed(p), to_contract_scalar(-BlsScalar::one()))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert_eq!(mul, g1_compressed(-p));
+  }
+
+  #[tokio::test]
+  async fn test_g2_group() {
+      let (contract_instance, _id) = get_contract_instance().await;
+      let mut rng = rng();
+      let g = BlsG2Projective::generator();
+      let p = g * BlsScalar::random(&mut rng);
+      let k = BlsScalar::random(&mut rng);
+
+      let generator = contract_instance.g2_group_generator()
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert_eq!(generator, g2_compressed(g));
+
+      let identity = contract_instance.g2_group_is_identity(g2_compressed(BlsG2Projective::identity()))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(identity);
+
+      let identity = contract_instance.g2_group_is_identity(g2_compressed(p))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(!identity);
+
+      let double = contract_instance.g2_group_double(g2_compressed(p))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert_eq!(double, g2_compressed(p.double()));
+
+      let diff = contract_instance.g2_group_sub(g2_compressed(p), g2_compressed(g))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert_eq!(diff, g2_compressed(p - g));
+
+      let neg = contract_instance.g2_group_neg(g2_compressed(p))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert_eq!(neg, g2_compressed(-p));
+
+      let mul = contract_instance.g2_group_mul_scalar(g2_compressed(p), to_contract_scalar(k))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert_eq!(mul, g2_compressed(p * k));
+  }
+
+  #[tokio::test]
+  async fn test_scalar_field() {
+      let (contract_instance, _id) = get_contract_instance().await;
+      let mut rng = rng();
+
+      for a in [BlsScalar::one(), -BlsScalar::one(), BlsScalar::random(&mut rng), BlsScalar::random(&mut rng)] {
+          let square = contract_instance.scalar_field_square(to_contract_scalar(a))
+              .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+              .call().await.unwrap().value;
+          assert!(square == to_contract_scalar(a.square()));
+
+          let double = contract_instance.scalar_field_double(to_contract_scalar(a))
+              .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+              .call().await.unwrap().value;
+          assert!(double == to_contract_scalar(a.double()));
+
+          let inverse = contract_instance.scalar_field_invert(to_contract_scalar(a))
+              .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+              .call().await.unwrap().value;
+          assert!(inverse == to_contract_scalar(a.invert().unwrap()));
+
+          // the root is either a or -a
+          let root = contract_instance.scalar_field_sqrt(to_contract_scalar(a.square()))
+              .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+              .call().await.unwrap().value;
+          assert!(root == to_contract_scalar(a) || root == to_contract_scalar(-a));
+      }
+  }
+
+  #[tokio::test]
+  async fn test_scalar_repr() {
+      let (contract_instance, _id) = get_contract_instance().await;
+      let mut rng = rng();
+
+      for a in [BlsScalar::zero(), BlsScalar::one(), -BlsScalar::one(), BlsScalar::random(&mut rng)] {
+          let repr = contract_instance.scalar_to_repr(to_contract_scalar(a))
+              .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+              .call().await.unwrap().value;
+          assert_eq!(repr, a.to_bytes());
+
+          let from_repr = contract_instance.scalar_from_repr(a.to_bytes())
+              .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+              .call().await.unwrap().value;
+          assert!(from_repr == to_contract_scalar(a));
+      }
+  }
+}
+
+mod revert {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_scalar_field_invert_zero() {
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.scalar_field_invert(to_contract_scalar(BlsScalar::zero()))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await;
+      assert!(res.is_err());
+  }
+
+  #[tokio::test]
+  async fn test_scalar_field_sqrt_non_square() {
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      // 7 is the multiplicative generator of the scalar field, so it is not a square
+      let res = contract_instance.scalar_field_sqrt(to_contract_scalar(BlsScalar::from(7u64)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await;
+      assert!(res.is_err());
+  }
+
+  #[tokio::test]
+  async fn test_scalar_from_repr_modulus() {
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      // q in little-endian bytes
+      let modulus = [
+          1, 0, 0, 0, 255, 255, 255, 255, 254, 91, 254, 255, 2, 164, 189, 83, 5, 216, 161, 9, 8,
+          216, 57, 51, 72, 125, 157, 41, 83, 167, 237, 115,
+      ];
+      let res = contract_instance.scalar_from_repr(modulus)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await;
+      assert!(res.is_err());
+  }
+}
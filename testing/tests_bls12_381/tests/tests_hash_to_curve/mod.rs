@@ -0,0 +1,226 @@
+use crate::utils::{helpers::get_contract_instance, Fp, Fp2, G2Affine, Choice};
+use fuels::{
+    prelude::*,
+    tx::{ConsensusParameters, ContractId},
+};
+
+// Test vectors from https://www.rfc-editor.org/rfc/rfc9380.html, appendix J and K.
+
+// Returns the bytes padded with zeroes to 64 bytes, as the contract expects
+fn pad_64(bytes: &[u8]) -> [u8; 64] {
+  let mut res = [0u8; 64];
+  res[..bytes.len()].copy_from_slice(bytes);
+  res
+}
+
+const DST_EXPANDER: &[u8] = b"QUUX-V01-CS02-with-expander-SHA256-128";
+const DST_G1: &[u8] = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+const DST_G2: &[u8] = b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_";
+
+mod success {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_expand_message_xmd_empty() {
+      let msg: &[u8] = b"";
+      let expected: [u8; 32] = [
+          0x68, 0xa9, 0x85, 0xb8, 0x7e, 0xb6, 0xb4, 0x69,
+          0x52, 0x12, 0x89, 0x11, 0xf2, 0xa4, 0x41, 0x2b,
+          0xbc, 0x30, 0x2a, 0x9d, 0x75, 0x96, 0x67, 0xf8,
+          0x7f, 0x7a, 0x21, 0xd8, 0x03, 0xf0, 0x72, 0x35,
+      ];
+
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.expand_message_xmd_32(pad_64(msg), msg.len() as u64, pad_64(DST_EXPANDER), DST_EXPANDER.len() as u64)
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap().value;
+
+      assert!(res.0 == expected);
+  }
+
+  #[tokio::test]
+  async fn test_expand_message_xmd_abc() {
+      let msg: &[u8] = b"abc";
+      let expected: [u8; 32] = [
+          0xd8, 0xcc, 0xab, 0x23, 0xb5, 0x98, 0x5c, 0xce,
+          0xa8, 0x65, 0xc6, 0xc9, 0x7b, 0x6e, 0x5b, 0x83,
+          0x50, 0xe7, 0x94, 0xe6, 0x03, 0xb4, 0xb9, 0x79,
+          0x02, 0xf5, 0x3a, 0x8a, 0x0d, 0x60, 0x56, 0x15,
+      ];
+
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.expand_message_xmd_32(pad_64(msg), msg.len() as u64, pad_64(DST_EXPANDER), DST_EXPANDER.len() as u64)
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap().value;
+
+      assert!(res.0 == expected);
+  }
+
+  #[tokio::test]
+  async fn test_hash_to_curve_g1_empty() {
+      let msg: &[u8] = b"";
+      let x = Fp{ls: [
+          0x1a8d_b5e0_780f_9eae,
+          0x3a63_98bb_c224_2f9b,
+          0xfa97_3061_215a_d413,
+          0xfdbf_0c6a_afe2_3802,
+          0x66cc_60ab_b572_7423,
+          0x024b_9f0b_a097_9b56,
+      ]};
+      let y = Fp{ls: [
+          0x14e1_7466_d681_1282,
+          0x9984_18c3_b8b2_5975,
+          0x2a54_a46d_3205_6c75,
+          0x8521_7284_a43c_506c,
+          0xbeb9_0e94_4acd_0498,
+          0x0a25_7f5a_aa7a_0601,
+      ]};
+
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.hash_to_g1(pad_64(msg), msg.len() as u64, pad_64(DST_G1), DST_G1.len() as u64)
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap().value;
+
+      assert!(res.x == x);
+      assert!(res.y == y);
+      assert!(res.infinity.c == 0);
+  }
+
+  #[tokio::test]
+  async fn test_hash_to_curve_g1_abc() {
+      let msg: &[u8] = b"abc";
+      let x = Fp{ls: [
+          0xa414_9ad8_68ec_0c00,
+          0xdf10_d2cf_d608_4f15,
+          0x5186_9420_b900_48d7,
+          0x3003_73b5_f0fa_32e5,
+          0x41eb_f029_cfc2_beab,
+          0x0a0c_2f64_4a78_b365,
+      ]};
+      let y = Fp{ls: [
+          0x1804_f351_a1eb_61a9,
+          0x51c3_9ba4_5655_7852,
+          0xdd00_d8a8_e4b6_d2dd,
+          0xe8e9_1a05_b30e_1ccf,
+          0x1137_17b1_cb29_7436,
+          0x07c6_b6b6_2deb_e364,
+      ]};
+
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.hash_to_g1(pad_64(msg), msg.len() as u64, pad_64(DST_G1), DST_G1.len() as u64)
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap().value;
+
+      assert!(res.x == x);
+      assert!(res.y == y);
+      assert!(res.infinity.c == 0);
+  }
+
+  #[tokio::test]
+  async fn test_hash_to_curve_g2_empty() {
+      let msg: &[u8] = b"";
+      let expected = G2Affine {
+          x: Fp2 {
+              c_0: Fp{ls: [
+                  0x24f8_1411_51eb_c033,
+                  0x1282_8afa_3f9f_71c5,
+                  0xe5e2_149f_8122_1e5f,
+                  0x30ea_6feb_d95e_8478,
+                  0x344f_6aa1_26b5_f274,
+                  0x12d9_dd56_33c5_4a65,
+              ]},
+              c_1: Fp{ls: [
+                  0xd277_0d53_1e23_7244,
+                  0x034d_b588_6af2_edba,
+                  0xed16_3201_19ce_50a4,
+                  0xc2a7_23b8_fccc_0bbe,
+                  0x0ec0_c8c9_a677_6a9b,
+                  0x0f2b_a6a4_6bd8_699c,
+              ]},
+          },
+          y: Fp2 {
+              c_0: Fp{ls: [
+                  0xfda6_172a_cb32_aa61,
+                  0xd24b_e3e8_9832_a5d8,
+                  0x7695_ade1_d05c_16e5,
+                  0x233a_68c3_663d_a129,
+                  0x893a_ccac_16ee_2070,
+                  0x11ea_d40d_54c3_ceac,
+              ]},
+              c_1: Fp{ls: [
+                  0x3973_71b8_d889_5f8b,
+                  0x318e_4c3a_8ed4_d7e7,
+                  0x634f_ad6b_1151_ecc5,
+                  0x64b5_6ce6_1e3e_9e0d,
+                  0xf543_f635_52d7_6582,
+                  0x169d_98e5_1940_4998,
+              ]},
+          },
+          infinity: Choice{ c: 0 },
+      };
+
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.hash_to_g2(pad_64(msg), msg.len() as u64, pad_64(DST_G2), DST_G2.len() as u64)
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap().value;
+
+      assert!(res == expected);
+  }
+
+  #[tokio::test]
+  async fn test_hash_to_curve_g2_abc() {
+      let msg: &[u8] = b"abc";
+      let expected = G2Affine {
+          x: Fp2 {
+              c_0: Fp{ls: [
+                  0x8ca9_6085_ec41_56f9,
+                  0x22e0_b051_0d95_16c8,
+                  0x1f15_6ccd_36fe_a6f1,
+                  0x27d6_1a18_c2bd_378d,
+                  0x57ec_dc4d_f9ea_c249,
+                  0x13bc_8646_d942_a9a3,
+              ]},
+              c_1: Fp{ls: [
+                  0xb694_2706_f58d_f761,
+                  0xbdda_1f0c_7631_a5cf,
+                  0xc396_23a0_c69e_a8d5,
+                  0xe035_30e4_bc27_e005,
+                  0x291b_5ef9_6da5_580d,
+                  0x04b3_5768_e9d2_2e11,
+              ]},
+          },
+          y: Fp2 {
+              c_0: Fp{ls: [
+                  0x9299_efbf_62fb_74b8,
+                  0xdf39_cc9a_0819_3213,
+                  0x1664_4330_7b3f_f6ba,
+                  0x7945_8fe9_7815_a2b4,
+                  0x9e11_50f4_05b3_84eb,
+                  0x1614_33e2_62ff_ecc4,
+              ]},
+              c_1: Fp{ls: [
+                  0x0560_e19d_3a80_e7b2,
+                  0x613d_b5ca_e007_ab67,
+                  0xf523_5b9f_2a20_b239,
+                  0x9d3a_8762_0d60_9742,
+                  0x7566_c5ed_8958_d144,
+                  0x0381_5e51_2dd0_7f01,
+              ]},
+          },
+          infinity: Choice{ c: 0 },
+      };
+
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.hash_to_g2(pad_64(msg), msg.len() as u64, pad_64(DST_G2), DST_G2.len() as u64)
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap().value;
+
+      assert!(res == expected);
+  }
+}
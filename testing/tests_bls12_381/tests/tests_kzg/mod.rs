@@ -0,0 +1,250 @@
+use crate::utils::{helpers::get_contract_instance, Scalar};
+use bls12_381::{G1Affine as BlsG1Affine, G2Affine as BlsG2Affine};
+use fuels::{
+    prelude::*,
+    tx::{ConsensusParameters, ContractId},
+};
+
+// Test vectors generated with c-kzg-4844 (https://github.com/ethereum/c-kzg-4844) and the
+// Ethereum mainnet trusted setup. Scalars are given in Montgomery form, with the canonical value above them.
+
+// [τ]G2, the second G2 point of the trusted setup
+fn setup_g2() -> [u8; 96] {
+  [
+      0xb5, 0xbf, 0xd7, 0xdd, 0x8c, 0xde, 0xb1, 0x28, 0x84, 0x3b, 0xc2, 0x87,
+      0x23, 0x0a, 0xf3, 0x89, 0x26, 0x18, 0x70, 0x75, 0xcb, 0xfb, 0xef, 0xa8,
+      0x10, 0x09, 0xa2, 0xce, 0x61, 0x5a, 0xc5, 0x3d, 0x29, 0x14, 0xe5, 0x87,
+      0x0c, 0xb4, 0x52, 0xd2, 0xaf, 0xaa, 0xab, 0x24, 0xf3, 0x49, 0x9f, 0x72,
+      0x18, 0x5c, 0xbf, 0xee, 0x53, 0x49, 0x27, 0x14, 0x73, 0x44, 0x29, 0xb7,
+      0xb3, 0x86, 0x08, 0xe2, 0x39, 0x26, 0xc9, 0x11, 0xcc, 0xec, 0xea, 0xc9,
+      0xa3, 0x68, 0x51, 0x47, 0x7b, 0xa4, 0xc6, 0x0b, 0x08, 0x70, 0x41, 0xde,
+      0x62, 0x10, 0x00, 0xed, 0xc9, 0x8e, 0xda, 0xda, 0x20, 0xc1, 0xde, 0xf2,
+  ]
+}
+
+fn commitment_1() -> [u8; 48] {
+  [
+      0x85, 0x3e, 0x49, 0xaf, 0xa3, 0xbb, 0xc0, 0xe6, 0x56, 0x66, 0x44, 0xb9,
+      0x15, 0xdc, 0xa2, 0x4d, 0xb8, 0xde, 0xfa, 0x68, 0x2b, 0xe7, 0x2f, 0x35,
+      0x36, 0x0b, 0x12, 0x79, 0xaf, 0x02, 0x55, 0x5e, 0x37, 0xb1, 0xa3, 0xc8,
+      0x06, 0xa1, 0x64, 0x5d, 0x69, 0x23, 0x8f, 0xf5, 0x79, 0xdd, 0xb0, 0xfd,
+  ]
+}
+
+// 0x1a00000000000000000000000000000000000000000000000000000000000056
+fn z_1() -> Scalar {
+  Scalar{ ls: [
+      0xb479_a275_cac6_a326,
+      0x5783_a467_76fb_0fa0,
+      0xd548_e0f7_9a72_b0b9,
+      0x6222_27f6_87fc_92ca,
+  ]}
+}
+
+// 0x1c8c412da45768c8249a2a8a0c8a2d8425d72dc827024b8573c8d1d82fc527cd
+fn y_1() -> Scalar {
+  Scalar{ ls: [
+      0x64aa_f61c_0073_c376,
+      0xbb59_ea69_ae01_95b8,
+      0x09b7_eea5_9a74_24fd,
+      0x2dd8_4f6b_6229_551a,
+  ]}
+}
+
+fn proof_1() -> [u8; 48] {
+  [
+      0xa5, 0xc2, 0xcd, 0x99, 0xe4, 0xcd, 0x79, 0x42, 0xfe, 0x67, 0x79, 0xd4,
+      0x7a, 0xdc, 0x9e, 0x5e, 0x9e, 0x6f, 0x87, 0x33, 0x3a, 0xc0, 0x32, 0x69,
+      0xfe, 0x74, 0x3e, 0x44, 0xa8, 0x57, 0x24, 0xd5, 0x97, 0x31, 0xe8, 0x6b,
+      0xba, 0x78, 0x91, 0x62, 0x5d, 0x67, 0xa9, 0x40, 0xdb, 0x03, 0x92, 0x5e,
+  ]
+}
+
+fn commitment_2() -> [u8; 48] {
+  [
+      0x88, 0x05, 0xd9, 0x40, 0xbf, 0x95, 0xdd, 0x3f, 0x1e, 0x1e, 0x52, 0x6f,
+      0x8f, 0xc4, 0xfb, 0xd2, 0xd5, 0x37, 0xdf, 0x40, 0x6e, 0xf9, 0x33, 0xef,
+      0xab, 0x88, 0x9a, 0xa7, 0x2b, 0x7f, 0x79, 0x91, 0x05, 0x5e, 0xf0, 0x58,
+      0x64, 0xd9, 0x4d, 0x22, 0xec, 0xdf, 0xec, 0xd2, 0x62, 0x60, 0xe3, 0x3a,
+  ]
+}
+
+// 0x1a00000000000000000000000000000000000000000000000000000000000057
+fn z_2() -> Scalar {
+  Scalar{ ls: [
+      0xb479_a278_cac6_a323,
+      0x5c4a_b85e_76ff_fba3,
+      0x3b9b_58df_7d8d_28a9,
+      0x0659_31fd_0b24_1af2,
+  ]}
+}
+
+// 0x715739688d85b1b454f8e648bd378bbe311e7b6588ba8fdffcb2d8f0b99a2557
+fn y_2() -> Scalar {
+  Scalar{ ls: [
+      0xdcff_a94c_ffed_4875,
+      0x830c_09c5_bc26_6b1f,
+      0x191c_91c1_e645_87b0,
+      0x02ac_9903_d4e5_9f9f,
+  ]}
+}
+
+fn proof_2() -> [u8; 48] {
+  [
+      0x8c, 0x1a, 0xce, 0x0a, 0xbb, 0x06, 0x2d, 0x39, 0x3e, 0x4c, 0x22, 0xf9,
+      0x9c, 0xcf, 0x60, 0x0f, 0xcc, 0x93, 0xa3, 0xef, 0x89, 0x9c, 0xc5, 0x4b,
+      0xf9, 0xc9, 0xbd, 0xbb, 0x71, 0x0c, 0x0e, 0x3b, 0xf0, 0xbb, 0xb3, 0xa6,
+      0xa2, 0xf5, 0x1d, 0xd5, 0x99, 0x12, 0xe8, 0xff, 0xc7, 0x30, 0x51, 0xe3,
+  ]
+}
+
+// returns the compressed G1 point with the smallest x-coordinate on the curve, which is not in the q-order subgroup
+fn non_torsion_free_g1() -> [u8; 48] {
+  let p = (1u8..).find_map(|x| {
+      let mut bytes = [0u8; 48];
+      bytes[0] = 0x80;
+      bytes[47] = x;
+      Option::<BlsG1Affine>::from(BlsG1Affine::from_compressed_unchecked(&bytes))
+  }).unwrap();
+  assert!(!bool::from(p.is_torsion_free()));
+  p.to_compressed()
+}
+
+// returns the compressed G2 point with the smallest x-coordinate in Fp on the curve, which is not in the q-order subgroup
+fn non_torsion_free_g2() -> [u8; 96] {
+  let p = (1u8..).find_map(|x| {
+      let mut bytes = [0u8; 96];
+      bytes[0] = 0x80;
+      bytes[95] = x;
+      Option::<BlsG2Affine>::from(BlsG2Affine::from_compressed_unchecked(&bytes))
+  }).unwrap();
+  assert!(!bool::from(p.is_torsion_free()));
+  p.to_compressed()
+}
+
+mod success {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_verify_kzg_proof() {
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.kzg_verify_proof(commitment_1(), z_1(), y_1(), proof_1(), setup_g2())
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap().value;
+
+      assert!(res);
+  }
+
+  #[tokio::test]
+  async fn test_verify_kzg_proof_wrong_evaluation() {
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      // y_2 is not the evaluation of the first polynomial at z_1
+      let res = contract_instance.kzg_verify_proof(commitment_1(), z_1(), y_2(), proof_1(), setup_g2())
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap().value;
+
+      assert!(!res);
+  }
+
+  #[tokio::test]
+  async fn test_verify_kzg_proof_wrong_proof() {
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.kzg_verify_proof(commitment_2(), z_2(), y_2(), proof_1(), setup_g2())
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap().value;
+
+      assert!(!res);
+  }
+
+  #[tokio::test]
+  async fn test_verify_kzg_proof_batch() {
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.kzg_verify_proof_batch_2(
+          [commitment_1(), commitment_2()],
+          [z_1(), z_2()],
+          [y_1(), y_2()],
+          [proof_1(), proof_2()],
+          setup_g2(),
+      )
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap().value;
+
+      assert!(res);
+  }
+
+  #[tokio::test]
+  async fn test_verify_kzg_proof_batch_swapped_proofs() {
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      // the proofs are swapped, so neither of them is valid
+      let res = contract_instance.kzg_verify_proof_batch_2(
+          [commitment_1(), commitment_2()],
+          [z_1(), z_2()],
+          [y_1(), y_2()],
+          [proof_2(), proof_1()],
+          setup_g2(),
+      )
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap().value;
+
+      assert!(!res);
+  }
+}
+
+mod revert {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_verify_kzg_proof_invalid_setup_g2() {
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.kzg_verify_proof(commitment_1(), z_1(), y_1(), proof_1(), non_torsion_free_g2())
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await;
+
+      assert!(res.is_err());
+  }
+
+  #[tokio::test]
+  async fn test_verify_kzg_proof_invalid_commitment() {
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.kzg_verify_proof(non_torsion_free_g1(), z_1(), y_1(), proof_1(), setup_g2())
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await;
+
+      assert!(res.is_err());
+  }
+
+  #[tokio::test]
+  async fn test_verify_kzg_proof_invalid_proof() {
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.kzg_verify_proof(commitment_1(), z_1(), y_1(), non_torsion_free_g1(), setup_g2())
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await;
+
+      assert!(res.is_err());
+  }
+
+  #[tokio::test]
+  async fn test_verify_kzg_proof_batch_invalid_setup_g2() {
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.kzg_verify_proof_batch_2(
+          [commitment_1(), commitment_2()],
+          [z_1(), z_2()],
+          [y_1(), y_2()],
+          [proof_1(), proof_2()],
+          non_torsion_free_g2(),
+      )
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await;
+
+      assert!(res.is_err());
+  }
+}
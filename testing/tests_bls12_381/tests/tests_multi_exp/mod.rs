@@ -0,0 +1,110 @@
+use crate::utils::{helpers::get_contract_instance, Scalar};
+use bls12_381::{G1Affine as BlsG1Affine, G1Projective as BlsG1Projective, Scalar as BlsScalar};
+use ff::Field;
+use fuels::{
+    prelude::*,
+    tx::{ConsensusParameters, ContractId, Receipt},
+};
+use rand_core::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+// returns the Montgomery form of s, which is how the contract represents scalars
+fn to_contract_scalar(s: BlsScalar) -> Scalar {
+  // R = 2^256 mod q
+  let r = BlsScalar::from_raw([
+      0x0000_0001_ffff_fffe,
+      0x5884_b7fa_0003_4802,
+      0x998c_4fef_ecbc_4ff5,
+      0x1824_b159_acc5_056f,
+  ]);
+  let bytes = (s * r).to_bytes();
+  Scalar{ ls: bytes
+      .chunks(8)
+      .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+      .collect::<Vec<u64>>()
+      .try_into()
+      .unwrap()
+  }
+}
+
+// returns 8 random points and scalars
+fn random_inputs() -> (Vec<BlsG1Affine>, Vec<BlsScalar>) {
+  let mut rng = XorShiftRng::from_seed([
+      0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc, 0xe5,
+  ]);
+  let points = (0..8)
+      .map(|_| BlsG1Affine::from(BlsG1Projective::generator() * BlsScalar::random(&mut rng)))
+      .collect();
+  let scalars = (0..8).map(|_| BlsScalar::random(&mut rng)).collect();
+  (points, scalars)
+}
+
+fn expected_multi_exp(points: &[BlsG1Affine], scalars: &[BlsScalar]) -> [u8; 48] {
+  let sum: BlsG1Projective = points.iter().zip(scalars).map(|(p, s)| p * s).sum();
+  BlsG1Affine::from(sum).to_compressed()
+}
+
+fn gas_used(receipts: &[Receipt]) -> u64 {
+  receipts.iter().find_map(|r| match r {
+      Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+      _ => None,
+  }).unwrap()
+}
+
+async fn multi_exp_on_contract(points: &[BlsG1Affine], scalars: &[BlsScalar]) -> ([u8; 48], u64) {
+  let (contract_instance, _id) = get_contract_instance().await;
+
+  let res = contract_instance.g1_multi_exp_8(
+      points.iter().map(|p| p.to_compressed()).collect::<Vec<_>>().try_into().unwrap(),
+      scalars.iter().map(|s| to_contract_scalar(*s)).collect::<Vec<_>>().try_into().unwrap(),
+  )
+  .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+  .call().await.unwrap();
+  (res.value, gas_used(&res.receipts))
+}
+
+async fn naive_multi_exp_on_contract(points: &[BlsG1Affine], scalars: &[BlsScalar]) -> ([u8; 48], u64) {
+  let (contract_instance, _id) = get_contract_instance().await;
+
+  let res = contract_instance.g1_naive_multi_exp_8(
+      points.iter().map(|p| p.to_compressed()).collect::<Vec<_>>().try_into().unwrap(),
+      scalars.iter().map(|s| to_contract_scalar(*s)).collect::<Vec<_>>().try_into().unwrap(),
+  )
+  .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+  .call().await.unwrap();
+  (res.value, gas_used(&res.receipts))
+}
+
+mod success {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_multi_exp() {
+      let (points, scalars) = random_inputs();
+      let (res, _gas) = multi_exp_on_contract(&points, &scalars).await;
+
+      assert!(res == expected_multi_exp(&points, &scalars));
+  }
+
+  #[tokio::test]
+  async fn test_multi_exp_identity_and_zero() {
+      let (mut points, mut scalars) = random_inputs();
+      points[2] = BlsG1Affine::identity();
+      scalars[5] = BlsScalar::zero();
+      scalars[6] = BlsScalar::one();
+      let (res, _gas) = multi_exp_on_contract(&points, &scalars).await;
+
+      assert!(res == expected_multi_exp(&points, &scalars));
+  }
+
+  #[tokio::test]
+  async fn test_multi_exp_gas_against_naive() {
+      let (points, scalars) = random_inputs();
+      let (res, gas) = multi_exp_on_contract(&points, &scalars).await;
+      let (naive_res, naive_gas) = naive_multi_exp_on_contract(&points, &scalars).await;
+
+      println!("multi_exp of 8 points: {} gas, 8 naive multiplications: {} gas", gas, naive_gas);
+      assert!(res == naive_res);
+      assert!(gas < naive_gas);
+  }
+}
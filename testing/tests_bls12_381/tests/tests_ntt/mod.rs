@@ -0,0 +1,150 @@
+use crate::utils::{helpers::get_contract_instance, Scalar};
+use ark_bls12_381::Fr;
+use ark_ff::{Field, PrimeField};
+use ark_poly::{EvaluationDomain, Radix2EvaluationDomain};
+use fuels::{
+    prelude::*,
+    tx::{ConsensusParameters, ContractId},
+};
+
+// returns the Montgomery form of s, which is how the contract represents scalars
+fn to_contract_scalar(s: Fr) -> Scalar {
+  // R = 2^256 mod q
+  let r = Fr::from(2u64).pow([256]);
+  Scalar{ ls: (s * r).into_bigint().0 }
+}
+
+fn to_contract_scalars<const N: usize>(v: &[Fr]) -> [Scalar; N] {
+  v.iter().map(|s| to_contract_scalar(*s)).collect::<Vec<_>>().try_into().unwrap()
+}
+
+// The polynomial 1 + 2X + ... + 8X^7
+fn test_coeffs() -> Vec<Fr> {
+  (1..=8u64).map(Fr::from).collect()
+}
+
+fn domain_8() -> Radix2EvaluationDomain<Fr> {
+  Radix2EvaluationDomain::<Fr>::new(8).unwrap()
+}
+
+// The polynomial 1 + 2X + ... + 16X^15
+fn test_coeffs_16() -> Vec<Fr> {
+  (1..=16u64).map(Fr::from).collect()
+}
+
+fn domain_16() -> Radix2EvaluationDomain<Fr> {
+  Radix2EvaluationDomain::<Fr>::new(16).unwrap()
+}
+
+mod success {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_evaluation_domain_generator() {
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.evaluation_domain_generator(8)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+
+      assert!(res == to_contract_scalar(domain_8().group_gen));
+  }
+
+  #[tokio::test]
+  async fn test_ntt() {
+      let expected = domain_8().fft(&test_coeffs());
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.ntt_8(to_contract_scalars(&test_coeffs()))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+
+      assert!(res == to_contract_scalars(&expected));
+  }
+
+  #[tokio::test]
+  async fn test_intt() {
+      let evals = domain_8().fft(&test_coeffs());
+      let expected = domain_8().ifft(&evals);
+      // Interpolating the evaluations gives back the original polynomial
+      assert!(expected == test_coeffs());
+
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.intt_8(to_contract_scalars(&evals))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+
+      assert!(res == to_contract_scalars(&expected));
+  }
+
+  #[tokio::test]
+  async fn test_ntt_16() {
+      let expected = domain_16().fft(&test_coeffs_16());
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.ntt_16(to_contract_scalars(&test_coeffs_16()))
+          .tx_params(TxParameters::default().set_gas_limit(1_000_000_000))
+          .call().await.unwrap().value;
+
+      assert!(res == to_contract_scalars(&expected));
+  }
+
+  #[tokio::test]
+  async fn test_intt_16() {
+      let evals = domain_16().fft(&test_coeffs_16());
+      let expected = domain_16().ifft(&evals);
+      assert!(expected == test_coeffs_16());
+
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.intt_16(to_contract_scalars(&evals))
+          .tx_params(TxParameters::default().set_gas_limit(1_000_000_000))
+          .call().await.unwrap().value;
+
+      assert!(res == to_contract_scalars(&expected));
+  }
+
+  #[tokio::test]
+  async fn test_lagrange_coefficients() {
+      let tau = Fr::from(12345u64);
+      let expected = domain_8().evaluate_all_lagrange_coefficients(tau);
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.lagrange_coefficients_8(to_contract_scalar(tau))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+
+      assert!(res == to_contract_scalars(&expected));
+  }
+
+  #[tokio::test]
+  async fn test_lagrange_coefficients_in_domain() {
+      // For tau = generator^3, only the 3rd Lagrange polynomial is nonzero at tau
+      let tau = domain_8().element(3);
+      let expected = domain_8().evaluate_all_lagrange_coefficients(tau);
+      assert!(expected[3] == Fr::from(1u64));
+
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.lagrange_coefficients_8(to_contract_scalar(tau))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+
+      assert!(res == to_contract_scalars(&expected));
+  }
+}
+
+mod revert {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_evaluation_domain_not_power_of_two() {
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.evaluation_domain_generator(6)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await;
+      assert!(res.is_err());
+  }
+}
@@ -0,0 +1,167 @@
+use crate::utils::{helpers::get_contract_instance, Scalar};
+use fuels::{
+    prelude::*,
+    tx::{ConsensusParameters, ContractId},
+};
+
+// Test vectors generated with neptune 13.0.0 (https://github.com/lurk-lab/neptune), using
+// Poseidon::new_with_preimage and PoseidonConstants::new() for arity 2 and 4.
+// Scalars are given in Montgomery form, with the canonical value of the expected hashes above them.
+
+// returns the Montgomery form of the inputs used in the test vectors
+fn scalar(x: u64) -> Scalar {
+  match x {
+      0 => Scalar{ ls: [
+          0x0000_0000_0000_0000,
+          0x0000_0000_0000_0000,
+          0x0000_0000_0000_0000,
+          0x0000_0000_0000_0000,
+      ]},
+      1 => Scalar{ ls: [
+          0x0000_0001_ffff_fffe,
+          0x5884_b7fa_0003_4802,
+          0x998c_4fef_ecbc_4ff5,
+          0x1824_b159_acc5_056f,
+      ]},
+      2 => Scalar{ ls: [
+          0x0000_0003_ffff_fffc,
+          0xb109_6ff4_0006_9004,
+          0x3318_9fdf_d978_9fea,
+          0x3049_62b3_598a_0adf,
+      ]},
+      3 => Scalar{ ls: [
+          0x0000_0005_ffff_fffa,
+          0x098e_27ee_0009_d806,
+          0xcca4_efcf_c634_efe0,
+          0x486e_140d_064f_104e,
+      ]},
+      4 => Scalar{ ls: [
+          0x0000_0007_ffff_fff8,
+          0x6212_dfe8_000d_2008,
+          0x6631_3fbf_b2f1_3fd5,
+          0x6092_c566_b314_15be,
+      ]},
+      123456789 => Scalar{ ls: [
+          0x103f_ec96_efc0_1369,
+          0x3b34_98e3_f067_a897,
+          0xcb25_ae0c_9fd9_35bd,
+          0x2880_8dfe_eca4_5273,
+      ]},
+      987654321 => Scalar{ ls: [
+          0x81ff_64cd_7e00_9b32,
+          0xa758_aedd_8361_5cce,
+          0xf234_dfb4_2ae1_1d74,
+          0x4d98_0ed1_d199_cf69,
+      ]},
+      _ => panic!("no Montgomery form for {}", x),
+  }
+}
+
+// 0x48fe0b1331196f6cdb33a7c6e5af61b76fd388e1ef1d3d418be5147f0e4613d4
+fn expected_hash_2_0_0() -> Scalar {
+  Scalar{ ls: [
+      0x962d_b6db_54ea_e66c,
+      0xd0ba_fe04_6071_af8d,
+      0x2204_f0c9_d6dd_76da,
+      0x299f_cdf5_b798_aa4d,
+  ]}
+}
+
+// 0x6d6f8106657f1f4d7babcbaf436a9d7669c04e726e5896d89317d9833e5fa9be
+fn expected_hash_2_1_2() -> Scalar {
+  Scalar{ ls: [
+      0x5494_12de_4002_2d7c,
+      0x5ba9_6fa0_f90b_1dc3,
+      0xd8b8_782c_321d_3767,
+      0x2bb2_3503_780a_4ff4,
+  ]}
+}
+
+// 0x0b8bd82367d3769a9158b833c50ee02dd49e3fcf733ecbacd7a8169635e6cd9f
+fn expected_hash_2_large() -> Scalar {
+  Scalar{ ls: [
+      0x3b72_c48c_7683_a565,
+      0x95d3_dcdc_4607_277a,
+      0xf477_a86b_d7e9_661c,
+      0x516e_349e_322c_4d2a,
+  ]}
+}
+
+// 0x65cec475d81e7e5f0f13b878b866d3eef99a58932383621b64472e952b9711bc
+fn expected_hash_4_0_0_0_0() -> Scalar {
+  Scalar{ ls: [
+      0xfab5_8415_3720_028b,
+      0x15e1_6de9_be4b_f4a6,
+      0x1915_0316_fddf_67b0,
+      0x12ae_4638_3e7f_0193,
+  ]}
+}
+
+// 0x3d181224e2607dea961f35d9f769acb7cdefca33095ca2f3146437bcf428d9c5
+fn expected_hash_4_1_2_3_4() -> Scalar {
+  Scalar{ ls: [
+      0xef0d_53b2_0406_018f,
+      0xfd38_460a_fea5_21ea,
+      0xd9fd_957d_91bd_b3da,
+      0x7312_98c0_c73e_670c,
+  ]}
+}
+
+mod success {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_poseidon_hash_2_0_0() {
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.poseidon_hash_2([scalar(0), scalar(0)])
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+
+      assert!(res == expected_hash_2_0_0());
+  }
+
+  #[tokio::test]
+  async fn test_poseidon_hash_2_1_2() {
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.poseidon_hash_2([scalar(1), scalar(2)])
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+
+      assert!(res == expected_hash_2_1_2());
+  }
+
+  #[tokio::test]
+  async fn test_poseidon_hash_2_large() {
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.poseidon_hash_2([scalar(123456789), scalar(987654321)])
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+
+      assert!(res == expected_hash_2_large());
+  }
+
+  #[tokio::test]
+  async fn test_poseidon_hash_4_0_0_0_0() {
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.poseidon_hash_4([scalar(0), scalar(0), scalar(0), scalar(0)])
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+
+      assert!(res == expected_hash_4_0_0_0_0());
+  }
+
+  #[tokio::test]
+  async fn test_poseidon_hash_4_1_2_3_4() {
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.poseidon_hash_4([scalar(1), scalar(2), scalar(3), scalar(4)])
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+
+      assert!(res == expected_hash_4_1_2_3_4());
+  }
+}
@@ -4,6 +4,24 @@ use fuels::{
     tx::{ConsensusParameters, ContractId},
 };
 
+// -1 mod q, in Montgomery form
+fn minus_one() -> Scalar {
+  Scalar{ ls: [
+      0xffff_fffd_0000_0003,
+      0xfb38_ec08_fffb_13fc,
+      0x99ad_8818_1ce5_880f,
+      0x5bc8_f5f9_7cd8_77d8,
+  ]}
+}
+
+// q - 1 in little-endian bytes
+fn minus_one_bytes() -> [u8; 32] {
+  [
+      0, 0, 0, 0, 255, 255, 255, 255, 254, 91, 254, 255, 2, 164, 189, 83, 5, 216, 161, 9, 8,
+      216, 57, 51, 72, 125, 157, 41, 83, 167, 237, 115,
+  ]
+}
+
 mod success {
   use super::*;
 
@@ -14,27 +32,26 @@ mod success {
           0x53bd_a402_fffe_5bfe,
           0x3339_d808_09a1_d805,
           0x73ed_a753_299d_7d48,
-      ].to_vec()};
+      ]};
 
       let a_2 = Scalar{ ls: [
           0xffff_ffff_0000_0000,
           0x53bd_a402_fffe_5bfe,
           0x3339_d808_09a1_d805,
           0x73ed_a753_299d_7d48,
-      ].to_vec()};
+      ]};
 
       let expected_res = Scalar{ ls:[
               0xffff_fffe_ffff_ffff,
               0x53bd_a402_fffe_5bfe,
               0x3339_d808_09a1_d805,
               0x73ed_a753_299d_7d48,
-          ].to_vec()};
+          ]};
 
       let (contract_instance, _id) = get_contract_instance().await;
 
       let res = contract_instance.add_scalar(a, a_2)
-      .tx_params(TxParameters::new(None, Some(100_000_000), None))
-      .call_params(CallParameters::new(None, None, Some(100_000_000)))
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
       .call().await.unwrap().value;
       
       assert!(res == expected_res);
@@ -44,66 +61,273 @@ mod success {
           0x53bd_a402_fffe_5bfe,
           0x3339_d808_09a1_d805,
           0x73ed_a753_299d_7d48,
-      ].to_vec()};
+      ]};
 
-      let one = Scalar{ ls: [1,0,0,0].to_vec() };
+      let one = Scalar{ ls: [1,0,0,0] };
       let res_2 = contract_instance.add_scalar(a_3, one)
-      .tx_params(TxParameters::new(None, Some(100_000_000), None))
-      .call_params(CallParameters::new(None, None, Some(100_000_000)))
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
       .call().await.unwrap().value;
       
-      assert!(res_2 == Scalar{ ls: [0,0,0,0].to_vec() });
-  }
-
-  /*BLOCKED
-  error: Internal compiler error: Verification failed: Function anon_11103 return type must match its RET instructions.
-  Please file an issue on the repository and include the code that triggered this error.
-  */
-  // #[tokio::test]
-  // async fn test_sqrt() {
-  //     let zero = Scalar{ ls: [0,0,0,0].to_vec() };
-  //     let (contract_instance, _id) = get_contract_instance().await;
-  //     let square_root = contract_instance.scalar_sqrt(zero)
-  //         .tx_params(TxParameters::new(None, Some(100_000_000), None))
-  //         .call_params(CallParameters::new(None, None, Some(100_000_000)))
-  //         .call().await.unwrap().value;
-  //     assert_eq!(square_root, Scalar{ ls: [0,0,0,0].to_vec() });
-  // }
-
-
-  /*BLOCKED
-  error: Internal compiler error: Verification failed: Function anon_11103 return type must match its RET instructions.
-  Please file an issue on the repository and include the code that triggered this error.
-  */
-  // #[tokio::test]
-  // async fn test_sqrt() {
-  //     let mut square = Scalar{ ls:[
-  //         0x46cd_85a5_f273_077e,
-  //         0x1d30_c47d_d68f_c735,
-  //         0x77f6_56f6_0bec_a0eb,
-  //         0x494a_a01b_df32_468d,
-  //     ].to_vec()};
-
-  //     let mut none_count = 0;
-  //     let (contract_instance, _id) = get_contract_instance().await;
-
-
-  //     let j = 0;
-  //     while j < 101 {
-  //         let square_root = contract_instance.scalar_sqrt(square)
-  //             .tx_params(TxParameters::new(None, Some(100_000_000), None))
-  //             .call_params(CallParameters::new(None, None, Some(100_000_000)))
-  //             .call().await.unwrap().value;
-  //         // let square_root = square.sqrt();
-  //         if square_root.is_none() {
-  //             none_count += 1;
-  //         } else {
-  //             assert_eq!(square_root.unwrap() * square_root.unwrap(), square);
-  //         }
-  //         square -= Scalar::one();
-  //         j += 1;
-  //     }
-
-  //     assert_eq!(49, none_count);
-  // }
-}
\ No newline at end of file
+      assert!(res_2 == Scalar{ ls: [0,0,0,0] });
+  }
+
+  #[tokio::test]
+  async fn test_sqrt_zero() {
+      let zero = Scalar{ ls: [0,0,0,0] };
+      let (contract_instance, _id) = get_contract_instance().await;
+      let square_root = contract_instance.sqrt_scalar(zero)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert_eq!(square_root, Scalar{ ls: [0,0,0,0] });
+  }
+
+  #[tokio::test]
+  async fn test_sqrt_four() {
+      // 4
+      let four = Scalar{ ls: [
+          0x0000_0007_ffff_fff8,
+          0x6212_dfe8_000d_2008,
+          0x6631_3fbf_b2f1_3fd5,
+          0x6092_c566_b314_15be,
+      ]};
+      // 2
+      let two = Scalar{ ls: [
+          0x0000_0003_ffff_fffc,
+          0xb109_6ff4_0006_9004,
+          0x3318_9fdf_d978_9fea,
+          0x3049_62b3_598a_0adf,
+      ]};
+      // -2
+      let minus_two = Scalar{ ls: [
+          0xffff_fffb_0000_0005,
+          0xa2b4_340e_fff7_cbfa,
+          0x0021_3828_3029_381a,
+          0x43a4_449f_d013_7269,
+      ]};
+
+      let (contract_instance, _id) = get_contract_instance().await;
+      let square_root = contract_instance.sqrt_scalar(four)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(square_root == two || square_root == minus_two);
+  }
+
+  #[tokio::test]
+  async fn test_sqrt() {
+      // From the zkcrypto sqrt test, which starts at
+      // [0x46cd_85a5_f273_077e, 0x1d30_c47d_d68f_c735, 0x77f6_56f6_0bec_a0eb, 0x494a_a01b_df32_468d]
+      // and counts down. This is that value minus 2, which is a square.
+      let square = Scalar{ ls: [
+          0x46cd_85a1_f273_0782,
+          0x6c27_5489_d689_3731,
+          0x44dd_b716_3274_0100,
+          0x1901_3d68_85a8_3bae,
+      ]};
+      let root_1 = Scalar{ ls: [
+          0xdccb_0461_1926_07c4,
+          0x79ff_875f_effb_ea84,
+          0x83f8_d82e_fa4f_b8f8,
+          0x6701_be14_7d65_368d,
+      ]};
+      let root_2 = Scalar{ ls: [
+          0x2334_fb9d_e6d9_f83d,
+          0xd9be_1ca3_1002_717a,
+          0xaf40_ffd9_0f52_1f0c,
+          0x0ceb_e93e_ac38_46ba,
+      ]};
+
+      let (contract_instance, _id) = get_contract_instance().await;
+      let square_root = contract_instance.sqrt_scalar(square)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(square_root == root_1 || square_root == root_2);
+  }
+
+  #[tokio::test]
+  async fn test_pow_vartime() {
+      // 3
+      let three = Scalar{ ls: [
+          0x0000_0005_ffff_fffa,
+          0x098e_27ee_0009_d806,
+          0xcca4_efcf_c634_efe0,
+          0x486e_140d_064f_104e,
+      ]};
+      // 3^5 = 243
+      let expected = Scalar{ ls: [
+          0x0000_0217_ffff_fde8,
+          0xaaf0_99b8_036f_6218,
+          0xbee3_af2b_d523_b4d8,
+          0x4669_a9e0_de41_b0d4,
+      ]};
+
+      let (contract_instance, _id) = get_contract_instance().await;
+      let res = contract_instance.pow_vartime_scalar(three, [5, 0, 0, 0])
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(res == expected);
+  }
+  #[tokio::test]
+  async fn test_invert() {
+      // R^2 (as a raw value) has inverse 1 (as a raw value)
+      let r2 = Scalar{ ls: [
+          0xc999_e990_f3f2_9c6d,
+          0x2b6c_edcb_8792_5c23,
+          0x05d3_1496_7254_398f,
+          0x0748_d9d9_9f59_ff11,
+      ]};
+
+      let (contract_instance, _id) = get_contract_instance().await;
+      let res = contract_instance.invert_scalar(r2)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(res == Scalar{ ls: [1, 0, 0, 0] });
+
+      let res_2 = contract_instance.invert_scalar(minus_one())
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(res_2 == minus_one());
+  }
+
+  #[tokio::test]
+  async fn test_scalar_from_bytes() {
+      let (contract_instance, _id) = get_contract_instance().await;
+      let res = contract_instance.scalar_from_bytes(minus_one_bytes())
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(res == minus_one());
+
+      let res_2 = contract_instance.scalar_from_bytes([0u8; 32])
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(res_2 == Scalar{ ls: [0, 0, 0, 0] });
+  }
+
+  #[tokio::test]
+  async fn test_scalar_to_bytes() {
+      let (contract_instance, _id) = get_contract_instance().await;
+      let res = contract_instance.scalar_to_bytes(minus_one())
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(res == minus_one_bytes());
+  }
+
+  #[tokio::test]
+  async fn test_from_bytes_wide_r2() {
+      let mut bytes = [0u8; 64];
+      bytes[..32].copy_from_slice(&[
+          254, 255, 255, 255, 1, 0, 0, 0, 2, 72, 3, 0, 250, 183, 132, 88, 245, 79, 188, 236, 239,
+          79, 140, 153, 111, 5, 197, 172, 89, 177, 36, 24,
+      ]);
+      let r2 = Scalar{ ls: [
+          0xc999_e990_f3f2_9c6d,
+          0x2b6c_edcb_8792_5c23,
+          0x05d3_1496_7254_398f,
+          0x0748_d9d9_9f59_ff11,
+      ]};
+
+      let (contract_instance, _id) = get_contract_instance().await;
+      let res = contract_instance.scalar_from_bytes_wide(bytes)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(res == r2);
+  }
+
+  #[tokio::test]
+  async fn test_from_bytes_wide_negative_one() {
+      let mut bytes = [0u8; 64];
+      bytes[..32].copy_from_slice(&minus_one_bytes());
+
+      let (contract_instance, _id) = get_contract_instance().await;
+      let res = contract_instance.scalar_from_bytes_wide(bytes)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(res == minus_one());
+  }
+
+  #[tokio::test]
+  async fn test_from_bytes_wide_maximum() {
+      let expected = Scalar{ ls: [
+          0xc62c_1805_439b_73b1,
+          0xc2b9_551e_8ced_218e,
+          0xda44_ec81_daf9_a422,
+          0x5605_aa60_1c16_2e79,
+      ]};
+
+      let (contract_instance, _id) = get_contract_instance().await;
+      let res = contract_instance.scalar_from_bytes_wide([0xff; 64])
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(res == expected);
+  }
+}
+
+mod revert {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_sqrt_non_square() {
+      // From the zkcrypto sqrt test: the starting value itself is not a square
+      let non_square = Scalar{ ls: [
+          0x46cd_85a5_f273_077e,
+          0x1d30_c47d_d68f_c735,
+          0x77f6_56f6_0bec_a0eb,
+          0x494a_a01b_df32_468d,
+      ]};
+
+      let (contract_instance, _id) = get_contract_instance().await;
+      let res = contract_instance.sqrt_scalar(non_square)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await;
+      assert!(res.is_err());
+  }
+
+  #[tokio::test]
+  async fn test_sqrt_five() {
+      // 5 is a quadratic non-residue mod q
+      let five = Scalar{ ls: [
+          0x0000_000a_ffff_fff5,
+          0x66d9_f3df_0012_0c0b,
+          0xcc83_b7a7_960b_b7c5,
+          0x04c9_cf6d_363b_9de5,
+      ]};
+
+      let (contract_instance, _id) = get_contract_instance().await;
+      let res = contract_instance.sqrt_scalar(five)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await;
+      assert!(res.is_err());
+  }
+
+  #[tokio::test]
+  async fn test_invert_zero() {
+      let (contract_instance, _id) = get_contract_instance().await;
+      let res = contract_instance.invert_scalar(Scalar{ ls: [0, 0, 0, 0] })
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await;
+      assert!(res.is_err());
+  }
+
+  #[tokio::test]
+  async fn test_scalar_from_bytes_modulus() {
+      // q itself is not a canonical encoding
+      let modulus_bytes = [
+          1, 0, 0, 0, 255, 255, 255, 255, 254, 91, 254, 255, 2, 164, 189, 83, 5, 216, 161, 9, 8,
+          216, 57, 51, 72, 125, 157, 41, 83, 167, 237, 115,
+      ];
+
+      let (contract_instance, _id) = get_contract_instance().await;
+      let res = contract_instance.scalar_from_bytes(modulus_bytes)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await;
+      assert!(res.is_err());
+  }
+
+  #[tokio::test]
+  async fn test_scalar_from_bytes_max() {
+      let (contract_instance, _id) = get_contract_instance().await;
+      let res = contract_instance.scalar_from_bytes([0xff; 32])
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await;
+      assert!(res.is_err());
+  }
+}
@@ -0,0 +1,300 @@
+use crate::utils::{helpers::get_contract_instance, Fp, Fp2, G1Affine, G2Affine, Choice};
+use fuels::{
+    prelude::*,
+    tx::{ConsensusParameters, ContractId},
+};
+
+// Zcash serialization format, see https://github.com/zkcrypto/pairing/tree/master/src/bls12_381#serialization
+
+fn g1_generator() -> G1Affine {
+  G1Affine {
+      x: Fp{ls: [
+          0x5cb3_8790_fd53_0c16,
+          0x7817_fc67_9976_fff5,
+          0x154f_95c7_143b_a1c1,
+          0xf0ae_6acd_f3d0_e747,
+          0xedce_6ecc_21db_f440,
+          0x1201_7741_9e0b_fb75,
+      ]},
+      y: Fp{ls: [
+          0xbaac_93d5_0ce7_2271,
+          0x8c22_631a_7918_fd8e,
+          0xdd59_5f13_5707_25ce,
+          0x51ac_5829_5040_5194,
+          0x0e1c_8c3f_ad00_59c0,
+          0x0bbc_3efc_5008_a26a,
+      ]},
+      infinity: Choice{ c: 0 },
+  }
+}
+
+fn g2_generator() -> G2Affine {
+  G2Affine {
+      x: Fp2 {
+          c_0: Fp{ls: [
+              0xf5f2_8fa2_0294_0a10,
+              0xb3f5_fb26_87b4_961a,
+              0xa1a8_93b5_3e2a_e580,
+              0x9894_999d_1a3c_aee9,
+              0x6f67_b763_1863_366b,
+              0x0581_9192_4350_bcd7,
+          ]},
+          c_1: Fp{ls: [
+              0xa5a9_c075_9e23_f606,
+              0xaaa0_c59d_bccd_60c3,
+              0x3bb1_7e18_e286_7806,
+              0x1b1a_b6cc_8541_b367,
+              0xc2b6_ed0e_f215_8547,
+              0x1192_2a09_7360_edf3,
+          ]},
+      },
+      y: Fp2 {
+          c_0: Fp{ls: [
+              0x4c73_0af8_6049_4c4a,
+              0x597c_fa1f_5e36_9c5a,
+              0xe7e6_856c_aa0a_635a,
+              0xbbef_b5e9_6e0d_495f,
+              0x07d3_a975_f0ef_25a2,
+              0x0083_fd8e_7e80_dae5,
+          ]},
+          c_1: Fp{ls: [
+              0xadc0_fc92_df64_b05d,
+              0x18aa_270a_2b14_61dc,
+              0x86ad_ac6a_3be4_eba0,
+              0x7949_5c4e_c93d_a33a,
+              0xe717_5850_a43c_caed,
+              0x0b2b_c2a1_63de_1bf2,
+          ]},
+      },
+      infinity: Choice{ c: 0 },
+  }
+}
+
+fn g1_generator_compressed() -> [u8; 48] {
+  [
+      0x97, 0xf1, 0xd3, 0xa7, 0x31, 0x97, 0xd7, 0x94, 0x26, 0x95, 0x63, 0x8c,
+      0x4f, 0xa9, 0xac, 0x0f, 0xc3, 0x68, 0x8c, 0x4f, 0x97, 0x74, 0xb9, 0x05,
+      0xa1, 0x4e, 0x3a, 0x3f, 0x17, 0x1b, 0xac, 0x58, 0x6c, 0x55, 0xe8, 0x3f,
+      0xf9, 0x7a, 0x1a, 0xef, 0xfb, 0x3a, 0xf0, 0x0a, 0xdb, 0x22, 0xc6, 0xbb,
+  ]
+}
+
+fn g1_generator_uncompressed() -> [u8; 96] {
+  [
+      0x17, 0xf1, 0xd3, 0xa7, 0x31, 0x97, 0xd7, 0x94, 0x26, 0x95, 0x63, 0x8c,
+      0x4f, 0xa9, 0xac, 0x0f, 0xc3, 0x68, 0x8c, 0x4f, 0x97, 0x74, 0xb9, 0x05,
+      0xa1, 0x4e, 0x3a, 0x3f, 0x17, 0x1b, 0xac, 0x58, 0x6c, 0x55, 0xe8, 0x3f,
+      0xf9, 0x7a, 0x1a, 0xef, 0xfb, 0x3a, 0xf0, 0x0a, 0xdb, 0x22, 0xc6, 0xbb,
+      0x08, 0xb3, 0xf4, 0x81, 0xe3, 0xaa, 0xa0, 0xf1, 0xa0, 0x9e, 0x30, 0xed,
+      0x74, 0x1d, 0x8a, 0xe4, 0xfc, 0xf5, 0xe0, 0x95, 0xd5, 0xd0, 0x0a, 0xf6,
+      0x00, 0xdb, 0x18, 0xcb, 0x2c, 0x04, 0xb3, 0xed, 0xd0, 0x3c, 0xc7, 0x44,
+      0xa2, 0x88, 0x8a, 0xe4, 0x0c, 0xaa, 0x23, 0x29, 0x46, 0xc5, 0xe7, 0xe1,
+  ]
+}
+
+fn g2_generator_compressed() -> [u8; 96] {
+  [
+      0x93, 0xe0, 0x2b, 0x60, 0x52, 0x71, 0x9f, 0x60, 0x7d, 0xac, 0xd3, 0xa0,
+      0x88, 0x27, 0x4f, 0x65, 0x59, 0x6b, 0xd0, 0xd0, 0x99, 0x20, 0xb6, 0x1a,
+      0xb5, 0xda, 0x61, 0xbb, 0xdc, 0x7f, 0x50, 0x49, 0x33, 0x4c, 0xf1, 0x12,
+      0x13, 0x94, 0x5d, 0x57, 0xe5, 0xac, 0x7d, 0x05, 0x5d, 0x04, 0x2b, 0x7e,
+      0x02, 0x4a, 0xa2, 0xb2, 0xf0, 0x8f, 0x0a, 0x91, 0x26, 0x08, 0x05, 0x27,
+      0x2d, 0xc5, 0x10, 0x51, 0xc6, 0xe4, 0x7a, 0xd4, 0xfa, 0x40, 0x3b, 0x02,
+      0xb4, 0x51, 0x0b, 0x64, 0x7a, 0xe3, 0xd1, 0x77, 0x0b, 0xac, 0x03, 0x26,
+      0xa8, 0x05, 0xbb, 0xef, 0xd4, 0x80, 0x56, 0xc8, 0xc1, 0x21, 0xbd, 0xb8,
+  ]
+}
+
+fn g2_generator_uncompressed() -> [u8; 192] {
+  [
+      0x13, 0xe0, 0x2b, 0x60, 0x52, 0x71, 0x9f, 0x60, 0x7d, 0xac, 0xd3, 0xa0,
+      0x88, 0x27, 0x4f, 0x65, 0x59, 0x6b, 0xd0, 0xd0, 0x99, 0x20, 0xb6, 0x1a,
+      0xb5, 0xda, 0x61, 0xbb, 0xdc, 0x7f, 0x50, 0x49, 0x33, 0x4c, 0xf1, 0x12,
+      0x13, 0x94, 0x5d, 0x57, 0xe5, 0xac, 0x7d, 0x05, 0x5d, 0x04, 0x2b, 0x7e,
+      0x02, 0x4a, 0xa2, 0xb2, 0xf0, 0x8f, 0x0a, 0x91, 0x26, 0x08, 0x05, 0x27,
+      0x2d, 0xc5, 0x10, 0x51, 0xc6, 0xe4, 0x7a, 0xd4, 0xfa, 0x40, 0x3b, 0x02,
+      0xb4, 0x51, 0x0b, 0x64, 0x7a, 0xe3, 0xd1, 0x77, 0x0b, 0xac, 0x03, 0x26,
+      0xa8, 0x05, 0xbb, 0xef, 0xd4, 0x80, 0x56, 0xc8, 0xc1, 0x21, 0xbd, 0xb8,
+      0x06, 0x06, 0xc4, 0xa0, 0x2e, 0xa7, 0x34, 0xcc, 0x32, 0xac, 0xd2, 0xb0,
+      0x2b, 0xc2, 0x8b, 0x99, 0xcb, 0x3e, 0x28, 0x7e, 0x85, 0xa7, 0x63, 0xaf,
+      0x26, 0x74, 0x92, 0xab, 0x57, 0x2e, 0x99, 0xab, 0x3f, 0x37, 0x0d, 0x27,
+      0x5c, 0xec, 0x1d, 0xa1, 0xaa, 0xa9, 0x07, 0x5f, 0xf0, 0x5f, 0x79, 0xbe,
+      0x0c, 0xe5, 0xd5, 0x27, 0x72, 0x7d, 0x6e, 0x11, 0x8c, 0xc9, 0xcd, 0xc6,
+      0xda, 0x2e, 0x35, 0x1a, 0xad, 0xfd, 0x9b, 0xaa, 0x8c, 0xbd, 0xd3, 0xa7,
+      0x6d, 0x42, 0x9a, 0x69, 0x51, 0x60, 0xd1, 0x2c, 0x92, 0x3a, 0xc9, 0xcc,
+      0x3b, 0xac, 0xa2, 0x89, 0xe1, 0x93, 0x54, 0x86, 0x08, 0xb8, 0x28, 0x01,
+  ]
+}
+
+mod success {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_g1_to_compressed() {
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.g1_to_compressed(g1_generator())
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap().value;
+
+      assert!(res == g1_generator_compressed());
+  }
+
+  #[tokio::test]
+  async fn test_g1_identity_to_compressed() {
+      let identity = G1Affine {
+          x: Fp{ls: [0, 0, 0, 0, 0, 0]},
+          y: Fp{ls: [
+              0x7609_0000_0002_fffd,
+              0xebf4_000b_c40c_0002,
+              0x5f48_9857_53c7_58ba,
+              0x77ce_5853_7052_5745,
+              0x5c07_1a97_a256_ec6d,
+              0x15f6_5ec3_fa80_e493,
+          ]},
+          infinity: Choice{ c: 1 },
+      };
+      let mut expected = [0u8; 48];
+      expected[0] = 0xc0;
+
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.g1_to_compressed(identity)
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap().value;
+
+      assert!(res == expected);
+  }
+
+  #[tokio::test]
+  async fn test_g1_from_compressed() {
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.g1_from_compressed(g1_generator_compressed())
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap().value;
+
+      assert!(res == g1_generator());
+  }
+
+  #[tokio::test]
+  async fn test_g1_to_uncompressed() {
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.g1_to_uncompressed(g1_generator())
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap().value;
+
+      assert!(res == g1_generator_uncompressed());
+  }
+
+  #[tokio::test]
+  async fn test_g1_from_uncompressed() {
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.g1_from_uncompressed(g1_generator_uncompressed())
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap().value;
+
+      assert!(res == g1_generator());
+  }
+
+  #[tokio::test]
+  async fn test_g2_to_compressed() {
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.g2_to_compressed(g2_generator())
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap().value;
+
+      assert!(res == g2_generator_compressed());
+  }
+
+  #[tokio::test]
+  async fn test_g2_from_compressed() {
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.g2_from_compressed(g2_generator_compressed())
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap().value;
+
+      assert!(res == g2_generator());
+  }
+
+  #[tokio::test]
+  async fn test_g2_to_uncompressed() {
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.g2_to_uncompressed(g2_generator())
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap().value;
+
+      assert!(res == g2_generator_uncompressed());
+  }
+
+  #[tokio::test]
+  async fn test_g2_from_uncompressed() {
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.g2_from_uncompressed(g2_generator_uncompressed())
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap().value;
+
+      assert!(res == g2_generator());
+  }
+}
+
+mod revert {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_g1_from_compressed_without_compression_flag() {
+      // Clear the compression flag
+      let mut bytes = g1_generator_compressed();
+      bytes[0] &= 0b0111_1111;
+
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.g1_from_compressed(bytes)
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await;
+
+      assert!(res.is_err());
+  }
+
+  #[tokio::test]
+  async fn test_g1_from_compressed_not_on_curve() {
+      // x = 1 is not the x-coordinate of a point on the curve, since 5 is not a square in Fp
+      let mut bytes = [0u8; 48];
+      bytes[0] = 0x80;
+      bytes[47] = 1;
+
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.g1_from_compressed(bytes)
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await;
+
+      assert!(res.is_err());
+  }
+
+  #[tokio::test]
+  async fn test_g1_from_uncompressed_non_canonical() {
+      // Set the x-coordinate to p, which is not a canonical field element
+      let mut bytes = g1_generator_uncompressed();
+      bytes[..48].copy_from_slice(&[
+          0x1a, 0x01, 0x11, 0xea, 0x39, 0x7f, 0xe6, 0x9a, 0x4b, 0x1b, 0xa7, 0xb6,
+          0x43, 0x4b, 0xac, 0xd7, 0x64, 0x77, 0x4b, 0x84, 0xf3, 0x85, 0x12, 0xbf,
+          0x67, 0x30, 0xd2, 0xa0, 0xf6, 0xb0, 0xf6, 0x24, 0x1e, 0xab, 0xff, 0xfe,
+          0xb1, 0x53, 0xff, 0xff, 0xb9, 0xfe, 0xff, 0xff, 0xff, 0xff, 0xaa, 0xab,
+      ]);
+
+      let (contract_instance, _id) = get_contract_instance().await;
+
+      let res = contract_instance.g1_from_uncompressed(bytes)
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await;
+
+      assert!(res.is_err());
+  }
+}
@@ -4,8 +4,16 @@ use edwards25519::{field_element::Element,
     field_element::multiply64, 
     field_element::add_multiply64, 
     field_element::shift_right_by51, 
-    ge25519::*};
-use std::u128::U128;
+    ge25519::*,
+    scalar::Scalar,
+    sha512::sha512,
+    ed25519::ed25519_verify,
+    x25519::x25519,
+    ristretto::RistrettoPoint,
+    keccak::keccak_f1600,
+    sr25519::sr25519_verify};
+use utils::{choice::CtOption, ff::{Field, PrimeField}, group::Group};
+use std::{u128::U128, vec::Vec};
 
 abi EdwardsTestContract {
     // Light functions, all OK
@@ -21,11 +29,150 @@ abi EdwardsTestContract {
     #[storage()]fn scalar_mult(a: Element, x: u32) -> Element;
     #[storage()]fn shift_right_by51(a: U128) -> u64;
 
-    // Can't test yet because of Immediate18TooLarge
     #[storage()]fn inverse(a: Element) -> Element;
 
-    // Can't compile
-    // #[storage()]fn dbl_p1p1(p: ge25519_p2) -> ge25519_p1p1;
+    #[storage()]fn from_bytes(bytes: [u8; 32]) -> Element;
+    #[storage()]fn to_bytes(a: Element) -> [u8; 32];
+    #[storage()]fn pow22523(a: Element) -> Element;
+    #[storage()]fn sqrt_ratio_i(u: Element, v: Element) -> (bool, Element);
+    #[storage()]fn is_negative(a: Element) -> bool;
+    #[storage()]fn is_zero(a: Element) -> bool;
+
+    #[storage()]fn ge25519_identity() -> ge25519;
+    #[storage()]fn ge25519_add(p: ge25519, q: ge25519) -> ge25519;
+    #[storage()]fn ge25519_double(p: ge25519) -> ge25519;
+    #[storage()]fn ge25519_neg(p: ge25519) -> ge25519;
+    #[storage()]fn ge25519_eq(p: ge25519, q: ge25519) -> bool;
+    #[storage()]fn ge25519_scalarmult(p: ge25519, s: [u8; 32]) -> ge25519;
+    #[storage()]fn ge25519_scalarmult_base(s: [u8; 32]) -> ge25519;
+
+    #[storage()]fn scalar_from_bytes_mod_order_wide(bytes: [u8; 64]) -> [u8; 32];
+    #[storage()]fn scalar_from_bytes_mod_order(bytes: [u8; 32]) -> [u8; 32];
+    #[storage()]fn scalar_is_canonical(bytes: [u8; 32]) -> bool;
+    #[storage()]fn scalar_add(a: [u8; 32], b: [u8; 32]) -> [u8; 32];
+    #[storage()]fn scalar_mul(a: [u8; 32], b: [u8; 32]) -> [u8; 32];
+    #[storage()]fn scalar_eq(a: [u8; 32], b: [u8; 32]) -> bool;
+
+    #[storage()]fn pack(p: ge25519) -> [u8; 32];
+    #[storage()]fn unpack(bytes: [u8; 32]) -> ge25519;
+
+    #[storage()]fn sha512(msg: Vec<u8>) -> [u8; 64];
+    #[storage()]fn ed25519_verify(pubkey: [u8; 32], msg: Vec<u8>, sig: [u8; 64]) -> bool;
+
+    #[storage()]fn x25519(scalar: [u8; 32], u: [u8; 32]) -> [u8; 32];
+
+    #[storage()]fn ristretto_basepoint_mul(s: [u8; 32]) -> [u8; 32];
+    #[storage()]fn ristretto_decode_encode(bytes: [u8; 32]) -> [u8; 32];
+    #[storage()]fn ristretto_from_uniform_bytes(bytes: [u8; 64]) -> [u8; 32];
+    #[storage()]fn ristretto_eq(a: [u8; 32], b: [u8; 32]) -> bool;
+    #[storage()]fn ristretto_add(a: [u8; 32], b: [u8; 32]) -> [u8; 32];
+
+    #[storage()]fn keccak_f1600(state: [u64; 25]) -> [u64; 25];
+    #[storage()]fn sr25519_verify(pubkey: [u8; 32], context: Vec<u8>, msg: Vec<u8>, sig: [u8; 64]) -> bool;
+
+    #[storage()]fn dbl_p1p1(p: ge25519_p2) -> ge25519_p1p1;
+    #[storage()]fn p1p1_to_p2(p: ge25519_p1p1) -> ge25519_p2;
+
+    // These go through the generic functions below, so they test the Group, Field and PrimeField impls.
+    // Points are given and returned compressed, field elements and scalars as their canonical little-endian encoding
+    #[storage()]fn ge25519_group_generator() -> [u8; 32];
+    #[storage()]fn ge25519_group_is_identity(p: [u8; 32]) -> bool;
+    #[storage()]fn ge25519_group_double(p: [u8; 32]) -> [u8; 32];
+    #[storage()]fn ge25519_group_sub(p1: [u8; 32], p2: [u8; 32]) -> [u8; 32];
+    #[storage()]fn ge25519_group_neg(p: [u8; 32]) -> [u8; 32];
+    #[storage()]fn ge25519_group_mul_scalar(p: [u8; 32], k: [u8; 32]) -> [u8; 32];
+    #[storage()]fn ristretto_group_generator() -> [u8; 32];
+    #[storage()]fn ristretto_group_is_identity(p: [u8; 32]) -> bool;
+    #[storage()]fn ristretto_group_double(p: [u8; 32]) -> [u8; 32];
+    #[storage()]fn ristretto_group_sub(p1: [u8; 32], p2: [u8; 32]) -> [u8; 32];
+    #[storage()]fn ristretto_group_neg(p: [u8; 32]) -> [u8; 32];
+    #[storage()]fn ristretto_group_mul_scalar(p: [u8; 32], k: [u8; 32]) -> [u8; 32];
+    #[storage()]fn element_field_square(a: [u8; 32]) -> [u8; 32];
+    #[storage()]fn element_field_double(a: [u8; 32]) -> [u8; 32];
+    // reverts if a is zero
+    #[storage()]fn element_field_invert(a: [u8; 32]) -> [u8; 32];
+    // reverts if a is not a square
+    #[storage()]fn element_field_sqrt(a: [u8; 32]) -> [u8; 32];
+    // reverts if the encoding is not canonical
+    #[storage()]fn element_from_repr(bytes: [u8; 32]) -> [u8; 32];
+    #[storage()]fn element_is_odd(a: [u8; 32]) -> bool;
+    #[storage()]fn scalar_field_square(a: [u8; 32]) -> [u8; 32];
+    #[storage()]fn scalar_field_double(a: [u8; 32]) -> [u8; 32];
+    // reverts if a is zero
+    #[storage()]fn scalar_field_invert(a: [u8; 32]) -> [u8; 32];
+    // reverts if a is not a square
+    #[storage()]fn scalar_field_sqrt(a: [u8; 32]) -> [u8; 32];
+    // reverts if the encoding is not canonical
+    #[storage()]fn scalar_from_repr(bytes: [u8; 32]) -> [u8; 32];
+    #[storage()]fn scalar_is_odd(a: [u8; 32]) -> bool;
+}
+
+fn group_generator<G, S>() -> G where G: Group<S> {
+    G::generator()
+}
+
+fn group_is_identity<G, S>(p: G) -> bool where G: Group<S> {
+    p.is_identity().unwrap_as_bool()
+}
+
+fn group_double<G, S>(p: G) -> G where G: Group<S> {
+    p.double()
+}
+
+fn group_sub<G, S>(p1: G, p2: G) -> G where G: Group<S> {
+    p1 - p2
+}
+
+fn group_neg<G, S>(p: G) -> G where G: Group<S> {
+    p.neg()
+}
+
+fn group_mul_scalar<G, S>(p: G, k: S) -> G where G: Group<S> {
+    p.mul_scalar(k)
+}
+
+fn field_square<F>(a: F) -> F where F: Field {
+    a.square()
+}
+
+fn field_double<F>(a: F) -> F where F: Field {
+    a.double()
+}
+
+fn field_invert<F>(a: F) -> CtOption<F> where F: Field {
+    a.invert()
+}
+
+fn field_sqrt<F>(a: F) -> CtOption<F> where F: Field {
+    a.sqrt()
+}
+
+fn prime_field_from_repr<F>(bytes: [u8; 32]) -> CtOption<F> where F: PrimeField {
+    F::from_repr(bytes)
+}
+
+fn prime_field_to_repr<F>(a: F) -> [u8; 32] where F: PrimeField {
+    a.to_repr()
+}
+
+fn prime_field_is_odd<F>(a: F) -> bool where F: PrimeField {
+    a.is_odd().unwrap_as_bool()
+}
+
+fn ge(bytes: [u8; 32]) -> ge25519 {
+    unpack(bytes).unwrap()
+}
+
+fn ristretto(bytes: [u8; 32]) -> RistrettoPoint {
+    RistrettoPoint::decode(bytes).unwrap()
+}
+
+fn element(bytes: [u8; 32]) -> Element {
+    prime_field_from_repr::<Element>(bytes).unwrap()
+}
+
+fn scalar(bytes: [u8; 32]) -> Scalar {
+    prime_field_from_repr::<Scalar>(bytes).unwrap()
 }
 
 impl EdwardsTestContract for Contract {
@@ -77,7 +224,239 @@ impl EdwardsTestContract for Contract {
         a.inverse()
     }
 
-    // #[storage()]fn dbl_p1p1(p: ge25519_p2) -> ge25519_p1p1 {
-    //     dbl_p1p1(p)
-    // }
+    #[storage()]fn from_bytes(bytes: [u8; 32]) -> Element {
+        Element::from_bytes(bytes)
+    }
+
+    #[storage()]fn to_bytes(a: Element) -> [u8; 32] {
+        a.to_bytes()
+    }
+
+    #[storage()]fn pow22523(a: Element) -> Element {
+        a.pow22523()
+    }
+
+    #[storage()]fn sqrt_ratio_i(u: Element, v: Element) -> (bool, Element) {
+        let (was_square, r) = Element::sqrt_ratio_i(u, v);
+        (was_square.unwrap_as_bool(), r)
+    }
+
+    #[storage()]fn is_negative(a: Element) -> bool {
+        a.is_negative().unwrap_as_bool()
+    }
+
+    #[storage()]fn is_zero(a: Element) -> bool {
+        a.is_zero().unwrap_as_bool()
+    }
+
+    #[storage()]fn ge25519_identity() -> ge25519 {
+        ge25519_identity()
+    }
+
+    #[storage()]fn ge25519_add(p: ge25519, q: ge25519) -> ge25519 {
+        p + q
+    }
+
+    #[storage()]fn ge25519_double(p: ge25519) -> ge25519 {
+        ge25519_double(p)
+    }
+
+    #[storage()]fn ge25519_neg(p: ge25519) -> ge25519 {
+        ge25519_neg(p)
+    }
+
+    #[storage()]fn ge25519_eq(p: ge25519, q: ge25519) -> bool {
+        p == q
+    }
+
+    #[storage()]fn ge25519_scalarmult(p: ge25519, s: [u8; 32]) -> ge25519 {
+        ge25519_scalarmult(p, Scalar::from_bits(s))
+    }
+
+    #[storage()]fn ge25519_scalarmult_base(s: [u8; 32]) -> ge25519 {
+        ge25519_scalarmult_base(Scalar::from_bits(s))
+    }
+
+    #[storage()]fn scalar_from_bytes_mod_order_wide(bytes: [u8; 64]) -> [u8; 32] {
+        Scalar::from_bytes_mod_order_wide(bytes).to_bytes()
+    }
+
+    #[storage()]fn scalar_from_bytes_mod_order(bytes: [u8; 32]) -> [u8; 32] {
+        Scalar::from_bytes_mod_order(bytes).to_bytes()
+    }
+
+    #[storage()]fn scalar_is_canonical(bytes: [u8; 32]) -> bool {
+        Scalar::is_canonical(bytes).unwrap_as_bool()
+    }
+
+    #[storage()]fn scalar_add(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        let a = Scalar::from_canonical_bytes(a).unwrap();
+        let b = Scalar::from_canonical_bytes(b).unwrap();
+        (a + b).to_bytes()
+    }
+
+    #[storage()]fn scalar_mul(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        let a = Scalar::from_canonical_bytes(a).unwrap();
+        let b = Scalar::from_canonical_bytes(b).unwrap();
+        (a * b).to_bytes()
+    }
+
+    #[storage()]fn scalar_eq(a: [u8; 32], b: [u8; 32]) -> bool {
+        Scalar::from_canonical_bytes(a).unwrap() == Scalar::from_canonical_bytes(b).unwrap()
+    }
+
+    #[storage()]fn pack(p: ge25519) -> [u8; 32] {
+        pack(p)
+    }
+
+    #[storage()]fn unpack(bytes: [u8; 32]) -> ge25519 {
+        unpack(bytes).unwrap()
+    }
+
+    #[storage()]fn sha512(msg: Vec<u8>) -> [u8; 64] {
+        sha512(msg)
+    }
+
+    #[storage()]fn ed25519_verify(pubkey: [u8; 32], msg: Vec<u8>, sig: [u8; 64]) -> bool {
+        ed25519_verify(pubkey, msg, sig)
+    }
+
+    #[storage()]fn x25519(scalar: [u8; 32], u: [u8; 32]) -> [u8; 32] {
+        x25519(scalar, u)
+    }
+
+    #[storage()]fn ristretto_basepoint_mul(s: [u8; 32]) -> [u8; 32] {
+        let p = RistrettoPoint { point: ge25519_scalarmult_base(Scalar::from_bits(s)) };
+        p.encode()
+    }
+
+    #[storage()]fn ristretto_decode_encode(bytes: [u8; 32]) -> [u8; 32] {
+        RistrettoPoint::decode(bytes).unwrap().encode()
+    }
+
+    #[storage()]fn ristretto_from_uniform_bytes(bytes: [u8; 64]) -> [u8; 32] {
+        RistrettoPoint::from_uniform_bytes(bytes).encode()
+    }
+
+    #[storage()]fn ristretto_eq(a: [u8; 32], b: [u8; 32]) -> bool {
+        RistrettoPoint::decode(a).unwrap() == RistrettoPoint::decode(b).unwrap()
+    }
+
+    #[storage()]fn ristretto_add(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        (RistrettoPoint::decode(a).unwrap() + RistrettoPoint::decode(b).unwrap()).encode()
+    }
+
+    #[storage()]fn keccak_f1600(state: [u64; 25]) -> [u64; 25] {
+        let mut res = state;
+        keccak_f1600(res);
+        res
+    }
+
+    #[storage()]fn sr25519_verify(pubkey: [u8; 32], context: Vec<u8>, msg: Vec<u8>, sig: [u8; 64]) -> bool {
+        sr25519_verify(pubkey, context, msg, sig)
+    }
+
+    #[storage()]fn dbl_p1p1(p: ge25519_p2) -> ge25519_p1p1 {
+        dbl_p1p1(p)
+    }
+
+    #[storage()]fn p1p1_to_p2(p: ge25519_p1p1) -> ge25519_p2 {
+        p1p1_to_p2(p)
+    }
+
+    #[storage()]fn ge25519_group_generator() -> [u8; 32] {
+        pack(group_generator::<ge25519, Scalar>())
+    }
+
+    #[storage()]fn ge25519_group_is_identity(p: [u8; 32]) -> bool {
+        group_is_identity::<ge25519, Scalar>(ge(p))
+    }
+
+    #[storage()]fn ge25519_group_double(p: [u8; 32]) -> [u8; 32] {
+        pack(group_double::<ge25519, Scalar>(ge(p)))
+    }
+
+    #[storage()]fn ge25519_group_sub(p1: [u8; 32], p2: [u8; 32]) -> [u8; 32] {
+        pack(group_sub::<ge25519, Scalar>(ge(p1), ge(p2)))
+    }
+
+    #[storage()]fn ge25519_group_neg(p: [u8; 32]) -> [u8; 32] {
+        pack(group_neg::<ge25519, Scalar>(ge(p)))
+    }
+
+    #[storage()]fn ge25519_group_mul_scalar(p: [u8; 32], k: [u8; 32]) -> [u8; 32] {
+        pack(group_mul_scalar(ge(p), scalar(k)))
+    }
+
+    #[storage()]fn ristretto_group_generator() -> [u8; 32] {
+        group_generator::<RistrettoPoint, Scalar>().encode()
+    }
+
+    #[storage()]fn ristretto_group_is_identity(p: [u8; 32]) -> bool {
+        group_is_identity::<RistrettoPoint, Scalar>(ristretto(p))
+    }
+
+    #[storage()]fn ristretto_group_double(p: [u8; 32]) -> [u8; 32] {
+        group_double::<RistrettoPoint, Scalar>(ristretto(p)).encode()
+    }
+
+    #[storage()]fn ristretto_group_sub(p1: [u8; 32], p2: [u8; 32]) -> [u8; 32] {
+        group_sub::<RistrettoPoint, Scalar>(ristretto(p1), ristretto(p2)).encode()
+    }
+
+    #[storage()]fn ristretto_group_neg(p: [u8; 32]) -> [u8; 32] {
+        group_neg::<RistrettoPoint, Scalar>(ristretto(p)).encode()
+    }
+
+    #[storage()]fn ristretto_group_mul_scalar(p: [u8; 32], k: [u8; 32]) -> [u8; 32] {
+        group_mul_scalar(ristretto(p), scalar(k)).encode()
+    }
+
+    #[storage()]fn element_field_square(a: [u8; 32]) -> [u8; 32] {
+        prime_field_to_repr(field_square(element(a)))
+    }
+
+    #[storage()]fn element_field_double(a: [u8; 32]) -> [u8; 32] {
+        prime_field_to_repr(field_double(element(a)))
+    }
+
+    #[storage()]fn element_field_invert(a: [u8; 32]) -> [u8; 32] {
+        prime_field_to_repr(field_invert(element(a)).unwrap())
+    }
+
+    #[storage()]fn element_field_sqrt(a: [u8; 32]) -> [u8; 32] {
+        prime_field_to_repr(field_sqrt(element(a)).unwrap())
+    }
+
+    #[storage()]fn element_from_repr(bytes: [u8; 32]) -> [u8; 32] {
+        prime_field_to_repr(element(bytes))
+    }
+
+    #[storage()]fn element_is_odd(a: [u8; 32]) -> bool {
+        prime_field_is_odd(element(a))
+    }
+
+    #[storage()]fn scalar_field_square(a: [u8; 32]) -> [u8; 32] {
+        prime_field_to_repr(field_square(scalar(a)))
+    }
+
+    #[storage()]fn scalar_field_double(a: [u8; 32]) -> [u8; 32] {
+        prime_field_to_repr(field_double(scalar(a)))
+    }
+
+    #[storage()]fn scalar_field_invert(a: [u8; 32]) -> [u8; 32] {
+        prime_field_to_repr(field_invert(scalar(a)).unwrap())
+    }
+
+    #[storage()]fn scalar_field_sqrt(a: [u8; 32]) -> [u8; 32] {
+        prime_field_to_repr(field_sqrt(scalar(a)).unwrap())
+    }
+
+    #[storage()]fn scalar_from_repr(bytes: [u8; 32]) -> [u8; 32] {
+        prime_field_to_repr(scalar(bytes))
+    }
+
+    #[storage()]fn scalar_is_odd(a: [u8; 32]) -> bool {
+        prime_field_is_odd(scalar(a))
+    }
 }
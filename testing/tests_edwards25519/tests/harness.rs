@@ -1,3 +1,11 @@
 mod utils;
 mod tests_field_element;
-//  mod tests_point_conversions;
\ No newline at end of file
+mod tests_point_conversions;
+mod tests_ge25519;
+mod tests_ed25519;
+mod tests_scalar;
+mod tests_x25519;
+mod tests_ristretto;
+mod tests_sr25519;
+mod tests_wycheproof;
+mod tests_group;
@@ -0,0 +1,171 @@
+use crate::utils::helpers::get_contract_methods;
+use fuels::{
+    prelude::*,
+    tx::{ConsensusParameters, ContractId},
+};
+use sha2::{Digest, Sha512};
+
+/*
+Test vectors from RFC 8032 section 7.1, https://www.rfc-editor.org/rfc/rfc8032#section-7.1
+and edge cases in the categories of the Wycheproof eddsa suite (https://github.com/google/wycheproof):
+modified messages and signatures, non-canonical S, and encodings that aren't valid points.
+The expected results of the edge cases agree with ed25519-dalek's `verify_strict`.
+The full Wycheproof suite is run in tests_wycheproof.
+*/
+
+const RFC8032_TEST_1_PUBKEY: &str = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+const RFC8032_TEST_1_SIG: &str = "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b";
+
+// The encoding of the identity (0, 1), which is a point of small order
+const IDENTITY: &str = "0100000000000000000000000000000000000000000000000000000000000000";
+
+fn to_array_32(s: &str) -> [u8; 32] {
+  hex::decode(s).unwrap().try_into().unwrap()
+}
+
+fn to_array_64(s: &str) -> [u8; 64] {
+  hex::decode(s).unwrap().try_into().unwrap()
+}
+
+async fn verify(pubkey: &str, msg: &str, sig: &str) -> bool {
+  let (_instance, _id) = get_contract_methods().await;
+
+  _instance.ed25519_verify(to_array_32(pubkey), hex::decode(msg).unwrap(), to_array_64(sig))
+      .tx_params(TxParameters::default().set_gas_limit(1_000_000_000))
+      .call().await.unwrap().value
+}
+
+async fn sha512(msg: Vec<u8>) -> [u8; 64] {
+  let (_instance, _id) = get_contract_methods().await;
+
+  _instance.sha512(msg)
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap().value
+}
+
+mod success {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_sha512() {
+      // lengths around the padding boundaries of a 128-byte block
+      for len in [0usize, 3, 111, 112, 128, 200] {
+          let msg: Vec<u8> = (0..len).map(|i| i as u8).collect();
+          let expected: [u8; 64] = Sha512::digest(&msg).into();
+          assert!(sha512(msg).await == expected);
+      }
+  }
+
+  #[tokio::test]
+  async fn test_rfc8032_test_1() {
+      assert!(verify(RFC8032_TEST_1_PUBKEY, "", RFC8032_TEST_1_SIG).await);
+  }
+
+  #[tokio::test]
+  async fn test_rfc8032_test_2() {
+      assert!(verify(
+          "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+          "72",
+          "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+      ).await);
+  }
+
+  #[tokio::test]
+  async fn test_rfc8032_test_3() {
+      assert!(verify(
+          "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+          "af82",
+          "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a",
+      ).await);
+  }
+
+  #[tokio::test]
+  async fn test_small_order_pubkey_and_r() {
+      // With A = R = identity and S = 0 the cofactorless equation holds for every message,
+      // so this is rejected, as in ed25519-dalek's `verify_strict`
+      let sig = format!("{}{}", IDENTITY, "00".repeat(32));
+      assert!(!verify(IDENTITY, "af82", &sig).await);
+  }
+
+  #[tokio::test]
+  async fn test_small_order_r() {
+      // R = (0, -1) has order 2
+      let sig = format!("{}{}", "ecffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f", &RFC8032_TEST_1_SIG[64..]);
+      assert!(!verify(RFC8032_TEST_1_PUBKEY, "", &sig).await);
+  }
+
+  #[tokio::test]
+  async fn test_modified_message() {
+      assert!(!verify(RFC8032_TEST_1_PUBKEY, "00", RFC8032_TEST_1_SIG).await);
+  }
+
+  #[tokio::test]
+  async fn test_wrong_pubkey() {
+      assert!(!verify(
+          "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+          "",
+          RFC8032_TEST_1_SIG,
+      ).await);
+  }
+
+  #[tokio::test]
+  async fn test_modified_r() {
+      assert!(!verify(
+          RFC8032_TEST_1_PUBKEY,
+          "",
+          "e4564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+      ).await);
+  }
+
+  #[tokio::test]
+  async fn test_modified_s() {
+      assert!(!verify(
+          RFC8032_TEST_1_PUBKEY,
+          "",
+          "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc71e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+      ).await);
+  }
+
+  #[tokio::test]
+  async fn test_s_not_reduced() {
+      // S + ℓ satisfies the verification equation, but isn't a canonical encoding
+      assert!(!verify(
+          RFC8032_TEST_1_PUBKEY,
+          "",
+          "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901554c8c7872aa064e049dbb3013fbf29380d25bf5f0595bbe24655141438e7a101b",
+      ).await);
+  }
+
+  #[tokio::test]
+  async fn test_pubkey_not_on_curve() {
+      // there is no point with y = 2
+      assert!(!verify(
+          "0200000000000000000000000000000000000000000000000000000000000000",
+          "",
+          RFC8032_TEST_1_SIG,
+      ).await);
+  }
+
+  #[tokio::test]
+  async fn test_r_not_on_curve() {
+      assert!(!verify(
+          RFC8032_TEST_1_PUBKEY,
+          "",
+          "02000000000000000000000000000000000000000000000000000000000000005fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+      ).await);
+  }
+
+  #[tokio::test]
+  async fn test_r_non_canonical() {
+      // R is the identity, encoded with y = p + 1
+      let sig = format!("{}{}", "eeffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f", "00".repeat(32));
+      assert!(!verify(IDENTITY, "", &sig).await);
+  }
+
+  #[tokio::test]
+  async fn test_r_negative_zero() {
+      // R is the identity, with the sign bit of x = 0 set
+      let sig = format!("{}{}", "0100000000000000000000000000000000000000000000000000000000000080", "00".repeat(32));
+      assert!(!verify(IDENTITY, "", &sig).await);
+  }
+}
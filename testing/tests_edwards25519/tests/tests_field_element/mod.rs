@@ -30,8 +30,7 @@ mod success {
       let (_instance, _id) = get_contract_methods().await;
 
       let res = _instance.add(ZERO, a)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
       assert!(res == expected_res);
   }
@@ -57,8 +56,7 @@ mod success {
       let (_instance, _id) = get_contract_methods().await;
 
       let res = _instance.add(a, ZERO)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
       assert!(res == expected_res);
   }
@@ -90,8 +88,7 @@ mod success {
       let (_instance, _id) = get_contract_methods().await;
 
       let res = _instance.add(a, b)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
       assert!(res == expected_res);
   }
@@ -123,8 +120,7 @@ mod success {
       let (_instance, _id) = get_contract_methods().await;
 
       let res = _instance.add(a, a_again)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
       assert!(res == expected_res);
   }
@@ -138,8 +134,7 @@ mod success {
       let (_instance, _id) = get_contract_methods().await;
 
       let res = _instance.multiply64(a, b)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
       assert!(res.lower == 5960040633016627860);
       assert!(res.upper == 5338139427034470684);
@@ -154,8 +149,7 @@ mod success {
       let (_instance, _id) = get_contract_methods().await;
 
       let res = _instance.add_multiply64(r, a, b)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
       assert!(res.lower == 10881738262824685884);
       assert!(res.upper == 3182762646142);
@@ -170,8 +164,7 @@ mod success {
       let (_instance, _id) = get_contract_methods().await;
 
       let res = _instance.add_multiply64(r, a, b)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
       assert!(res.lower == 18148778710141221224);
       assert!(res.upper == 279563898809);
@@ -197,8 +190,7 @@ mod success {
       let (_instance, _id) = get_contract_methods().await;
 
       let res = _instance.carry_propagate(a)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
       assert!(res == expected_res);
   }
@@ -223,8 +215,7 @@ mod success {
       let (_instance, _id) = get_contract_methods().await;
 
       let res = _instance.carry_propagate(a)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
       assert!(res == expected_res);
   }
@@ -249,8 +240,7 @@ mod success {
       let (_instance, _id) = get_contract_methods().await;
 
       let res = _instance.reduce(a)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
       assert!(res == expected_res);
   }
@@ -275,8 +265,7 @@ mod success {
       let (_instance, _id) = get_contract_methods().await;
 
       let res = _instance.reduce(a)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
       assert!(res == expected_res);
   }
@@ -301,8 +290,7 @@ mod success {
       let (_instance, _id) = get_contract_methods().await;
 
       let res = _instance.reduce(a)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
       assert!(res == expected_res);
   }
@@ -327,8 +315,7 @@ mod success {
       let (_instance, _id) = get_contract_methods().await;
 
       let res = _instance.reduce(a)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
       assert!(res == expected_res);
   }
@@ -340,8 +327,7 @@ mod success {
       let (_instance, _id) = get_contract_methods().await;
 
       let res = _instance.shift_right_by51(a)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
       assert!(res == 131072);
   }
@@ -353,8 +339,7 @@ mod success {
       let (_instance, _id) = get_contract_methods().await;
 
       let res = _instance.shift_right_by51(a)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
       assert!(res == 2861655916544);
   }
@@ -366,8 +351,7 @@ mod success {
       let (_instance, _id) = get_contract_methods().await;
 
       let res = _instance.shift_right_by51(a)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
       assert!(res == 2861655924735);
   }
@@ -385,8 +369,7 @@ mod success {
       let (_instance, _id) = get_contract_methods().await;
 
       let res = _instance.scalar_mult(a, 0)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
       assert!(res == ZERO);
   }
@@ -411,8 +394,7 @@ mod success {
       let (_instance, _id) = get_contract_methods().await;
 
       let res = _instance.scalar_mult(a, 1)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
       assert!(res == expected_res);
   }
@@ -437,8 +419,7 @@ mod success {
       let (_instance, _id) = get_contract_methods().await;
 
       let res = _instance.scalar_mult(a, 2)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
       assert!(res == expected_res);
   }
@@ -463,8 +444,7 @@ mod success {
       let (_instance, _id) = get_contract_methods().await;
 
       let res = _instance.scalar_mult(a, 4294967295)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
       assert!(res == expected_res);
   }
@@ -489,8 +469,7 @@ mod success {
       let (_instance, _id) = get_contract_methods().await;
 
       let squared_res = _instance.square(a)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
       assert!(squared_res == a_square);
   }
@@ -515,8 +494,7 @@ mod success {
       let (_instance, _id) = get_contract_methods().await;
 
       let subtract_res = _instance.subtract(a, ZERO)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
       assert!(subtract_res == expected_res);
   }
@@ -541,8 +519,7 @@ mod success {
       let (_instance, _id) = get_contract_methods().await;
 
       let subtract_res = _instance.subtract(a, ONE)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
       assert!(subtract_res == expected_res);
   }
@@ -573,8 +550,7 @@ mod success {
       let (_instance, _id) = get_contract_methods().await;
 
       let subtract_res = _instance.subtract(a, b)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
 
       assert!(subtract_res == expected_res);
@@ -606,8 +582,7 @@ mod success {
       let (_instance, _id) = get_contract_methods().await;
 
       let subtract_res = _instance.subtract(a, b)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
           
       assert!(subtract_res == expected_res);
@@ -639,15 +614,210 @@ mod success {
       let (_instance, _id) = get_contract_methods().await;
 
       let subtract_res = _instance.subtract(b, a)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
           
       assert!(subtract_res == expected_res);
   }
 
-  //Testing not done because of error Immediate18TooLarge
-  /* 
+  #[tokio::test]
+  async fn test_from_bytes_to_bytes() {
+      let bytes: [u8; 32] = hex::decode("c5c127cb958a72a8dc85611620847600c9d781c36f288bdbc96e6771a748ff09").unwrap().try_into().unwrap();
+      let expected_res = Element{ 
+          l_0: 715325916561861, 
+          l_1: 1128975921026318, 
+          l_2: 1696955067652624,
+          l_3: 2081297221826529,
+          l_4: 175872643896950
+      };
+
+      let (_instance, _id) = get_contract_methods().await;
+
+      let res = _instance.from_bytes(bytes)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(res == expected_res);
+
+      let res_bytes = _instance.to_bytes(res)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(res_bytes == bytes);
+  }
+
+  #[tokio::test]
+  async fn test_from_bytes_ignores_top_bit() {
+      // same as above, with the most significant bit set
+      let bytes: [u8; 32] = hex::decode("c5c127cb958a72a8dc85611620847600c9d781c36f288bdbc96e6771a748ff89").unwrap().try_into().unwrap();
+      let expected_res = Element{ 
+          l_0: 715325916561861, 
+          l_1: 1128975921026318, 
+          l_2: 1696955067652624,
+          l_3: 2081297221826529,
+          l_4: 175872643896950
+      };
+
+      let (_instance, _id) = get_contract_methods().await;
+
+      let res = _instance.from_bytes(bytes)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(res == expected_res);
+  }
+
+  #[tokio::test]
+  async fn test_to_bytes_non_canonical() {
+      // p + 1 is encoded as 1
+      let a = Element{ 
+          l_0: 2251799813685230, 
+          l_1: 2251799813685247, 
+          l_2: 2251799813685247,
+          l_3: 2251799813685247,
+          l_4: 2251799813685247
+      };
+      let mut expected_res = [0u8; 32];
+      expected_res[0] = 1;
+
+      let (_instance, _id) = get_contract_methods().await;
+
+      let res = _instance.to_bytes(a)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(res == expected_res);
+  }
+
+  #[tokio::test]
+  async fn test_pow22523() {
+      let a = Element{ 
+          l_0: 715325916561861, 
+          l_1: 1128975921026318, 
+          l_2: 1696955067652624,
+          l_3: 2081297221826529,
+          l_4: 175872643896950
+      };
+      // a^((p-5)/8)
+      let expected_res = Element{ 
+          l_0: 49907992288470, 
+          l_1: 1582126565786104, 
+          l_2: 1556337929872669,
+          l_3: 1750426357273875,
+          l_4: 1709912296589521
+      };
+
+      let (_instance, _id) = get_contract_methods().await;
+
+      let res = _instance.pow22523(a)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(res == expected_res);
+  }
+
+  #[tokio::test]
+  async fn test_sqrt_ratio_i() {
+      let two = Element{ l_0: 2, l_1: 0, l_2: 0, l_3: 0, l_4: 0 };
+      let four = Element{ l_0: 4, l_1: 0, l_2: 0, l_3: 0, l_4: 0 };
+      let five = Element{ l_0: 5, l_1: 0, l_2: 0, l_3: 0, l_4: 0 };
+      // sqrt(i*2), which is the nonnegative root
+      let sqrt_2i = Element{ 
+          l_0: 533094393274172, 
+          l_1: 2016890930128738, 
+          l_2: 18285341111199,
+          l_3: 134597186663265,
+          l_4: 1486323764102114
+      };
+
+      let (_instance, _id) = get_contract_methods().await;
+
+      // 4/1 is square
+      let res = _instance.sqrt_ratio_i(four, ONE)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(res == (true, two));
+
+      // 2/1 isn't square
+      let res = _instance.sqrt_ratio_i(two, ONE)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(res == (false, sqrt_2i));
+
+      // 0/5 is square
+      let res = _instance.sqrt_ratio_i(ZERO, five)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(res == (true, ZERO));
+
+      // 1/0 is undefined
+      let res = _instance.sqrt_ratio_i(ONE, ZERO)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(res == (false, ZERO));
+  }
+
+  #[tokio::test]
+  async fn test_is_negative() {
+      // p - 1 is even, so it's not negative
+      let p_minus_1 = Element{ 
+          l_0: 2251799813685228, 
+          l_1: 2251799813685247, 
+          l_2: 2251799813685247,
+          l_3: 2251799813685247,
+          l_4: 2251799813685247
+      };
+      // p + 1 reduces to 1, which is negative
+      let p_plus_1 = Element{ 
+          l_0: 2251799813685230, 
+          l_1: 2251799813685247, 
+          l_2: 2251799813685247,
+          l_3: 2251799813685247,
+          l_4: 2251799813685247
+      };
+
+      let (_instance, _id) = get_contract_methods().await;
+
+      let res = _instance.is_negative(ONE)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(res);
+
+      let res = _instance.is_negative(p_minus_1)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(!res);
+
+      let res = _instance.is_negative(p_plus_1)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(res);
+  }
+
+  #[tokio::test]
+  async fn test_is_zero() {
+      // p, which is 0 after reduction
+      let p = Element{ 
+          l_0: 2251799813685229, 
+          l_1: 2251799813685247, 
+          l_2: 2251799813685247,
+          l_3: 2251799813685247,
+          l_4: 2251799813685247
+      };
+
+      let (_instance, _id) = get_contract_methods().await;
+
+      let res = _instance.is_zero(ZERO)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(res);
+
+      let res = _instance.is_zero(p)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(res);
+
+      let res = _instance.is_zero(ONE)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(!res);
+  }
+
   #[tokio::test]
   async fn test_inverse_random() {
       /*
@@ -673,11 +843,9 @@ mod success {
       let (_instance, _id) = get_contract_methods().await;
 
       let res = _instance.inverse(a)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
 
-          assert!(res == expected_res);
+      assert!(res == expected_res);
   }
-  */
 }
\ No newline at end of file
@@ -0,0 +1,328 @@
+use crate::utils::{helpers::get_contract_methods, Element, ge25519};
+use fuels::{
+    prelude::*,
+    tx::{ConsensusParameters, ContractId},
+};
+
+/*
+The test points are the ones in tests_point_conversions, in extended coordinates with z = 1 and t = x*y.
+The scalars of aG and bG are unknown, so those are tested through relations that hold for any point.
+Expected values were computed with a Python reference implementation of the curve arithmetic.
+*/
+
+const ZERO: Element = Element{ l_0: 0, l_1: 0, l_2: 0, l_3: 0, l_4: 0 };
+const ONE: Element = Element{ l_0: 1, l_1: 0, l_2: 0, l_3: 0, l_4: 0 };
+
+const IDENTITY: ge25519 = ge25519{ x: ZERO, y: ONE, z: ONE, t: ZERO };
+
+// ℓ = 2^252 + 27742317777372353535851937790883648493, little-endian
+const L: [u8; 32] = [237, 211, 245, 92, 26, 99, 18, 88, 214, 156, 247, 162, 222, 249, 222, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16];
+
+// returns the little-endian encoding of a small scalar
+fn scalar_bytes(s: u64) -> [u8; 32] {
+  let mut res = [0u8; 32];
+  res[..8].copy_from_slice(&s.to_le_bytes());
+  res
+}
+
+// G
+fn base_point() -> ge25519 {
+  ge25519 {
+      x: Element{ l_0: 1738742601995546, 
+          l_1: 1146398526822698, 
+          l_2: 2070867633025821, 
+          l_3: 562264141797630, 
+          l_4: 587772402128613 },
+      y: Element{ l_0: 1801439850948184, 
+          l_1: 1351079888211148, 
+          l_2: 450359962737049, 
+          l_3: 900719925474099, 
+          l_4: 1801439850948198 },
+      z: ONE,
+      t: Element{ l_0: 1841354044333475, 
+          l_1: 16398895984059, 
+          l_2: 755974180946558, 
+          l_3: 900171276175154, 
+          l_4: 1821297809914039 },
+  }
+}
+
+// 2G, hex c9a3f86aae465f0e56513864510f3997561fa2c9e85ea21dc2292309f3cd6022
+fn base_point_2() -> ge25519 {
+  ge25519 {
+      x: Element{ l_0: 1584731938606606, 
+          l_1: 178923850428536, 
+          l_2: 228280466683449, 
+          l_3: 622454613327500, 
+          l_4: 961744189650336 },
+      y: Element{ l_0: 2048039769908169, 
+          l_1: 611908456202699, 
+          l_2: 1838921828590653, 
+          l_3: 1373353662182500, 
+          l_4: 604786679386674 },
+      z: ONE,
+      t: Element{ l_0: 632586093835265, 
+          l_1: 343317749454563, 
+          l_2: 1142187722401459, 
+          l_3: 1246910387029049, 
+          l_4: 643809026290422 },
+  }
+}
+
+// 5G, hex edc876d6831fd2105d0b4389ca2e283166469289146e2ce06faefe98b22548df
+fn base_point_5() -> ge25519 {
+  ge25519 {
+      x: Element{ l_0: 172349289591347, 
+          l_1: 2014045392908840, 
+          l_2: 1326661600583783, 
+          l_3: 1194063046802408, 
+          l_4: 1301660503301685 },
+      y: Element{ l_0: 597601052707053, 
+          l_1: 370708851696154, 
+          l_2: 1769224146297019, 
+          l_3: 2031829141359172, 
+          l_4: 1676215595929578 },
+      z: ONE,
+      t: Element{ l_0: 370247452852688, 
+          l_1: 175926773542019, 
+          l_2: 1366829968581946, 
+          l_3: 1871474830157891, 
+          l_4: 2047038905238931 },
+  }
+}
+
+// aG, hex 14e35209936de59710e4a3a55b1887a6f3a390c0b1b2d132a0158ff3b60581e0
+fn point_a() -> ge25519 {
+  ge25519 {
+      x: Element{ l_0: 2086768841072835, 
+          l_1: 611102075416886, 
+          l_2: 1984140036993915, 
+          l_3: 1712130547774869, 
+          l_4: 1826202781818604 },
+      y: Element{ l_0: 1527853167600404, 
+          l_1: 972743461966588, 
+          l_2: 636135367384161, 
+          l_3: 791757505255648, 
+          l_4: 1697716206778609 },
+      z: ONE,
+      t: Element{ l_0: 898318464440995, 
+          l_1: 1994093056556569, 
+          l_2: 256237379361503, 
+          l_3: 1798330795446009, 
+          l_4: 301902336357579 },
+  }
+}
+
+// bG, hex cca4cc575d5eb9057834ad8b759272d37feb95c9f7197bf251814f37a4413f1d
+fn point_b() -> ge25519 {
+  ge25519 {
+      x: Element{ l_0: 292545019983850, 
+          l_1: 1623928580815737, 
+          l_2: 1117035527609297, 
+          l_3: 673370884933664, 
+          l_4: 1871124403239312 },
+      y: Element{ l_0: 385229974709452, 
+          l_1: 1883968723943607, 
+          l_2: 1785254684510793, 
+          l_3: 185788432972772, 
+          l_4: 514520342820088 },
+      z: ONE,
+      t: Element{ l_0: 2207231806875108, 
+          l_1: 1534853386282694, 
+          l_2: 1531058997931328, 
+          l_3: 364571826877863, 
+          l_4: 1348104415474297 },
+  }
+}
+
+// aG + bG, hex 30cad1638f13e0d7be32b935626c19a3bebade98f93c438bee7d58e62e165880
+fn point_a_plus_b() -> ge25519 {
+  ge25519 {
+      x: Element{ l_0: 1474539934028813, 
+          l_1: 1862958494745211, 
+          l_2: 1904165447734680, 
+          l_3: 1254659160042216, 
+          l_4: 1841902755737401 },
+      y: Element{ l_0: 21506575944240, 
+          l_1: 1203652343094012, 
+          l_2: 979574574572977, 
+          l_3: 1960728296586444, 
+          l_4: 6053268710791 },
+      z: ONE,
+      t: Element{ l_0: 1864163799752013, 
+          l_1: 2068257707659611, 
+          l_2: 1068802727862152, 
+          l_3: 1169796554836673, 
+          l_4: 472889171279364 },
+  }
+}
+
+// 2aG, hex cc9b912d55f804279bde8d5d8a458eaa8da62859ff892eb8a227cd2aee590e76
+fn point_2a() -> ge25519 {
+  ge25519 {
+      x: Element{ l_0: 2223422152274752, 
+          l_1: 828169575404835, 
+          l_2: 1401727644175431, 
+          l_3: 762591713158111, 
+          l_4: 177197897524299 },
+      y: Element{ l_0: 1398944627268556, 
+          l_1: 364701709198560, 
+          l_2: 1304683132631318, 
+          l_3: 1074618387726252, 
+          l_4: 2076864166407378 },
+      z: ONE,
+      t: Element{ l_0: 1871970303441507, 
+          l_1: 684484928485086, 
+          l_2: 1795919310697133, 
+          l_3: 1201910045297369, 
+          l_4: 917521924552549 },
+  }
+}
+
+async fn add(p: ge25519, q: ge25519) -> ge25519 {
+  let (_instance, _id) = get_contract_methods().await;
+
+  _instance.ge25519_add(p, q)
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap().value
+}
+
+async fn double(p: ge25519) -> ge25519 {
+  let (_instance, _id) = get_contract_methods().await;
+
+  _instance.ge25519_double(p)
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap().value
+}
+
+async fn neg(p: ge25519) -> ge25519 {
+  let (_instance, _id) = get_contract_methods().await;
+
+  _instance.ge25519_neg(p)
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap().value
+}
+
+async fn equals(p: ge25519, q: ge25519) -> bool {
+  let (_instance, _id) = get_contract_methods().await;
+
+  _instance.ge25519_eq(p, q)
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap().value
+}
+
+async fn scalarmult(p: ge25519, s: [u8; 32]) -> ge25519 {
+  let (_instance, _id) = get_contract_methods().await;
+
+  _instance.ge25519_scalarmult(p, s)
+      .tx_params(TxParameters::default().set_gas_limit(1_000_000_000))
+      .call().await.unwrap().value
+}
+
+async fn scalarmult_base(s: [u8; 32]) -> ge25519 {
+  let (_instance, _id) = get_contract_methods().await;
+
+  _instance.ge25519_scalarmult_base(s)
+      .tx_params(TxParameters::default().set_gas_limit(1_000_000_000))
+      .call().await.unwrap().value
+}
+
+mod success {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_identity() {
+      let (_instance, _id) = get_contract_methods().await;
+
+      let res = _instance.ge25519_identity()
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+
+      assert!(res == IDENTITY);
+  }
+
+  #[tokio::test]
+  async fn test_eq() {
+      assert!(equals(base_point(), base_point()).await);
+      assert!(!equals(base_point(), base_point_2()).await);
+      assert!(!equals(point_a(), point_b()).await);
+  }
+
+  #[tokio::test]
+  async fn test_eq_projective() {
+      // G with all coordinates multiplied by 2 is still G
+      let g = base_point();
+      let g_scaled = ge25519 {
+          x: Element{ l_0: 2 * g.x.l_0, l_1: 2 * g.x.l_1, l_2: 2 * g.x.l_2, l_3: 2 * g.x.l_3, l_4: 2 * g.x.l_4 },
+          y: Element{ l_0: 2 * g.y.l_0, l_1: 2 * g.y.l_1, l_2: 2 * g.y.l_2, l_3: 2 * g.y.l_3, l_4: 2 * g.y.l_4 },
+          z: Element{ l_0: 2, l_1: 0, l_2: 0, l_3: 0, l_4: 0 },
+          t: Element{ l_0: 2 * g.t.l_0, l_1: 2 * g.t.l_1, l_2: 2 * g.t.l_2, l_3: 2 * g.t.l_3, l_4: 2 * g.t.l_4 },
+      };
+      assert!(equals(g_scaled, base_point()).await);
+  }
+
+  #[tokio::test]
+  async fn test_add_identity() {
+      assert!(equals(add(base_point(), IDENTITY).await, base_point()).await);
+      assert!(equals(add(IDENTITY, point_a()).await, point_a()).await);
+  }
+
+  #[tokio::test]
+  async fn test_double_base_point() {
+      assert!(equals(double(base_point()).await, base_point_2()).await);
+  }
+
+  #[tokio::test]
+  async fn test_double_identity() {
+      assert!(equals(double(IDENTITY).await, IDENTITY).await);
+  }
+
+  #[tokio::test]
+  async fn test_add_base_points() {
+      // G + 2G + 2G = 5G
+      let g_3 = add(base_point(), base_point_2()).await;
+      assert!(equals(add(g_3, base_point_2()).await, base_point_5()).await);
+  }
+
+  #[tokio::test]
+  async fn test_add_equals_double() {
+      assert!(equals(add(base_point(), base_point()).await, base_point_2()).await);
+      assert!(equals(add(point_a(), point_a()).await, point_2a()).await);
+      assert!(equals(double(point_a()).await, point_2a()).await);
+  }
+
+  #[tokio::test]
+  async fn test_add_a_b() {
+      assert!(equals(add(point_a(), point_b()).await, point_a_plus_b()).await);
+      assert!(equals(add(point_b(), point_a()).await, point_a_plus_b()).await);
+  }
+
+  #[tokio::test]
+  async fn test_neg() {
+      assert!(equals(add(point_a(), neg(point_a()).await).await, IDENTITY).await);
+      assert!(equals(add(point_a_plus_b(), neg(point_b()).await).await, point_a()).await);
+      assert!(equals(neg(IDENTITY).await, IDENTITY).await);
+  }
+
+  #[tokio::test]
+  async fn test_scalarmult_base() {
+      assert!(equals(scalarmult_base(scalar_bytes(0)).await, IDENTITY).await);
+      assert!(equals(scalarmult_base(scalar_bytes(1)).await, base_point()).await);
+      assert!(equals(scalarmult_base(scalar_bytes(2)).await, base_point_2()).await);
+      assert!(equals(scalarmult_base(scalar_bytes(5)).await, base_point_5()).await);
+  }
+
+  #[tokio::test]
+  async fn test_scalarmult() {
+      assert!(equals(scalarmult(point_a(), scalar_bytes(2)).await, point_2a()).await);
+      assert!(equals(scalarmult(base_point_2(), scalar_bytes(1)).await, base_point_2()).await);
+  }
+
+  #[tokio::test]
+  async fn test_scalarmult_by_order() {
+      // all test points are in the prime-order subgroup
+      assert!(equals(scalarmult_base(L).await, IDENTITY).await);
+      assert!(equals(scalarmult(point_a(), L).await, IDENTITY).await);
+      assert!(equals(scalarmult(point_b(), L).await, IDENTITY).await);
+  }
+}
@@ -0,0 +1,354 @@
+use crate::utils::helpers::get_contract_methods;
+use curve25519_dalek::{
+    constants::{ED25519_BASEPOINT_POINT, RISTRETTO_BASEPOINT_POINT},
+    edwards::EdwardsPoint,
+    ristretto::RistrettoPoint,
+    scalar::Scalar as DalekScalar,
+    traits::Identity,
+};
+use fuels::{
+    prelude::*,
+    tx::{ConsensusParameters, ContractId},
+};
+use num_bigint::BigUint;
+use sha2::{Digest, Sha512};
+
+/*
+These test the Group impls of ge25519 and RistrettoPoint, and the Field and PrimeField impls of Element and Scalar,
+through the generic functions of the test contract.
+Points, scalars and group operations are checked against curve25519-dalek. Field elements mod p = 2^255 - 19 are
+checked against num-bigint, since curve25519-dalek doesn't expose its field arithmetic.
+*/
+
+// ℓ = 2^252 + 27742317777372353535851937790883648493, little-endian
+const L: [u8; 32] = [
+  0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
+  0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
+];
+
+// returns 64 pseudorandom bytes, derived from `seed`
+fn wide_bytes(seed: u8) -> [u8; 64] {
+  Sha512::digest([seed]).into()
+}
+
+// returns a pseudorandom scalar, derived from `seed`
+fn random_scalar(seed: u8) -> DalekScalar {
+  DalekScalar::from_bytes_mod_order_wide(&wide_bytes(seed))
+}
+
+// p = 2^255 - 19
+fn p() -> BigUint {
+  (BigUint::from(1u8) << 255) - 19u8
+}
+
+// returns a pseudorandom field element, derived from `seed`
+fn random_element(seed: u8) -> BigUint {
+  BigUint::from_bytes_le(&wide_bytes(seed)) % p()
+}
+
+// returns the canonical little-endian encoding of a field element
+fn element_bytes(a: &BigUint) -> [u8; 32] {
+  let bytes = a.to_bytes_le();
+  let mut res = [0u8; 32];
+  res[..bytes.len()].copy_from_slice(&bytes);
+  res
+}
+
+fn from_element_bytes(bytes: [u8; 32]) -> BigUint {
+  BigUint::from_bytes_le(&bytes)
+}
+
+fn ed(p: EdwardsPoint) -> [u8; 32] {
+  p.compress().to_bytes()
+}
+
+fn ristretto(p: RistrettoPoint) -> [u8; 32] {
+  p.compress().to_bytes()
+}
+
+mod success {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_ge25519_group() {
+      let (_instance, _id) = get_contract_methods().await;
+      let g = ED25519_BASEPOINT_POINT;
+      let p = g * random_scalar(0);
+      let k = random_scalar(1);
+
+      let generator = _instance.ge25519_group_generator()
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert_eq!(generator, ed(g));
+
+      let identity = _instance.ge25519_group_is_identity(ed(EdwardsPoint::identity()))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(identity);
+
+      let identity = _instance.ge25519_group_is_identity(ed(p))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(!identity);
+
+      let double = _instance.ge25519_group_double(ed(p))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert_eq!(double, ed(p + p));
+
+      let diff = _instance.ge25519_group_sub(ed(p), ed(g))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert_eq!(diff, ed(p - g));
+
+      let diff = _instance.ge25519_group_sub(ed(p), ed(p))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert_eq!(diff, ed(EdwardsPoint::identity()));
+
+      let neg = _instance.ge25519_group_neg(ed(p))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert_eq!(neg, ed(-p));
+
+      let mul = _instance.ge25519_group_mul_scalar(ed(p), k.to_bytes())
+          .tx_params(TxParameters::default().set_gas_limit(1_000_000_000))
+          .call().await.unwrap().value;
+      assert_eq!(mul, ed(p * k));
+
+      // [ℓ - 1]P = -P
+      let mul = _instance.ge25519_group_mul_scalar(ed(p), (-DalekScalar::ONE).to_bytes())
+          .tx_params(TxParameters::default().set_gas_limit(1_000_000_000))
+          .call().await.unwrap().value;
+      assert_eq!(mul, ed(-p));
+  }
+
+  #[tokio::test]
+  async fn test_ristretto_group() {
+      let (_instance, _id) = get_contract_methods().await;
+      let g = RISTRETTO_BASEPOINT_POINT;
+      let p = g * random_scalar(2);
+      let k = random_scalar(3);
+
+      let generator = _instance.ristretto_group_generator()
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert_eq!(generator, ristretto(g));
+
+      let identity = _instance.ristretto_group_is_identity(ristretto(RistrettoPoint::identity()))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(identity);
+
+      let identity = _instance.ristretto_group_is_identity(ristretto(p))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(!identity);
+
+      let double = _instance.ristretto_group_double(ristretto(p))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert_eq!(double, ristretto(p + p));
+
+      let diff = _instance.ristretto_group_sub(ristretto(p), ristretto(g))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert_eq!(diff, ristretto(p - g));
+
+      let neg = _instance.ristretto_group_neg(ristretto(p))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert_eq!(neg, ristretto(-p));
+
+      let mul = _instance.ristretto_group_mul_scalar(ristretto(p), k.to_bytes())
+          .tx_params(TxParameters::default().set_gas_limit(1_000_000_000))
+          .call().await.unwrap().value;
+      assert_eq!(mul, ristretto(p * k));
+  }
+
+  #[tokio::test]
+  async fn test_element_field() {
+      let (_instance, _id) = get_contract_methods().await;
+      let p = p();
+
+      for a in [BigUint::from(1u8), &p - 1u8, random_element(4), random_element(5)] {
+          let square = _instance.element_field_square(element_bytes(&a))
+              .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+              .call().await.unwrap().value;
+          assert_eq!(square, element_bytes(&(&a * &a % &p)));
+
+          let double = _instance.element_field_double(element_bytes(&a))
+              .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+              .call().await.unwrap().value;
+          assert_eq!(double, element_bytes(&(&a * 2u8 % &p)));
+
+          let inverse = _instance.element_field_invert(element_bytes(&a))
+              .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+              .call().await.unwrap().value;
+          assert_eq!(inverse, element_bytes(&a.modpow(&(&p - 2u8), &p)));
+
+          // the root is either a or -a
+          let root = _instance.element_field_sqrt(element_bytes(&(&a * &a % &p)))
+              .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+              .call().await.unwrap().value;
+          assert!(root == element_bytes(&a) || root == element_bytes(&(&p - &a)));
+      }
+
+      // -1 is a square, since p = 1 mod 4
+      let root = _instance.element_field_sqrt(element_bytes(&(&p - 1u8)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      let root = from_element_bytes(root);
+      assert_eq!(&root * &root % &p, &p - 1u8);
+  }
+
+  #[tokio::test]
+  async fn test_element_repr() {
+      let (_instance, _id) = get_contract_methods().await;
+      let p = p();
+
+      for a in [BigUint::from(0u8), BigUint::from(1u8), &p - 1u8, random_element(6)] {
+          let res = _instance.element_from_repr(element_bytes(&a))
+              .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+              .call().await.unwrap().value;
+          assert_eq!(res, element_bytes(&a));
+
+          let is_odd = _instance.element_is_odd(element_bytes(&a))
+              .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+              .call().await.unwrap().value;
+          assert_eq!(is_odd, a.bit(0));
+      }
+  }
+
+  #[tokio::test]
+  async fn test_scalar_field() {
+      let (_instance, _id) = get_contract_methods().await;
+
+      for a in [DalekScalar::ONE, -DalekScalar::ONE, random_scalar(7), random_scalar(8)] {
+          let square = _instance.scalar_field_square(a.to_bytes())
+              .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+              .call().await.unwrap().value;
+          assert_eq!(square, (a * a).to_bytes());
+
+          let double = _instance.scalar_field_double(a.to_bytes())
+              .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+              .call().await.unwrap().value;
+          assert_eq!(double, (a + a).to_bytes());
+
+          // invert_unchecked computes a^(ℓ-2)
+          let inverse = _instance.scalar_field_invert(a.to_bytes())
+              .tx_params(TxParameters::default().set_gas_limit(1_000_000_000))
+              .call().await.unwrap().value;
+          assert_eq!(inverse, a.invert().to_bytes());
+
+          // sqrt_unchecked uses Atkin's algorithm, the root is either a or -a
+          let root = _instance.scalar_field_sqrt((a * a).to_bytes())
+              .tx_params(TxParameters::default().set_gas_limit(1_000_000_000))
+              .call().await.unwrap().value;
+          assert!(root == a.to_bytes() || root == (-a).to_bytes());
+      }
+
+      // -1 is a square, since ℓ = 1 mod 4
+      let root = _instance.scalar_field_sqrt((-DalekScalar::ONE).to_bytes())
+          .tx_params(TxParameters::default().set_gas_limit(1_000_000_000))
+          .call().await.unwrap().value;
+      let root = Option::<DalekScalar>::from(DalekScalar::from_canonical_bytes(root)).unwrap();
+      assert_eq!(root * root, -DalekScalar::ONE);
+  }
+
+  #[tokio::test]
+  async fn test_scalar_repr() {
+      let (_instance, _id) = get_contract_methods().await;
+
+      for a in [DalekScalar::ZERO, DalekScalar::ONE, -DalekScalar::ONE, random_scalar(9)] {
+          let res = _instance.scalar_from_repr(a.to_bytes())
+              .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+              .call().await.unwrap().value;
+          assert_eq!(res, a.to_bytes());
+
+          let is_odd = _instance.scalar_is_odd(a.to_bytes())
+              .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+              .call().await.unwrap().value;
+          assert_eq!(is_odd, a.to_bytes()[0] & 1 == 1);
+      }
+  }
+}
+
+mod revert {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_element_field_invert_zero() {
+      let (_instance, _id) = get_contract_methods().await;
+
+      let res = _instance.element_field_invert([0u8; 32])
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await;
+      assert!(res.is_err());
+  }
+
+  #[tokio::test]
+  async fn test_element_field_sqrt_non_square() {
+      let (_instance, _id) = get_contract_methods().await;
+
+      // 2 is not a square, since p = 5 mod 8
+      let res = _instance.element_field_sqrt(element_bytes(&BigUint::from(2u8)))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await;
+      assert!(res.is_err());
+  }
+
+  #[tokio::test]
+  async fn test_element_from_repr_non_canonical() {
+      let (_instance, _id) = get_contract_methods().await;
+      let p = p();
+
+      // p, 2^255 - 1 = p + 18, and 1 with the unused top bit set
+      let mut one_with_top_bit = element_bytes(&BigUint::from(1u8));
+      one_with_top_bit[31] |= 0x80;
+      for bytes in [element_bytes(&p), element_bytes(&(&p + 18u8)), one_with_top_bit] {
+          let res = _instance.element_from_repr(bytes)
+              .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+              .call().await;
+          assert!(res.is_err());
+      }
+  }
+
+  #[tokio::test]
+  async fn test_scalar_field_invert_zero() {
+      let (_instance, _id) = get_contract_methods().await;
+
+      let res = _instance.scalar_field_invert([0u8; 32])
+          .tx_params(TxParameters::default().set_gas_limit(1_000_000_000))
+          .call().await;
+      assert!(res.is_err());
+  }
+
+  #[tokio::test]
+  async fn test_scalar_field_sqrt_non_square() {
+      let (_instance, _id) = get_contract_methods().await;
+
+      // 2 is not a square, since ℓ = 5 mod 8
+      let mut two = [0u8; 32];
+      two[0] = 2;
+      let res = _instance.scalar_field_sqrt(two)
+          .tx_params(TxParameters::default().set_gas_limit(1_000_000_000))
+          .call().await;
+      assert!(res.is_err());
+  }
+
+  #[tokio::test]
+  async fn test_scalar_from_repr_non_canonical() {
+      let (_instance, _id) = get_contract_methods().await;
+
+      // ℓ, ℓ + 1 and the largest 256-bit value
+      let mut l_plus_one = L;
+      l_plus_one[0] += 1;
+      for bytes in [L, l_plus_one, [0xff; 32]] {
+          let res = _instance.scalar_from_repr(bytes)
+              .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+              .call().await;
+          assert!(res.is_err());
+      }
+  }
+}
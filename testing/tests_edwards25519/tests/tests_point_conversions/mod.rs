@@ -1,4 +1,4 @@
-use crate::utils::{helpers::get_contract_methods, Element, ge25519_p2};
+use crate::utils::{helpers::get_contract_methods, Element, ge25519, ge25519_p2, ge25519_p1p1};
 use fuels::{
     prelude::*,
     tx::{ConsensusParameters, ContractId},
@@ -30,40 +30,196 @@ bG: y-coord: 1322883701476444084111756054582385414316858462541559081912313124200
 
 */
 
-mod success {
-  use super::*;
+const ONE: Element = Element{ l_0: 1, l_1: 0, l_2: 0, l_3: 0, l_4: 0 };
 
-  /*
-  // Can't be compiled yet Immediate18TooLarge
-  #[tokio::test]
-  async fn test_p1p1_to_p2() {
-      let x = Element{ l_0: 1738742601995546, 
+// The test points as (hex encoding, affine x, affine y), with the coordinates in radix 51
+fn test_points() -> Vec<(&'static str, Element, Element)> {
+  vec![
+      // G
+      ("5866666666666666666666666666666666666666666666666666666666666666",
+      Element{ l_0: 1738742601995546, 
           l_1: 1146398526822698, 
           l_2: 2070867633025821, 
           l_3: 562264141797630, 
-          l_4: 587772402128613 };
-      let y = Element{ l_0: 1801439850948184, 
+          l_4: 587772402128613 },
+      Element{ l_0: 1801439850948184, 
           l_1: 1351079888211148, 
           l_2: 450359962737049, 
           l_3: 900719925474099, 
-          l_4: 1801439850948198 };
+          l_4: 1801439850948198 }),
+      // 2G
+      ("c9a3f86aae465f0e56513864510f3997561fa2c9e85ea21dc2292309f3cd6022",
+      Element{ l_0: 1584731938606606, 
+          l_1: 178923850428536, 
+          l_2: 228280466683449, 
+          l_3: 622454613327500, 
+          l_4: 961744189650336 },
+      Element{ l_0: 2048039769908169, 
+          l_1: 611908456202699, 
+          l_2: 1838921828590653, 
+          l_3: 1373353662182500, 
+          l_4: 604786679386674 }),
+      // 5G
+      ("edc876d6831fd2105d0b4389ca2e283166469289146e2ce06faefe98b22548df",
+      Element{ l_0: 172349289591347, 
+          l_1: 2014045392908840, 
+          l_2: 1326661600583783, 
+          l_3: 1194063046802408, 
+          l_4: 1301660503301685 },
+      Element{ l_0: 597601052707053, 
+          l_1: 370708851696154, 
+          l_2: 1769224146297019, 
+          l_3: 2031829141359172, 
+          l_4: 1676215595929578 }),
+      // aG
+      ("14e35209936de59710e4a3a55b1887a6f3a390c0b1b2d132a0158ff3b60581e0",
+      Element{ l_0: 2086768841072835, 
+          l_1: 611102075416886, 
+          l_2: 1984140036993915, 
+          l_3: 1712130547774869, 
+          l_4: 1826202781818604 },
+      Element{ l_0: 1527853167600404, 
+          l_1: 972743461966588, 
+          l_2: 636135367384161, 
+          l_3: 791757505255648, 
+          l_4: 1697716206778609 }),
+      // bG
+      ("cca4cc575d5eb9057834ad8b759272d37feb95c9f7197bf251814f37a4413f1d",
+      Element{ l_0: 292545019983850, 
+          l_1: 1623928580815737, 
+          l_2: 1117035527609297, 
+          l_3: 673370884933664, 
+          l_4: 1871124403239312 },
+      Element{ l_0: 385229974709452, 
+          l_1: 1883968723943607, 
+          l_2: 1785254684510793, 
+          l_3: 185788432972772, 
+          l_4: 514520342820088 }),
+  ]
+}
+
+fn to_array_32(s: &str) -> [u8; 32] {
+  hex::decode(s).unwrap().try_into().unwrap()
+}
+
+// returns the point with affine coordinates (x, y) in extended coordinates, with z = ONE and t = 0.
+// t isn't used for packing.
+fn affine_point(x: Element, y: Element) -> ge25519 {
+  ge25519{ x: x, y: y, z: ONE, t: Element{ l_0: 0, l_1: 0, l_2: 0, l_3: 0, l_4: 0 } }
+}
+
+async fn pack(p: ge25519) -> [u8; 32] {
+  let (_instance, _id) = get_contract_methods().await;
+
+  _instance.pack(p)
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap().value
+}
+
+async fn unpack(bytes: [u8; 32]) -> ge25519 {
+  let (_instance, _id) = get_contract_methods().await;
+
+  _instance.unpack(bytes)
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap().value
+}
+
+mod success {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_unpack() {
+      for (encoding, x, y) in test_points() {
+          let res = unpack(to_array_32(encoding)).await;
+          assert!(res.x == x);
+          assert!(res.y == y);
+          assert!(res.z == ONE);
+      }
+  }
+
+  #[tokio::test]
+  async fn test_pack() {
+      for (encoding, x, y) in test_points() {
+          assert!(pack(affine_point(x, y)).await == to_array_32(encoding));
+      }
+  }
+
+  #[tokio::test]
+  async fn test_pack_projective() {
+      // G with x, y and z multiplied by 2 has the same encoding
+      let (encoding, x, y) = test_points()[0].clone();
+      let p = ge25519{ 
+          x: Element{ l_0: 2 * x.l_0, l_1: 2 * x.l_1, l_2: 2 * x.l_2, l_3: 2 * x.l_3, l_4: 2 * x.l_4 },
+          y: Element{ l_0: 2 * y.l_0, l_1: 2 * y.l_1, l_2: 2 * y.l_2, l_3: 2 * y.l_3, l_4: 2 * y.l_4 },
+          z: Element{ l_0: 2, l_1: 0, l_2: 0, l_3: 0, l_4: 0 },
+          t: Element{ l_0: 0, l_1: 0, l_2: 0, l_3: 0, l_4: 0 },
+      };
+      assert!(pack(p).await == to_array_32(encoding));
+  }
+
+  #[tokio::test]
+  async fn test_unpack_pack_identity() {
+      let encoding = "0100000000000000000000000000000000000000000000000000000000000000";
+      let res = unpack(to_array_32(encoding)).await;
+      assert!(res.x == Element{ l_0: 0, l_1: 0, l_2: 0, l_3: 0, l_4: 0 });
+      assert!(res.y == ONE);
+      assert!(pack(res).await == to_array_32(encoding));
+  }
+
+  #[tokio::test]
+  async fn test_p1p1_to_p2() {
+      // doubling G with dbl_p1p1 and converting the result with p1p1_to_p2 gives 2G
+      let (_, x, y) = test_points()[0].clone();
       let z = Element{ l_0: 1, l_1: 0, l_2: 0, l_3: 0, l_4: 0 };
       let test_point = ge25519_p2 { x:x, y:y, z:z };
 
       let (_instance, _id) = get_contract_methods().await;
 
-      let res = _instance.dbl_p1p1(test_point)
-          .tx_params(TxParameters::new(None, Some(100_000_000), None, None))
-          .call_params(CallParameters::new(None, None, Some(100_000_000)))
+      let p1p1 = _instance.dbl_p1p1(test_point)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
           .call().await.unwrap().value;
 
-      print!("{}", res.x);
-      print!("{}", res.y);
-      print!("{}", res.z);
-      print!("{}", res.t);
-      // assert!(res.x == 24727413235106541002554574571675588834622768167397638456726423682521233608206);
-      // assert!(res.y == 15549675580280190176352668710449542251549572066445060580507079593062643049417);
+      let res = _instance.p1p1_to_p2(p1p1)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+
+      // pack only uses x, y and z
+      let p = ge25519{ 
+          x: res.x,
+          y: res.y,
+          z: res.z,
+          t: Element{ l_0: 0, l_1: 0, l_2: 0, l_3: 0, l_4: 0 },
+      };
+      assert!(pack(p).await == to_array_32("c9a3f86aae465f0e56513864510f3997561fa2c9e85ea21dc2292309f3cd6022"));
+  }
+}
+
+mod revert {
+  use super::*;
+
+  async fn unpack_fails(encoding: &str) -> bool {
+      let (_instance, _id) = get_contract_methods().await;
+
+      _instance.unpack(to_array_32(encoding))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.is_err()
+  }
 
+  #[tokio::test]
+  async fn test_unpack_not_on_curve() {
+      // there is no point with y = 2
+      assert!(unpack_fails("0200000000000000000000000000000000000000000000000000000000000000").await);
+  }
+
+  #[tokio::test]
+  async fn test_unpack_non_canonical() {
+      // y = p + 1 isn't reduced
+      assert!(unpack_fails("eeffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f").await);
+  }
+
+  #[tokio::test]
+  async fn test_unpack_negative_zero() {
+      // x = 0 with the sign bit set
+      assert!(unpack_fails("0100000000000000000000000000000000000000000000000000000000000080").await);
   }
-   */
-} 
\ No newline at end of file
+}
\ No newline at end of file
@@ -0,0 +1,146 @@
+use crate::utils::helpers::get_contract_methods;
+use curve25519_dalek::scalar::Scalar as DalekScalar;
+use fuels::{
+    prelude::*,
+    tx::{ConsensusParameters, ContractId},
+};
+use sha2::{Digest, Sha512};
+
+// ℓ = 2^252 + 27742317777372353535851937790883648493, little-endian
+const L: [u8; 32] = [
+  0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
+  0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
+];
+
+// returns 64 pseudorandom bytes, derived from `seed`
+fn wide_bytes(seed: u8) -> [u8; 64] {
+  Sha512::digest([seed]).into()
+}
+
+// returns a pseudorandom scalar, derived from `seed`
+fn random_scalar(seed: u8) -> DalekScalar {
+  DalekScalar::from_bytes_mod_order_wide(&wide_bytes(seed))
+}
+
+mod success {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_from_bytes_mod_order_wide() {
+      let (_instance, _id) = get_contract_methods().await;
+
+      for seed in 0..4 {
+          let bytes = wide_bytes(seed);
+          let res = _instance.scalar_from_bytes_mod_order_wide(bytes)
+              .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+              .call().await.unwrap().value;
+
+          assert!(res == DalekScalar::from_bytes_mod_order_wide(&bytes).to_bytes());
+      }
+
+      // 2^512 - 1
+      let res = _instance.scalar_from_bytes_mod_order_wide([0xff; 64])
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(res == DalekScalar::from_bytes_mod_order_wide(&[0xff; 64]).to_bytes());
+  }
+
+  #[tokio::test]
+  async fn test_from_bytes_mod_order() {
+      let (_instance, _id) = get_contract_methods().await;
+
+      for bytes in [L, [0xff; 32], [0u8; 32]] {
+          let res = _instance.scalar_from_bytes_mod_order(bytes)
+              .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+              .call().await.unwrap().value;
+
+          assert!(res == DalekScalar::from_bytes_mod_order(bytes).to_bytes());
+      }
+  }
+
+  #[tokio::test]
+  async fn test_is_canonical() {
+      let mut l_minus_1 = L;
+      l_minus_1[0] -= 1;
+      let (_instance, _id) = get_contract_methods().await;
+
+      for bytes in [[0u8; 32], l_minus_1, L, [0xff; 32], random_scalar(0).to_bytes()] {
+          let res = _instance.scalar_is_canonical(bytes)
+              .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+              .call().await.unwrap().value;
+
+          assert!(res == bool::from(DalekScalar::from_canonical_bytes(bytes).is_some()));
+      }
+  }
+
+  #[tokio::test]
+  async fn test_add() {
+      let (_instance, _id) = get_contract_methods().await;
+
+      // the last pair overflows ℓ
+      let minus_one = -DalekScalar::ONE;
+      let pairs = [
+          (random_scalar(1), random_scalar(2)),
+          (random_scalar(3), random_scalar(4)),
+          (minus_one, minus_one),
+      ];
+      for (a, b) in pairs {
+          let res = _instance.scalar_add(a.to_bytes(), b.to_bytes())
+              .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+              .call().await.unwrap().value;
+
+          assert!(res == (a + b).to_bytes());
+      }
+  }
+
+  #[tokio::test]
+  async fn test_mul() {
+      let (_instance, _id) = get_contract_methods().await;
+
+      let minus_one = -DalekScalar::ONE;
+      let pairs = [
+          (random_scalar(5), random_scalar(6)),
+          (random_scalar(7), DalekScalar::ONE),
+          (minus_one, minus_one),
+          (random_scalar(8), DalekScalar::ZERO),
+      ];
+      for (a, b) in pairs {
+          let res = _instance.scalar_mul(a.to_bytes(), b.to_bytes())
+              .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+              .call().await.unwrap().value;
+
+          assert!(res == (a * b).to_bytes());
+      }
+  }
+
+  #[tokio::test]
+  async fn test_eq() {
+      let (_instance, _id) = get_contract_methods().await;
+
+      let a = random_scalar(9).to_bytes();
+      let b = random_scalar(10).to_bytes();
+      let equal = _instance.scalar_eq(a, a)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      let not_equal = _instance.scalar_eq(a, b)
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+
+      assert!(equal);
+      assert!(!not_equal);
+  }
+}
+
+mod revert {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_add_non_canonical() {
+      let (_instance, _id) = get_contract_methods().await;
+
+      let res = _instance.scalar_add(L, random_scalar(11).to_bytes())
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await;
+      assert!(res.is_err());
+  }
+}
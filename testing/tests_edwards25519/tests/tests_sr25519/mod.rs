@@ -0,0 +1,160 @@
+use crate::utils::helpers::get_contract_methods;
+use fuels::{
+    prelude::*,
+    tx::{ConsensusParameters, ContractId},
+};
+
+/*
+Sr25519 test vectors generated with the schnorrkel crate (v0.11.4): keypairs from MiniSecretKey::from_bytes(&[n; 32])
+with ExpansionMode::Ed25519, signed with `sign` on signing_context(context).bytes(msg).
+The rejected signatures also fail schnorrkel's `verify_simple`.
+The 200-byte message is longer than the STROBE-128 rate, so the permutation is applied while absorbing it.
+*/
+
+// (pubkey, context, msg, sig), all hex encoded
+const VECTORS: [(&str, &str, &str, &str); 4] = [
+  (
+    "189dac29296d31814dc8c56cf3d36a0543372bba7538fa322a4aebfebc39e056",
+    "737562737472617465",
+    "",
+    "0858cf573b4119349a5075969d7b9ba1dfe8d00f1529efae6fef43800885c76afe03eee2164b9ac280b8a3242b9a6168bd84c340da4d3a55402a331976f6c98d",
+  ),
+  (
+    "1a4fee48c1ba1a48e8cd43782a8485d635aa91cfb82cbb477f0c1c576bc4031c",
+    "737562737472617465",
+    "68656c6c6f20776f726c64",
+    "a0dc06aac1793f46a9aa88839f017e5cc50654c9bfb120f23c5186d0fb9c58389fc25daa0569d8c00d24fc61185d69aba5c9c44a9ea048f139fe35f4b8490482",
+  ),
+  (
+    "8ee504148e75c34e8f051899b3c6e4241ff18dc1c9211260b6a6a434bedb485f",
+    "",
+    "566572696679696e672074686174204920616d20746865206f776e6572",
+    "cceef0775120224175167204f2cfc1bde713d3b81169e873208df1289345105d9631dfe9507814280e20c56c5b7a256f3aefc2b57bed305dbffd2d21ccdbb884",
+  ),
+  (
+    "c2e2bd71e04a6af2897c3414d6fd403477245060fd22daaa412ff51b83c0c22e",
+    "676f6f64",
+    "6161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161616161",
+    "9472be4007a29c21a655cec021db5667a3b0e8c83793107dc4c65dcc0d0af518e355ef6c94110ca749a281f683864ac191bb651e3dc3bcf7764e7d9e2069b48a",
+  ),
+];
+
+fn to_array_32(s: &str) -> [u8; 32] {
+  hex::decode(s).unwrap().try_into().unwrap()
+}
+
+fn to_array_64(s: &str) -> [u8; 64] {
+  hex::decode(s).unwrap().try_into().unwrap()
+}
+
+async fn verify(pubkey: &str, context: &str, msg: &str, sig: &str) -> bool {
+  let (_instance, _id) = get_contract_methods().await;
+
+  _instance.sr25519_verify(to_array_32(pubkey), hex::decode(context).unwrap(), hex::decode(msg).unwrap(), to_array_64(sig))
+      .tx_params(TxParameters::default().set_gas_limit(1_000_000_000))
+      .call().await.unwrap().value
+}
+
+mod success {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_keccak_f1600_zero_state() {
+      // Keccak-f[1600] applied to the all-zero state, from the Keccak team's KeccakF-1600-IntermediateValues.txt
+      let expected_res: [u64; 25] = [
+          0xF1258F7940E1DDE7, 0x84D5CCF933C0478A, 0xD598261EA65AA9EE, 0xBD1547306F80494D,
+          0x8B284E056253D057, 0xFF97A42D7F8E6FD4, 0x90FEE5A0A44647C4, 0x8C5BDA0CD6192E76,
+          0xAD30A6F71B19059C, 0x30935AB7D08FFC64, 0xEB5AA93F2317D635, 0xA9A6E6260D712103,
+          0x81A57C16DBCF555F, 0x43B831CD0347C826, 0x01F22F1A11A5569F, 0x05E5635A21D9AE61,
+          0x64BEFEF28CC970F2, 0x613670957BC46611, 0xB87C5A554FD00ECB, 0x8C3EE88A1CCF32C8,
+          0x940C7922AE3A2614, 0x1841F924A2C509E4, 0x16F53526E70465C2, 0x75F644E97F30A13B,
+          0xEAF1FF7B5CECA249,
+      ];
+
+      let (_instance, _id) = get_contract_methods().await;
+
+      let res = _instance.keccak_f1600([0u64; 25])
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+      assert!(res == expected_res);
+  }
+
+  #[tokio::test]
+  async fn test_verify_empty_message() {
+      let (pubkey, context, msg, sig) = VECTORS[0];
+      assert!(verify(pubkey, context, msg, sig).await);
+  }
+
+  #[tokio::test]
+  async fn test_verify_hello_world() {
+      let (pubkey, context, msg, sig) = VECTORS[1];
+      assert!(verify(pubkey, context, msg, sig).await);
+  }
+
+  #[tokio::test]
+  async fn test_verify_empty_context() {
+      let (pubkey, context, msg, sig) = VECTORS[2];
+      assert!(verify(pubkey, context, msg, sig).await);
+  }
+
+  #[tokio::test]
+  async fn test_verify_long_message() {
+      let (pubkey, context, msg, sig) = VECTORS[3];
+      assert!(verify(pubkey, context, msg, sig).await);
+  }
+
+  #[tokio::test]
+  async fn test_verify_modified_message() {
+      let (pubkey, context, _, sig) = VECTORS[1];
+      // "hello worlD"
+      assert!(!verify(pubkey, context, "68656c6c6f20776f726c44", sig).await);
+  }
+
+  #[tokio::test]
+  async fn test_verify_wrong_context() {
+      let (pubkey, _, msg, sig) = VECTORS[1];
+      // "substratf"
+      assert!(!verify(pubkey, "737562737472617466", msg, sig).await);
+  }
+
+  #[tokio::test]
+  async fn test_verify_wrong_pubkey() {
+      let (_, context, msg, sig) = VECTORS[1];
+      let (pubkey, _, _, _) = VECTORS[0];
+      assert!(!verify(pubkey, context, msg, sig).await);
+  }
+
+  #[tokio::test]
+  async fn test_verify_not_marked_schnorrkel() {
+      // the signature of VECTORS[1] with the most significant bit of s cleared
+      let (pubkey, context, msg, _) = VECTORS[1];
+      assert!(!verify(pubkey, context, msg, "a0dc06aac1793f46a9aa88839f017e5cc50654c9bfb120f23c5186d0fb9c58389fc25daa0569d8c00d24fc61185d69aba5c9c44a9ea048f139fe35f4b8490402").await);
+  }
+
+  #[tokio::test]
+  async fn test_verify_preaudit_signature() {
+      // The signature from schnorrkel's can_verify_know_preaudit_deprecated_message test. It is only accepted by
+      // verify_simple_preaudit_deprecated, and isn't marked as a schnorrkel signature.
+      assert!(!verify(
+          "b4bfa1f7a5166695eb75299fd1c4c03ea212871c342f2c5dfea0902b2c246918",
+          // "substrate"
+          "737562737472617465",
+          "566572696679696e672074686174204920616d20746865206f776e6572206f662035473968514c64734b5173774e50674234393944654135506b464262676b4c504a576b6b533646414d367847513878442e20486173683a2032323134353561330a",
+          "5a9755f069939f45d96aaf125cf5ce7ba1db998686f87f2fb3cbdea922078741a73891ba265f70c31436e18a9acd14d189d73c12317ab6c313285cd938453202",
+      ).await);
+  }
+
+  #[tokio::test]
+  async fn test_verify_non_canonical_s() {
+      // the signature of VECTORS[1] with s replaced by s + ℓ
+      let (pubkey, context, msg, _) = VECTORS[1];
+      assert!(!verify(pubkey, context, msg, "a0dc06aac1793f46a9aa88839f017e5cc50654c9bfb120f23c5186d0fb9c58388c96530720ccea18e4c0f304f75648c0a5c9c44a9ea048f139fe35f4b8490492").await);
+  }
+
+  #[tokio::test]
+  async fn test_verify_invalid_pubkey() {
+      // p, which isn't a canonical ristretto255 encoding
+      let (_, context, msg, sig) = VECTORS[1];
+      assert!(!verify("edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f", context, msg, sig).await);
+  }
+}
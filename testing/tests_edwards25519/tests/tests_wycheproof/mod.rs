@@ -0,0 +1,88 @@
+use crate::utils::helpers::get_contract_methods;
+use fuels::prelude::*;
+use serde::Deserialize;
+
+/*
+The Ed25519 test vectors of Wycheproof, https://github.com/google/wycheproof/blob/master/testvectors/eddsa_test.json
+The file is read from tests/tests_wycheproof/eddsa_test.json and is used unmodified.
+Cases with result "valid" must verify and cases with result "invalid" must not. There are no "acceptable" cases
+for Ed25519, but they would still be run, with either outcome allowed. Every case of the file must be run.
+*/
+
+const VECTORS_PATH: &str = "tests/tests_wycheproof/eddsa_test.json";
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TestFile {
+  number_of_tests: usize,
+  test_groups: Vec<TestGroup>,
+}
+
+#[derive(Deserialize)]
+struct TestGroup {
+  // Older versions of the file call this field "key"
+  #[serde(rename = "publicKey", alias = "key")]
+  public_key: PublicKey,
+  tests: Vec<TestCase>,
+}
+
+#[derive(Deserialize)]
+struct PublicKey {
+  curve: String,
+  pk: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TestCase {
+  tc_id: u64,
+  comment: String,
+  msg: String,
+  sig: String,
+  result: String,
+}
+
+fn load_test_file() -> TestFile {
+  let json = std::fs::read_to_string(VECTORS_PATH).unwrap_or_else(|e| {
+      panic!("could not read {} ({}), copy it from the Wycheproof repository", VECTORS_PATH, e)
+  });
+  serde_json::from_str(&json).unwrap()
+}
+
+mod success {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_wycheproof_eddsa() {
+      let (_instance, _id) = get_contract_methods().await;
+      let file = load_test_file();
+
+      let mut count = 0;
+      for group in file.test_groups {
+          assert_eq!(group.public_key.curve, "edwards25519");
+          let pubkey: [u8; 32] = hex::decode(&group.public_key.pk).unwrap().try_into().unwrap();
+
+          for case in group.tests {
+              let expected = match case.result.as_str() {
+                  "valid" => Some(true),
+                  "invalid" => Some(false),
+                  "acceptable" => None,
+                  other => panic!("tcId {}: unknown result {}", case.tc_id, other),
+              };
+              let sig = hex::decode(&case.sig).unwrap();
+              // Truncated or overlong signatures can't be passed to the contract, which takes exactly 64 bytes
+              let verified = match <[u8; 64]>::try_from(sig) {
+                  Ok(sig) => _instance.ed25519_verify(pubkey, hex::decode(&case.msg).unwrap(), sig)
+                      .tx_params(TxParameters::default().set_gas_limit(1_000_000_000))
+                      .call().await.unwrap().value,
+                  Err(_) => false,
+              };
+              if let Some(expected) = expected {
+                  assert_eq!(verified, expected, "tcId {}: {}", case.tc_id, case.comment);
+              }
+              count += 1;
+          }
+      }
+      assert_eq!(count, file.number_of_tests);
+  }
+}
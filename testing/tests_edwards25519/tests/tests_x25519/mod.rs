@@ -0,0 +1,88 @@
+use crate::utils::{helpers::get_contract_methods, EdwardsTestContractMethods};
+use fuels::{
+    prelude::*,
+    tx::{ConsensusParameters, ContractId},
+};
+
+/*
+Test vectors from RFC 7748, https://www.rfc-editor.org/rfc/rfc7748
+*/
+
+fn to_array_32(s: &str) -> [u8; 32] {
+  hex::decode(s).unwrap().try_into().unwrap()
+}
+
+async fn x25519(instance: &EdwardsTestContractMethods, scalar: [u8; 32], u: [u8; 32]) -> [u8; 32] {
+  instance.x25519(scalar, u)
+      .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+      .call().await.unwrap().value
+}
+
+// Applies the iteration of RFC 7748 section 5.2 `iterations` times, starting with k = u = 9:
+// k, u = X25519(k, u), k
+async fn iterate(iterations: usize) -> [u8; 32] {
+  let (_instance, _id) = get_contract_methods().await;
+  let mut k = [0u8; 32];
+  k[0] = 9;
+  let mut u = k;
+  for _ in 0..iterations {
+      let res = x25519(&_instance, k, u).await;
+      u = k;
+      k = res;
+  }
+  k
+}
+
+mod success {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_x25519_vector_1() {
+      let (_instance, _id) = get_contract_methods().await;
+      let res = x25519(&_instance, 
+          to_array_32("a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4"),
+          to_array_32("e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c"),
+      ).await;
+      assert!(res == to_array_32("c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552"));
+  }
+
+  #[tokio::test]
+  async fn test_x25519_vector_2() {
+      // the most significant bit of u is set, and has to be ignored
+      let (_instance, _id) = get_contract_methods().await;
+      let res = x25519(&_instance, 
+          to_array_32("4b66e9d4d1b4673c5ad22691957d6af5c11b6421e0ea01d42ca4169e7918ba0d"),
+          to_array_32("e5210f12786811d3f4b7959d0538ae2c31dbe7106fc03c3efc4cd549c715a493"),
+      ).await;
+      assert!(res == to_array_32("95cbde9476e8907d7aade45cb4b873f88b595a68799fa152e6f8f7647aac7957"));
+  }
+
+  #[tokio::test]
+  async fn test_x25519_1_iteration() {
+      assert!(iterate(1).await == to_array_32("422c8e7a6227d7bca1350b3e2bb7279f7897b87bb6854b783c60e80311ae3079"));
+  }
+
+  #[tokio::test]
+  async fn test_x25519_1000_iterations() {
+      assert!(iterate(1000).await == to_array_32("684cf59ba83309552800ef566f2f4d3c1c3887c49360e3875f2eb94d99532c51"));
+  }
+
+  #[tokio::test]
+  async fn test_diffie_hellman() {
+      // RFC 7748 section 6.1
+      let alice_private = to_array_32("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a");
+      let alice_public = to_array_32("8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a");
+      let bob_private = to_array_32("5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb");
+      let bob_public = to_array_32("de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f");
+      let shared_secret = to_array_32("4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742");
+
+      let (_instance, _id) = get_contract_methods().await;
+      let mut basepoint = [0u8; 32];
+      basepoint[0] = 9;
+
+      assert!(x25519(&_instance, alice_private, basepoint).await == alice_public);
+      assert!(x25519(&_instance, bob_private, basepoint).await == bob_public);
+      assert!(x25519(&_instance, alice_private, bob_public).await == shared_secret);
+      assert!(x25519(&_instance, bob_private, alice_public).await == shared_secret);
+  }
+}
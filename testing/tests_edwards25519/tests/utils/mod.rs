@@ -2,19 +2,23 @@ use fuels::{
   prelude::*,
   tx::{ConsensusParameters, ContractId},
 };
+use fuel_core_chain_config::ChainConfig;
 
-abigen!(EdwardsTestContract, "out/debug/tests_edwards25519-abi.json");
+abigen!(Contract(
+    name = "EdwardsTestContract",
+    abi = "out/debug/tests_edwards25519-abi.json"
+));
 
 pub mod helpers {
   use super::*;
 
-  pub async fn get_contract_methods() -> (EdwardsTestContractMethods, Bech32ContractId) {
+  pub async fn get_contract_methods() -> (EdwardsTestContractMethods<WalletUnlocked>, ContractId) {
     let mut wallet = WalletUnlocked::new_random(None);
     let num_assets = 1;
     let coins_per_asset = 100;
     let amount_per_coin = 100000;
 
-    let (coins, asset_ids) = setup_multiple_assets_coins(
+    let (coins, _asset_ids) = setup_multiple_assets_coins(
         wallet.address(),
         num_assets,
         coins_per_asset,
@@ -22,9 +26,13 @@ pub mod helpers {
     );
 
     // Custom gas limit
-    let consensus_parameters_config = ConsensusParameters::DEFAULT.with_max_gas_per_tx(1000000000);
+    let consensus_parameters_config = ConsensusParameters::DEFAULT
+      .with_max_gas_per_tx(100_000_000_000).with_gas_per_byte(0);
+    let mut chain_config = ChainConfig::local_testnet();
+    // This is needed to allow for expensive operations
+    chain_config.block_gas_limit = 100_000_000_000;
 
-    let (client, addr) = setup_test_client(coins, vec![], None, Some(consensus_parameters_config)).await;
+    let (client, _addr) = setup_test_client(coins, vec![], None, Some(chain_config), Some(consensus_parameters_config)).await;
     
     let provider = Provider::new(client);
     wallet.set_provider(provider.clone());
@@ -32,11 +40,10 @@ pub mod helpers {
     let id = Contract::deploy(
         "./out/debug/tests_edwards25519.bin",
         &wallet,
-        TxParameters::default(),
-        StorageConfiguration::default(),
+        DeployConfiguration::default(),
     ).await.unwrap();
 
-    let instance = EdwardsTestContract::new(id.to_string(), wallet);
-    (instance.methods(), id)
+    let instance = EdwardsTestContract::new(id.clone(), wallet);
+    (instance.methods(), id.into())
   }
 }
\ No newline at end of file
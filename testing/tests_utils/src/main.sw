@@ -0,0 +1,475 @@
+contract;
+
+use utils::{
+    bigint::{U256, U384, U512},
+    choice::{Choice, CtOption, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess},
+    monty::{MontyField, MontyField384},
+};
+use p256::field::P256FieldParams;
+use bls12_381::{fp::FpParams, scalar::ScalarParams};
+
+abi UtilsTestContract {
+    // U256
+    fn u256_adc(a: U256, b: U256, carry: u64) -> (U256, u64);
+    fn u256_sbb(a: U256, b: U256, borrow: u64) -> (U256, u64);
+    fn u256_mul_wide(a: U256, b: U256) -> (U256, U256);
+    fn u256_shl_vartime(a: U256, shift: u64) -> U256;
+    fn u256_shr_vartime(a: U256, shift: u64) -> U256;
+    fn u256_from_be_bytes(bytes: [u8; 32]) -> U256;
+    fn u256_to_be_bytes(a: U256) -> [u8; 32];
+    fn u256_ct_eq(a: U256, b: U256) -> bool;
+    fn u256_ct_gt(a: U256, b: U256) -> bool;
+    fn u256_ct_lt(a: U256, b: U256) -> bool;
+    fn u256_conditional_select(a: U256, b: U256, choice: u8) -> U256;
+    fn u256_add_mod(a: U256, b: U256, p: U256) -> U256;
+    fn u256_sub_mod(a: U256, b: U256, p: U256) -> U256;
+
+    // U384
+    fn u384_adc(a: U384, b: U384, carry: u64) -> (U384, u64);
+    fn u384_sbb(a: U384, b: U384, borrow: u64) -> (U384, u64);
+    fn u384_mul_wide(a: U384, b: U384) -> (U384, U384);
+    fn u384_shl_vartime(a: U384, shift: u64) -> U384;
+    fn u384_shr_vartime(a: U384, shift: u64) -> U384;
+    fn u384_from_be_bytes(bytes: [u8; 48]) -> U384;
+    fn u384_to_be_bytes(a: U384) -> [u8; 48];
+    fn u384_ct_eq(a: U384, b: U384) -> bool;
+    fn u384_ct_gt(a: U384, b: U384) -> bool;
+    fn u384_ct_lt(a: U384, b: U384) -> bool;
+    fn u384_conditional_select(a: U384, b: U384, choice: u8) -> U384;
+    fn u384_add_mod(a: U384, b: U384, p: U384) -> U384;
+    fn u384_sub_mod(a: U384, b: U384, p: U384) -> U384;
+
+    // U512
+    fn u512_adc(a: U512, b: U512, carry: u64) -> (U512, u64);
+    fn u512_sbb(a: U512, b: U512, borrow: u64) -> (U512, u64);
+    fn u512_mul_wide(a: U512, b: U512) -> (U512, U512);
+    fn u512_shl_vartime(a: U512, shift: u64) -> U512;
+    fn u512_shr_vartime(a: U512, shift: u64) -> U512;
+    fn u512_from_be_bytes(bytes: [u8; 64]) -> U512;
+    fn u512_to_be_bytes(a: U512) -> [u8; 64];
+    fn u512_ct_eq(a: U512, b: U512) -> bool;
+    fn u512_ct_gt(a: U512, b: U512) -> bool;
+    fn u512_ct_lt(a: U512, b: U512) -> bool;
+    fn u512_conditional_select(a: U512, b: U512, choice: u8) -> U512;
+    fn u512_add_mod(a: U512, b: U512, p: U512) -> U512;
+    fn u512_sub_mod(a: U512, b: U512, p: U512) -> U512;
+
+    // conversions between widths
+    fn u512_from_halves(lo: U256, hi: U256) -> U512;
+    fn u512_split(a: U512) -> (U256, U256);
+    fn u256_resize_512(a: U256) -> U512;
+
+    // choice
+    fn choice_not(a: u8) -> u8;
+    fn choice_ct_eq(a: u8, b: u8) -> bool;
+    fn u8_ct_eq(a: u8, b: u8) -> bool;
+    fn u8_ct_gt(a: u8, b: u8) -> bool;
+    fn u8_ct_lt(a: u8, b: u8) -> bool;
+    fn u32_ct_eq(a: u32, b: u32) -> bool;
+    fn u32_ct_gt(a: u32, b: u32) -> bool;
+    fn u32_ct_lt(a: u32, b: u32) -> bool;
+    fn u64_ct_gt(a: u64, b: u64) -> bool;
+    fn u64_ct_lt(a: u64, b: u64) -> bool;
+    fn bytes_ct_eq(a: [u8; 32], b: [u8; 32]) -> bool;
+    fn bytes_conditional_select(a: [u8; 32], b: [u8; 32], choice: u8) -> [u8; 32];
+    fn limbs4_ct_eq(a: [u64; 4], b: [u64; 4]) -> bool;
+    fn limbs4_conditional_select(a: [u64; 4], b: [u64; 4], choice: u8) -> [u64; 4];
+    fn limbs6_ct_eq(a: [u64; 6], b: [u64; 6]) -> bool;
+    fn limbs6_conditional_select(a: [u64; 6], b: [u64; 6], choice: u8) -> [u64; 6];
+    fn b256_ct_eq(a: b256, b: b256) -> bool;
+    fn b256_conditional_select(a: b256, b: b256, choice: u8) -> b256;
+
+    // CtOption
+    fn ct_option_map(value: u64, is_some: u8, mapped: [u64; 4]) -> CtOption<[u64; 4]>;
+    fn ct_option_and_then(value: u64, is_some: u8, next_value: [u64; 4], next_is_some: u8) -> CtOption<[u64; 4]>;
+    fn ct_option_unwrap_or(value: u64, is_some: u8, def: u64) -> u64;
+    fn ct_option_or_else(value: u64, is_some: u8, alternative_value: u64, alternative_is_some: u8) -> CtOption<u64>;
+
+    // MontyField, P-256 field
+    fn p256_to_montgomery(a: U256) -> U256;
+    fn p256_from_montgomery(a: U256) -> U256;
+    fn p256_mul(a: U256, b: U256) -> U256;
+    fn p256_square(a: U256) -> U256;
+    fn p256_invert(a: U256) -> (U256, bool);
+    fn p256_pow_vartime(a: U256, exp: U256) -> U256;
+    fn p256_from_wide(lo: U256, hi: U256) -> U256;
+    // MontyField, BLS12-381 scalar field
+    fn bls_scalar_to_montgomery(a: U256) -> U256;
+    fn bls_scalar_from_montgomery(a: U256) -> U256;
+    fn bls_scalar_mul(a: U256, b: U256) -> U256;
+    fn bls_scalar_square(a: U256) -> U256;
+    fn bls_scalar_invert(a: U256) -> (U256, bool);
+    fn bls_scalar_pow_vartime(a: U256, exp: U256) -> U256;
+    fn bls_scalar_from_wide(lo: U256, hi: U256) -> U256;
+    // MontyField, BLS12-381 base field
+    fn bls_fp_to_montgomery(a: U384) -> U384;
+    fn bls_fp_from_montgomery(a: U384) -> U384;
+    fn bls_fp_mul(a: U384, b: U384) -> U384;
+    fn bls_fp_square(a: U384) -> U384;
+    fn bls_fp_invert(a: U384) -> (U384, bool);
+    fn bls_fp_pow_vartime(a: U384, exp: U384) -> U384;
+    fn bls_fp_from_wide(lo: U384, hi: U384) -> U384;
+}
+
+impl UtilsTestContract for Contract {
+    // U256
+    fn u256_adc(a: U256, b: U256, carry: u64) -> (U256, u64) {
+        a.adc(b, carry)
+    }
+
+    fn u256_sbb(a: U256, b: U256, borrow: u64) -> (U256, u64) {
+        a.sbb(b, borrow)
+    }
+
+    fn u256_mul_wide(a: U256, b: U256) -> (U256, U256) {
+        a.mul_wide(b)
+    }
+
+    fn u256_shl_vartime(a: U256, shift: u64) -> U256 {
+        a.shl_vartime(shift)
+    }
+
+    fn u256_shr_vartime(a: U256, shift: u64) -> U256 {
+        a.shr_vartime(shift)
+    }
+
+    fn u256_from_be_bytes(bytes: [u8; 32]) -> U256 {
+        U256::from_be_bytes(bytes)
+    }
+
+    fn u256_to_be_bytes(a: U256) -> [u8; 32] {
+        a.to_be_bytes()
+    }
+
+    fn u256_ct_eq(a: U256, b: U256) -> bool {
+        a.ct_eq(b).unwrap_as_bool()
+    }
+
+    fn u256_ct_gt(a: U256, b: U256) -> bool {
+        a.ct_gt(b).unwrap_as_bool()
+    }
+
+    fn u256_ct_lt(a: U256, b: U256) -> bool {
+        a.ct_lt(b).unwrap_as_bool()
+    }
+
+    fn u256_conditional_select(a: U256, b: U256, choice: u8) -> U256 {
+        U256::conditional_select(a, b, Choice::from(choice))
+    }
+
+    fn u256_add_mod(a: U256, b: U256, p: U256) -> U256 {
+        a.add_mod(b, p)
+    }
+
+    fn u256_sub_mod(a: U256, b: U256, p: U256) -> U256 {
+        a.sub_mod(b, p)
+    }
+
+    // U384
+    fn u384_adc(a: U384, b: U384, carry: u64) -> (U384, u64) {
+        a.adc(b, carry)
+    }
+
+    fn u384_sbb(a: U384, b: U384, borrow: u64) -> (U384, u64) {
+        a.sbb(b, borrow)
+    }
+
+    fn u384_mul_wide(a: U384, b: U384) -> (U384, U384) {
+        a.mul_wide(b)
+    }
+
+    fn u384_shl_vartime(a: U384, shift: u64) -> U384 {
+        a.shl_vartime(shift)
+    }
+
+    fn u384_shr_vartime(a: U384, shift: u64) -> U384 {
+        a.shr_vartime(shift)
+    }
+
+    fn u384_from_be_bytes(bytes: [u8; 48]) -> U384 {
+        U384::from_be_bytes(bytes)
+    }
+
+    fn u384_to_be_bytes(a: U384) -> [u8; 48] {
+        a.to_be_bytes()
+    }
+
+    fn u384_ct_eq(a: U384, b: U384) -> bool {
+        a.ct_eq(b).unwrap_as_bool()
+    }
+
+    fn u384_ct_gt(a: U384, b: U384) -> bool {
+        a.ct_gt(b).unwrap_as_bool()
+    }
+
+    fn u384_ct_lt(a: U384, b: U384) -> bool {
+        a.ct_lt(b).unwrap_as_bool()
+    }
+
+    fn u384_conditional_select(a: U384, b: U384, choice: u8) -> U384 {
+        U384::conditional_select(a, b, Choice::from(choice))
+    }
+
+    fn u384_add_mod(a: U384, b: U384, p: U384) -> U384 {
+        a.add_mod(b, p)
+    }
+
+    fn u384_sub_mod(a: U384, b: U384, p: U384) -> U384 {
+        a.sub_mod(b, p)
+    }
+
+    // U512
+    fn u512_adc(a: U512, b: U512, carry: u64) -> (U512, u64) {
+        a.adc(b, carry)
+    }
+
+    fn u512_sbb(a: U512, b: U512, borrow: u64) -> (U512, u64) {
+        a.sbb(b, borrow)
+    }
+
+    fn u512_mul_wide(a: U512, b: U512) -> (U512, U512) {
+        a.mul_wide(b)
+    }
+
+    fn u512_shl_vartime(a: U512, shift: u64) -> U512 {
+        a.shl_vartime(shift)
+    }
+
+    fn u512_shr_vartime(a: U512, shift: u64) -> U512 {
+        a.shr_vartime(shift)
+    }
+
+    fn u512_from_be_bytes(bytes: [u8; 64]) -> U512 {
+        U512::from_be_bytes(bytes)
+    }
+
+    fn u512_to_be_bytes(a: U512) -> [u8; 64] {
+        a.to_be_bytes()
+    }
+
+    fn u512_ct_eq(a: U512, b: U512) -> bool {
+        a.ct_eq(b).unwrap_as_bool()
+    }
+
+    fn u512_ct_gt(a: U512, b: U512) -> bool {
+        a.ct_gt(b).unwrap_as_bool()
+    }
+
+    fn u512_ct_lt(a: U512, b: U512) -> bool {
+        a.ct_lt(b).unwrap_as_bool()
+    }
+
+    fn u512_conditional_select(a: U512, b: U512, choice: u8) -> U512 {
+        U512::conditional_select(a, b, Choice::from(choice))
+    }
+
+    fn u512_add_mod(a: U512, b: U512, p: U512) -> U512 {
+        a.add_mod(b, p)
+    }
+
+    fn u512_sub_mod(a: U512, b: U512, p: U512) -> U512 {
+        a.sub_mod(b, p)
+    }
+
+    // conversions between widths
+    fn u512_from_halves(lo: U256, hi: U256) -> U512 {
+        U512::from_halves(lo, hi)
+    }
+
+    fn u512_split(a: U512) -> (U256, U256) {
+        a.split()
+    }
+
+    fn u256_resize_512(a: U256) -> U512 {
+        a.resize_512()
+    }
+
+    // choice
+    fn choice_not(a: u8) -> u8 {
+        (!Choice::from(a)).unwrap_u8()
+    }
+
+    fn choice_ct_eq(a: u8, b: u8) -> bool {
+        Choice::from(a).ct_eq(Choice::from(b)).unwrap_as_bool()
+    }
+
+    fn u8_ct_eq(a: u8, b: u8) -> bool {
+        a.ct_eq(b).unwrap_as_bool()
+    }
+
+    fn u8_ct_gt(a: u8, b: u8) -> bool {
+        a.ct_gt(b).unwrap_as_bool()
+    }
+
+    fn u8_ct_lt(a: u8, b: u8) -> bool {
+        a.ct_lt(b).unwrap_as_bool()
+    }
+
+    fn u32_ct_eq(a: u32, b: u32) -> bool {
+        a.ct_eq(b).unwrap_as_bool()
+    }
+
+    fn u32_ct_gt(a: u32, b: u32) -> bool {
+        a.ct_gt(b).unwrap_as_bool()
+    }
+
+    fn u32_ct_lt(a: u32, b: u32) -> bool {
+        a.ct_lt(b).unwrap_as_bool()
+    }
+
+    fn u64_ct_gt(a: u64, b: u64) -> bool {
+        a.ct_gt(b).unwrap_as_bool()
+    }
+
+    fn u64_ct_lt(a: u64, b: u64) -> bool {
+        a.ct_lt(b).unwrap_as_bool()
+    }
+
+    fn bytes_ct_eq(a: [u8; 32], b: [u8; 32]) -> bool {
+        a.ct_eq(b).unwrap_as_bool()
+    }
+
+    fn bytes_conditional_select(a: [u8; 32], b: [u8; 32], choice: u8) -> [u8; 32] {
+        // select through unwrap_or, which calls the conditional_select of [u8; 32]
+        CtOption::new(a, Choice::from(choice)).unwrap_or(b)
+    }
+
+    fn limbs4_ct_eq(a: [u64; 4], b: [u64; 4]) -> bool {
+        a.ct_eq(b).unwrap_as_bool()
+    }
+
+    fn limbs4_conditional_select(a: [u64; 4], b: [u64; 4], choice: u8) -> [u64; 4] {
+        // select through unwrap_or, which calls the conditional_select of [u64; 4]
+        CtOption::new(a, Choice::from(choice)).unwrap_or(b)
+    }
+
+    fn limbs6_ct_eq(a: [u64; 6], b: [u64; 6]) -> bool {
+        a.ct_eq(b).unwrap_as_bool()
+    }
+
+    fn limbs6_conditional_select(a: [u64; 6], b: [u64; 6], choice: u8) -> [u64; 6] {
+        // select through unwrap_or, which calls the conditional_select of [u64; 6]
+        CtOption::new(a, Choice::from(choice)).unwrap_or(b)
+    }
+
+    fn b256_ct_eq(a: b256, b: b256) -> bool {
+        a.ct_eq(b).unwrap_as_bool()
+    }
+
+    fn b256_conditional_select(a: b256, b: b256, choice: u8) -> b256 {
+        b256::conditional_select(a, b, Choice::from(choice))
+    }
+
+    // CtOption
+    fn ct_option_map(value: u64, is_some: u8, mapped: [u64; 4]) -> CtOption<[u64; 4]> {
+        CtOption::new(value, Choice::from(is_some)).map(mapped)
+    }
+
+    fn ct_option_and_then(value: u64, is_some: u8, next_value: [u64; 4], next_is_some: u8) -> CtOption<[u64; 4]> {
+        CtOption::new(value, Choice::from(is_some)).and_then(CtOption::new(next_value, Choice::from(next_is_some)))
+    }
+
+    fn ct_option_unwrap_or(value: u64, is_some: u8, def: u64) -> u64 {
+        CtOption::new(value, Choice::from(is_some)).unwrap_or(def)
+    }
+
+    fn ct_option_or_else(value: u64, is_some: u8, alternative_value: u64, alternative_is_some: u8) -> CtOption<u64> {
+        CtOption::new(value, Choice::from(is_some)).or_else(CtOption::new(alternative_value, Choice::from(alternative_is_some)))
+    }
+
+    // MontyField, P-256 field
+    // Apart from the conversion functions, inputs and outputs are canonical values
+    // that are converted to and from Montgomery form in the contract.
+    fn p256_to_montgomery(a: U256) -> U256 {
+        MontyField::new(a, P256FieldParams {}).montgomery_form
+    }
+
+    fn p256_from_montgomery(a: U256) -> U256 {
+        MontyField::from_montgomery(a, P256FieldParams {}).retrieve()
+    }
+
+    fn p256_mul(a: U256, b: U256) -> U256 {
+        (MontyField::new(a, P256FieldParams {}) * MontyField::new(b, P256FieldParams {})).retrieve()
+    }
+
+    fn p256_square(a: U256) -> U256 {
+        MontyField::new(a, P256FieldParams {}).square().retrieve()
+    }
+
+    fn p256_invert(a: U256) -> (U256, bool) {
+        let res = MontyField::new(a, P256FieldParams {}).invert();
+        (res.value.retrieve(), res.is_some())
+    }
+
+    fn p256_pow_vartime(a: U256, exp: U256) -> U256 {
+        MontyField::new(a, P256FieldParams {}).pow_vartime(exp).retrieve()
+    }
+
+    fn p256_from_wide(lo: U256, hi: U256) -> U256 {
+        MontyField::from_wide(lo, hi, P256FieldParams {}).retrieve()
+    }
+
+    // MontyField, BLS12-381 scalar field
+    // Apart from the conversion functions, inputs and outputs are canonical values
+    // that are converted to and from Montgomery form in the contract.
+    fn bls_scalar_to_montgomery(a: U256) -> U256 {
+        MontyField::new(a, ScalarParams {}).montgomery_form
+    }
+
+    fn bls_scalar_from_montgomery(a: U256) -> U256 {
+        MontyField::from_montgomery(a, ScalarParams {}).retrieve()
+    }
+
+    fn bls_scalar_mul(a: U256, b: U256) -> U256 {
+        (MontyField::new(a, ScalarParams {}) * MontyField::new(b, ScalarParams {})).retrieve()
+    }
+
+    fn bls_scalar_square(a: U256) -> U256 {
+        MontyField::new(a, ScalarParams {}).square().retrieve()
+    }
+
+    fn bls_scalar_invert(a: U256) -> (U256, bool) {
+        let res = MontyField::new(a, ScalarParams {}).invert();
+        (res.value.retrieve(), res.is_some())
+    }
+
+    fn bls_scalar_pow_vartime(a: U256, exp: U256) -> U256 {
+        MontyField::new(a, ScalarParams {}).pow_vartime(exp).retrieve()
+    }
+
+    fn bls_scalar_from_wide(lo: U256, hi: U256) -> U256 {
+        MontyField::from_wide(lo, hi, ScalarParams {}).retrieve()
+    }
+
+    // MontyField, BLS12-381 base field
+    // Apart from the conversion functions, inputs and outputs are canonical values
+    // that are converted to and from Montgomery form in the contract.
+    fn bls_fp_to_montgomery(a: U384) -> U384 {
+        MontyField384::new(a, FpParams {}).montgomery_form
+    }
+
+    fn bls_fp_from_montgomery(a: U384) -> U384 {
+        MontyField384::from_montgomery(a, FpParams {}).retrieve()
+    }
+
+    fn bls_fp_mul(a: U384, b: U384) -> U384 {
+        (MontyField384::new(a, FpParams {}) * MontyField384::new(b, FpParams {})).retrieve()
+    }
+
+    fn bls_fp_square(a: U384) -> U384 {
+        MontyField384::new(a, FpParams {}).square().retrieve()
+    }
+
+    fn bls_fp_invert(a: U384) -> (U384, bool) {
+        let res = MontyField384::new(a, FpParams {}).invert();
+        (res.value.retrieve(), res.is_some())
+    }
+
+    fn bls_fp_pow_vartime(a: U384, exp: U384) -> U384 {
+        MontyField384::new(a, FpParams {}).pow_vartime(exp).retrieve()
+    }
+
+    fn bls_fp_from_wide(lo: U384, hi: U384) -> U384 {
+        MontyField384::from_wide(lo, hi, FpParams {}).retrieve()
+    }
+}
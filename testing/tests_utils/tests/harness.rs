@@ -0,0 +1,4 @@
+mod utils;
+mod tests_bigint;
+mod tests_choice;
+mod tests_monty;
\ No newline at end of file
@@ -0,0 +1,524 @@
+use fuels::prelude::TxParameters;
+use crate::utils::helpers::*;
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+// Every operation is checked against num-bigint on values at the limb boundaries,
+// all-ones limbs and values around a modulus.
+
+mod u256 {
+  use super::*;
+
+  // odd modulus used for the modular operations
+  fn modulus() -> BigUint {
+    p256_p()
+  }
+
+  fn values() -> Vec<BigUint> {
+    vec![
+        BigUint::zero(),
+        BigUint::one(),
+        pow2(64) - 1u64,
+        pow2(64),
+        pow2(128) - 1u64,
+        pow2(255),
+        // all limbs 0xffff_ffff_ffff_ffff
+        pow2(256) - 1u64,
+        p256_p() - 1u64,
+        p256_p(),
+        p256_p() + 1u64,
+        parse("41624337018869194729192205381537838788846303834619688597471765238035829032504"),
+    ]
+  }
+
+  // the values that are smaller than the modulus
+  fn reduced_values() -> Vec<BigUint> {
+    values().into_iter().filter(|v| *v < modulus()).collect()
+  }
+
+  #[tokio::test]
+  async fn test_adc() {
+    let (_methods, _id) = get_contract_methods().await;
+    for a in values() {
+      for b in values() {
+        for carry in [0u64, 1] {
+          let (res, carry_out) = _methods
+            .u256_adc(u256(&a), u256(&b), carry)
+            .call().await.unwrap().value;
+          let expected = &a + &b + carry;
+          assert_eq!(from_limbs(&res.ls), &expected % pow2(256));
+          assert_eq!(BigUint::from(carry_out), expected >> 256);
+        }
+      }
+    }
+  }
+
+  #[tokio::test]
+  async fn test_sbb() {
+    let (_methods, _id) = get_contract_methods().await;
+    for a in values() {
+      for b in values() {
+        // borrows are masks
+        for borrow in [0u64, u64::MAX] {
+          let (res, borrow_out) = _methods
+            .u256_sbb(u256(&a), u256(&b), borrow)
+            .call().await.unwrap().value;
+          let expected = (&a + pow2(256)) - &b - (borrow >> 63);
+          assert_eq!(from_limbs(&res.ls), &expected % pow2(256));
+          assert_eq!(borrow_out, if expected < pow2(256) { u64::MAX } else { 0 });
+        }
+      }
+    }
+  }
+
+  #[tokio::test]
+  async fn test_mul_wide() {
+    let (_methods, _id) = get_contract_methods().await;
+    for a in values() {
+      for b in values() {
+        let (lo, hi) = _methods
+          .u256_mul_wide(u256(&a), u256(&b))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+        let expected = &a * &b;
+        assert_eq!(from_limbs(&lo.ls), &expected % pow2(256));
+        assert_eq!(from_limbs(&hi.ls), expected >> 256);
+      }
+    }
+  }
+
+  #[tokio::test]
+  async fn test_shifts() {
+    let (_methods, _id) = get_contract_methods().await;
+    for a in values() {
+      for shift in [0, 1, 63, 64, 65, 127, 128, 191, 192, 255] {
+        let res = _methods
+          .u256_shl_vartime(u256(&a), shift)
+          .call().await.unwrap().value;
+        assert_eq!(from_limbs(&res.ls), (&a << shift) % pow2(256));
+
+        let res = _methods
+          .u256_shr_vartime(u256(&a), shift)
+          .call().await.unwrap().value;
+        assert_eq!(from_limbs(&res.ls), &a >> shift);
+      }
+    }
+  }
+
+  #[tokio::test]
+  async fn test_be_bytes() {
+    let (_methods, _id) = get_contract_methods().await;
+    for a in values() {
+      let bytes = _methods
+        .u256_to_be_bytes(u256(&a))
+        .call().await.unwrap().value;
+      assert_eq!(bytes, to_be_bytes::<32>(&a));
+
+      let res = _methods
+        .u256_from_be_bytes(to_be_bytes::<32>(&a))
+        .call().await.unwrap().value;
+      assert_eq!(from_limbs(&res.ls), a);
+    }
+  }
+
+  #[tokio::test]
+  async fn test_ct_compare() {
+    let (_methods, _id) = get_contract_methods().await;
+    for a in values() {
+      for b in values() {
+        let eq = _methods.u256_ct_eq(u256(&a), u256(&b)).call().await.unwrap().value;
+        let gt = _methods.u256_ct_gt(u256(&a), u256(&b)).call().await.unwrap().value;
+        let lt = _methods.u256_ct_lt(u256(&a), u256(&b)).call().await.unwrap().value;
+        assert_eq!(eq, a == b);
+        assert_eq!(gt, a > b);
+        assert_eq!(lt, a < b);
+      }
+    }
+  }
+
+  #[tokio::test]
+  async fn test_conditional_select() {
+    let (_methods, _id) = get_contract_methods().await;
+    let a = pow2(256) - 1u64;
+    let b = modulus();
+    let res = _methods.u256_conditional_select(u256(&a), u256(&b), 1).call().await.unwrap().value;
+    assert_eq!(from_limbs(&res.ls), a);
+    let res = _methods.u256_conditional_select(u256(&a), u256(&b), 0).call().await.unwrap().value;
+    assert_eq!(from_limbs(&res.ls), b);
+  }
+
+  #[tokio::test]
+  async fn test_add_sub_mod() {
+    let (_methods, _id) = get_contract_methods().await;
+    let p = modulus();
+    for a in reduced_values() {
+      for b in reduced_values() {
+        let res = _methods
+          .u256_add_mod(u256(&a), u256(&b), u256(&p))
+          .call().await.unwrap().value;
+        assert_eq!(from_limbs(&res.ls), (&a + &b) % &p);
+
+        let res = _methods
+          .u256_sub_mod(u256(&a), u256(&b), u256(&p))
+          .call().await.unwrap().value;
+        assert_eq!(from_limbs(&res.ls), (&a + &p - &b) % &p);
+      }
+    }
+  }
+}
+
+mod u384 {
+  use super::*;
+
+  // odd modulus used for the modular operations
+  fn modulus() -> BigUint {
+    bls12_381_p()
+  }
+
+  fn values() -> Vec<BigUint> {
+    vec![
+        BigUint::zero(),
+        BigUint::one(),
+        pow2(64) - 1u64,
+        pow2(64),
+        pow2(192) - 1u64,
+        pow2(192),
+        pow2(383),
+        // all limbs 0xffff_ffff_ffff_ffff
+        pow2(384) - 1u64,
+        bls12_381_p() - 1u64,
+        bls12_381_p(),
+        bls12_381_p() + 1u64,
+        bls12_381_q().pow(3) % bls12_381_p(),
+    ]
+  }
+
+  // the values that are smaller than the modulus
+  fn reduced_values() -> Vec<BigUint> {
+    values().into_iter().filter(|v| *v < modulus()).collect()
+  }
+
+  #[tokio::test]
+  async fn test_adc() {
+    let (_methods, _id) = get_contract_methods().await;
+    for a in values() {
+      for b in values() {
+        for carry in [0u64, 1] {
+          let (res, carry_out) = _methods
+            .u384_adc(u384(&a), u384(&b), carry)
+            .call().await.unwrap().value;
+          let expected = &a + &b + carry;
+          assert_eq!(from_limbs(&res.ls), &expected % pow2(384));
+          assert_eq!(BigUint::from(carry_out), expected >> 384);
+        }
+      }
+    }
+  }
+
+  #[tokio::test]
+  async fn test_sbb() {
+    let (_methods, _id) = get_contract_methods().await;
+    for a in values() {
+      for b in values() {
+        // borrows are masks
+        for borrow in [0u64, u64::MAX] {
+          let (res, borrow_out) = _methods
+            .u384_sbb(u384(&a), u384(&b), borrow)
+            .call().await.unwrap().value;
+          let expected = (&a + pow2(384)) - &b - (borrow >> 63);
+          assert_eq!(from_limbs(&res.ls), &expected % pow2(384));
+          assert_eq!(borrow_out, if expected < pow2(384) { u64::MAX } else { 0 });
+        }
+      }
+    }
+  }
+
+  #[tokio::test]
+  async fn test_mul_wide() {
+    let (_methods, _id) = get_contract_methods().await;
+    for a in values() {
+      for b in values() {
+        let (lo, hi) = _methods
+          .u384_mul_wide(u384(&a), u384(&b))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+        let expected = &a * &b;
+        assert_eq!(from_limbs(&lo.ls), &expected % pow2(384));
+        assert_eq!(from_limbs(&hi.ls), expected >> 384);
+      }
+    }
+  }
+
+  #[tokio::test]
+  async fn test_shifts() {
+    let (_methods, _id) = get_contract_methods().await;
+    for a in values() {
+      for shift in [0, 1, 63, 64, 65, 191, 192, 320, 383] {
+        let res = _methods
+          .u384_shl_vartime(u384(&a), shift)
+          .call().await.unwrap().value;
+        assert_eq!(from_limbs(&res.ls), (&a << shift) % pow2(384));
+
+        let res = _methods
+          .u384_shr_vartime(u384(&a), shift)
+          .call().await.unwrap().value;
+        assert_eq!(from_limbs(&res.ls), &a >> shift);
+      }
+    }
+  }
+
+  #[tokio::test]
+  async fn test_be_bytes() {
+    let (_methods, _id) = get_contract_methods().await;
+    for a in values() {
+      let bytes = _methods
+        .u384_to_be_bytes(u384(&a))
+        .call().await.unwrap().value;
+      assert_eq!(bytes, to_be_bytes::<48>(&a));
+
+      let res = _methods
+        .u384_from_be_bytes(to_be_bytes::<48>(&a))
+        .call().await.unwrap().value;
+      assert_eq!(from_limbs(&res.ls), a);
+    }
+  }
+
+  #[tokio::test]
+  async fn test_ct_compare() {
+    let (_methods, _id) = get_contract_methods().await;
+    for a in values() {
+      for b in values() {
+        let eq = _methods.u384_ct_eq(u384(&a), u384(&b)).call().await.unwrap().value;
+        let gt = _methods.u384_ct_gt(u384(&a), u384(&b)).call().await.unwrap().value;
+        let lt = _methods.u384_ct_lt(u384(&a), u384(&b)).call().await.unwrap().value;
+        assert_eq!(eq, a == b);
+        assert_eq!(gt, a > b);
+        assert_eq!(lt, a < b);
+      }
+    }
+  }
+
+  #[tokio::test]
+  async fn test_conditional_select() {
+    let (_methods, _id) = get_contract_methods().await;
+    let a = pow2(384) - 1u64;
+    let b = modulus();
+    let res = _methods.u384_conditional_select(u384(&a), u384(&b), 1).call().await.unwrap().value;
+    assert_eq!(from_limbs(&res.ls), a);
+    let res = _methods.u384_conditional_select(u384(&a), u384(&b), 0).call().await.unwrap().value;
+    assert_eq!(from_limbs(&res.ls), b);
+  }
+
+  #[tokio::test]
+  async fn test_add_sub_mod() {
+    let (_methods, _id) = get_contract_methods().await;
+    let p = modulus();
+    for a in reduced_values() {
+      for b in reduced_values() {
+        let res = _methods
+          .u384_add_mod(u384(&a), u384(&b), u384(&p))
+          .call().await.unwrap().value;
+        assert_eq!(from_limbs(&res.ls), (&a + &b) % &p);
+
+        let res = _methods
+          .u384_sub_mod(u384(&a), u384(&b), u384(&p))
+          .call().await.unwrap().value;
+        assert_eq!(from_limbs(&res.ls), (&a + &p - &b) % &p);
+      }
+    }
+  }
+}
+
+mod u512 {
+  use super::*;
+
+  // odd modulus used for the modular operations
+  fn modulus() -> BigUint {
+    p256_p() * p256_p()
+  }
+
+  fn values() -> Vec<BigUint> {
+    vec![
+        BigUint::zero(),
+        BigUint::one(),
+        pow2(64) - 1u64,
+        pow2(64),
+        pow2(256) - 1u64,
+        pow2(256),
+        pow2(511),
+        // all limbs 0xffff_ffff_ffff_ffff
+        pow2(512) - 1u64,
+        modulus() - 1u64,
+        modulus(),
+        modulus() + 1u64,
+        p256_p() * bls12_381_q(),
+    ]
+  }
+
+  // the values that are smaller than the modulus
+  fn reduced_values() -> Vec<BigUint> {
+    values().into_iter().filter(|v| *v < modulus()).collect()
+  }
+
+  #[tokio::test]
+  async fn test_adc() {
+    let (_methods, _id) = get_contract_methods().await;
+    for a in values() {
+      for b in values() {
+        for carry in [0u64, 1] {
+          let (res, carry_out) = _methods
+            .u512_adc(u512(&a), u512(&b), carry)
+            .call().await.unwrap().value;
+          let expected = &a + &b + carry;
+          assert_eq!(from_limbs(&res.ls), &expected % pow2(512));
+          assert_eq!(BigUint::from(carry_out), expected >> 512);
+        }
+      }
+    }
+  }
+
+  #[tokio::test]
+  async fn test_sbb() {
+    let (_methods, _id) = get_contract_methods().await;
+    for a in values() {
+      for b in values() {
+        // borrows are masks
+        for borrow in [0u64, u64::MAX] {
+          let (res, borrow_out) = _methods
+            .u512_sbb(u512(&a), u512(&b), borrow)
+            .call().await.unwrap().value;
+          let expected = (&a + pow2(512)) - &b - (borrow >> 63);
+          assert_eq!(from_limbs(&res.ls), &expected % pow2(512));
+          assert_eq!(borrow_out, if expected < pow2(512) { u64::MAX } else { 0 });
+        }
+      }
+    }
+  }
+
+  #[tokio::test]
+  async fn test_mul_wide() {
+    let (_methods, _id) = get_contract_methods().await;
+    for a in values() {
+      for b in values() {
+        let (lo, hi) = _methods
+          .u512_mul_wide(u512(&a), u512(&b))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+        let expected = &a * &b;
+        assert_eq!(from_limbs(&lo.ls), &expected % pow2(512));
+        assert_eq!(from_limbs(&hi.ls), expected >> 512);
+      }
+    }
+  }
+
+  #[tokio::test]
+  async fn test_shifts() {
+    let (_methods, _id) = get_contract_methods().await;
+    for a in values() {
+      for shift in [0, 1, 63, 64, 65, 255, 256, 448, 511] {
+        let res = _methods
+          .u512_shl_vartime(u512(&a), shift)
+          .call().await.unwrap().value;
+        assert_eq!(from_limbs(&res.ls), (&a << shift) % pow2(512));
+
+        let res = _methods
+          .u512_shr_vartime(u512(&a), shift)
+          .call().await.unwrap().value;
+        assert_eq!(from_limbs(&res.ls), &a >> shift);
+      }
+    }
+  }
+
+  #[tokio::test]
+  async fn test_be_bytes() {
+    let (_methods, _id) = get_contract_methods().await;
+    for a in values() {
+      let bytes = _methods
+        .u512_to_be_bytes(u512(&a))
+        .call().await.unwrap().value;
+      assert_eq!(bytes, to_be_bytes::<64>(&a));
+
+      let res = _methods
+        .u512_from_be_bytes(to_be_bytes::<64>(&a))
+        .call().await.unwrap().value;
+      assert_eq!(from_limbs(&res.ls), a);
+    }
+  }
+
+  #[tokio::test]
+  async fn test_ct_compare() {
+    let (_methods, _id) = get_contract_methods().await;
+    for a in values() {
+      for b in values() {
+        let eq = _methods.u512_ct_eq(u512(&a), u512(&b)).call().await.unwrap().value;
+        let gt = _methods.u512_ct_gt(u512(&a), u512(&b)).call().await.unwrap().value;
+        let lt = _methods.u512_ct_lt(u512(&a), u512(&b)).call().await.unwrap().value;
+        assert_eq!(eq, a == b);
+        assert_eq!(gt, a > b);
+        assert_eq!(lt, a < b);
+      }
+    }
+  }
+
+  #[tokio::test]
+  async fn test_conditional_select() {
+    let (_methods, _id) = get_contract_methods().await;
+    let a = pow2(512) - 1u64;
+    let b = modulus();
+    let res = _methods.u512_conditional_select(u512(&a), u512(&b), 1).call().await.unwrap().value;
+    assert_eq!(from_limbs(&res.ls), a);
+    let res = _methods.u512_conditional_select(u512(&a), u512(&b), 0).call().await.unwrap().value;
+    assert_eq!(from_limbs(&res.ls), b);
+  }
+
+  #[tokio::test]
+  async fn test_add_sub_mod() {
+    let (_methods, _id) = get_contract_methods().await;
+    let p = modulus();
+    for a in reduced_values() {
+      for b in reduced_values() {
+        let res = _methods
+          .u512_add_mod(u512(&a), u512(&b), u512(&p))
+          .call().await.unwrap().value;
+        assert_eq!(from_limbs(&res.ls), (&a + &b) % &p);
+
+        let res = _methods
+          .u512_sub_mod(u512(&a), u512(&b), u512(&p))
+          .call().await.unwrap().value;
+        assert_eq!(from_limbs(&res.ls), (&a + &p - &b) % &p);
+      }
+    }
+  }
+}
+
+mod conversions {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_halves() {
+    let (_methods, _id) = get_contract_methods().await;
+    let lo = p256_p() - 1u64;
+    let hi = pow2(256) - 1u64;
+    let res = _methods
+      .u512_from_halves(u256(&lo), u256(&hi))
+      .call().await.unwrap().value;
+    let expected = (&hi << 256) + &lo;
+    assert_eq!(from_limbs(&res.ls), expected);
+
+    let (res_lo, res_hi) = _methods
+      .u512_split(u512(&expected))
+      .call().await.unwrap().value;
+    assert_eq!(from_limbs(&res_lo.ls), lo);
+    assert_eq!(from_limbs(&res_hi.ls), hi);
+  }
+
+  #[tokio::test]
+  async fn test_resize_512() {
+    let (_methods, _id) = get_contract_methods().await;
+    let a = pow2(256) - 1u64;
+    let res = _methods
+      .u256_resize_512(u256(&a))
+      .call().await.unwrap().value;
+    assert_eq!(from_limbs(&res.ls), a);
+  }
+}
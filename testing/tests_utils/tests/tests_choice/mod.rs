@@ -0,0 +1,203 @@
+use crate::utils::helpers::*;
+use fuels::types::Bits256;
+
+// values around the boundaries of the u8, u32 and u64 ranges and the 32 bit halves of a u64
+const U8_VALUES: [u8; 6] = [0, 1, 127, 128, 254, 255];
+const U32_VALUES: [u32; 7] = [0, 1, 0xffff, 0x1_0000, 0x7fff_ffff, 0xffff_fffe, 0xffff_ffff];
+const U64_VALUES: [u64; 9] = [
+    0,
+    1,
+    0xffff_ffff,
+    0x1_0000_0000,
+    0x7fff_ffff_ffff_ffff,
+    0x8000_0000_0000_0000,
+    0x8000_0000_ffff_ffff,
+    0xffff_ffff_ffff_fffe,
+    0xffff_ffff_ffff_ffff,
+];
+
+// pairs of arrays that are equal, or differ in only the first or the last limb
+fn limbs4_pairs() -> Vec<([u64; 4], [u64; 4])> {
+  let a = [u64::MAX, 0, u64::MAX, 0x8000_0000_0000_0000];
+  vec![
+      (a, a),
+      ([0; 4], [0; 4]),
+      ([u64::MAX; 4], [u64::MAX; 4]),
+      (a, [a[0] - 1, a[1], a[2], a[3]]),
+      (a, [a[0], a[1], a[2], a[3] + 1]),
+      ([0; 4], [u64::MAX; 4]),
+  ]
+}
+
+fn limbs6_pairs() -> Vec<([u64; 6], [u64; 6])> {
+  let a = [u64::MAX, 0, u64::MAX, 0, 1, 0x8000_0000_0000_0000];
+  vec![
+      (a, a),
+      ([0; 6], [0; 6]),
+      ([u64::MAX; 6], [u64::MAX; 6]),
+      (a, [a[0] - 1, a[1], a[2], a[3], a[4], a[5]]),
+      (a, [a[0], a[1], a[2], a[3], a[4], a[5] + 1]),
+      ([0; 6], [u64::MAX; 6]),
+  ]
+}
+
+fn bytes_pairs() -> Vec<([u8; 32], [u8; 32])> {
+  let mut a = [0u8; 32];
+  for (i, byte) in a.iter_mut().enumerate() {
+      *byte = (i as u8) * 7;
+  }
+  let mut first_differs = a;
+  first_differs[0] = 1;
+  let mut last_differs = a;
+  last_differs[31] ^= 0x80;
+  vec![
+      (a, a),
+      ([255; 32], [255; 32]),
+      (a, first_differs),
+      (a, last_differs),
+      ([0; 32], [255; 32]),
+  ]
+}
+
+mod success {
+  use super::*;
+
+  #[tokio::test]
+  async fn test_choice() {
+    let (_methods, _id) = get_contract_methods().await;
+    assert_eq!(_methods.choice_not(0).call().await.unwrap().value, 1);
+    assert_eq!(_methods.choice_not(1).call().await.unwrap().value, 0);
+    for a in [0u8, 1] {
+      for b in [0u8, 1] {
+        let res = _methods.choice_ct_eq(a, b).call().await.unwrap().value;
+        assert_eq!(res, a == b);
+      }
+    }
+  }
+
+  #[tokio::test]
+  async fn test_u8_compare() {
+    let (_methods, _id) = get_contract_methods().await;
+    for a in U8_VALUES {
+      for b in U8_VALUES {
+        assert_eq!(_methods.u8_ct_eq(a, b).call().await.unwrap().value, a == b);
+        assert_eq!(_methods.u8_ct_gt(a, b).call().await.unwrap().value, a > b);
+        assert_eq!(_methods.u8_ct_lt(a, b).call().await.unwrap().value, a < b);
+      }
+    }
+  }
+
+  #[tokio::test]
+  async fn test_u32_compare() {
+    let (_methods, _id) = get_contract_methods().await;
+    for a in U32_VALUES {
+      for b in U32_VALUES {
+        assert_eq!(_methods.u32_ct_eq(a, b).call().await.unwrap().value, a == b);
+        assert_eq!(_methods.u32_ct_gt(a, b).call().await.unwrap().value, a > b);
+        assert_eq!(_methods.u32_ct_lt(a, b).call().await.unwrap().value, a < b);
+      }
+    }
+  }
+
+  #[tokio::test]
+  async fn test_u64_compare() {
+    let (_methods, _id) = get_contract_methods().await;
+    for a in U64_VALUES {
+      for b in U64_VALUES {
+        assert_eq!(_methods.u64_ct_gt(a, b).call().await.unwrap().value, a > b);
+        assert_eq!(_methods.u64_ct_lt(a, b).call().await.unwrap().value, a < b);
+      }
+    }
+  }
+
+  #[tokio::test]
+  async fn test_bytes() {
+    let (_methods, _id) = get_contract_methods().await;
+    for (a, b) in bytes_pairs() {
+      assert_eq!(_methods.bytes_ct_eq(a, b).call().await.unwrap().value, a == b);
+      assert_eq!(_methods.bytes_conditional_select(a, b, 1).call().await.unwrap().value, a);
+      assert_eq!(_methods.bytes_conditional_select(a, b, 0).call().await.unwrap().value, b);
+    }
+  }
+
+  #[tokio::test]
+  async fn test_limbs() {
+    let (_methods, _id) = get_contract_methods().await;
+    for (a, b) in limbs4_pairs() {
+      assert_eq!(_methods.limbs4_ct_eq(a, b).call().await.unwrap().value, a == b);
+      assert_eq!(_methods.limbs4_conditional_select(a, b, 1).call().await.unwrap().value, a);
+      assert_eq!(_methods.limbs4_conditional_select(a, b, 0).call().await.unwrap().value, b);
+    }
+    for (a, b) in limbs6_pairs() {
+      assert_eq!(_methods.limbs6_ct_eq(a, b).call().await.unwrap().value, a == b);
+      assert_eq!(_methods.limbs6_conditional_select(a, b, 1).call().await.unwrap().value, a);
+      assert_eq!(_methods.limbs6_conditional_select(a, b, 0).call().await.unwrap().value, b);
+    }
+  }
+
+  #[tokio::test]
+  async fn test_b256() {
+    let (_methods, _id) = get_contract_methods().await;
+    for (a, b) in bytes_pairs() {
+      let (a, b) = (Bits256(a), Bits256(b));
+      assert_eq!(_methods.b256_ct_eq(a, b).call().await.unwrap().value, a == b);
+      assert_eq!(_methods.b256_conditional_select(a, b, 1).call().await.unwrap().value, a);
+      assert_eq!(_methods.b256_conditional_select(a, b, 0).call().await.unwrap().value, b);
+    }
+  }
+
+  #[tokio::test]
+  async fn test_ct_option_map() {
+    let (_methods, _id) = get_contract_methods().await;
+    let mapped = [1, 2, 3, u64::MAX];
+
+    let res = _methods.ct_option_map(5, 1, mapped).call().await.unwrap().value;
+    assert_eq!(res.is_some.c, 1);
+    assert_eq!(res.value, mapped);
+
+    let res = _methods.ct_option_map(5, 0, mapped).call().await.unwrap().value;
+    assert_eq!(res.is_some.c, 0);
+  }
+
+  #[tokio::test]
+  async fn test_ct_option_and_then() {
+    let (_methods, _id) = get_contract_methods().await;
+    let next = [1, 2, 3, u64::MAX];
+    for is_some in [0u8, 1] {
+      for next_is_some in [0u8, 1] {
+        let res = _methods.ct_option_and_then(5, is_some, next, next_is_some).call().await.unwrap().value;
+        assert_eq!(res.is_some.c, is_some & next_is_some);
+        if res.is_some.c == 1 {
+          assert_eq!(res.value, next);
+        }
+      }
+    }
+  }
+
+  #[tokio::test]
+  async fn test_ct_option_unwrap_or() {
+    let (_methods, _id) = get_contract_methods().await;
+    assert_eq!(_methods.ct_option_unwrap_or(5, 1, 7).call().await.unwrap().value, 5);
+    assert_eq!(_methods.ct_option_unwrap_or(5, 0, 7).call().await.unwrap().value, 7);
+  }
+
+  #[tokio::test]
+  async fn test_ct_option_or_else() {
+    let (_methods, _id) = get_contract_methods().await;
+
+    // some or_else anything is self
+    for alternative_is_some in [0u8, 1] {
+      let res = _methods.ct_option_or_else(5, 1, 7, alternative_is_some).call().await.unwrap().value;
+      assert_eq!(res.is_some.c, 1);
+      assert_eq!(res.value, 5);
+    }
+
+    // none or_else alternative is the alternative
+    let res = _methods.ct_option_or_else(5, 0, 7, 1).call().await.unwrap().value;
+    assert_eq!(res.is_some.c, 1);
+    assert_eq!(res.value, 7);
+
+    let res = _methods.ct_option_or_else(5, 0, 7, 0).call().await.unwrap().value;
+    assert_eq!(res.is_some.c, 0);
+  }
+}
@@ -0,0 +1,371 @@
+use fuels::prelude::TxParameters;
+use crate::utils::helpers::*;
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+// The contract converts canonical inputs into Montgomery form, does the operation with MontyField
+// and converts the result back, so every test also covers the conversions.
+// The expected values are computed with num-bigint.
+
+mod p256_field {
+  use super::*;
+
+  fn modulus() -> BigUint {
+    p256_p()
+  }
+
+  // canonical values, including the edge cases 0, 1, p - 2 and p - 1
+  fn values() -> Vec<BigUint> {
+    vec![
+        BigUint::zero(),
+        BigUint::one(),
+        BigUint::from(2u64),
+        pow2(64) - 1u64,
+        pow2(64),
+        parse("41624337018869194729192205381537838788846303834619688597471765238035829032504"),
+        parse("112889434785065900135211481371037383646282385554418514861667765615237067913479"),
+        modulus() - 2u64,
+        modulus() - 1u64,
+    ]
+  }
+
+  fn r() -> BigUint {
+    pow2(256) % modulus()
+  }
+
+  fn inverse(a: &BigUint) -> BigUint {
+    a.modpow(&(modulus() - 2u64), &modulus())
+  }
+
+  #[tokio::test]
+  async fn test_montgomery_conversion() {
+    let (_methods, _id) = get_contract_methods().await;
+    let p = modulus();
+    for a in values() {
+      let res = _methods.p256_to_montgomery(u256(&a)).call().await.unwrap().value;
+      assert_eq!(from_limbs(&res.ls), (&a * r()) % &p);
+
+      let res = _methods.p256_from_montgomery(u256(&a)).call().await.unwrap().value;
+      assert_eq!(from_limbs(&res.ls), (&a * inverse(&r())) % &p);
+    }
+  }
+
+  #[tokio::test]
+  async fn test_mul() {
+    let (_methods, _id) = get_contract_methods().await;
+    let p = modulus();
+    for a in values() {
+      for b in values() {
+        let res = _methods
+          .p256_mul(u256(&a), u256(&b))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+        assert_eq!(from_limbs(&res.ls), (&a * &b) % &p);
+      }
+    }
+  }
+
+  #[tokio::test]
+  async fn test_square() {
+    let (_methods, _id) = get_contract_methods().await;
+    let p = modulus();
+    for a in values() {
+      let res = _methods
+        .p256_square(u256(&a))
+        .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+        .call().await.unwrap().value;
+      assert_eq!(from_limbs(&res.ls), (&a * &a) % &p);
+    }
+  }
+
+  #[tokio::test]
+  async fn test_invert() {
+    let (_methods, _id) = get_contract_methods().await;
+    for a in values() {
+      let (res, is_some) = _methods
+        .p256_invert(u256(&a))
+        .tx_params(TxParameters::default().set_gas_limit(100_000_000_000))
+        .call().await.unwrap().value;
+      // zero has no inverse
+      assert_eq!(is_some, !a.is_zero());
+      if is_some {
+        assert_eq!(from_limbs(&res.ls), inverse(&a));
+      }
+    }
+  }
+
+  #[tokio::test]
+  async fn test_pow_vartime() {
+    let (_methods, _id) = get_contract_methods().await;
+    let p = modulus();
+    let a = values()[5].clone();
+    for exp in [BigUint::zero(), BigUint::one(), pow2(64) + 3u64, &p - 1u64] {
+      let res = _methods
+        .p256_pow_vartime(u256(&a), u256(&exp))
+        .tx_params(TxParameters::default().set_gas_limit(100_000_000_000))
+        .call().await.unwrap().value;
+      assert_eq!(from_limbs(&res.ls), a.modpow(&exp, &p));
+    }
+  }
+
+  #[tokio::test]
+  async fn test_from_wide() {
+    let (_methods, _id) = get_contract_methods().await;
+    let p = modulus();
+    let all_ones = pow2(256) - 1u64;
+    for (lo, hi) in [
+        (BigUint::zero(), BigUint::one()),
+        (all_ones.clone(), all_ones.clone()),
+        (&p - 1u64, &p - 1u64),
+        (p.clone(), p.clone()),
+    ] {
+      let res = _methods
+        .p256_from_wide(u256(&lo), u256(&hi))
+        .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+        .call().await.unwrap().value;
+      assert_eq!(from_limbs(&res.ls), ((&hi << 256) + &lo) % &p);
+    }
+  }
+}
+
+mod bls12_381_scalar {
+  use super::*;
+
+  fn modulus() -> BigUint {
+    bls12_381_q()
+  }
+
+  // canonical values, including the edge cases 0, 1, p - 2 and p - 1
+  fn values() -> Vec<BigUint> {
+    vec![
+        BigUint::zero(),
+        BigUint::one(),
+        BigUint::from(2u64),
+        pow2(64) - 1u64,
+        pow2(64),
+        parse("25647486012135049223548926380413412869463478958624195048567341373436245346331"),
+        pow2(254) + 12345u64,
+        modulus() - 2u64,
+        modulus() - 1u64,
+    ]
+  }
+
+  fn r() -> BigUint {
+    pow2(256) % modulus()
+  }
+
+  fn inverse(a: &BigUint) -> BigUint {
+    a.modpow(&(modulus() - 2u64), &modulus())
+  }
+
+  #[tokio::test]
+  async fn test_montgomery_conversion() {
+    let (_methods, _id) = get_contract_methods().await;
+    let p = modulus();
+    for a in values() {
+      let res = _methods.bls_scalar_to_montgomery(u256(&a)).call().await.unwrap().value;
+      assert_eq!(from_limbs(&res.ls), (&a * r()) % &p);
+
+      let res = _methods.bls_scalar_from_montgomery(u256(&a)).call().await.unwrap().value;
+      assert_eq!(from_limbs(&res.ls), (&a * inverse(&r())) % &p);
+    }
+  }
+
+  #[tokio::test]
+  async fn test_mul() {
+    let (_methods, _id) = get_contract_methods().await;
+    let p = modulus();
+    for a in values() {
+      for b in values() {
+        let res = _methods
+          .bls_scalar_mul(u256(&a), u256(&b))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+        assert_eq!(from_limbs(&res.ls), (&a * &b) % &p);
+      }
+    }
+  }
+
+  #[tokio::test]
+  async fn test_square() {
+    let (_methods, _id) = get_contract_methods().await;
+    let p = modulus();
+    for a in values() {
+      let res = _methods
+        .bls_scalar_square(u256(&a))
+        .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+        .call().await.unwrap().value;
+      assert_eq!(from_limbs(&res.ls), (&a * &a) % &p);
+    }
+  }
+
+  #[tokio::test]
+  async fn test_invert() {
+    let (_methods, _id) = get_contract_methods().await;
+    for a in values() {
+      let (res, is_some) = _methods
+        .bls_scalar_invert(u256(&a))
+        .tx_params(TxParameters::default().set_gas_limit(100_000_000_000))
+        .call().await.unwrap().value;
+      // zero has no inverse
+      assert_eq!(is_some, !a.is_zero());
+      if is_some {
+        assert_eq!(from_limbs(&res.ls), inverse(&a));
+      }
+    }
+  }
+
+  #[tokio::test]
+  async fn test_pow_vartime() {
+    let (_methods, _id) = get_contract_methods().await;
+    let p = modulus();
+    let a = values()[5].clone();
+    for exp in [BigUint::zero(), BigUint::one(), pow2(64) + 3u64, &p - 1u64] {
+      let res = _methods
+        .bls_scalar_pow_vartime(u256(&a), u256(&exp))
+        .tx_params(TxParameters::default().set_gas_limit(100_000_000_000))
+        .call().await.unwrap().value;
+      assert_eq!(from_limbs(&res.ls), a.modpow(&exp, &p));
+    }
+  }
+
+  #[tokio::test]
+  async fn test_from_wide() {
+    let (_methods, _id) = get_contract_methods().await;
+    let p = modulus();
+    let all_ones = pow2(256) - 1u64;
+    for (lo, hi) in [
+        (BigUint::zero(), BigUint::one()),
+        (all_ones.clone(), all_ones.clone()),
+        (&p - 1u64, &p - 1u64),
+        (p.clone(), p.clone()),
+    ] {
+      let res = _methods
+        .bls_scalar_from_wide(u256(&lo), u256(&hi))
+        .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+        .call().await.unwrap().value;
+      assert_eq!(from_limbs(&res.ls), ((&hi << 256) + &lo) % &p);
+    }
+  }
+}
+
+mod bls12_381_fp {
+  use super::*;
+
+  fn modulus() -> BigUint {
+    bls12_381_p()
+  }
+
+  // canonical values, including the edge cases 0, 1, p - 2 and p - 1
+  fn values() -> Vec<BigUint> {
+    vec![
+        BigUint::zero(),
+        BigUint::one(),
+        BigUint::from(2u64),
+        pow2(64) - 1u64,
+        pow2(64),
+        bls12_381_q().pow(3) % bls12_381_p(),
+        pow2(380) + 6789u64,
+        modulus() - 2u64,
+        modulus() - 1u64,
+    ]
+  }
+
+  fn r() -> BigUint {
+    pow2(384) % modulus()
+  }
+
+  fn inverse(a: &BigUint) -> BigUint {
+    a.modpow(&(modulus() - 2u64), &modulus())
+  }
+
+  #[tokio::test]
+  async fn test_montgomery_conversion() {
+    let (_methods, _id) = get_contract_methods().await;
+    let p = modulus();
+    for a in values() {
+      let res = _methods.bls_fp_to_montgomery(u384(&a)).call().await.unwrap().value;
+      assert_eq!(from_limbs(&res.ls), (&a * r()) % &p);
+
+      let res = _methods.bls_fp_from_montgomery(u384(&a)).call().await.unwrap().value;
+      assert_eq!(from_limbs(&res.ls), (&a * inverse(&r())) % &p);
+    }
+  }
+
+  #[tokio::test]
+  async fn test_mul() {
+    let (_methods, _id) = get_contract_methods().await;
+    let p = modulus();
+    for a in values() {
+      for b in values() {
+        let res = _methods
+          .bls_fp_mul(u384(&a), u384(&b))
+          .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+          .call().await.unwrap().value;
+        assert_eq!(from_limbs(&res.ls), (&a * &b) % &p);
+      }
+    }
+  }
+
+  #[tokio::test]
+  async fn test_square() {
+    let (_methods, _id) = get_contract_methods().await;
+    let p = modulus();
+    for a in values() {
+      let res = _methods
+        .bls_fp_square(u384(&a))
+        .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+        .call().await.unwrap().value;
+      assert_eq!(from_limbs(&res.ls), (&a * &a) % &p);
+    }
+  }
+
+  #[tokio::test]
+  async fn test_invert() {
+    let (_methods, _id) = get_contract_methods().await;
+    for a in values() {
+      let (res, is_some) = _methods
+        .bls_fp_invert(u384(&a))
+        .tx_params(TxParameters::default().set_gas_limit(100_000_000_000))
+        .call().await.unwrap().value;
+      // zero has no inverse
+      assert_eq!(is_some, !a.is_zero());
+      if is_some {
+        assert_eq!(from_limbs(&res.ls), inverse(&a));
+      }
+    }
+  }
+
+  #[tokio::test]
+  async fn test_pow_vartime() {
+    let (_methods, _id) = get_contract_methods().await;
+    let p = modulus();
+    let a = values()[5].clone();
+    for exp in [BigUint::zero(), BigUint::one(), pow2(64) + 3u64, &p - 1u64] {
+      let res = _methods
+        .bls_fp_pow_vartime(u384(&a), u384(&exp))
+        .tx_params(TxParameters::default().set_gas_limit(100_000_000_000))
+        .call().await.unwrap().value;
+      assert_eq!(from_limbs(&res.ls), a.modpow(&exp, &p));
+    }
+  }
+
+  #[tokio::test]
+  async fn test_from_wide() {
+    let (_methods, _id) = get_contract_methods().await;
+    let p = modulus();
+    let all_ones = pow2(384) - 1u64;
+    for (lo, hi) in [
+        (BigUint::zero(), BigUint::one()),
+        (all_ones.clone(), all_ones.clone()),
+        (&p - 1u64, &p - 1u64),
+        (p.clone(), p.clone()),
+    ] {
+      let res = _methods
+        .bls_fp_from_wide(u384(&lo), u384(&hi))
+        .tx_params(TxParameters::default().set_gas_limit(100_000_000))
+        .call().await.unwrap().value;
+      assert_eq!(from_limbs(&res.ls), ((&hi << 384) + &lo) % &p);
+    }
+  }
+}
@@ -0,0 +1,102 @@
+use fuels::{prelude::*,
+  tx::{ConsensusParameters, ContractId},
+};
+use fuel_core_chain_config::ChainConfig;
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+abigen!(Contract(
+    name = "UtilsTestContract",
+    abi = "out/debug/tests_utils-abi.json"
+));
+
+pub mod helpers {
+  use super::*;
+
+  pub async fn get_contract_methods() -> (UtilsTestContractMethods<WalletUnlocked>, ContractId) {
+    let mut wallet = WalletUnlocked::new_random(None);
+    let num_assets = 1;
+    let coins_per_asset = 100;
+    let amount_per_coin = 100000;
+    let (coins, _asset_ids) = setup_multiple_assets_coins(
+        wallet.address(),
+        num_assets,
+        coins_per_asset,
+        amount_per_coin,
+    );
+    // Custom gas limit
+    let consensus_parameters_config = ConsensusParameters::DEFAULT
+      .with_max_gas_per_tx(100_000_000_000).with_gas_per_byte(0);
+    let mut chain_config = ChainConfig::local_testnet();
+    // This is needed to allow for expensive operations
+    chain_config.block_gas_limit = 100_000_000_000;
+    let (client, _addr) = setup_test_client(coins, vec![], None, Some(chain_config), Some(consensus_parameters_config)).await;
+    let provider = Provider::new(client);
+    wallet.set_provider(provider.clone());
+    let id = Contract::deploy(
+        "./out/debug/tests_utils.bin",
+        &wallet,
+        DeployConfiguration::default(),
+    )
+    .await
+    .unwrap();
+    let instance = UtilsTestContract::new(id.clone(), wallet);
+    (instance.methods(), id.into())
+  }
+
+  // little endian limbs of n, which must fit in N limbs
+  pub fn to_limbs<const N: usize>(n: &BigUint) -> [u64; N] {
+    let digits = n.to_u64_digits();
+    assert!(digits.len() <= N);
+    let mut ls = [0u64; N];
+    ls[..digits.len()].copy_from_slice(&digits);
+    ls
+  }
+
+  pub fn from_limbs(ls: &[u64]) -> BigUint {
+    ls.iter().rev().fold(BigUint::zero(), |acc, l| (acc << 64) + *l)
+  }
+
+  // big endian encoding of n in exactly len bytes
+  pub fn to_be_bytes<const N: usize>(n: &BigUint) -> [u8; N] {
+    let bytes = n.to_bytes_be();
+    let mut res = [0u8; N];
+    res[N - bytes.len()..].copy_from_slice(&bytes);
+    res
+  }
+
+  pub fn u256(n: &BigUint) -> U256 {
+    U256 { ls: to_limbs(n) }
+  }
+
+  pub fn u384(n: &BigUint) -> U384 {
+    U384 { ls: to_limbs(n) }
+  }
+
+  pub fn u512(n: &BigUint) -> U512 {
+    U512 { ls: to_limbs(n) }
+  }
+
+  pub fn parse(n: &str) -> BigUint {
+    BigUint::parse_bytes(n.as_bytes(), 10).unwrap()
+  }
+
+  pub fn pow2(bits: usize) -> BigUint {
+    BigUint::one() << bits
+  }
+
+  // P-256 base field modulus
+  pub fn p256_p() -> BigUint {
+    parse("115792089210356248762697446949407573530086143415290314195533631308867097853951")
+  }
+
+  // BLS12-381 base field modulus
+  pub fn bls12_381_p() -> BigUint {
+    parse("4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559787")
+  }
+
+  // BLS12-381 scalar field modulus
+  pub fn bls12_381_q() -> BigUint {
+    parse("52435875175126190479447740508185965837690552500527637822603658699938581184513")
+  }
+}
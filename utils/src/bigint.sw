@@ -0,0 +1,702 @@
+library;
+
+use ::integer_utils::{adc, sbb, mac};
+use ::choice::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess};
+use core::ops::Eq;
+
+/*
+Fixed-width unsigned big integers, modeled on `Uint` from https://github.com/RustCrypto/crypto-bigint.
+Sway doesn't have const generics, so instead of a single `Uint<LIMBS>` there is a type for each width
+the curve libraries need: U256, U384 and U512. All of them have the same functions.
+
+Limbs are little endian: ls[0] is the least significant limb.
+Borrows are given as masks, as returned by `integer_utils::sbb`: 0 if there was no borrow, 0xffff...ffff otherwise.
+All functions are constant time, except for the ones ending in `_vartime`, which are variable time in their shift.
+*/
+
+// 256-bit unsigned integer
+pub struct U256 {
+    ls: [u64; 4],
+}
+
+impl U256 {
+    pub fn zero() -> Self {
+        U256 { ls: [0, 0, 0, 0] }
+    }
+
+    pub fn one() -> Self {
+        U256 { ls: [1, 0, 0, 0] }
+    }
+
+    pub fn from_u64(w: u64) -> Self {
+        U256 { ls: [w, 0, 0, 0] }
+    }
+
+    // returns self + rhs + carry, and the carry out
+    pub fn adc(self, rhs: Self, carry: u64) -> (Self, u64) {
+        let mut res = [0, 0, 0, 0];
+        let mut carry = carry;
+        let mut i = 0;
+        while i < 4 {
+            let (w, c) = adc(self.ls[i], rhs.ls[i], carry);
+            res[i] = w;
+            carry = c;
+            i += 1;
+        }
+        (U256 { ls: res }, carry)
+    }
+
+    // returns self - (rhs + borrow), and the borrow out
+    pub fn sbb(self, rhs: Self, borrow: u64) -> (Self, u64) {
+        let mut res = [0, 0, 0, 0];
+        let mut borrow = borrow;
+        let mut i = 0;
+        while i < 4 {
+            let (w, b) = sbb(self.ls[i], rhs.ls[i], borrow);
+            res[i] = w;
+            borrow = b;
+            i += 1;
+        }
+        (U256 { ls: res }, borrow)
+    }
+
+    // returns the full 512-bit product self * rhs, as (lo, hi)
+    pub fn mul_wide(self, rhs: Self) -> (Self, Self) {
+        // Schoolbook multiplication
+        let mut res = [0, 0, 0, 0, 0, 0, 0, 0];
+        let mut i = 0;
+        while i < 4 {
+            let mut carry = 0;
+            let mut j = 0;
+            while j < 4 {
+                let (w, c) = mac(res[i + j], self.ls[i], rhs.ls[j], carry);
+                res[i + j] = w;
+                carry = c;
+                j += 1;
+            }
+            res[i + 4] = carry;
+            i += 1;
+        }
+        (
+            U256 { ls: [res[0], res[1], res[2], res[3]] },
+            U256 { ls: [res[4], res[5], res[6], res[7]] },
+        )
+    }
+
+    // returns self << shift, for shift < 256
+    pub fn shl_vartime(self, shift: u64) -> Self {
+        let limb_shift = shift / 64;
+        let bit_shift = shift % 64;
+        let mut res = [0, 0, 0, 0];
+        let mut i = limb_shift;
+        while i < 4 {
+            let src = i - limb_shift;
+            res[i] = self.ls[src] << bit_shift;
+            if bit_shift > 0 && src > 0 {
+                res[i] = res[i] | (self.ls[src - 1] >> (64 - bit_shift));
+            }
+            i += 1;
+        }
+        U256 { ls: res }
+    }
+
+    // returns self >> shift, for shift < 256
+    pub fn shr_vartime(self, shift: u64) -> Self {
+        let limb_shift = shift / 64;
+        let bit_shift = shift % 64;
+        let mut res = [0, 0, 0, 0];
+        let mut i = 0;
+        while i + limb_shift < 4 {
+            let src = i + limb_shift;
+            res[i] = self.ls[src] >> bit_shift;
+            if bit_shift > 0 && src + 1 < 4 {
+                res[i] = res[i] | (self.ls[src + 1] << (64 - bit_shift));
+            }
+            i += 1;
+        }
+        U256 { ls: res }
+    }
+
+    // returns the limbs of self, each and-ed with mask
+    fn and_mask(self, mask: u64) -> Self {
+        U256 { ls: [
+            self.ls[0] & mask,
+            self.ls[1] & mask,
+            self.ls[2] & mask,
+            self.ls[3] & mask,
+        ] }
+    }
+
+    pub fn from_be_bytes(bytes: [u8; 32]) -> Self {
+        let mut res = [0, 0, 0, 0];
+        let mut i = 0;
+        while i < 32 {
+            // byte i is in limb (31 - i) / 8, at position (31 - i) % 8 from the least significant byte
+            let byte: u64 = bytes[i];
+            res[(31 - i) / 8] = res[(31 - i) / 8] | (byte << (8 * ((31 - i) % 8)));
+            i += 1;
+        }
+        U256 { ls: res }
+    }
+
+    pub fn to_be_bytes(self) -> [u8; 32] {
+        let mut res = [0u8; 32];
+        let mut i = 0;
+        while i < 32 {
+            res[i] = (self.ls[(31 - i) / 8] >> (8 * ((31 - i) % 8))) & 0xff;
+            i += 1;
+        }
+        res
+    }
+}
+
+// This goes in a separate impl, because if we use previously defined functions in the U256 impl,
+// Sway will not recognize them from inside the same impl
+impl U256 {
+    // returns (self + carry * 2^256) - rhs mod p, assuming that value is smaller than 2p
+    pub fn sub_mod_with_carry(self, carry: u64, rhs: Self, p: Self) -> Self {
+        let (w, borrow) = self.sbb(rhs, 0);
+        let (_, borrow) = sbb(carry, 0, borrow);
+
+        // If underflow occurred on the final limb, borrow = 0xfff...fff, otherwise
+        // borrow = 0x000...000. Thus, we use it as a mask to conditionally add the modulus.
+        let (w, _) = w.adc(p.and_mask(borrow), 0);
+        w
+    }
+
+    // returns self - rhs mod p, for self and rhs smaller than p
+    pub fn sub_mod(self, rhs: Self, p: Self) -> Self {
+        let (w, borrow) = self.sbb(rhs, 0);
+        let (w, _) = w.adc(p.and_mask(borrow), 0);
+        w
+    }
+
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        let (w, _) = self.adc(rhs, 0);
+        w
+    }
+
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        let (w, _) = self.sbb(rhs, 0);
+        w
+    }
+}
+
+impl U256 {
+    // returns self + rhs mod p, for self and rhs smaller than p
+    pub fn add_mod(self, rhs: Self, p: Self) -> Self {
+        let (w, carry) = self.adc(rhs, 0);
+        // Try to subtract the modulus, to make sure the result is smaller than p
+        w.sub_mod_with_carry(carry, p, p)
+    }
+}
+
+impl ConstantTimeEq for U256 {
+    fn ct_eq(self, other: Self) -> Choice {
+        let mut res = Choice::from(1u8);
+        let mut i = 0;
+        while i < 4 {
+            res = res & self.ls[i].ct_eq(other.ls[i]);
+            i += 1;
+        }
+        res
+    }
+}
+
+impl ConstantTimeGreater for U256 {
+    // self > other if and only if other - self underflows
+    fn ct_gt(self, other: Self) -> Choice {
+        let (_, borrow) = other.sbb(self, 0);
+        Choice::from(borrow & 1)
+    }
+}
+
+impl ConstantTimeLess for U256 {}
+
+impl ConditionallySelectable for U256 {
+    // Select a if choice == 1 or select b if choice == 0, in constant time.
+    fn conditional_select(a: Self, b: Self, choice: Choice) -> Self {
+        U256 { ls: [
+            u64::conditional_select(a.ls[0], b.ls[0], choice),
+            u64::conditional_select(a.ls[1], b.ls[1], choice),
+            u64::conditional_select(a.ls[2], b.ls[2], choice),
+            u64::conditional_select(a.ls[3], b.ls[3], choice),
+        ] }
+    }
+}
+
+impl Eq for U256 {
+    fn eq(self, other: Self) -> bool {
+        self.ct_eq(other).unwrap_as_bool()
+    }
+}
+
+// 384-bit unsigned integer
+pub struct U384 {
+    ls: [u64; 6],
+}
+
+impl U384 {
+    pub fn zero() -> Self {
+        U384 { ls: [0, 0, 0, 0, 0, 0] }
+    }
+
+    pub fn one() -> Self {
+        U384 { ls: [1, 0, 0, 0, 0, 0] }
+    }
+
+    pub fn from_u64(w: u64) -> Self {
+        U384 { ls: [w, 0, 0, 0, 0, 0] }
+    }
+
+    // returns self + rhs + carry, and the carry out
+    pub fn adc(self, rhs: Self, carry: u64) -> (Self, u64) {
+        let mut res = [0, 0, 0, 0, 0, 0];
+        let mut carry = carry;
+        let mut i = 0;
+        while i < 6 {
+            let (w, c) = adc(self.ls[i], rhs.ls[i], carry);
+            res[i] = w;
+            carry = c;
+            i += 1;
+        }
+        (U384 { ls: res }, carry)
+    }
+
+    // returns self - (rhs + borrow), and the borrow out
+    pub fn sbb(self, rhs: Self, borrow: u64) -> (Self, u64) {
+        let mut res = [0, 0, 0, 0, 0, 0];
+        let mut borrow = borrow;
+        let mut i = 0;
+        while i < 6 {
+            let (w, b) = sbb(self.ls[i], rhs.ls[i], borrow);
+            res[i] = w;
+            borrow = b;
+            i += 1;
+        }
+        (U384 { ls: res }, borrow)
+    }
+
+    // returns the full 768-bit product self * rhs, as (lo, hi)
+    pub fn mul_wide(self, rhs: Self) -> (Self, Self) {
+        // Schoolbook multiplication
+        let mut res = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        let mut i = 0;
+        while i < 6 {
+            let mut carry = 0;
+            let mut j = 0;
+            while j < 6 {
+                let (w, c) = mac(res[i + j], self.ls[i], rhs.ls[j], carry);
+                res[i + j] = w;
+                carry = c;
+                j += 1;
+            }
+            res[i + 6] = carry;
+            i += 1;
+        }
+        (
+            U384 { ls: [res[0], res[1], res[2], res[3], res[4], res[5]] },
+            U384 { ls: [res[6], res[7], res[8], res[9], res[10], res[11]] },
+        )
+    }
+
+    // returns self << shift, for shift < 384
+    pub fn shl_vartime(self, shift: u64) -> Self {
+        let limb_shift = shift / 64;
+        let bit_shift = shift % 64;
+        let mut res = [0, 0, 0, 0, 0, 0];
+        let mut i = limb_shift;
+        while i < 6 {
+            let src = i - limb_shift;
+            res[i] = self.ls[src] << bit_shift;
+            if bit_shift > 0 && src > 0 {
+                res[i] = res[i] | (self.ls[src - 1] >> (64 - bit_shift));
+            }
+            i += 1;
+        }
+        U384 { ls: res }
+    }
+
+    // returns self >> shift, for shift < 384
+    pub fn shr_vartime(self, shift: u64) -> Self {
+        let limb_shift = shift / 64;
+        let bit_shift = shift % 64;
+        let mut res = [0, 0, 0, 0, 0, 0];
+        let mut i = 0;
+        while i + limb_shift < 6 {
+            let src = i + limb_shift;
+            res[i] = self.ls[src] >> bit_shift;
+            if bit_shift > 0 && src + 1 < 6 {
+                res[i] = res[i] | (self.ls[src + 1] << (64 - bit_shift));
+            }
+            i += 1;
+        }
+        U384 { ls: res }
+    }
+
+    // returns the limbs of self, each and-ed with mask
+    fn and_mask(self, mask: u64) -> Self {
+        U384 { ls: [
+            self.ls[0] & mask,
+            self.ls[1] & mask,
+            self.ls[2] & mask,
+            self.ls[3] & mask,
+            self.ls[4] & mask,
+            self.ls[5] & mask,
+        ] }
+    }
+
+    pub fn from_be_bytes(bytes: [u8; 48]) -> Self {
+        let mut res = [0, 0, 0, 0, 0, 0];
+        let mut i = 0;
+        while i < 48 {
+            // byte i is in limb (47 - i) / 8, at position (47 - i) % 8 from the least significant byte
+            let byte: u64 = bytes[i];
+            res[(47 - i) / 8] = res[(47 - i) / 8] | (byte << (8 * ((47 - i) % 8)));
+            i += 1;
+        }
+        U384 { ls: res }
+    }
+
+    pub fn to_be_bytes(self) -> [u8; 48] {
+        let mut res = [0u8; 48];
+        let mut i = 0;
+        while i < 48 {
+            res[i] = (self.ls[(47 - i) / 8] >> (8 * ((47 - i) % 8))) & 0xff;
+            i += 1;
+        }
+        res
+    }
+}
+
+// This goes in a separate impl, because if we use previously defined functions in the U384 impl,
+// Sway will not recognize them from inside the same impl
+impl U384 {
+    // returns (self + carry * 2^384) - rhs mod p, assuming that value is smaller than 2p
+    pub fn sub_mod_with_carry(self, carry: u64, rhs: Self, p: Self) -> Self {
+        let (w, borrow) = self.sbb(rhs, 0);
+        let (_, borrow) = sbb(carry, 0, borrow);
+
+        // If underflow occurred on the final limb, borrow = 0xfff...fff, otherwise
+        // borrow = 0x000...000. Thus, we use it as a mask to conditionally add the modulus.
+        let (w, _) = w.adc(p.and_mask(borrow), 0);
+        w
+    }
+
+    // returns self - rhs mod p, for self and rhs smaller than p
+    pub fn sub_mod(self, rhs: Self, p: Self) -> Self {
+        let (w, borrow) = self.sbb(rhs, 0);
+        let (w, _) = w.adc(p.and_mask(borrow), 0);
+        w
+    }
+
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        let (w, _) = self.adc(rhs, 0);
+        w
+    }
+
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        let (w, _) = self.sbb(rhs, 0);
+        w
+    }
+}
+
+impl U384 {
+    // returns self + rhs mod p, for self and rhs smaller than p
+    pub fn add_mod(self, rhs: Self, p: Self) -> Self {
+        let (w, carry) = self.adc(rhs, 0);
+        // Try to subtract the modulus, to make sure the result is smaller than p
+        w.sub_mod_with_carry(carry, p, p)
+    }
+}
+
+impl ConstantTimeEq for U384 {
+    fn ct_eq(self, other: Self) -> Choice {
+        let mut res = Choice::from(1u8);
+        let mut i = 0;
+        while i < 6 {
+            res = res & self.ls[i].ct_eq(other.ls[i]);
+            i += 1;
+        }
+        res
+    }
+}
+
+impl ConstantTimeGreater for U384 {
+    // self > other if and only if other - self underflows
+    fn ct_gt(self, other: Self) -> Choice {
+        let (_, borrow) = other.sbb(self, 0);
+        Choice::from(borrow & 1)
+    }
+}
+
+impl ConstantTimeLess for U384 {}
+
+impl ConditionallySelectable for U384 {
+    // Select a if choice == 1 or select b if choice == 0, in constant time.
+    fn conditional_select(a: Self, b: Self, choice: Choice) -> Self {
+        U384 { ls: [
+            u64::conditional_select(a.ls[0], b.ls[0], choice),
+            u64::conditional_select(a.ls[1], b.ls[1], choice),
+            u64::conditional_select(a.ls[2], b.ls[2], choice),
+            u64::conditional_select(a.ls[3], b.ls[3], choice),
+            u64::conditional_select(a.ls[4], b.ls[4], choice),
+            u64::conditional_select(a.ls[5], b.ls[5], choice),
+        ] }
+    }
+}
+
+impl Eq for U384 {
+    fn eq(self, other: Self) -> bool {
+        self.ct_eq(other).unwrap_as_bool()
+    }
+}
+
+// 512-bit unsigned integer
+pub struct U512 {
+    ls: [u64; 8],
+}
+
+impl U512 {
+    pub fn zero() -> Self {
+        U512 { ls: [0, 0, 0, 0, 0, 0, 0, 0] }
+    }
+
+    pub fn one() -> Self {
+        U512 { ls: [1, 0, 0, 0, 0, 0, 0, 0] }
+    }
+
+    pub fn from_u64(w: u64) -> Self {
+        U512 { ls: [w, 0, 0, 0, 0, 0, 0, 0] }
+    }
+
+    // returns self + rhs + carry, and the carry out
+    pub fn adc(self, rhs: Self, carry: u64) -> (Self, u64) {
+        let mut res = [0, 0, 0, 0, 0, 0, 0, 0];
+        let mut carry = carry;
+        let mut i = 0;
+        while i < 8 {
+            let (w, c) = adc(self.ls[i], rhs.ls[i], carry);
+            res[i] = w;
+            carry = c;
+            i += 1;
+        }
+        (U512 { ls: res }, carry)
+    }
+
+    // returns self - (rhs + borrow), and the borrow out
+    pub fn sbb(self, rhs: Self, borrow: u64) -> (Self, u64) {
+        let mut res = [0, 0, 0, 0, 0, 0, 0, 0];
+        let mut borrow = borrow;
+        let mut i = 0;
+        while i < 8 {
+            let (w, b) = sbb(self.ls[i], rhs.ls[i], borrow);
+            res[i] = w;
+            borrow = b;
+            i += 1;
+        }
+        (U512 { ls: res }, borrow)
+    }
+
+    // returns the full 1024-bit product self * rhs, as (lo, hi)
+    pub fn mul_wide(self, rhs: Self) -> (Self, Self) {
+        // Schoolbook multiplication
+        let mut res = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        let mut i = 0;
+        while i < 8 {
+            let mut carry = 0;
+            let mut j = 0;
+            while j < 8 {
+                let (w, c) = mac(res[i + j], self.ls[i], rhs.ls[j], carry);
+                res[i + j] = w;
+                carry = c;
+                j += 1;
+            }
+            res[i + 8] = carry;
+            i += 1;
+        }
+        (
+            U512 { ls: [res[0], res[1], res[2], res[3], res[4], res[5], res[6], res[7]] },
+            U512 { ls: [res[8], res[9], res[10], res[11], res[12], res[13], res[14], res[15]] },
+        )
+    }
+
+    // returns self << shift, for shift < 512
+    pub fn shl_vartime(self, shift: u64) -> Self {
+        let limb_shift = shift / 64;
+        let bit_shift = shift % 64;
+        let mut res = [0, 0, 0, 0, 0, 0, 0, 0];
+        let mut i = limb_shift;
+        while i < 8 {
+            let src = i - limb_shift;
+            res[i] = self.ls[src] << bit_shift;
+            if bit_shift > 0 && src > 0 {
+                res[i] = res[i] | (self.ls[src - 1] >> (64 - bit_shift));
+            }
+            i += 1;
+        }
+        U512 { ls: res }
+    }
+
+    // returns self >> shift, for shift < 512
+    pub fn shr_vartime(self, shift: u64) -> Self {
+        let limb_shift = shift / 64;
+        let bit_shift = shift % 64;
+        let mut res = [0, 0, 0, 0, 0, 0, 0, 0];
+        let mut i = 0;
+        while i + limb_shift < 8 {
+            let src = i + limb_shift;
+            res[i] = self.ls[src] >> bit_shift;
+            if bit_shift > 0 && src + 1 < 8 {
+                res[i] = res[i] | (self.ls[src + 1] << (64 - bit_shift));
+            }
+            i += 1;
+        }
+        U512 { ls: res }
+    }
+
+    // returns the limbs of self, each and-ed with mask
+    fn and_mask(self, mask: u64) -> Self {
+        U512 { ls: [
+            self.ls[0] & mask,
+            self.ls[1] & mask,
+            self.ls[2] & mask,
+            self.ls[3] & mask,
+            self.ls[4] & mask,
+            self.ls[5] & mask,
+            self.ls[6] & mask,
+            self.ls[7] & mask,
+        ] }
+    }
+
+    pub fn from_be_bytes(bytes: [u8; 64]) -> Self {
+        let mut res = [0, 0, 0, 0, 0, 0, 0, 0];
+        let mut i = 0;
+        while i < 64 {
+            // byte i is in limb (63 - i) / 8, at position (63 - i) % 8 from the least significant byte
+            let byte: u64 = bytes[i];
+            res[(63 - i) / 8] = res[(63 - i) / 8] | (byte << (8 * ((63 - i) % 8)));
+            i += 1;
+        }
+        U512 { ls: res }
+    }
+
+    pub fn to_be_bytes(self) -> [u8; 64] {
+        let mut res = [0u8; 64];
+        let mut i = 0;
+        while i < 64 {
+            res[i] = (self.ls[(63 - i) / 8] >> (8 * ((63 - i) % 8))) & 0xff;
+            i += 1;
+        }
+        res
+    }
+}
+
+// This goes in a separate impl, because if we use previously defined functions in the U512 impl,
+// Sway will not recognize them from inside the same impl
+impl U512 {
+    // returns (self + carry * 2^512) - rhs mod p, assuming that value is smaller than 2p
+    pub fn sub_mod_with_carry(self, carry: u64, rhs: Self, p: Self) -> Self {
+        let (w, borrow) = self.sbb(rhs, 0);
+        let (_, borrow) = sbb(carry, 0, borrow);
+
+        // If underflow occurred on the final limb, borrow = 0xfff...fff, otherwise
+        // borrow = 0x000...000. Thus, we use it as a mask to conditionally add the modulus.
+        let (w, _) = w.adc(p.and_mask(borrow), 0);
+        w
+    }
+
+    // returns self - rhs mod p, for self and rhs smaller than p
+    pub fn sub_mod(self, rhs: Self, p: Self) -> Self {
+        let (w, borrow) = self.sbb(rhs, 0);
+        let (w, _) = w.adc(p.and_mask(borrow), 0);
+        w
+    }
+
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        let (w, _) = self.adc(rhs, 0);
+        w
+    }
+
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        let (w, _) = self.sbb(rhs, 0);
+        w
+    }
+}
+
+impl U512 {
+    // returns self + rhs mod p, for self and rhs smaller than p
+    pub fn add_mod(self, rhs: Self, p: Self) -> Self {
+        let (w, carry) = self.adc(rhs, 0);
+        // Try to subtract the modulus, to make sure the result is smaller than p
+        w.sub_mod_with_carry(carry, p, p)
+    }
+}
+
+impl ConstantTimeEq for U512 {
+    fn ct_eq(self, other: Self) -> Choice {
+        let mut res = Choice::from(1u8);
+        let mut i = 0;
+        while i < 8 {
+            res = res & self.ls[i].ct_eq(other.ls[i]);
+            i += 1;
+        }
+        res
+    }
+}
+
+impl ConstantTimeGreater for U512 {
+    // self > other if and only if other - self underflows
+    fn ct_gt(self, other: Self) -> Choice {
+        let (_, borrow) = other.sbb(self, 0);
+        Choice::from(borrow & 1)
+    }
+}
+
+impl ConstantTimeLess for U512 {}
+
+impl ConditionallySelectable for U512 {
+    // Select a if choice == 1 or select b if choice == 0, in constant time.
+    fn conditional_select(a: Self, b: Self, choice: Choice) -> Self {
+        U512 { ls: [
+            u64::conditional_select(a.ls[0], b.ls[0], choice),
+            u64::conditional_select(a.ls[1], b.ls[1], choice),
+            u64::conditional_select(a.ls[2], b.ls[2], choice),
+            u64::conditional_select(a.ls[3], b.ls[3], choice),
+            u64::conditional_select(a.ls[4], b.ls[4], choice),
+            u64::conditional_select(a.ls[5], b.ls[5], choice),
+            u64::conditional_select(a.ls[6], b.ls[6], choice),
+            u64::conditional_select(a.ls[7], b.ls[7], choice),
+        ] }
+    }
+}
+
+impl Eq for U512 {
+    fn eq(self, other: Self) -> bool {
+        self.ct_eq(other).unwrap_as_bool()
+    }
+}
+
+// Conversions between the widths
+
+impl U512 {
+    // returns lo + hi * 2^256
+    pub fn from_halves(lo: U256, hi: U256) -> Self {
+        U512 { ls: [lo.ls[0], lo.ls[1], lo.ls[2], lo.ls[3], hi.ls[0], hi.ls[1], hi.ls[2], hi.ls[3]] }
+    }
+
+    // returns (lo, hi) such that self = lo + hi * 2^256
+    pub fn split(self) -> (U256, U256) {
+        (
+            U256 { ls: [self.ls[0], self.ls[1], self.ls[2], self.ls[3]] },
+            U256 { ls: [self.ls[4], self.ls[5], self.ls[6], self.ls[7]] },
+        )
+    }
+}
+
+impl U256 {
+    // returns self as a U512, zero extended
+    pub fn resize_512(self) -> U512 {
+        U512 { ls: [self.ls[0], self.ls[1], self.ls[2], self.ls[3], 0, 0, 0, 0] }
+    }
+}
@@ -33,7 +33,7 @@ SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use std::convert::From;
 use std::{option::Option, u128::U128, assert::assert};
-use core::ops::{Eq, BitwiseAnd, BitwiseOr, BitwiseXor};
+use core::ops::{Eq, Not, BitwiseAnd, BitwiseOr, BitwiseXor};
 
 /////////////// IMPORTANT<start> ///////////////
 
@@ -99,6 +99,13 @@ impl Choice {
     }
 }
 
+impl Not for Choice {
+    // Returns the choice with the opposite internal value, so `!choice` can be used
+    fn not(self) -> Self {
+        Choice::from_bool(opposite_choice_value(self.c))
+    }
+}
+
 // This trait intends to do conditional selection in constant time
 pub trait ConditionallySelectable {
     // Select a if choice == 1 or select b if choice == 0, in constant time.
@@ -215,18 +222,37 @@ impl<T> CtOption<T> {
         self.value
     }
 
-    /*
-    A function from the original lib we can't implement:
-        pub fn unwrap_or(self, def: T) -> T
-    where
-        T: ConditionallySelectable,
-    {
-        T::conditional_select(&def, &self.value, self.is_some)
+}
+
+impl<T> CtOption<T> {
+    // Sway has no closures, so unlike in subtle `map` and `and_then` don't take a function.
+    // Instead the caller applies the function to `self.value` (which is always present) and passes the result in.
+    // As in subtle, the function is thus evaluated regardless of whether the optional is some.
+
+    // returns the mapped value, which is some if and only if self is some
+    pub fn map<U>(self, mapped: U) -> CtOption<U> {
+        CtOption::new(mapped, self.is_some)
     }
 
-    There is no type restriction possible on generics in Sway
-    See https://discord.com/channels/732892373507375164/734213700835082330/1007097764242522273
-    */
+    // returns the result of the next computation, which is some if and only if both self and next are some
+    pub fn and_then<U>(self, next: CtOption<U>) -> CtOption<U> {
+        CtOption::new(next.value, self.is_some & next.is_some)
+    }
+}
+
+impl<T> CtOption<T> where T: ConditionallySelectable {
+    // return the wrapped value if the optional is some, otherwise return def
+    pub fn unwrap_or(self, def: T) -> T {
+        T::conditional_select(self.value, def, self.is_some)
+    }
+
+    // return self if it is some, otherwise return alternative
+    pub fn or_else(self, alternative: CtOption<T>) -> CtOption<T> {
+        CtOption {
+            value: T::conditional_select(self.value, alternative.value, self.is_some),
+            is_some: Choice::conditional_select(self.is_some, alternative.is_some, self.is_some),
+        }
+    }
 }
 
 // An Equal like trait that returns a Choice (instead of a bool)
@@ -262,3 +288,200 @@ impl ConstantTimeEq for u64 {
         Choice::from(res)
     }
 }
+
+impl ConstantTimeEq for u8 {
+    fn ct_eq(self, other: u8) -> Choice {
+        let a: u64 = self;
+        let b: u64 = other;
+        a.ct_eq(b)
+    }
+}
+
+impl ConstantTimeEq for u32 {
+    fn ct_eq(self, other: u32) -> Choice {
+        let a: u64 = self;
+        let b: u64 = other;
+        a.ct_eq(b)
+    }
+}
+
+impl ConstantTimeEq for Choice {
+    fn ct_eq(self, other: Choice) -> Choice {
+        self.c.ct_eq(other.c)
+    }
+}
+
+// Fixed-size arrays. Sway has no const generics, so these are given for the sizes used by the curve libraries.
+
+impl ConstantTimeEq for [u8; 32] {
+    fn ct_eq(self, other: [u8; 32]) -> Choice {
+        let mut res = Choice::from(1u8);
+        let mut i = 0;
+        while i < 32 {
+            res = res & self[i].ct_eq(other[i]);
+            i += 1;
+        }
+        res
+    }
+}
+
+impl ConstantTimeEq for [u64; 4] {
+    fn ct_eq(self, other: [u64; 4]) -> Choice {
+        self[0].ct_eq(other[0])
+        & self[1].ct_eq(other[1])
+        & self[2].ct_eq(other[2])
+        & self[3].ct_eq(other[3])
+    }
+}
+
+impl ConstantTimeEq for [u64; 6] {
+    fn ct_eq(self, other: [u64; 6]) -> Choice {
+        self[0].ct_eq(other[0])
+        & self[1].ct_eq(other[1])
+        & self[2].ct_eq(other[2])
+        & self[3].ct_eq(other[3])
+        & self[4].ct_eq(other[4])
+        & self[5].ct_eq(other[5])
+    }
+}
+
+impl ConditionallySelectable for [u8; 32] {
+    // Select a if choice == 1 or select b if choice == 0, in constant time.
+    fn conditional_select(a: [u8; 32], b: [u8; 32], choice: Choice) -> [u8; 32] {
+        let mut res = [0u8; 32];
+        let mut i = 0;
+        while i < 32 {
+            res[i] = u8::conditional_select(a[i], b[i], choice);
+            i += 1;
+        }
+        res
+    }
+}
+
+impl ConditionallySelectable for [u64; 4] {
+    // Select a if choice == 1 or select b if choice == 0, in constant time.
+    fn conditional_select(a: [u64; 4], b: [u64; 4], choice: Choice) -> [u64; 4] {
+        [
+            u64::conditional_select(a[0], b[0], choice),
+            u64::conditional_select(a[1], b[1], choice),
+            u64::conditional_select(a[2], b[2], choice),
+            u64::conditional_select(a[3], b[3], choice),
+        ]
+    }
+}
+
+impl ConditionallySelectable for [u64; 6] {
+    // Select a if choice == 1 or select b if choice == 0, in constant time.
+    fn conditional_select(a: [u64; 6], b: [u64; 6], choice: Choice) -> [u64; 6] {
+        [
+            u64::conditional_select(a[0], b[0], choice),
+            u64::conditional_select(a[1], b[1], choice),
+            u64::conditional_select(a[2], b[2], choice),
+            u64::conditional_select(a[3], b[3], choice),
+            u64::conditional_select(a[4], b[4], choice),
+            u64::conditional_select(a[5], b[5], choice),
+        ]
+    }
+}
+
+// b256 is handled as its 4 big endian u64 words
+fn b256_to_words(b: b256) -> [u64; 4] {
+    asm(r1: b) { r1: [u64; 4] }
+}
+
+fn words_to_b256(w: [u64; 4]) -> b256 {
+    asm(r1: w) { r1: b256 }
+}
+
+impl ConstantTimeEq for b256 {
+    fn ct_eq(self, other: b256) -> Choice {
+        b256_to_words(self).ct_eq(b256_to_words(other))
+    }
+}
+
+impl ConditionallySelectable for b256 {
+    // Select a if choice == 1 or select b if choice == 0, in constant time.
+    fn conditional_select(a: b256, b: b256, choice: Choice) -> b256 {
+        let a_words = b256_to_words(a);
+        let b_words = b256_to_words(b);
+        words_to_b256([
+            u64::conditional_select(a_words[0], b_words[0], choice),
+            u64::conditional_select(a_words[1], b_words[1], choice),
+            u64::conditional_select(a_words[2], b_words[2], choice),
+            u64::conditional_select(a_words[3], b_words[3], choice),
+        ])
+    }
+}
+
+// A Greater than like trait that returns a Choice (instead of a bool)
+pub trait ConstantTimeGreater {
+    // returns (self > other), as a choice
+    fn ct_gt(self, other: Self) -> Choice;
+}
+
+impl ConstantTimeGreater for u64 {
+    fn ct_gt(self, other: u64) -> Choice {
+        // comments from reference impl
+        // All the bits in self that are greater than their corresponding bits in other.
+        let gtb = self & (other ^ u64::max());
+        // All the bits in self that are less than their corresponding bits in other.
+        let mut ltb = (self ^ u64::max()) & other;
+
+        // Set all the bits below the highest bit that is less to 1
+        let mut pow = 1;
+        while pow < 64 {
+            ltb = ltb | (ltb >> pow);
+            pow += pow;
+        }
+
+        // Select the highest bit where self is greater, if it's above all the bits where it's less
+        let mut bit = gtb & (ltb ^ u64::max());
+        pow = 1;
+        while pow < 64 {
+            bit = bit | (bit >> pow);
+            pow += pow;
+        }
+
+        // bit is now 1 in its lowest position if and only if self > other
+        let res: u8 = bit & 1;
+        Choice::from(res)
+    }
+}
+
+impl ConstantTimeGreater for u8 {
+    fn ct_gt(self, other: u8) -> Choice {
+        let a: u64 = self;
+        let b: u64 = other;
+        a.ct_gt(b)
+    }
+}
+
+impl ConstantTimeGreater for u32 {
+    fn ct_gt(self, other: u32) -> Choice {
+        let a: u64 = self;
+        let b: u64 = other;
+        a.ct_gt(b)
+    }
+}
+
+// A Less than like trait that returns a Choice (instead of a bool).
+// As in subtle, it is derived from ConstantTimeEq and ConstantTimeGreater
+pub trait ConstantTimeLess: ConstantTimeEq + ConstantTimeGreater {
+} {
+    // returns (self < other), as a choice
+    fn ct_lt(self, other: Self) -> Choice {
+        !self.ct_gt(other) & !self.ct_eq(other)
+    }
+}
+
+impl ConstantTimeLess for u8 {}
+impl ConstantTimeLess for u32 {}
+impl ConstantTimeLess for u64 {}
+
+// Conditionally negate a value in place, in constant time.
+// subtle implements this for every type that is ConditionallySelectable and has a negation.
+// Sway has no such blanket impls, so the curve libraries implement it for their own types.
+pub trait ConditionallyNegatable {
+    // Negate self if choice == 1, leave it unchanged if choice == 0
+    fn conditional_negate(ref mut self, choice: Choice);
+}
@@ -0,0 +1,49 @@
+library;
+
+use ::choice::{Choice, CtOption};
+
+/////////////// IMPORTANT<start> ///////////////
+
+// These traits are modeled on the zkcrypto ff crate
+// see https://github.com/zkcrypto/ff/blob/main/src/lib.rs
+// Addition, subtraction and multiplication are given by the core::ops traits Add, Subtract and Multiply.
+
+/////////////// IMPORTANT<end> ///////////////
+
+/// An element of a finite field.
+pub trait Field {
+    // Returns the additive identity
+    fn zero() -> Self;
+
+    // Returns the multiplicative identity
+    fn one() -> Self;
+
+    // Returns whether self is the additive identity, as a Choice
+    fn is_zero(self) -> Choice;
+
+    // Returns self * self
+    fn square(self) -> Self;
+
+    // Returns self + self
+    fn double(self) -> Self;
+
+    // Returns the multiplicative inverse of self. Is none if self is zero.
+    fn invert(self) -> CtOption<Self>;
+
+    // Returns a square root of self. Is none if self is not a square.
+    fn sqrt(self) -> CtOption<Self>;
+}
+
+/// An element of a prime field with a 32-byte canonical encoding.
+/// Since Sway doesn't have associated types, the encoding (`Repr` in ff) is fixed to 32 bytes.
+/// Prime fields with a larger modulus, such as the base field of BLS12-381, only implement Field.
+pub trait PrimeField: Field {
+    // Returns the element with the canonical encoding `bytes`. Is none if the encoding is not canonical.
+    fn from_repr(bytes: [u8; 32]) -> CtOption<Self>;
+
+    // Returns the canonical encoding of self. The byte order is the one used by the curve's own encoding.
+    fn to_repr(self) -> [u8; 32];
+
+    // Returns whether the (reduced) element is odd, as a Choice
+    fn is_odd(self) -> Choice;
+}
@@ -0,0 +1,51 @@
+library;
+
+use ::choice::Choice;
+use core::ops::{Add, Subtract};
+
+/////////////// IMPORTANT<start> ///////////////
+
+// These traits are modeled on the zkcrypto group crate
+// see https://github.com/zkcrypto/group/blob/main/src/lib.rs
+// Addition and subtraction are given by the core::ops traits Add and Subtract, negation by Neg below.
+// Sway doesn't have associated types, so the scalar type (`Scalar` in group) is a type parameter of Group.
+
+/////////////// IMPORTANT<end> ///////////////
+
+// Negation, which core::ops doesn't provide
+pub trait Neg {
+    // Returns -self
+    fn neg(self) -> Self;
+}
+
+/// An element of a cryptographic group, usually a point in projective or extended coordinates.
+/// S is the scalar field of the group, for example `Group<Scalar>` for the G1 and G2 points of BLS12-381.
+pub trait Group<S>: Add + Subtract + Neg {
+    // Returns the identity of the group
+    fn identity() -> Self;
+
+    // Returns the fixed generator of the group
+    fn generator() -> Self;
+
+    // Returns whether self is the identity, as a Choice
+    fn is_identity(self) -> Choice;
+
+    // Returns self + self
+    fn double(self) -> Self;
+
+    // Returns [scalar]self
+    fn mul_scalar(self, scalar: S) -> Self;
+}
+
+/// A point on an elliptic curve in affine coordinates.
+/// Conversion to and from the projective representation is done with the curve's own conversion functions.
+pub trait CurveAffine {
+    // Returns the point at infinity
+    fn identity() -> Self;
+
+    // Returns the fixed generator of the curve
+    fn generator() -> Self;
+
+    // Returns whether self is the point at infinity, as a Choice
+    fn is_identity(self) -> Choice;
+}
@@ -7,24 +7,13 @@ pub fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
     (res.lower, res.upper)
 }
 
-// TODO rewrite without if branch. This one is used with variable a and b
-// If x >= y: x-y, else max::U128 - (y-x)
-pub fn subtract_wrap(x: U128, y: U128) -> U128 {
-    if y > x {
-        (U128::max() - (y - x)) + U128::from((0,1))
-    } else {
-        x - y
-    }
-}
-
 /// Compute a - (b + borrow), returning the result and the new borrow as (result, borrow)
+/// The borrow is a mask: 0 if there was no borrow, 0xffff_ffff_ffff_ffff otherwise. Only the top bit of the input borrow is used.
 pub fn sbb(a: u64, b: u64, borrow: u64) -> (u64, u64) {
-    let a_128: U128 = U128::from((0, a));
-    let b_128: U128 = U128::from((0, b));
-    let borrow_128: U128 = U128::from((0, borrow >> 63));
-
-    let res: U128 = subtract_wrap(a_128, b_128 + borrow_128);
-    (res.lower, res.upper)
+    // a - b - borrow = a + !b + 1 - borrow - 2^64, so the sum carries exactly when there is no borrow.
+    // This only uses additions, which (unlike U128 subtraction) don't branch on their inputs.
+    let (res, carry) = adc(a, b ^ 0xffff_ffff_ffff_ffff, 1 - (borrow >> 63));
+    (res, (1 - carry) * 0xffff_ffff_ffff_ffff)
 }
 
 //returns the result and new carry of a + b*c + carry as (result, carry)
@@ -1,4 +1,8 @@
 library;
 
 mod integer_utils;
-mod choice;
\ No newline at end of file
+mod choice;
+mod bigint;
+mod monty;
+mod ff;
+mod group;
\ No newline at end of file
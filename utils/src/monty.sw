@@ -0,0 +1,433 @@
+library;
+
+use ::integer_utils::{adc, mac};
+use ::bigint::{U256, U384};
+use ::choice::{Choice, CtOption, ConditionallySelectable, ConstantTimeEq, ConstantTimeLess};
+use core::ops::{Eq, Add, Subtract, Multiply};
+
+/*
+Generic arithmetic modulo an odd prime p, with elements kept in Montgomery form aR mod p.
+Modeled on `Residue` from https://github.com/RustCrypto/crypto-bigint and the zkcrypto field implementations.
+
+As for the big integers in `bigint`, Sway has no const generics, so there is a backend for each width:
+`MontyField` for primes up to 256 bits (R = 2^256) and `MontyField384` for primes up to 384 bits (R = 2^384).
+Both have the same functions.
+
+A prime field is added by implementing `MontyParams` for an empty struct, for example
+
+    pub struct Secp256k1FpParams {}
+
+    impl MontyParams for Secp256k1FpParams {
+        fn modulus() -> U256 { ... }
+        ...
+    }
+
+after which `MontyField<Secp256k1FpParams>` gives the field arithmetic. 384-bit fields implement `MontyParams384` instead.
+*/
+
+// The constants that configure a Montgomery field.
+pub trait MontyParams {
+    // the odd prime p
+    fn modulus() -> U256;
+    // R = 2^256 mod p, the Montgomery form of 1
+    fn r() -> U256;
+    // R^2 = 2^512 mod p, used to convert into Montgomery form
+    fn r2() -> U256;
+    // R^3 = 2^768 mod p, used to reduce 512-bit values
+    fn r3() -> U256;
+    // INV = -(p^{-1} mod 2^64) mod 2^64
+    fn inv() -> u64;
+}
+
+/*
+returns (lo + hi * 2^256) * R^{-1} mod p, for lo + hi * 2^256 < p * R
+
+Based on Algorithm 14.32 in Handbook of Applied Cryptography
+<http://cacr.uwaterloo.ca/hac/about/chap14.pdf>
+*/
+pub fn montgomery_reduce(lo: U256, hi: U256, modulus: U256, inv: u64) -> U256 {
+    let mut t = [lo.ls[0], lo.ls[1], lo.ls[2], lo.ls[3], hi.ls[0], hi.ls[1], hi.ls[2], hi.ls[3]];
+    let mut carry2 = 0;
+    let mut i = 0;
+    while i < 4 {
+        // k is chosen such that t[i] + k * p = 0 mod 2^64
+        let (k, _) = mac(0, t[i], inv, 0);
+        let mut carry = 0;
+        let mut j = 0;
+        while j < 4 {
+            let (w, c) = mac(t[i + j], k, modulus.ls[j], carry);
+            t[i + j] = w;
+            carry = c;
+            j += 1;
+        }
+        let (w, c) = adc(t[i + 4], carry2, carry);
+        t[i + 4] = w;
+        carry2 = c;
+        i += 1;
+    }
+
+    // The result is smaller than 2p, subtract p to ensure the element is always mod p
+    U256 { ls: [t[4], t[5], t[6], t[7]] }.sub_mod_with_carry(carry2, modulus, modulus)
+}
+
+// Element of the field given by P, in Montgomery form.
+// `params` holds no data, it only carries the type P.
+pub struct MontyField<P> {
+    montgomery_form: U256,
+    params: P,
+}
+
+impl<P> MontyField<P> where P: MontyParams {
+    // wraps a value that is already in Montgomery form and smaller than p
+    pub fn from_montgomery(montgomery_form: U256, params: P) -> Self {
+        MontyField { montgomery_form: montgomery_form, params: params }
+    }
+
+    pub fn zero(params: P) -> Self {
+        MontyField { montgomery_form: U256::zero(), params: params }
+    }
+
+    // Multiplicative identity, R in Montgomery form
+    pub fn one(params: P) -> Self {
+        MontyField { montgomery_form: P::r(), params: params }
+    }
+
+    // returns self + rhs mod p
+    pub fn add(self, rhs: Self) -> Self {
+        MontyField { montgomery_form: self.montgomery_form.add_mod(rhs.montgomery_form, P::modulus()), params: self.params }
+    }
+
+    // returns self - rhs mod p
+    pub fn sub(self, rhs: Self) -> Self {
+        MontyField { montgomery_form: self.montgomery_form.sub_mod(rhs.montgomery_form, P::modulus()), params: self.params }
+    }
+
+    // returns self * rhs mod p
+    pub fn mul(self, rhs: Self) -> Self {
+        let (lo, hi) = self.montgomery_form.mul_wide(rhs.montgomery_form);
+        MontyField { montgomery_form: montgomery_reduce(lo, hi, P::modulus(), P::inv()), params: self.params }
+    }
+
+    // returns the canonical value of self, out of Montgomery form
+    pub fn retrieve(self) -> U256 {
+        montgomery_reduce(self.montgomery_form, U256::zero(), P::modulus(), P::inv())
+    }
+
+    pub fn is_zero(self) -> Choice {
+        self.montgomery_form.ct_eq(U256::zero())
+    }
+}
+
+// This goes in a separate impl, because if we use previously defined functions in the MontyField impl,
+// Sway will not recognize them from inside the same impl
+impl<P> MontyField<P> where P: MontyParams {
+    // converts a value smaller than p into Montgomery form, by computing (a * R^2) / R = aR
+    pub fn new(value: U256, params: P) -> Self {
+        MontyField::from_montgomery(value, params).mul(MontyField::from_montgomery(P::r2(), params))
+    }
+
+    // reduces the 512-bit value lo + hi * 2^256 mod p, into Montgomery form
+    pub fn from_wide(lo: U256, hi: U256, params: P) -> Self {
+        // (lo * R^2) / R + (hi * R^3) / R = (lo + hi * 2^256) * R
+        let d0 = MontyField::from_montgomery(lo, params).mul(MontyField::from_montgomery(P::r2(), params));
+        let d1 = MontyField::from_montgomery(hi, params).mul(MontyField::from_montgomery(P::r3(), params));
+        d0.add(d1)
+    }
+
+    // returns -self mod p
+    pub fn neg(self) -> Self {
+        MontyField::zero(self.params).sub(self)
+    }
+
+    pub fn double(self) -> Self {
+        self.add(self)
+    }
+
+    pub fn square(self) -> Self {
+        self.mul(self)
+    }
+
+    // returns self ^ exp mod p
+    // exp is given in little endian
+    pub fn pow_vartime(self, exp: U256) -> Self {
+        let mut res = MontyField::one(self.params);
+        let mut i = 4;
+        while i > 0 {
+            i -= 1;
+
+            let mut j = 64;
+            while j > 0 {
+                j -= 1;
+                res = res.mul(res);
+                if ((exp.ls[i] >> j) & 1) == 1 {
+                    res = res.mul(self);
+                }
+            }
+        }
+        res
+    }
+
+    // Attempts to convert a big endian byte representation into a field element,
+    // failing if the input is not smaller than p.
+    pub fn from_be_bytes(bytes: [u8; 32], params: P) -> CtOption<Self> {
+        let value = U256::from_be_bytes(bytes);
+        let is_some = value.ct_lt(P::modulus());
+        CtOption::new(MontyField::new(value, params), is_some)
+    }
+
+    // returns the big endian bytes of the canonical value of self
+    pub fn to_be_bytes(self) -> [u8; 32] {
+        self.retrieve().to_be_bytes()
+    }
+}
+
+impl<P> MontyField<P> where P: MontyParams {
+    // returns the multiplicative inverse of self, or none if self is zero
+    pub fn invert(self) -> CtOption<Self> {
+        // By Fermat's little theorem, a^(p-2) * a = a^(p-1) = 1 mod p
+        let exp = P::modulus().wrapping_sub(U256::from_u64(2));
+        CtOption::new(self.pow_vartime(exp), !self.is_zero())
+    }
+}
+
+impl<P> ConstantTimeEq for MontyField<P> where P: MontyParams {
+    fn ct_eq(self, other: Self) -> Choice {
+        self.montgomery_form.ct_eq(other.montgomery_form)
+    }
+}
+
+impl<P> ConditionallySelectable for MontyField<P> where P: MontyParams {
+    // Select a if choice == 1 or select b if choice == 0, in constant time.
+    fn conditional_select(a: Self, b: Self, choice: Choice) -> Self {
+        MontyField { montgomery_form: U256::conditional_select(a.montgomery_form, b.montgomery_form, choice), params: a.params }
+    }
+}
+
+impl<P> Eq for MontyField<P> where P: MontyParams {
+    fn eq(self, other: Self) -> bool {
+        self.ct_eq(other).unwrap_as_bool()
+    }
+}
+
+impl<P> Add for MontyField<P> where P: MontyParams {
+    fn add(self, other: Self) -> Self {
+        self.add(other)
+    }
+}
+
+impl<P> Subtract for MontyField<P> where P: MontyParams {
+    fn subtract(self, other: Self) -> Self {
+        self.sub(other)
+    }
+}
+
+impl<P> Multiply for MontyField<P> where P: MontyParams {
+    fn multiply(self, other: Self) -> Self {
+        self.mul(other)
+    }
+}
+
+// The constants that configure a 384-bit Montgomery field.
+pub trait MontyParams384 {
+    // the odd prime p
+    fn modulus() -> U384;
+    // R = 2^384 mod p, the Montgomery form of 1
+    fn r() -> U384;
+    // R^2 = 2^768 mod p, used to convert into Montgomery form
+    fn r2() -> U384;
+    // R^3 = 2^1152 mod p, used to reduce 768-bit values
+    fn r3() -> U384;
+    // INV = -(p^{-1} mod 2^64) mod 2^64
+    fn inv() -> u64;
+}
+
+/*
+returns (lo + hi * 2^384) * R^{-1} mod p, for lo + hi * 2^384 < p * R
+
+Based on Algorithm 14.32 in Handbook of Applied Cryptography
+<http://cacr.uwaterloo.ca/hac/about/chap14.pdf>
+*/
+pub fn montgomery_reduce_384(lo: U384, hi: U384, modulus: U384, inv: u64) -> U384 {
+    let mut t = [
+        lo.ls[0], lo.ls[1], lo.ls[2], lo.ls[3], lo.ls[4], lo.ls[5],
+        hi.ls[0], hi.ls[1], hi.ls[2], hi.ls[3], hi.ls[4], hi.ls[5],
+    ];
+    let mut carry2 = 0;
+    let mut i = 0;
+    while i < 6 {
+        // k is chosen such that t[i] + k * p = 0 mod 2^64
+        let (k, _) = mac(0, t[i], inv, 0);
+        let mut carry = 0;
+        let mut j = 0;
+        while j < 6 {
+            let (w, c) = mac(t[i + j], k, modulus.ls[j], carry);
+            t[i + j] = w;
+            carry = c;
+            j += 1;
+        }
+        let (w, c) = adc(t[i + 6], carry2, carry);
+        t[i + 6] = w;
+        carry2 = c;
+        i += 1;
+    }
+
+    // The result is smaller than 2p, subtract p to ensure the element is always mod p
+    U384 { ls: [t[6], t[7], t[8], t[9], t[10], t[11]] }.sub_mod_with_carry(carry2, modulus, modulus)
+}
+
+// Element of the 384-bit field given by P, in Montgomery form.
+// `params` holds no data, it only carries the type P.
+pub struct MontyField384<P> {
+    montgomery_form: U384,
+    params: P,
+}
+
+impl<P> MontyField384<P> where P: MontyParams384 {
+    // wraps a value that is already in Montgomery form and smaller than p
+    pub fn from_montgomery(montgomery_form: U384, params: P) -> Self {
+        MontyField384 { montgomery_form: montgomery_form, params: params }
+    }
+
+    pub fn zero(params: P) -> Self {
+        MontyField384 { montgomery_form: U384::zero(), params: params }
+    }
+
+    // Multiplicative identity, R in Montgomery form
+    pub fn one(params: P) -> Self {
+        MontyField384 { montgomery_form: P::r(), params: params }
+    }
+
+    // returns self + rhs mod p
+    pub fn add(self, rhs: Self) -> Self {
+        MontyField384 { montgomery_form: self.montgomery_form.add_mod(rhs.montgomery_form, P::modulus()), params: self.params }
+    }
+
+    // returns self - rhs mod p
+    pub fn sub(self, rhs: Self) -> Self {
+        MontyField384 { montgomery_form: self.montgomery_form.sub_mod(rhs.montgomery_form, P::modulus()), params: self.params }
+    }
+
+    // returns self * rhs mod p
+    pub fn mul(self, rhs: Self) -> Self {
+        let (lo, hi) = self.montgomery_form.mul_wide(rhs.montgomery_form);
+        MontyField384 { montgomery_form: montgomery_reduce_384(lo, hi, P::modulus(), P::inv()), params: self.params }
+    }
+
+    // returns the canonical value of self, out of Montgomery form
+    pub fn retrieve(self) -> U384 {
+        montgomery_reduce_384(self.montgomery_form, U384::zero(), P::modulus(), P::inv())
+    }
+
+    pub fn is_zero(self) -> Choice {
+        self.montgomery_form.ct_eq(U384::zero())
+    }
+}
+
+// This goes in a separate impl, because if we use previously defined functions in the MontyField384 impl,
+// Sway will not recognize them from inside the same impl
+impl<P> MontyField384<P> where P: MontyParams384 {
+    // converts a value smaller than p into Montgomery form, by computing (a * R^2) / R = aR
+    pub fn new(value: U384, params: P) -> Self {
+        MontyField384::from_montgomery(value, params).mul(MontyField384::from_montgomery(P::r2(), params))
+    }
+
+    // reduces the 768-bit value lo + hi * 2^384 mod p, into Montgomery form
+    pub fn from_wide(lo: U384, hi: U384, params: P) -> Self {
+        // (lo * R^2) / R + (hi * R^3) / R = (lo + hi * 2^384) * R
+        let d0 = MontyField384::from_montgomery(lo, params).mul(MontyField384::from_montgomery(P::r2(), params));
+        let d1 = MontyField384::from_montgomery(hi, params).mul(MontyField384::from_montgomery(P::r3(), params));
+        d0.add(d1)
+    }
+
+    // returns -self mod p
+    pub fn neg(self) -> Self {
+        MontyField384::zero(self.params).sub(self)
+    }
+
+    pub fn double(self) -> Self {
+        self.add(self)
+    }
+
+    pub fn square(self) -> Self {
+        self.mul(self)
+    }
+
+    // returns self ^ exp mod p
+    // exp is given in little endian
+    pub fn pow_vartime(self, exp: U384) -> Self {
+        let mut res = MontyField384::one(self.params);
+        let mut i = 6;
+        while i > 0 {
+            i -= 1;
+
+            let mut j = 64;
+            while j > 0 {
+                j -= 1;
+                res = res.mul(res);
+                if ((exp.ls[i] >> j) & 1) == 1 {
+                    res = res.mul(self);
+                }
+            }
+        }
+        res
+    }
+
+    // Attempts to convert a big endian byte representation into a field element,
+    // failing if the input is not smaller than p.
+    pub fn from_be_bytes(bytes: [u8; 48], params: P) -> CtOption<Self> {
+        let value = U384::from_be_bytes(bytes);
+        let is_some = value.ct_lt(P::modulus());
+        CtOption::new(MontyField384::new(value, params), is_some)
+    }
+
+    // returns the big endian bytes of the canonical value of self
+    pub fn to_be_bytes(self) -> [u8; 48] {
+        self.retrieve().to_be_bytes()
+    }
+}
+
+impl<P> MontyField384<P> where P: MontyParams384 {
+    // returns the multiplicative inverse of self, or none if self is zero
+    pub fn invert(self) -> CtOption<Self> {
+        // By Fermat's little theorem, a^(p-2) * a = a^(p-1) = 1 mod p
+        let exp = P::modulus().wrapping_sub(U384::from_u64(2));
+        CtOption::new(self.pow_vartime(exp), !self.is_zero())
+    }
+}
+
+impl<P> ConstantTimeEq for MontyField384<P> where P: MontyParams384 {
+    fn ct_eq(self, other: Self) -> Choice {
+        self.montgomery_form.ct_eq(other.montgomery_form)
+    }
+}
+
+impl<P> ConditionallySelectable for MontyField384<P> where P: MontyParams384 {
+    // Select a if choice == 1 or select b if choice == 0, in constant time.
+    fn conditional_select(a: Self, b: Self, choice: Choice) -> Self {
+        MontyField384 { montgomery_form: U384::conditional_select(a.montgomery_form, b.montgomery_form, choice), params: a.params }
+    }
+}
+
+impl<P> Eq for MontyField384<P> where P: MontyParams384 {
+    fn eq(self, other: Self) -> bool {
+        self.ct_eq(other).unwrap_as_bool()
+    }
+}
+
+impl<P> Add for MontyField384<P> where P: MontyParams384 {
+    fn add(self, other: Self) -> Self {
+        self.add(other)
+    }
+}
+
+impl<P> Subtract for MontyField384<P> where P: MontyParams384 {
+    fn subtract(self, other: Self) -> Self {
+        self.sub(other)
+    }
+}
+
+impl<P> Multiply for MontyField384<P> where P: MontyParams384 {
+    fn multiply(self, other: Self) -> Self {
+        self.mul(other)
+    }
+}
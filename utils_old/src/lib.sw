@@ -1,4 +0,0 @@
-library utils;
-
-dep choice;
-dep integer_utils;
\ No newline at end of file